@@ -0,0 +1,8 @@
+-- Your SQL goes here
+DELETE FROM follow a
+    USING follow b
+WHERE a.follower_uuid = b.follower_uuid
+  AND a.followed_uuid = b.followed_uuid
+  AND a.uuid > b.uuid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS follow_follower_followed_uindex ON follow (follower_uuid, followed_uuid);
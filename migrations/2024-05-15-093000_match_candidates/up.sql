@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS match_candidates
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_candidates_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL,
+    reference_uuid UUID NOT NULL,
+    title VARCHAR NOT NULL,
+    image_url VARCHAR NOT NULL,
+    metadata TEXT,
+    created_by UUID NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS match_candidates_match_uuid_uindex ON match_candidates (match_uuid);
+CREATE INDEX IF NOT EXISTS match_candidates_reference_uuid_uindex ON match_candidates (reference_uuid);
+CREATE UNIQUE INDEX IF NOT EXISTS match_candidates_match_reference_uindex ON match_candidates (match_uuid, reference_uuid);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS match_votes;
+ALTER TABLE matches DROP COLUMN IF EXISTS winner_candidate_uuid;
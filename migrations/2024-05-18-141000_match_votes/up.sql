@@ -0,0 +1,16 @@
+-- Your SQL goes here
+ALTER TABLE matches ADD COLUMN IF NOT EXISTS winner_candidate_uuid UUID;
+
+CREATE TABLE IF NOT EXISTS match_votes
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_votes_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL,
+    candidate_uuid UUID NOT NULL REFERENCES match_candidates (uuid) ON DELETE CASCADE,
+    user_uuid UUID NOT NULL,
+    liked BOOLEAN NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS match_votes_match_uuid_uindex ON match_votes (match_uuid);
+CREATE INDEX IF NOT EXISTS match_votes_user_uuid_uindex ON match_votes (user_uuid);
+CREATE UNIQUE INDEX IF NOT EXISTS match_votes_candidate_user_uindex ON match_votes (candidate_uuid, user_uuid);
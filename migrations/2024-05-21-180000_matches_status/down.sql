@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE matches DROP CONSTRAINT IF EXISTS matches_status_check;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+UPDATE matches
+SET status = 'pending'
+WHERE status NOT IN ('pending', 'active', 'expired', 'completed', 'cancelled');
+
+ALTER TABLE matches DROP CONSTRAINT IF EXISTS matches_status_check;
+ALTER TABLE matches
+    ADD CONSTRAINT matches_status_check
+        CHECK (status IN ('pending', 'active', 'expired', 'completed', 'cancelled'));
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS match_invites
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_invites_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL REFERENCES matches (uuid) ON DELETE CASCADE,
+    user_uuid UUID NOT NULL,
+    invited_by UUID NOT NULL,
+    status VARCHAR NOT NULL DEFAULT 'pending'
+        CONSTRAINT match_invites_status_check CHECK (status IN ('pending', 'accepted', 'declined')),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS match_invites_user_uuid_index ON match_invites (user_uuid, status);
+CREATE UNIQUE INDEX IF NOT EXISTS match_invites_match_user_uindex ON match_invites (match_uuid, user_uuid);
+
+-- the creator has always been able to open their match, make that explicit
+UPDATE matches
+SET participants_uuid = array_append(participants_uuid, creator_uuid)
+WHERE NOT (creator_uuid = ANY (participants_uuid));
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS match_ballots;
+
+ALTER TABLE matches DROP CONSTRAINT IF EXISTS matches_decision_method_check;
+ALTER TABLE matches DROP COLUMN IF EXISTS decision_method;
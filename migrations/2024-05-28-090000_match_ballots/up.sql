@@ -0,0 +1,19 @@
+-- Your SQL goes here
+ALTER TABLE matches
+    ADD COLUMN IF NOT EXISTS decision_method VARCHAR NOT NULL DEFAULT 'unanimous';
+
+ALTER TABLE matches
+    ADD CONSTRAINT matches_decision_method_check
+        CHECK (decision_method IN ('unanimous', 'approval', 'ranked', 'borda'));
+
+CREATE TABLE IF NOT EXISTS match_ballots
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_ballots_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL REFERENCES matches (uuid) ON DELETE CASCADE,
+    user_uuid UUID NOT NULL,
+    candidate_uuids UUID[] NOT NULL,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS match_ballots_match_user_uindex ON match_ballots (match_uuid, user_uuid);
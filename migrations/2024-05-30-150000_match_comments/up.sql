@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS match_comments
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_comments_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL REFERENCES matches (uuid) ON DELETE CASCADE,
+    candidate_uuid UUID REFERENCES match_candidates (uuid) ON DELETE CASCADE,
+    parent_uuid UUID REFERENCES match_comments (uuid) ON DELETE CASCADE,
+    author_uuid UUID NOT NULL,
+    body VARCHAR NOT NULL,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL,
+    deleted_at BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS match_comments_match_created_index ON match_comments (match_uuid, created_at, uuid);
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS groups
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_groups_pk PRIMARY KEY,
+    owner_uuid UUID NOT NULL,
+    title VARCHAR NOT NULL,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS groups_owner_index ON groups (owner_uuid);
+
+CREATE TABLE IF NOT EXISTS group_members
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_group_members_pk PRIMARY KEY,
+    group_uuid UUID NOT NULL REFERENCES groups (uuid) ON DELETE CASCADE,
+    user_uuid UUID NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS group_members_group_user_uindex ON group_members (group_uuid, user_uuid);
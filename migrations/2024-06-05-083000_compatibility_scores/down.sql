@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS matches_participants_index;
+DROP INDEX IF EXISTS match_votes_match_user_index;
+DROP TABLE IF EXISTS compatibility_scores;
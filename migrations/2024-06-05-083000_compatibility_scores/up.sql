@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS compatibility_scores
+(
+    user_uuid UUID NOT NULL,
+    other_uuid UUID NOT NULL,
+    shared_matches BIGINT NOT NULL,
+    compared_votes BIGINT NOT NULL,
+    agreed_votes BIGINT NOT NULL,
+    shared_favourites BIGINT NOT NULL,
+    union_favourites BIGINT NOT NULL,
+    score BIGINT,
+    computed_at BIGINT NOT NULL,
+    CONSTRAINT table_compatibility_scores_pk PRIMARY KEY (user_uuid, other_uuid),
+    CONSTRAINT compatibility_scores_pair_check CHECK (user_uuid < other_uuid)
+);
+
+CREATE INDEX IF NOT EXISTS match_votes_match_user_index ON match_votes (match_uuid, user_uuid);
+CREATE INDEX IF NOT EXISTS matches_participants_index ON matches USING GIN (participants_uuid);
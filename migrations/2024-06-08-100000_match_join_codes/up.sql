@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS match_join_codes
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_join_codes_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL REFERENCES matches (uuid) ON DELETE CASCADE,
+    code VARCHAR NOT NULL,
+    created_by UUID NOT NULL,
+    max_participants BIGINT NOT NULL,
+    uses BIGINT NOT NULL DEFAULT 0,
+    expires_at BIGINT,
+    revoked_at BIGINT,
+    created_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS match_join_codes_code_uindex ON match_join_codes (code);
+CREATE INDEX IF NOT EXISTS match_join_codes_match_uuid_index ON match_join_codes (match_uuid);
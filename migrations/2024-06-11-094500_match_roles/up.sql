@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS match_roles
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_match_roles_pk PRIMARY KEY,
+    match_uuid UUID NOT NULL REFERENCES matches (uuid) ON DELETE CASCADE,
+    user_uuid UUID NOT NULL,
+    role VARCHAR NOT NULL CHECK (role IN ('moderator', 'viewer')),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS match_roles_match_user_uindex ON match_roles (match_uuid, user_uuid);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE match_candidates DROP COLUMN IF EXISTS item_uuid;
+ALTER TABLE favourite DROP COLUMN IF EXISTS item_uuid;
+DROP TABLE IF EXISTS item_external_ids;
+DROP TABLE IF EXISTS items;
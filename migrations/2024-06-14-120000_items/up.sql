@@ -0,0 +1,28 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS items
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_items_pk PRIMARY KEY,
+    kind VARCHAR NOT NULL,
+    title VARCHAR(128) NOT NULL,
+    year INTEGER,
+    image_url VARCHAR,
+    provider VARCHAR NOT NULL,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS items_title_index ON items (title);
+
+CREATE TABLE IF NOT EXISTS item_external_ids
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_item_external_ids_pk PRIMARY KEY,
+    item_uuid UUID NOT NULL REFERENCES items (uuid) ON DELETE CASCADE,
+    source VARCHAR NOT NULL,
+    external_id VARCHAR NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS item_external_ids_source_uindex ON item_external_ids (source, external_id);
+CREATE INDEX IF NOT EXISTS item_external_ids_item_uuid_index ON item_external_ids (item_uuid);
+
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS item_uuid UUID REFERENCES items (uuid) ON DELETE SET NULL;
+ALTER TABLE match_candidates ADD COLUMN IF NOT EXISTS item_uuid UUID REFERENCES items (uuid) ON DELETE SET NULL;
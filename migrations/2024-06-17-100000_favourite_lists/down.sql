@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM favourite WHERE list_uuid IS NOT NULL;
+DROP INDEX IF EXISTS favourite_user_list_favourite_uindex;
+DROP INDEX IF EXISTS favourite_list_uuid_index;
+ALTER TABLE favourite DROP COLUMN IF EXISTS pinned;
+ALTER TABLE favourite DROP COLUMN IF EXISTS position;
+ALTER TABLE favourite DROP COLUMN IF EXISTS list_uuid;
+DROP TABLE IF EXISTS favourite_lists;
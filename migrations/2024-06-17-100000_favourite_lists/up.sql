@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS favourite_lists
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_lists_pk PRIMARY KEY,
+    owner_uuid UUID NOT NULL,
+    title VARCHAR(64) NOT NULL,
+    visibility VARCHAR NOT NULL DEFAULT 'public' CHECK (visibility IN ('public', 'followers', 'private')),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS favourite_lists_owner_uuid_index ON favourite_lists (owner_uuid);
+
+-- favourites without a list make up the default list
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS list_uuid UUID REFERENCES favourite_lists (uuid) ON DELETE CASCADE;
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS position BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS pinned BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX IF NOT EXISTS favourite_list_uuid_index ON favourite (list_uuid);
+
+-- a favourite is kept at most once per list, the default list included
+DELETE FROM favourite a
+    USING favourite b
+WHERE a.user_uuid = b.user_uuid
+  AND a.favourite_uuid = b.favourite_uuid
+  AND a.list_uuid IS NOT DISTINCT FROM b.list_uuid
+  AND a.uuid > b.uuid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS favourite_user_list_favourite_uindex
+    ON favourite (user_uuid, list_uuid, favourite_uuid) NULLS NOT DISTINCT;
+
+UPDATE favourite
+SET position = ranked.position
+FROM (
+    SELECT uuid, ROW_NUMBER() OVER (PARTITION BY user_uuid ORDER BY title, uuid) - 1 AS position
+    FROM favourite
+) ranked
+WHERE favourite.uuid = ranked.uuid;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS item_ratings
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_item_ratings_pk PRIMARY KEY,
+    user_uuid UUID NOT NULL,
+    item_uuid UUID NOT NULL,
+    rating SMALLINT NOT NULL CHECK (rating BETWEEN 1 AND 10),
+    review VARCHAR(500),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS item_ratings_user_item_uindex ON item_ratings (user_uuid, item_uuid);
+CREATE INDEX IF NOT EXISTS item_ratings_item_uuid_index ON item_ratings (item_uuid);
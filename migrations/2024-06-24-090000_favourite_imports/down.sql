@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS favourite_import_rows;
+DROP TABLE IF EXISTS favourite_imports;
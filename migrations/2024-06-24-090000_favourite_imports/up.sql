@@ -0,0 +1,32 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS favourite_imports
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_imports_pk PRIMARY KEY,
+    user_uuid UUID NOT NULL,
+    list_uuid UUID REFERENCES favourite_lists (uuid) ON DELETE CASCADE,
+    format VARCHAR NOT NULL CHECK (format IN ('csv', 'json')),
+    status VARCHAR NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'running', 'done', 'failed')),
+    -- the uploaded file, cleared once the import is processed
+    content TEXT NOT NULL,
+    rows_count INTEGER NOT NULL DEFAULT 0,
+    imported_count INTEGER NOT NULL DEFAULT 0,
+    duplicates_count INTEGER NOT NULL DEFAULT 0,
+    unmatched_count INTEGER NOT NULL DEFAULT 0,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS favourite_imports_user_uuid_index ON favourite_imports (user_uuid);
+CREATE INDEX IF NOT EXISTS favourite_imports_status_index ON favourite_imports (status, created_at);
+
+CREATE TABLE IF NOT EXISTS favourite_import_rows
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_import_rows_pk PRIMARY KEY,
+    import_uuid UUID NOT NULL REFERENCES favourite_imports (uuid) ON DELETE CASCADE,
+    row_number INTEGER NOT NULL,
+    title VARCHAR,
+    external_id VARCHAR,
+    reason VARCHAR NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS favourite_import_rows_import_uuid_index ON favourite_import_rows (import_uuid, row_number);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP MATERIALIZED VIEW IF EXISTS favourite_following_trends;
+DROP MATERIALIZED VIEW IF EXISTS favourite_trends;
+DROP INDEX IF EXISTS favourite_created_at_index;
+ALTER TABLE favourite DROP COLUMN IF EXISTS created_at;
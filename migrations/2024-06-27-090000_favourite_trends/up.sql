@@ -0,0 +1,37 @@
+-- Your SQL goes here
+-- favourites that existed before count as added now
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS created_at BIGINT NOT NULL DEFAULT (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT;
+
+CREATE INDEX IF NOT EXISTS favourite_created_at_index ON favourite (created_at);
+
+-- favourites of public lists only, windows are relative to the last refresh
+CREATE MATERIALIZED VIEW IF NOT EXISTS favourite_trends AS
+SELECT favourite.favourite_uuid AS item_uuid,
+       MAX(favourite.title) AS title,
+       COUNT(DISTINCT favourite.user_uuid) FILTER (WHERE favourite.created_at >= (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT - 86400000) AS day_count,
+       COUNT(DISTINCT favourite.user_uuid) FILTER (WHERE favourite.created_at >= (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT - 604800000) AS week_count,
+       COUNT(DISTINCT favourite.user_uuid) AS all_count,
+       (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT AS refreshed_at
+FROM favourite
+LEFT JOIN favourite_lists ON favourite_lists.uuid = favourite.list_uuid
+WHERE favourite_lists.uuid IS NULL OR favourite_lists.visibility = 'public'
+GROUP BY favourite.favourite_uuid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS favourite_trends_item_uuid_uindex ON favourite_trends (item_uuid);
+
+-- the same per follower, over the favourites of the users they follow that followers may see
+CREATE MATERIALIZED VIEW IF NOT EXISTS favourite_following_trends AS
+SELECT follow.follower_uuid,
+       favourite.favourite_uuid AS item_uuid,
+       MAX(favourite.title) AS title,
+       COUNT(DISTINCT favourite.user_uuid) FILTER (WHERE favourite.created_at >= (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT - 86400000) AS day_count,
+       COUNT(DISTINCT favourite.user_uuid) FILTER (WHERE favourite.created_at >= (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT - 604800000) AS week_count,
+       COUNT(DISTINCT favourite.user_uuid) AS all_count,
+       (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT AS refreshed_at
+FROM follow
+JOIN favourite ON favourite.user_uuid = follow.followed_uuid
+LEFT JOIN favourite_lists ON favourite_lists.uuid = favourite.list_uuid
+WHERE favourite_lists.uuid IS NULL OR favourite_lists.visibility IN ('public', 'followers')
+GROUP BY follow.follower_uuid, favourite.favourite_uuid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS favourite_following_trends_uindex ON favourite_following_trends (follower_uuid, item_uuid);
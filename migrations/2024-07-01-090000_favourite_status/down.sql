@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS favourite_status_transitions;
+DROP INDEX IF EXISTS favourite_user_uuid_status_index;
+ALTER TABLE favourite DROP COLUMN IF EXISTS status_updated_at;
+ALTER TABLE favourite DROP COLUMN IF EXISTS status;
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+-- favourites that existed before are still wanted, since they were added
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS status VARCHAR NOT NULL DEFAULT 'want'
+    CHECK (status IN ('want', 'in_progress', 'done', 'dropped'));
+ALTER TABLE favourite ADD COLUMN IF NOT EXISTS status_updated_at BIGINT NOT NULL DEFAULT (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT;
+
+UPDATE favourite SET status_updated_at = created_at;
+
+CREATE INDEX IF NOT EXISTS favourite_user_uuid_status_index ON favourite (user_uuid, status);
+
+-- every status change of a favourite, shared by all lists holding it
+CREATE TABLE IF NOT EXISTS favourite_status_transitions
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_status_transitions_pk PRIMARY KEY,
+    user_uuid UUID NOT NULL,
+    favourite_uuid UUID NOT NULL,
+    from_status VARCHAR NOT NULL,
+    to_status VARCHAR NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS favourite_status_transitions_favourite_index
+    ON favourite_status_transitions (user_uuid, favourite_uuid);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS favourite_notes;
+DROP TABLE IF EXISTS favourite_tags;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+-- tags and notes belong to the favourite rather than to one of its lists
+CREATE TABLE IF NOT EXISTS favourite_tags
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_tags_pk PRIMARY KEY,
+    user_uuid UUID NOT NULL,
+    favourite_uuid UUID NOT NULL,
+    tag VARCHAR(32) NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS favourite_tags_user_favourite_tag_uindex ON favourite_tags (user_uuid, favourite_uuid, tag);
+CREATE INDEX IF NOT EXISTS favourite_tags_user_tag_index ON favourite_tags (user_uuid, tag);
+
+CREATE TABLE IF NOT EXISTS favourite_notes
+(
+    uuid UUID DEFAULT uuid_generate_v4() NOT NULL CONSTRAINT table_favourite_notes_pk PRIMARY KEY,
+    user_uuid UUID NOT NULL,
+    favourite_uuid UUID NOT NULL,
+    note VARCHAR(1000) NOT NULL,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS favourite_notes_user_favourite_uindex ON favourite_notes (user_uuid, favourite_uuid);
@@ -1,6 +1,8 @@
 use std::env;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::Conn;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
@@ -40,6 +42,113 @@ pub fn from_env() -> Figment {
         .merge(("databases", databases))
 }
 
+const MATCH_MAX_EXPIRY_DAYS_DEFAULT: i64 = 30;
+const MATCH_EXPIRY_INTERVAL_SECS_DEFAULT: u64 = 60;
+
+/// Longest time a match may stay open, from `MATCH_MAX_EXPIRY_DAYS` (30 days by default).
+pub fn match_max_expiry_ms() -> i64 {
+    let days = env::var("MATCH_MAX_EXPIRY_DAYS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .unwrap_or(MATCH_MAX_EXPIRY_DAYS_DEFAULT);
+    days * 24 * 60 * 60 * 1000
+}
+
+/// How often overdue matches are expired, from `MATCH_EXPIRY_INTERVAL_SECS` (60s by default).
+pub fn match_expiry_interval() -> Duration {
+    let secs = env::var("MATCH_EXPIRY_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(MATCH_EXPIRY_INTERVAL_SECS_DEFAULT);
+    Duration::from_secs(secs)
+}
+
+const COMPATIBILITY_CACHE_TTL_SECS_DEFAULT: i64 = 60 * 60;
+
+/// How long computed compatibility scores are reused, from `COMPATIBILITY_CACHE_TTL_SECS`
+/// (one hour by default).
+pub fn compatibility_cache_ttl_ms() -> i64 {
+    let secs = env::var("COMPATIBILITY_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|value| *value >= 0)
+        .unwrap_or(COMPATIBILITY_CACHE_TTL_SECS_DEFAULT);
+    secs * 1000
+}
+
+const CATALOG_SYNC_INTERVAL_SECS_DEFAULT: u64 = 60 * 60;
+
+/// JSON or CSV file the item catalogue is synced from, `CATALOG_FIXTURE_PATH` (unset
+/// disables the sync).
+pub fn catalog_fixture_path() -> Option<PathBuf> {
+    env::var("CATALOG_FIXTURE_PATH")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// How often the item catalogue is synced, from `CATALOG_SYNC_INTERVAL_SECS` (one hour
+/// by default).
+pub fn catalog_sync_interval() -> Duration {
+    let secs = env::var("CATALOG_SYNC_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(CATALOG_SYNC_INTERVAL_SECS_DEFAULT);
+    Duration::from_secs(secs)
+}
+
+const FAVOURITE_IMPORT_INTERVAL_SECS_DEFAULT: u64 = 10;
+const FAVOURITE_IMPORT_MAX_BYTES_DEFAULT: u64 = 5 * 1024 * 1024;
+
+/// How often queued favourite imports are processed, from `FAVOURITE_IMPORT_INTERVAL_SECS`
+/// (10s by default).
+pub fn favourite_import_interval() -> Duration {
+    let secs = env::var("FAVOURITE_IMPORT_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(FAVOURITE_IMPORT_INTERVAL_SECS_DEFAULT);
+    Duration::from_secs(secs)
+}
+
+/// Largest accepted favourite import file, from `FAVOURITE_IMPORT_MAX_BYTES` (5 MiB by
+/// default).
+pub fn favourite_import_max_bytes() -> u64 {
+    env::var("FAVOURITE_IMPORT_MAX_BYTES")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(FAVOURITE_IMPORT_MAX_BYTES_DEFAULT)
+}
+
+const FAVOURITE_IMPORT_TIMEOUT_SECS_DEFAULT: i64 = 30 * 60;
+
+/// How long a running favourite import may go without finishing before another worker
+/// claims it again, from `FAVOURITE_IMPORT_TIMEOUT_SECS` (30 minutes by default).
+pub fn favourite_import_timeout_ms() -> i64 {
+    let secs = env::var("FAVOURITE_IMPORT_TIMEOUT_SECS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(FAVOURITE_IMPORT_TIMEOUT_SECS_DEFAULT);
+    secs * 1000
+}
+
+const FAVOURITE_TRENDS_INTERVAL_SECS_DEFAULT: u64 = 5 * 60;
+
+/// How often the trending favourites aggregate is refreshed, from
+/// `FAVOURITE_TRENDS_INTERVAL_SECS` (five minutes by default).
+pub fn favourite_trends_interval() -> Duration {
+    let secs = env::var("FAVOURITE_TRENDS_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(FAVOURITE_TRENDS_INTERVAL_SECS_DEFAULT);
+    Duration::from_secs(secs)
+}
+
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
 
 pub async fn run_db_migrations(rocket: Rocket<Build>) -> Rocket<Build> {
@@ -0,0 +1,97 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{CatalogError, CatalogItem, CatalogProvider};
+
+pub const FIXTURE_PROVIDER_NAME: &str = "fixture";
+
+/// Reads the catalogue from a local `.json` or `.csv` file, meant for development and tests.
+pub struct FixtureCatalogProvider {
+    path: PathBuf,
+}
+
+impl FixtureCatalogProvider {
+    pub fn new(path: PathBuf) -> Self {
+        FixtureCatalogProvider { path }
+    }
+}
+
+#[async_trait]
+impl CatalogProvider for FixtureCatalogProvider {
+    fn name(&self) -> &str {
+        FIXTURE_PROVIDER_NAME
+    }
+
+    async fn fetch_items(&self) -> Result<Vec<CatalogItem>, CatalogError> {
+        let content = rocket::tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|err| {
+                eprintln!("Catalog fixture {:?} unavailable: {}", self.path, err);
+                CatalogError::Unavailable
+            })?;
+        match self.path.extension().and_then(|value| value.to_str()) {
+            Some("json") => parse_json(&content),
+            Some("csv") => parse_csv(&content),
+            _ => {
+                eprintln!("Catalog fixture {:?} is neither json nor csv", self.path);
+                Err(CatalogError::FormatInvalid)
+            }
+        }
+    }
+}
+
+/// A JSON array of `CatalogItem` objects.
+pub fn parse_json(content: &str) -> Result<Vec<CatalogItem>, CatalogError> {
+    serde_json::from_str(content).map_err(|err| {
+        eprintln!("Catalog fixture json error: {}", err);
+        CatalogError::FormatInvalid
+    })
+}
+
+#[derive(Deserialize)]
+struct CatalogCsvRow {
+    uuid: Option<Uuid>,
+    kind: String,
+    title: String,
+    year: Option<i32>,
+    image_url: Option<String>,
+    external_ids: Option<String>,
+}
+
+/// CSV with a `uuid,kind,title,year,image_url,external_ids` header, external ids are
+/// written as `source=id` pairs separated by `;`.
+pub fn parse_csv(content: &str) -> Result<Vec<CatalogItem>, CatalogError> {
+    csv::Reader::from_reader(content.as_bytes())
+        .deserialize::<CatalogCsvRow>()
+        .map(|row| {
+            let row = row.map_err(|err| {
+                eprintln!("Catalog fixture csv error: {}", err);
+                CatalogError::FormatInvalid
+            })?;
+            Ok(CatalogItem {
+                uuid: row.uuid,
+                kind: row.kind,
+                title: row.title,
+                year: row.year,
+                image_url: row.image_url,
+                external_ids: parse_external_ids(row.external_ids.as_deref().unwrap_or(""))?,
+            })
+        })
+        .collect()
+}
+
+fn parse_external_ids(value: &str) -> Result<BTreeMap<String, String>, CatalogError> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((source, id)) if !source.trim().is_empty() && !id.trim().is_empty() => {
+                Ok((source.trim().to_owned(), id.trim().to_owned()))
+            }
+            _ => Err(CatalogError::FormatInvalid),
+        })
+        .collect()
+}
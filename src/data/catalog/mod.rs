@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+pub mod fixture;
+mod tests;
+
+/// An item as a metadata provider describes it, before it's validated and stored.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct CatalogItem {
+    /// Fixtures may pin the uuid so favourites can reference their items across databases.
+    #[serde(default)]
+    pub uuid: Option<Uuid>,
+    pub kind: String,
+    pub title: String,
+    #[serde(default)]
+    pub year: Option<i32>,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    /// Ids of the item in outside catalogues keyed by source, e.g. `imdb`.
+    #[serde(default)]
+    pub external_ids: BTreeMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum CatalogError {
+    Unavailable,
+    FormatInvalid,
+}
+
+/// Source of item metadata the `items` table is populated from.
+#[async_trait]
+pub trait CatalogProvider: Send + Sync {
+    /// Stored with every item the provider populated.
+    fn name(&self) -> &str;
+    async fn fetch_items(&self) -> Result<Vec<CatalogItem>, CatalogError>;
+}
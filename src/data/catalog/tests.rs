@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::data::catalog::{
+        fixture::{parse_csv, parse_json, FixtureCatalogProvider},
+        CatalogError, CatalogProvider,
+    };
+
+    #[test]
+    fn test_parse_json_defaults() {
+        let items = parse_json(r#"[{"kind": "movie", "title": "Heat"}]"#).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].uuid, None);
+        assert_eq!(items[0].year, None);
+        assert!(items[0].external_ids.is_empty());
+
+        let invalid = parse_json(r#"[{"title": "Heat"}]"#);
+        assert!(matches!(invalid, Err(CatalogError::FormatInvalid)));
+    }
+
+    #[test]
+    fn test_parse_csv_external_ids() {
+        let content = "uuid,kind,title,year,image_url,external_ids\n\
+                       ,movie,Heat,1995,,imdb=tt0113277; tmdb=949\n\
+                       ,book,Dune,,,\n";
+        let items = parse_csv(content).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].year, Some(1995));
+        assert_eq!(items[0].image_url, None);
+        assert_eq!(items[0].external_ids["imdb"], "tt0113277");
+        assert_eq!(items[0].external_ids["tmdb"], "949");
+        assert_eq!(items[1].year, None);
+        assert!(items[1].external_ids.is_empty());
+
+        let invalid =
+            parse_csv("uuid,kind,title,year,image_url,external_ids\n,movie,Heat,,,imdb\n");
+        assert!(matches!(invalid, Err(CatalogError::FormatInvalid)));
+    }
+
+    #[tokio::test]
+    async fn test_fixture_formats_match() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let json = FixtureCatalogProvider::new(fixtures.join("catalog.json"))
+            .fetch_items()
+            .await
+            .unwrap();
+        let csv = FixtureCatalogProvider::new(fixtures.join("catalog.csv"))
+            .fetch_items()
+            .await
+            .unwrap();
+        assert!(!json.is_empty());
+        assert_eq!(json, csv);
+    }
+}
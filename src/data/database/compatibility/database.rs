@@ -0,0 +1,245 @@
+use super::{
+    objects::{
+        CompatibilityCountsEntity, CompatibilityDbError, CompatibilityEntity,
+        CompatibilityRankingCountEntity, CompatibilityRankingEntity,
+    },
+    CompatibilityDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    schema::compatibility_scores,
+    Conn,
+};
+use diesel::{
+    sql_types, upsert::excluded, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+/// Each side only counts the favourites the other user may see, `$4` being the visible
+/// list visibilities for anyone and `$5` those for followers, so a cached pair reads the
+/// same from both sides.
+const COMPUTE_COMPATIBILITY_QUERY: &str = "
+WITH others AS (
+    SELECT DISTINCT unnest($2::uuid[]) AS other_uuid
+),
+shared AS (
+    SELECT others.other_uuid, matches.uuid AS match_uuid
+    FROM others
+    JOIN matches ON matches.status = $3
+        AND $1 = ANY (matches.participants_uuid)
+        AND others.other_uuid = ANY (matches.participants_uuid)
+),
+shared_counts AS (
+    SELECT other_uuid, COUNT(*) AS shared_matches
+    FROM shared
+    GROUP BY other_uuid
+),
+vote_counts AS (
+    SELECT shared.other_uuid,
+        COUNT(*) AS compared_votes,
+        COUNT(*) FILTER (WHERE mine.liked = theirs.liked) AS agreed_votes
+    FROM shared
+    JOIN match_votes mine ON mine.match_uuid = shared.match_uuid
+        AND mine.user_uuid = $1
+    JOIN match_votes theirs ON theirs.match_uuid = shared.match_uuid
+        AND theirs.user_uuid = shared.other_uuid
+        AND theirs.candidate_uuid = mine.candidate_uuid
+    GROUP BY shared.other_uuid
+),
+mine AS (
+    SELECT DISTINCT others.other_uuid, favourite.favourite_uuid
+    FROM others
+    JOIN favourite ON favourite.user_uuid = $1
+    WHERE favourite.list_uuid IS NULL OR favourite.list_uuid IN (
+        SELECT uuid FROM favourite_lists
+        WHERE visibility = ANY (CASE WHEN EXISTS (
+            SELECT 1 FROM follow
+            WHERE follow.follower_uuid = others.other_uuid AND follow.followed_uuid = $1
+        ) THEN $5 ELSE $4 END)
+    )
+),
+theirs AS (
+    SELECT DISTINCT others.other_uuid, favourite.favourite_uuid
+    FROM others
+    JOIN favourite ON favourite.user_uuid = others.other_uuid
+    WHERE favourite.list_uuid IS NULL OR favourite.list_uuid IN (
+        SELECT uuid FROM favourite_lists
+        WHERE visibility = ANY (CASE WHEN EXISTS (
+            SELECT 1 FROM follow
+            WHERE follow.follower_uuid = $1 AND follow.followed_uuid = others.other_uuid
+        ) THEN $5 ELSE $4 END)
+    )
+),
+favourite_counts AS (
+    SELECT others.other_uuid,
+        (SELECT COUNT(*) FROM mine
+            JOIN theirs ON theirs.other_uuid = mine.other_uuid
+                AND theirs.favourite_uuid = mine.favourite_uuid
+            WHERE mine.other_uuid = others.other_uuid) AS shared_favourites,
+        (SELECT COUNT(*) FROM (
+            SELECT favourite_uuid FROM mine WHERE mine.other_uuid = others.other_uuid
+            UNION
+            SELECT favourite_uuid FROM theirs WHERE theirs.other_uuid = others.other_uuid
+        ) both_favourites) AS union_favourites
+    FROM others
+)
+SELECT others.other_uuid,
+    COALESCE(shared_counts.shared_matches, 0) AS shared_matches,
+    COALESCE(vote_counts.compared_votes, 0) AS compared_votes,
+    COALESCE(vote_counts.agreed_votes, 0) AS agreed_votes,
+    favourite_counts.shared_favourites,
+    favourite_counts.union_favourites
+FROM others
+JOIN favourite_counts ON favourite_counts.other_uuid = others.other_uuid
+LEFT JOIN shared_counts ON shared_counts.other_uuid = others.other_uuid
+LEFT JOIN vote_counts ON vote_counts.other_uuid = others.other_uuid
+";
+
+/// Cached pairs of the user (`$1`) with the users they follow whose username matches `$2`.
+const COMPATIBILITY_RANKING: &str = "
+WITH ranking AS (
+    SELECT follow.followed_uuid AS other_uuid, follow.followed_username AS username,
+        follow.followed_avatar_url AS avatar_url, compatibility_scores.shared_matches,
+        compatibility_scores.compared_votes, compatibility_scores.agreed_votes,
+        compatibility_scores.shared_favourites, compatibility_scores.union_favourites,
+        compatibility_scores.score, compatibility_scores.computed_at
+    FROM follow
+    JOIN compatibility_scores
+        ON compatibility_scores.user_uuid = LEAST(follow.follower_uuid, follow.followed_uuid)
+        AND compatibility_scores.other_uuid = GREATEST(follow.follower_uuid, follow.followed_uuid)
+    WHERE follow.follower_uuid = $1 AND follow.followed_username ILIKE $2
+)
+";
+
+const COUNT_COMPATIBILITY_RANKING_QUERY: &str = "SELECT COUNT(*) AS total FROM ranking";
+
+const GET_COMPATIBILITY_RANKING_QUERY: &str = "
+SELECT * FROM ranking
+ORDER BY score DESC NULLS LAST, shared_matches DESC, other_uuid
+LIMIT $3 OFFSET $4
+";
+
+#[async_trait]
+impl CompatibilityDatabase for Conn {
+    async fn get_cached_compatibility(
+        &self,
+        user_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+        fresh_after: i64,
+    ) -> Result<Vec<CompatibilityEntity>, CompatibilityDbError> {
+        self.0
+            .run(move |db| {
+                compatibility_scores::table
+                    .filter(
+                        compatibility_scores::user_uuid
+                            .eq(user_uuid)
+                            .and(compatibility_scores::other_uuid.eq_any(&others_uuid))
+                            .or(compatibility_scores::other_uuid
+                                .eq(user_uuid)
+                                .and(compatibility_scores::user_uuid.eq_any(&others_uuid))),
+                    )
+                    .filter(compatibility_scores::computed_at.ge(fresh_after))
+                    .load::<CompatibilityEntity>(db)
+            })
+            .await
+            .map_err(CompatibilityDbError::from)
+    }
+
+    async fn compute_compatibility(
+        &self,
+        user_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+        completed_status: String,
+        visibilities: Vec<String>,
+        follower_visibilities: Vec<String>,
+    ) -> Result<Vec<CompatibilityCountsEntity>, CompatibilityDbError> {
+        self.0
+            .run(move |db| {
+                diesel::sql_query(COMPUTE_COMPATIBILITY_QUERY)
+                    .bind::<sql_types::Uuid, _>(user_uuid)
+                    .bind::<sql_types::Array<sql_types::Uuid>, _>(others_uuid)
+                    .bind::<sql_types::Text, _>(completed_status)
+                    .bind::<sql_types::Array<sql_types::Text>, _>(visibilities)
+                    .bind::<sql_types::Array<sql_types::Text>, _>(follower_visibilities)
+                    .load::<CompatibilityCountsEntity>(db)
+            })
+            .await
+            .map_err(CompatibilityDbError::from)
+    }
+
+    async fn get_compatibility_ranking(
+        &self,
+        user_uuid: Uuid,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<CompatibilityRankingEntity>, CompatibilityDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        let pattern = format!("%{}%", query);
+        self.0
+            .run(move |db| {
+                let total = diesel::sql_query(
+                    COMPATIBILITY_RANKING.to_owned() + COUNT_COMPATIBILITY_RANKING_QUERY,
+                )
+                .bind::<sql_types::Uuid, _>(user_uuid)
+                .bind::<sql_types::Text, _>(&pattern)
+                .get_result::<CompatibilityRankingCountEntity>(db)?
+                .total;
+                let result = diesel::sql_query(
+                    COMPATIBILITY_RANKING.to_owned() + GET_COMPATIBILITY_RANKING_QUERY,
+                )
+                .bind::<sql_types::Uuid, _>(user_uuid)
+                .bind::<sql_types::Text, _>(&pattern)
+                .bind::<sql_types::BigInt, _>(page_size)
+                .bind::<sql_types::BigInt, _>(offset)
+                .load::<CompatibilityRankingEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+
+    async fn store_compatibility(
+        &self,
+        entities: Vec<CompatibilityEntity>,
+    ) -> Result<(), CompatibilityDbError> {
+        self.0
+            .run(move |db| {
+                diesel::insert_into(compatibility_scores::table)
+                    .values(&entities)
+                    .on_conflict((
+                        compatibility_scores::user_uuid,
+                        compatibility_scores::other_uuid,
+                    ))
+                    .do_update()
+                    .set((
+                        compatibility_scores::shared_matches
+                            .eq(excluded(compatibility_scores::shared_matches)),
+                        compatibility_scores::compared_votes
+                            .eq(excluded(compatibility_scores::compared_votes)),
+                        compatibility_scores::agreed_votes
+                            .eq(excluded(compatibility_scores::agreed_votes)),
+                        compatibility_scores::shared_favourites
+                            .eq(excluded(compatibility_scores::shared_favourites)),
+                        compatibility_scores::union_favourites
+                            .eq(excluded(compatibility_scores::union_favourites)),
+                        compatibility_scores::score.eq(excluded(compatibility_scores::score)),
+                        compatibility_scores::computed_at
+                            .eq(excluded(compatibility_scores::computed_at)),
+                    ))
+                    .execute(db)
+            })
+            .await
+            .map(|_| ())
+            .map_err(CompatibilityDbError::from)
+    }
+}
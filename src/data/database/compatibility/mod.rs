@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{
+    CompatibilityCountsEntity, CompatibilityDbError, CompatibilityEntity,
+    CompatibilityRankingEntity,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait CompatibilityDatabase {
+    /// Cached pairs of `user_uuid` with any of `others_uuid` computed at or after `fresh_after`.
+    async fn get_cached_compatibility(
+        &self,
+        user_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+        fresh_after: i64,
+    ) -> Result<Vec<CompatibilityEntity>, CompatibilityDbError>;
+    /// Counts, in one query, the matches in `completed_status` that `user_uuid` shares
+    /// with each of `others_uuid`, how often their likes in those matches agree and how
+    /// their favourites overlap. Favourites in lists the other user of a pair may not see,
+    /// `visibilities` for anyone and `follower_visibilities` once they follow, are left out.
+    async fn compute_compatibility(
+        &self,
+        user_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+        completed_status: String,
+        visibilities: Vec<String>,
+        follower_visibilities: Vec<String>,
+    ) -> Result<Vec<CompatibilityCountsEntity>, CompatibilityDbError>;
+    /// Cached pairs of `user_uuid` with the users they follow whose username matches
+    /// `query`, by score with unscored users last, then by shared matches.
+    async fn get_compatibility_ranking(
+        &self,
+        user_uuid: Uuid,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<CompatibilityRankingEntity>, CompatibilityDbError>;
+    async fn store_compatibility(
+        &self,
+        entities: Vec<CompatibilityEntity>,
+    ) -> Result<(), CompatibilityDbError>;
+}
@@ -0,0 +1,77 @@
+use diesel::{result::Error, sql_types};
+use uuid::Uuid;
+
+use crate::schema::compatibility_scores;
+
+/// Cached agreement counts of two users, stored once per pair with `user_uuid < other_uuid`.
+#[derive(Queryable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = compatibility_scores)]
+pub struct CompatibilityEntity {
+    pub user_uuid: Uuid,
+    pub other_uuid: Uuid,
+    pub shared_matches: i64,
+    pub compared_votes: i64,
+    pub agreed_votes: i64,
+    pub shared_favourites: i64,
+    pub union_favourites: i64,
+    pub score: Option<i64>,
+    pub computed_at: i64,
+}
+
+/// Agreement counts between the requesting user and `other_uuid`.
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct CompatibilityCountsEntity {
+    #[diesel(sql_type = sql_types::Uuid)]
+    pub other_uuid: Uuid,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub shared_matches: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub compared_votes: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub agreed_votes: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub shared_favourites: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub union_favourites: i64,
+}
+
+/// Cached pair of the requesting user with a user they follow, `other_uuid`.
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct CompatibilityRankingEntity {
+    #[diesel(sql_type = sql_types::Uuid)]
+    pub other_uuid: Uuid,
+    #[diesel(sql_type = sql_types::Text)]
+    pub username: String,
+    #[diesel(sql_type = sql_types::Text)]
+    pub avatar_url: String,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub shared_matches: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub compared_votes: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub agreed_votes: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub shared_favourites: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub union_favourites: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub computed_at: i64,
+}
+
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct CompatibilityRankingCountEntity {
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub total: i64,
+}
+
+#[derive(Debug)]
+pub enum CompatibilityDbError {
+    InternalError,
+}
+
+impl From<Error> for CompatibilityDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database compatibility error: {:?}", err);
+        CompatibilityDbError::InternalError
+    }
+}
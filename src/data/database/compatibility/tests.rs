@@ -0,0 +1,298 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        compatibility::{objects::CompatibilityEntity, CompatibilityDatabase},
+        favourite::UserFavouritesDatabase,
+        favourite_lists::{objects::FavouriteListEntityCreate, FavouriteListsDatabase},
+        follow::{objects::FollowEntityCreate, FollowDatabase},
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        match_votes::{objects::MatchVoteEntityCreate, MatchVotesDatabase},
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_compute_and_cache_compatibility() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let first_user = Uuid::new_v4();
+        let second_user = Uuid::new_v4();
+        let stranger = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: first_user,
+                    participants_uuid: vec![first_user, second_user],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut candidates = Vec::new();
+        for index in 0..2 {
+            let candidate = connection
+                .add_candidate(
+                    MatchCandidateEntityCreate {
+                        match_uuid: match_entity.uuid,
+                        reference_uuid: Uuid::new_v4(),
+                        title: format!("candidate {}", index),
+                        image_url: "image_url".to_string(),
+                        metadata: None,
+                        created_by: first_user,
+                        created_at: current_time_ms + index,
+                    },
+                    10,
+                )
+                .await
+                .unwrap();
+            candidates.push(candidate);
+        }
+
+        let votes = [
+            (first_user, 0, true),
+            (second_user, 0, false),
+            (first_user, 1, true),
+            (second_user, 1, true),
+        ];
+        for (user_uuid, index, liked) in votes {
+            connection
+                .add_vote(
+                    MatchVoteEntityCreate {
+                        match_uuid: match_entity.uuid,
+                        candidate_uuid: candidates[index].uuid,
+                        user_uuid,
+                        liked,
+                        created_at: current_time_ms,
+                    },
+                    vec!["pending".to_string(), "active".to_string()],
+                    vec!["viewer".to_string()],
+                    "completed".to_string(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let shared_favourite = Uuid::new_v4().to_string();
+        let first_favourite = Uuid::new_v4().to_string();
+        let second_favourite = Uuid::new_v4().to_string();
+        let favourites = [
+            (first_user, &shared_favourite),
+            (second_user, &shared_favourite),
+            (first_user, &first_favourite),
+            (second_user, &second_favourite),
+        ];
+        for (user_uuid, favourite_uuid) in favourites {
+            connection
+                .add_favourite(&user_uuid.to_string(), favourite_uuid, "title")
+                .await
+                .unwrap();
+        }
+        // favourites in lists the other user may not see don't count
+        let private_list = connection
+            .add_list(FavouriteListEntityCreate {
+                owner_uuid: second_user,
+                title: "private".to_string(),
+                visibility: "private".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        connection
+            .add_list_entry(
+                second_user,
+                Some(private_list.uuid),
+                Uuid::parse_str(&first_favourite).unwrap(),
+                "title".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let counts = connection
+            .compute_compatibility(
+                first_user,
+                vec![second_user, stranger],
+                "completed".to_string(),
+                vec!["public".to_string()],
+                vec!["public".to_string(), "followers".to_string()],
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts.len(), 2);
+
+        let second = counts
+            .iter()
+            .find(|counts| counts.other_uuid == second_user)
+            .unwrap();
+        assert_eq!(second.shared_matches, 1);
+        assert_eq!(second.compared_votes, 2);
+        assert_eq!(second.agreed_votes, 1);
+        assert_eq!(second.shared_favourites, 1);
+        assert_eq!(second.union_favourites, 3);
+
+        let stranger_counts = counts
+            .iter()
+            .find(|counts| counts.other_uuid == stranger)
+            .unwrap();
+        assert_eq!(stranger_counts.shared_matches, 0);
+        assert_eq!(stranger_counts.compared_votes, 0);
+        assert_eq!(stranger_counts.shared_favourites, 0);
+        assert_eq!(stranger_counts.union_favourites, 2);
+
+        let (user_uuid, other_uuid) = if first_user < second_user {
+            (first_user, second_user)
+        } else {
+            (second_user, first_user)
+        };
+        let entity = CompatibilityEntity {
+            user_uuid,
+            other_uuid,
+            shared_matches: second.shared_matches,
+            compared_votes: second.compared_votes,
+            agreed_votes: second.agreed_votes,
+            shared_favourites: second.shared_favourites,
+            union_favourites: second.union_favourites,
+            score: Some(50),
+            computed_at: current_time_ms,
+        };
+        connection
+            .store_compatibility(vec![entity.clone()])
+            .await
+            .unwrap();
+        connection
+            .store_compatibility(vec![CompatibilityEntity {
+                computed_at: current_time_ms + 1,
+                ..entity.clone()
+            }])
+            .await
+            .unwrap();
+
+        let cached = connection
+            .get_cached_compatibility(second_user, vec![first_user, stranger], current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].computed_at, current_time_ms + 1);
+
+        let stale = connection
+            .get_cached_compatibility(first_user, vec![second_user], current_time_ms + 2)
+            .await
+            .unwrap();
+        assert!(stale.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_compatibility_ranking() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let pair = |other_uuid: Uuid, score: Option<i64>, shared_matches: i64| {
+            let (user_uuid, other_uuid) = if user < other_uuid {
+                (user, other_uuid)
+            } else {
+                (other_uuid, user)
+            };
+            CompatibilityEntity {
+                user_uuid,
+                other_uuid,
+                shared_matches,
+                compared_votes: 0,
+                agreed_votes: 0,
+                shared_favourites: 0,
+                union_favourites: 0,
+                score,
+                computed_at: 0,
+            }
+        };
+
+        // followed users with their cached score and shared matches, `erin` isn't cached
+        let followed = [
+            ("Alice", Some(40), 1),
+            ("bob", Some(90), 0),
+            ("Carol", Some(40), 3),
+            ("dave", None, 5),
+            ("erin", None, 0),
+        ];
+        let mut followed_uuid = Vec::new();
+        let mut pairs = Vec::new();
+        for (username, score, shared_matches) in followed {
+            let other_uuid = Uuid::new_v4();
+            connection
+                .follow_user(&FollowEntityCreate {
+                    follower_uuid: user,
+                    followed_uuid: other_uuid,
+                    followed_username: username.to_string(),
+                    follower_username: "user".to_string(),
+                    followed_avatar_url: "url".to_string(),
+                    follower_avatar_url: "url".to_string(),
+                })
+                .await
+                .unwrap();
+            if username != "erin" {
+                pairs.push(pair(other_uuid, score, shared_matches));
+            }
+            followed_uuid.push(other_uuid);
+        }
+        // a cached pair with someone the user doesn't follow is left out
+        pairs.push(pair(Uuid::new_v4(), Some(100), 0));
+        connection.store_compatibility(pairs).await.unwrap();
+
+        let ranking = connection
+            .get_compatibility_ranking(user, String::new(), 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(ranking.total, 4);
+        assert!(!ranking.has_more);
+        let ranked: Vec<Uuid> = ranking
+            .result
+            .iter()
+            .map(|entity| entity.other_uuid)
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                followed_uuid[1],
+                followed_uuid[2],
+                followed_uuid[0],
+                followed_uuid[3],
+            ]
+        );
+        assert_eq!(ranking.result[0].username, "bob");
+        assert_eq!(ranking.result[1].shared_matches, 3);
+
+        let first_page = connection
+            .get_compatibility_ranking(user, "A".to_string(), 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(first_page.total, 3);
+        assert!(first_page.has_more);
+        let usernames: Vec<&str> = first_page
+            .result
+            .iter()
+            .map(|entity| entity.username.as_str())
+            .collect();
+        assert_eq!(usernames, vec!["Carol", "Alice"]);
+
+        let second_page = connection
+            .get_compatibility_ranking(user, "A".to_string(), 2, 2)
+            .await
+            .unwrap();
+        assert_eq!(second_page.page, 2);
+        assert!(!second_page.has_more);
+        assert_eq!(second_page.result.len(), 1);
+        assert_eq!(second_page.result[0].username, "dave");
+    }
+}
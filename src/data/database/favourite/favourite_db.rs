@@ -1,19 +1,80 @@
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::{
+    dsl::{count_distinct, exists, not},
+    sql_types, BoolExpressionMethods, Connection, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, OptionalExtension, PgConnection, PgExpressionMethods,
+    PgTextExpressionMethods, QueryDsl, QueryResult, RunQueryDsl,
+};
+use uuid::Uuid;
 
 use crate::{
     data::{
         database::utils::paging::{correct_page_number, parce_uuid},
         repository::objects::{PagingDomainRequest, PagingDomainResponse},
     },
-    schema::favourite,
+    schema::{
+        favourite, favourite_lists, favourite_notes, favourite_status_transitions, favourite_tags,
+        follow, items, users,
+    },
     Conn,
 };
 
 use super::{
-    objects::{FavouriteEntity, FavouriteEntityResponse},
+    objects::{
+        FavouriteCommonCountsEntity, FavouriteEntity, FavouriteEntityFilter,
+        FavouriteEntityResponse, FavouriteOverlapEntity, FavouriteStatusTransitionEntity,
+        FavouriteStatusTransitionEntityCreate, FavouriteUserEntity,
+    },
     FavouriteDbError, UserFavouritesDatabase,
 };
 
+/// Favourites of the requester (`$1`) and the visible ones of the other user (`$2`),
+/// `$3` being the visibilities of the other user's lists the requester may see.
+const COMMON_FAVOURITES_SETS: &str = "
+WITH mine AS (
+    SELECT favourite_uuid FROM favourite WHERE user_uuid = $1
+),
+theirs AS (
+    SELECT favourite_uuid FROM favourite
+    WHERE user_uuid = $2
+        AND (list_uuid IS NULL OR list_uuid IN (
+            SELECT uuid FROM favourite_lists WHERE visibility = ANY ($3)
+        ))
+)
+";
+
+const COUNT_COMMON_FAVOURITES_QUERY: &str = "
+SELECT
+    (SELECT COUNT(*) FROM (
+        SELECT favourite_uuid FROM mine INTERSECT SELECT favourite_uuid FROM theirs
+    ) common) AS common_count,
+    (SELECT COUNT(*) FROM (
+        SELECT favourite_uuid FROM mine EXCEPT SELECT favourite_uuid FROM theirs
+    ) mine_only) AS mine_count,
+    (SELECT COUNT(*) FROM (
+        SELECT favourite_uuid FROM theirs EXCEPT SELECT favourite_uuid FROM mine
+    ) theirs_only) AS theirs_count
+";
+
+/// One entry per favourite, the requester's when they have it, else a visible one of the
+/// other user, default lists first.
+const GET_COMMON_FAVOURITES_QUERY: &str = "
+SELECT * FROM (
+    SELECT DISTINCT ON (favourite.favourite_uuid) favourite.*
+    FROM ({selected}) selected
+    JOIN favourite ON favourite.favourite_uuid = selected.favourite_uuid
+    WHERE favourite.user_uuid = $1
+        OR (favourite.user_uuid = $2 AND (favourite.list_uuid IS NULL OR favourite.list_uuid IN (
+            SELECT uuid FROM favourite_lists WHERE visibility = ANY ($3)
+        )))
+    ORDER BY favourite.favourite_uuid,
+        favourite.user_uuid = $1 DESC,
+        favourite.list_uuid IS NULL DESC,
+        favourite.created_at ASC
+) entries
+ORDER BY entries.title ASC, entries.favourite_uuid ASC
+LIMIT $4 OFFSET $5
+";
+
 #[async_trait]
 impl UserFavouritesDatabase for Conn {
     async fn get_favourites_count<'a>(&self, uuid: &'a str) -> Result<i64, FavouriteDbError> {
@@ -22,6 +83,7 @@ impl UserFavouritesDatabase for Conn {
             .run(move |db| {
                 match favourite::table
                     .filter(favourite::user_uuid.eq(uuid))
+                    .filter(favourite::list_uuid.is_null())
                     .count()
                     .get_result::<i64>(db)
                 {
@@ -41,40 +103,11 @@ impl UserFavouritesDatabase for Conn {
         favourite_uuid: &'a str,
         title: &'a str,
     ) -> Result<FavouriteEntityResponse, super::FavouriteDbError> {
-        let is_existing = self.is_favourite(uuid, favourite_uuid).await;
-
-        if is_existing.unwrap_or(false) {
-            return Result::Err(super::FavouriteDbError::Conflict);
-        }
-
         let uuid = parce_uuid(uuid).map_err(|_| FavouriteDbError::UuidInvalid)?;
         let favourite_uuid_property =
             parce_uuid(favourite_uuid).map_err(|_| FavouriteDbError::UuidInvalid)?;
-
-        let favourite = FavouriteEntity {
-            user_uuid: uuid,
-            favourite_uuid: favourite_uuid_property,
-            title: title.to_owned(),
-        };
-
-        match self
-            .0
-            .run(move |db| {
-                diesel::insert_into(favourite::table)
-                    .values(favourite)
-                    .get_result(db)
-            })
+        self.add_list_entry(uuid, None, favourite_uuid_property, title.to_owned())
             .await
-        {
-            Ok(favourite) => {
-                log::info!("Added favourite: {:?}", favourite);
-                Result::Ok(favourite)
-            }
-            Err(err) => {
-                eprintln!("Error adding favourite: {}", err);
-                Result::Err(super::FavouriteDbError::InternalError)
-            }
-        }
     }
 
     async fn remove_favourite<'a>(
@@ -88,13 +121,16 @@ impl UserFavouritesDatabase for Conn {
 
         self.0
             .run(move |db| {
-                diesel::delete(
-                    favourite::table
-                        .filter(favourite::user_uuid.eq(uuid))
-                        .filter(favourite::favourite_uuid.eq(favourite_uuid)),
-                )
-                .execute(db)
-                .map(|_| ())
+                db.transaction(|db| {
+                    diesel::delete(
+                        favourite::table
+                            .filter(favourite::user_uuid.eq(uuid))
+                            .filter(favourite::list_uuid.is_null())
+                            .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+                    )
+                    .execute(db)?;
+                    clear_removed_favourites(db, uuid, vec![favourite_uuid])
+                })
                 .map_err(|err| {
                     eprintln!("Error removing favourite: {}", err);
                     super::FavouriteDbError::InternalError
@@ -115,6 +151,7 @@ impl UserFavouritesDatabase for Conn {
             .run(move |db| {
                 favourite::table
                     .filter(favourite::user_uuid.eq(uuid))
+                    .filter(favourite::list_uuid.is_null())
                     .filter(favourite::favourite_uuid.eq(favourite_uuid))
                     .first::<FavouriteEntityResponse>(db)
                     .map(|_| true)
@@ -126,47 +163,597 @@ impl UserFavouritesDatabase for Conn {
     async fn get_user_favourites<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: FavouriteEntityFilter,
     ) -> Result<PagingDomainResponse<FavouriteEntityResponse>, FavouriteDbError> {
-        let query = request.query.to_owned();
         let request_uuid =
             parce_uuid(request.user_uuid).map_err(|_| FavouriteDbError::UuidInvalid)?;
-        let page_number = correct_page_number(request.page);
-        let limit = request.page_size;
-        let offset = page_number * request.page_size;
+        self.get_list_entries(
+            request_uuid,
+            None,
+            request.query.to_owned(),
+            filter,
+            request.page,
+            request.page_size,
+        )
+        .await
+    }
+
+    async fn get_shared_favourites(
+        &self,
+        users_uuid: Vec<Uuid>,
+        shared_visibility: String,
+        limit: i64,
+    ) -> Result<Vec<FavouriteOverlapEntity>, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                let public_lists = favourite_lists::table
+                    .select(favourite_lists::uuid.nullable())
+                    .filter(favourite_lists::visibility.eq(shared_visibility));
+                favourite::table
+                    .filter(favourite::user_uuid.eq_any(users_uuid))
+                    .filter(
+                        favourite::list_uuid
+                            .is_null()
+                            .or(favourite::list_uuid.eq_any(public_lists)),
+                    )
+                    .group_by(favourite::favourite_uuid)
+                    .select((
+                        favourite::favourite_uuid,
+                        diesel::dsl::min(favourite::title),
+                        count_distinct(favourite::user_uuid),
+                    ))
+                    .order_by((
+                        count_distinct(favourite::user_uuid).desc(),
+                        diesel::dsl::min(favourite::title).asc(),
+                        favourite::favourite_uuid.asc(),
+                    ))
+                    .limit(limit)
+                    .load::<FavouriteOverlapEntity>(db)
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting shared favourites: {}", err);
+                FavouriteDbError::InternalError
+            })
+    }
+
+    async fn get_favourite_users(
+        &self,
+        request_uuid: Uuid,
+        favourite_uuid: Uuid,
+        visibilities: Vec<String>,
+        follower_visibilities: Vec<String>,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteUserEntity>, FavouriteDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let filtered = || {
+                    let visible_lists = favourite_lists::table
+                        .select(favourite_lists::uuid.nullable())
+                        .filter(favourite_lists::visibility.eq_any(visibilities.to_owned()));
+                    let follower_lists = favourite_lists::table
+                        .select(favourite_lists::uuid.nullable())
+                        .filter(
+                            favourite_lists::visibility.eq_any(follower_visibilities.to_owned()),
+                        );
+                    let holders = favourite::table
+                        .select(favourite::user_uuid)
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .filter(
+                            favourite::list_uuid
+                                .is_null()
+                                .or(favourite::list_uuid.eq_any(visible_lists)),
+                        );
+                    let follower_holders = favourite::table
+                        .select(favourite::user_uuid)
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .filter(favourite::list_uuid.eq_any(follower_lists));
+                    users::table
+                        .left_join(
+                            follow::table.on(follow::followed_uuid
+                                .eq(users::id)
+                                .and(follow::follower_uuid.eq(request_uuid))),
+                        )
+                        .filter(
+                            users::id.eq_any(holders).or(follow::uuid
+                                .nullable()
+                                .is_not_null()
+                                .and(users::id.eq_any(follower_holders))),
+                        )
+                        .filter(users::id.ne(request_uuid))
+                        .filter(users::username.ilike(format!("%{}%", query)))
+                };
+                let total: i64 = filtered().count().get_result(db)?;
+                let result = filtered()
+                    .select((
+                        users::id,
+                        users::username,
+                        users::avatar_url,
+                        follow::uuid.nullable().is_not_null(),
+                    ))
+                    .order((
+                        follow::uuid.nullable().is_null().asc(),
+                        users::username.asc(),
+                        users::id.asc(),
+                    ))
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<FavouriteUserEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
 
+    async fn count_common_favourites(
+        &self,
+        request_uuid: Uuid,
+        user_uuid: Uuid,
+        visibilities: Vec<String>,
+    ) -> Result<FavouriteCommonCountsEntity, FavouriteDbError> {
         self.0
             .run(move |db| {
-                let query_request = favourite::table
-                    .filter(favourite::user_uuid.eq(request_uuid))
-                    .filter(favourite::title.ilike(format!("%{}%", query)));
+                diesel::sql_query(COMMON_FAVOURITES_SETS.to_owned() + COUNT_COMMON_FAVOURITES_QUERY)
+                    .bind::<sql_types::Uuid, _>(request_uuid)
+                    .bind::<sql_types::Uuid, _>(user_uuid)
+                    .bind::<sql_types::Array<sql_types::Text>, _>(visibilities)
+                    .get_result::<FavouriteCommonCountsEntity>(db)
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error counting common favourites: {}", err);
+                FavouriteDbError::InternalError
+            })
+    }
 
-                let result_request = query_request.to_owned().limit(limit).offset(offset);
-                let results: Vec<FavouriteEntityResponse> = result_request
-                    .get_results::<FavouriteEntityResponse>(db)
-                    .map_err(|err| {
-                        eprintln!("Error getting users: {}", err);
-                        FavouriteDbError::InternalError
-                    })?;
+    async fn get_common_favourites(
+        &self,
+        request_uuid: Uuid,
+        user_uuid: Uuid,
+        visibilities: Vec<String>,
+        scope: String,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<FavouriteEntityResponse>, FavouriteDbError> {
+        let selected = match scope.as_str() {
+            "common" => {
+                "SELECT favourite_uuid FROM mine INTERSECT SELECT favourite_uuid FROM theirs"
+            }
+            "mine" => "SELECT favourite_uuid FROM mine EXCEPT SELECT favourite_uuid FROM theirs",
+            "theirs" => "SELECT favourite_uuid FROM theirs EXCEPT SELECT favourite_uuid FROM mine",
+            _ => return Err(FavouriteDbError::InternalError),
+        };
+        let query = COMMON_FAVOURITES_SETS.to_owned()
+            + &GET_COMMON_FAVOURITES_QUERY.replace("{selected}", selected);
+        self.0
+            .run(move |db| {
+                diesel::sql_query(query)
+                    .bind::<sql_types::Uuid, _>(request_uuid)
+                    .bind::<sql_types::Uuid, _>(user_uuid)
+                    .bind::<sql_types::Array<sql_types::Text>, _>(visibilities)
+                    .bind::<sql_types::BigInt, _>(limit)
+                    .bind::<sql_types::BigInt, _>(offset)
+                    .load::<FavouriteEntityResponse>(db)
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting common favourites: {}", err);
+                FavouriteDbError::InternalError
+            })
+    }
 
-                let total_request = query_request.to_owned();
-                let total_result = total_request.count().get_result(db).map_err(|err| {
-                    println!("Error getting total count: {}", err);
-                    FavouriteDbError::InternalError
-                })?;
+    async fn add_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        title: String,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // catalogue items lend their title so it doesn't drift between users
+                    let item = items::table
+                        .select((items::uuid, items::title))
+                        .find(favourite_uuid)
+                        .first::<(Uuid, String)>(db)
+                        .optional()?;
+                    // the status belongs to the favourite rather than to one of its lists
+                    let status = favourite::table
+                        .select((favourite::status, favourite::status_updated_at))
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .first::<(String, i64)>(db)
+                        .optional()?;
+                    let favourite = FavouriteEntity {
+                        user_uuid,
+                        favourite_uuid,
+                        title: item.as_ref().map_or(title, |(_, title)| title.to_owned()),
+                        item_uuid: item.map(|(item_uuid, _)| item_uuid),
+                        list_uuid,
+                        position: next_position(db, user_uuid, list_uuid)?,
+                        status: status.as_ref().map(|(status, _)| status.to_owned()),
+                        status_updated_at: status.map(|(_, updated_at)| updated_at),
+                    };
+                    // the unique index keeps concurrent adds of one entry from both landing
+                    let favourite = diesel::insert_into(favourite::table)
+                        .values(favourite)
+                        .on_conflict_do_nothing()
+                        .get_result::<FavouriteEntityResponse>(db)
+                        .optional()?
+                        .ok_or(FavouriteDbError::Conflict)?;
+                    log::info!("Added favourite: {:?}", favourite);
+                    Ok(favourite)
+                })
+            })
+            .await
+    }
 
-                let result_count = i64::try_from(results.len()).map_err(|err| {
-                    eprintln!("Error converting result count: {}", err);
-                    FavouriteDbError::InternalError
-                })?;
+    async fn remove_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+    ) -> Result<(), FavouriteDbError> {
+        let removed = self
+            .0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let removed = diesel::delete(
+                        favourite::table
+                            .filter(favourite::user_uuid.eq(user_uuid))
+                            .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                            .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+                    )
+                    .execute(db)?;
+                    clear_removed_favourites(db, user_uuid, vec![favourite_uuid])?;
+                    Ok::<usize, diesel::result::Error>(removed)
+                })
+            })
+            .await?;
+        if removed == 0 {
+            return Err(FavouriteDbError::EntryNotFound);
+        }
+        Ok(())
+    }
 
+    async fn get_list_entries(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        query: String,
+        filter: FavouriteEntityFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteEntityResponse>, FavouriteDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let query_request = || {
+                    let mut query_request = favourite::table
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                        .filter(favourite::title.ilike(format!("%{}%", query)))
+                        .into_boxed();
+                    if let Some(status) = filter.status.to_owned() {
+                        query_request = query_request.filter(favourite::status.eq(status));
+                    }
+                    for tag in filter.tags.iter() {
+                        query_request = query_request.filter(exists(
+                            favourite_tags::table
+                                .filter(favourite_tags::user_uuid.eq(favourite::user_uuid))
+                                .filter(
+                                    favourite_tags::favourite_uuid.eq(favourite::favourite_uuid),
+                                )
+                                .filter(favourite_tags::tag.eq(tag.to_owned())),
+                        ));
+                    }
+                    query_request
+                };
+                let total: i64 = query_request().count().get_result(db)?;
+                let result = query_request()
+                    .order((
+                        favourite::pinned.desc(),
+                        favourite::position.asc(),
+                        favourite::uuid.asc(),
+                    ))
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<FavouriteEntityResponse>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
                 Ok(PagingDomainResponse {
-                    total: total_result,
-                    result: results,
                     page: page_number + 1,
-                    page_size: request.page_size,
-                    has_more: offset + result_count < total_result,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+
+    async fn get_lists_entries_count(
+        &self,
+        lists_uuid: Vec<Uuid>,
+    ) -> Result<Vec<(Uuid, i64)>, FavouriteDbError> {
+        let counts = self
+            .0
+            .run(move |db| {
+                favourite::table
+                    .filter(favourite::list_uuid.eq_any(lists_uuid))
+                    .group_by(favourite::list_uuid)
+                    .select((favourite::list_uuid, diesel::dsl::count_star()))
+                    .load::<(Option<Uuid>, i64)>(db)
+            })
+            .await?;
+        Ok(counts
+            .into_iter()
+            .filter_map(|(list_uuid, count)| list_uuid.map(|list_uuid| (list_uuid, count)))
+            .collect())
+    }
+
+    async fn reorder_list_entries(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<(), FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let mut stored = favourite::table
+                        .select(favourite::favourite_uuid)
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                        .for_update()
+                        .load::<Uuid>(db)?;
+                    let mut requested = favourites_uuid.clone();
+                    stored.sort();
+                    requested.sort();
+                    if stored != requested {
+                        return Err(FavouriteDbError::OrderInvalid);
+                    }
+                    for (position, favourite_uuid) in favourites_uuid.into_iter().enumerate() {
+                        diesel::update(
+                            favourite::table
+                                .filter(favourite::user_uuid.eq(user_uuid))
+                                .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                                .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+                        )
+                        .set(favourite::position.eq(position as i64))
+                        .execute(db)?;
+                    }
+                    Ok(())
                 })
             })
             .await
     }
+
+    async fn move_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        to_list_uuid: Option<Uuid>,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let entry = favourite::table
+                        .select(favourite::uuid)
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .first::<Uuid>(db)
+                        .optional()?
+                        .ok_or(FavouriteDbError::EntryNotFound)?;
+                    let target = favourite::table
+                        .select(favourite::uuid)
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::list_uuid.is_not_distinct_from(to_list_uuid))
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .first::<Uuid>(db)
+                        .optional()?;
+                    if target.is_some() {
+                        return Err(FavouriteDbError::Conflict);
+                    }
+                    let position = next_position(db, user_uuid, to_list_uuid)?;
+                    diesel::update(favourite::table.find(entry))
+                        .set((
+                            favourite::list_uuid.eq(to_list_uuid),
+                            favourite::position.eq(position),
+                        ))
+                        .get_result::<FavouriteEntityResponse>(db)
+                        .map_err(FavouriteDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn pin_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        pinned: bool,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(
+                    favourite::table
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+                )
+                .set(favourite::pinned.eq(pinned))
+                .get_result::<FavouriteEntityResponse>(db)
+                .optional()
+            })
+            .await?
+            .ok_or(FavouriteDbError::EntryNotFound)
+    }
+
+    async fn get_favourite_status(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<(String, i64), FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                favourite::table
+                    .select((favourite::status, favourite::status_updated_at))
+                    .filter(favourite::user_uuid.eq(user_uuid))
+                    .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                    .first::<(String, i64)>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(FavouriteDbError::EntryNotFound)
+    }
+
+    async fn update_favourite_status(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+        status: String,
+        updated_at: i64,
+    ) -> Result<(), FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let from_status = favourite::table
+                        .select(favourite::status)
+                        .filter(favourite::user_uuid.eq(user_uuid))
+                        .filter(favourite::favourite_uuid.eq(favourite_uuid))
+                        .for_update()
+                        .first::<String>(db)
+                        .optional()?
+                        .ok_or(FavouriteDbError::EntryNotFound)?;
+                    if from_status == status {
+                        return Ok(());
+                    }
+                    diesel::update(
+                        favourite::table
+                            .filter(favourite::user_uuid.eq(user_uuid))
+                            .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+                    )
+                    .set((
+                        favourite::status.eq(status.to_owned()),
+                        favourite::status_updated_at.eq(updated_at),
+                    ))
+                    .execute(db)?;
+                    diesel::insert_into(favourite_status_transitions::table)
+                        .values(FavouriteStatusTransitionEntityCreate {
+                            user_uuid,
+                            favourite_uuid,
+                            from_status,
+                            to_status: status,
+                            created_at: updated_at,
+                        })
+                        .execute(db)?;
+                    Ok(())
+                })
+            })
+            .await
+    }
+
+    async fn get_favourite_status_transitions(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<Vec<FavouriteStatusTransitionEntity>, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                favourite_status_transitions::table
+                    .filter(favourite_status_transitions::user_uuid.eq(user_uuid))
+                    .filter(favourite_status_transitions::favourite_uuid.eq(favourite_uuid))
+                    .order((
+                        favourite_status_transitions::created_at.asc(),
+                        favourite_status_transitions::uuid.asc(),
+                    ))
+                    .load::<FavouriteStatusTransitionEntity>(db)
+            })
+            .await
+            .map_err(FavouriteDbError::from)
+    }
+
+    async fn get_favourites_status_count(
+        &self,
+        user_uuid: Uuid,
+    ) -> Result<Vec<(String, i64)>, FavouriteDbError> {
+        self.0
+            .run(move |db| {
+                favourite::table
+                    .filter(favourite::user_uuid.eq(user_uuid))
+                    .filter(favourite::list_uuid.is_null())
+                    .group_by(favourite::status)
+                    .select((favourite::status, diesel::dsl::count_star()))
+                    .load::<(String, i64)>(db)
+            })
+            .await
+            .map_err(FavouriteDbError::from)
+    }
+}
+
+/// Position after the last entry of the list.
+fn next_position(
+    db: &mut PgConnection,
+    user_uuid: Uuid,
+    list_uuid: Option<Uuid>,
+) -> Result<i64, FavouriteDbError> {
+    let last = favourite::table
+        .select(diesel::dsl::max(favourite::position))
+        .filter(favourite::user_uuid.eq(user_uuid))
+        .filter(favourite::list_uuid.is_not_distinct_from(list_uuid))
+        .first::<Option<i64>>(db)?;
+    Ok(last.map_or(0, |last| last + 1))
+}
+
+/// Drops the status history, tags and note of those of `favourites_uuid` that `user_uuid`
+/// no longer keeps in any list, so favouriting them again starts afresh.
+pub(crate) fn clear_removed_favourites(
+    db: &mut PgConnection,
+    user_uuid: Uuid,
+    favourites_uuid: Vec<Uuid>,
+) -> QueryResult<()> {
+    let kept = || {
+        favourite::table
+            .select(favourite::favourite_uuid)
+            .filter(favourite::user_uuid.eq(user_uuid))
+    };
+    diesel::delete(
+        favourite_status_transitions::table
+            .filter(favourite_status_transitions::user_uuid.eq(user_uuid))
+            .filter(favourite_status_transitions::favourite_uuid.eq_any(&favourites_uuid))
+            .filter(not(
+                favourite_status_transitions::favourite_uuid.eq_any(kept())
+            )),
+    )
+    .execute(db)?;
+    diesel::delete(
+        favourite_tags::table
+            .filter(favourite_tags::user_uuid.eq(user_uuid))
+            .filter(favourite_tags::favourite_uuid.eq_any(&favourites_uuid))
+            .filter(not(favourite_tags::favourite_uuid.eq_any(kept()))),
+    )
+    .execute(db)?;
+    diesel::delete(
+        favourite_notes::table
+            .filter(favourite_notes::user_uuid.eq(user_uuid))
+            .filter(favourite_notes::favourite_uuid.eq_any(&favourites_uuid))
+            .filter(not(favourite_notes::favourite_uuid.eq_any(kept()))),
+    )
+    .execute(db)?;
+    Ok(())
 }
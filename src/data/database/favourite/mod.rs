@@ -1,8 +1,14 @@
+use uuid::Uuid;
+
 use crate::data::repository::objects::{PagingDomainRequest, PagingDomainResponse};
 
-use self::objects::{FavouriteDbError, FavouriteEntityResponse};
+use self::objects::{
+    FavouriteCommonCountsEntity, FavouriteDbError, FavouriteEntityFilter, FavouriteEntityResponse,
+    FavouriteOverlapEntity, FavouriteStatusTransitionEntity, FavouriteUserEntity,
+};
 
 mod favourite_db;
+pub(crate) use favourite_db::clear_removed_favourites;
 pub mod objects;
 mod tests;
 
@@ -25,8 +31,131 @@ pub trait UserFavouritesDatabase {
         uuid: &'a str,
         favourite_uuid: &'a str,
     ) -> Result<bool, FavouriteDbError>;
+    /// Favourites of the default list matching `filter`.
     async fn get_user_favourites<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: FavouriteEntityFilter,
+    ) -> Result<PagingDomainResponse<FavouriteEntityResponse>, FavouriteDbError>;
+    /// Favourites of `users_uuid`, the ones most of them share first, at most `limit` rows.
+    /// Entries of named lists only count when the list has `shared_visibility`.
+    async fn get_shared_favourites(
+        &self,
+        users_uuid: Vec<Uuid>,
+        shared_visibility: String,
+        limit: i64,
+    ) -> Result<Vec<FavouriteOverlapEntity>, FavouriteDbError>;
+    /// Other users having `favourite_uuid`, the ones `request_uuid` follows first. Entries of
+    /// named lists count when the list has one of `visibilities`, or one of
+    /// `follower_visibilities` while `request_uuid` follows the owner.
+    #[allow(clippy::too_many_arguments)]
+    async fn get_favourite_users(
+        &self,
+        request_uuid: Uuid,
+        favourite_uuid: Uuid,
+        visibilities: Vec<String>,
+        follower_visibilities: Vec<String>,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteUserEntity>, FavouriteDbError>;
+    /// Counts the favourites `request_uuid` and `user_uuid` share and those only one of them
+    /// has. Entries of named lists of `user_uuid` only count when the list has one of
+    /// `visibilities`.
+    async fn count_common_favourites(
+        &self,
+        request_uuid: Uuid,
+        user_uuid: Uuid,
+        visibilities: Vec<String>,
+    ) -> Result<FavouriteCommonCountsEntity, FavouriteDbError>;
+    /// Favourites in `scope`, ordered by title: `common` to both users, `mine` when only
+    /// `request_uuid` has them or `theirs` when only `user_uuid` has them.
+    async fn get_common_favourites(
+        &self,
+        request_uuid: Uuid,
+        user_uuid: Uuid,
+        visibilities: Vec<String>,
+        scope: String,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<FavouriteEntityResponse>, FavouriteDbError>;
+    /// Appends the favourite to the end of `list_uuid`, `None` being the default list.
+    async fn add_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        title: String,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError>;
+    async fn remove_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+    ) -> Result<(), FavouriteDbError>;
+    /// Entries whose title matches `query` and matching `filter`, pinned ones first, then by
+    /// position.
+    async fn get_list_entries(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        query: String,
+        filter: FavouriteEntityFilter,
+        page: i64,
+        page_size: i64,
     ) -> Result<PagingDomainResponse<FavouriteEntityResponse>, FavouriteDbError>;
+    /// Amount of entries per list, empty lists are left out.
+    async fn get_lists_entries_count(
+        &self,
+        lists_uuid: Vec<Uuid>,
+    ) -> Result<Vec<(Uuid, i64)>, FavouriteDbError>;
+    /// Renumbers the entries in the order of `favourites_uuid`, which has to hold every
+    /// entry of the list exactly once.
+    async fn reorder_list_entries(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<(), FavouriteDbError>;
+    /// Moves the entry to the end of `to_list_uuid`, keeping whether it's pinned.
+    async fn move_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        to_list_uuid: Option<Uuid>,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError>;
+    async fn pin_list_entry(
+        &self,
+        user_uuid: Uuid,
+        list_uuid: Option<Uuid>,
+        favourite_uuid: Uuid,
+        pinned: bool,
+    ) -> Result<FavouriteEntityResponse, FavouriteDbError>;
+    /// Status of the favourite with the time it was last changed.
+    async fn get_favourite_status(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<(String, i64), FavouriteDbError>;
+    /// Sets the status on every list holding the favourite and records the transition,
+    /// nothing changes when it already has `status`.
+    async fn update_favourite_status(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+        status: String,
+        updated_at: i64,
+    ) -> Result<(), FavouriteDbError>;
+    /// Status changes of the favourite, oldest first.
+    async fn get_favourite_status_transitions(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<Vec<FavouriteStatusTransitionEntity>, FavouriteDbError>;
+    /// Amount of favourites of the default list per status, statuses without any are left out.
+    async fn get_favourites_status_count(
+        &self,
+        user_uuid: Uuid,
+    ) -> Result<Vec<(String, i64)>, FavouriteDbError>;
 }
@@ -1,6 +1,7 @@
+use diesel::{result::Error, sql_types};
 use uuid::Uuid;
 
-use crate::schema::favourite;
+use crate::schema::{favourite, favourite_status_transitions};
 
 /// Represents a new favorite item to be inserted into the database.
 #[derive(Insertable, PartialEq, Debug)]
@@ -9,15 +10,31 @@ pub struct FavouriteEntity {
     pub user_uuid: Uuid,
     pub favourite_uuid: Uuid,
     pub title: String,
+    pub item_uuid: Option<Uuid>,
+    pub list_uuid: Option<Uuid>,
+    pub position: i64,
+    /// Taken over from the same favourite in another list, `None` for a new one.
+    pub status: Option<String>,
+    pub status_updated_at: Option<i64>,
 }
 
 // Represents a favourite object retrieved from the database.
-#[derive(Queryable, PartialEq, Debug, Clone)]
+#[derive(Queryable, QueryableByName, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite)]
 pub struct FavouriteEntityResponse {
     pub uuid: Uuid,
     pub user_uuid: Uuid,
     pub favourite_uuid: Uuid,
     pub title: String,
+    /// Set when `favourite_uuid` refers to a catalogue item.
+    pub item_uuid: Option<Uuid>,
+    /// `None` for the default list.
+    pub list_uuid: Option<Uuid>,
+    pub position: i64,
+    pub pinned: bool,
+    pub created_at: i64,
+    pub status: String,
+    pub status_updated_at: i64,
 }
 
 // Represents a favourite object retrieved from the database.
@@ -27,6 +44,69 @@ pub struct FavouriteDataResponse {
     pub user_uuid: Uuid,
     pub favourite_uuid: Uuid,
     pub title: String,
+    pub item_uuid: Option<Uuid>,
+    pub list_uuid: Option<Uuid>,
+    pub position: i64,
+    pub pinned: bool,
+    pub created_at: i64,
+    pub status: String,
+    pub status_updated_at: i64,
+}
+
+/// A favourite shared by some of the requested users, `overlap` is how many of them have it.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteOverlapEntity {
+    pub favourite_uuid: Uuid,
+    pub title: Option<String>,
+    pub overlap: i64,
+}
+
+/// A user having some favourite, `is_following` tells whether the requester follows them.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteUserEntity {
+    pub uuid: Uuid,
+    pub username: String,
+    pub avatar_url: String,
+    pub is_following: bool,
+}
+
+/// Sizes of the favourite sets two users have in common or only one of them has.
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct FavouriteCommonCountsEntity {
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub common_count: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub mine_count: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub theirs_count: i64,
+}
+
+/// Optional narrowing of listed favourites, an entry has to carry every one of `tags`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FavouriteEntityFilter {
+    pub status: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// A status change of a favourite, shared by every list holding it.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteStatusTransitionEntity {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub from_status: String,
+    pub to_status: String,
+    pub created_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_status_transitions)]
+pub struct FavouriteStatusTransitionEntityCreate {
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub from_status: String,
+    pub to_status: String,
+    pub created_at: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -34,5 +114,14 @@ pub enum FavouriteDbError {
     UuidInvalid,
     UserNotFound,
     Conflict,
+    EntryNotFound,
+    OrderInvalid,
     InternalError,
 }
+
+impl From<Error> for FavouriteDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database favourite error: {:?}", err);
+        FavouriteDbError::InternalError
+    }
+}
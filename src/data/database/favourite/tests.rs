@@ -1,7 +1,14 @@
 #[cfg(test)]
 mod tests {
     use crate::data::database::{
-        favourite::UserFavouritesDatabase, tests::database_test_utls::run_migration_get_conn,
+        favourite::{
+            objects::{FavouriteDbError, FavouriteEntityFilter},
+            UserFavouritesDatabase,
+        },
+        favourite_lists::{objects::FavouriteListEntityCreate, FavouriteListsDatabase},
+        follow::{objects::FollowEntityCreate, FollowDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+        user::{objects::UserEntityCreate, UserDatabase},
     };
     use uuid::Uuid;
 
@@ -41,4 +48,349 @@ mod tests {
         assert!(count.is_ok());
         assert_eq!(count.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn test_get_shared_favourites_ranked_by_overlap() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let users: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let outsider = Uuid::new_v4().to_string();
+        let shared = Uuid::new_v4().to_string();
+        let partial = Uuid::new_v4().to_string();
+        let single = Uuid::new_v4().to_string();
+
+        for user in &users {
+            connection
+                .add_favourite(&user.to_string(), &shared, "Shared")
+                .await
+                .unwrap();
+        }
+        for user in &users[..2] {
+            connection
+                .add_favourite(&user.to_string(), &partial, "Partial")
+                .await
+                .unwrap();
+        }
+        connection
+            .add_favourite(&users[2].to_string(), &single, "Single")
+            .await
+            .unwrap();
+        connection
+            .add_favourite(&outsider, &single, "Single")
+            .await
+            .unwrap();
+
+        let result = connection
+            .get_shared_favourites(users.clone(), "public".to_string(), 10)
+            .await
+            .unwrap();
+        let ranked: Vec<(String, Option<String>, i64)> = result
+            .into_iter()
+            .map(|v| (v.favourite_uuid.to_string(), v.title, v.overlap))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                (shared.clone(), Some("Shared".to_string()), 3),
+                (partial, Some("Partial".to_string()), 2),
+                (single, Some("Single".to_string()), 1),
+            ]
+        );
+
+        let capped = connection
+            .get_shared_favourites(users, "public".to_string(), 1)
+            .await
+            .unwrap();
+        assert_eq!(capped.len(), 1);
+        assert_eq!(capped[0].favourite_uuid.to_string(), shared);
+    }
+
+    #[tokio::test]
+    async fn test_get_favourite_users_respects_visibility() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let suffix = Uuid::new_v4().to_simple().to_string();
+        let mut users = vec![];
+        for name in ["requester", "followed", "public", "private", "stranger"] {
+            let user = connection
+                .insert_user(UserEntityCreate {
+                    login: format!("{}_{}", name, suffix),
+                    username: format!("{}_{}", name, suffix),
+                    secret: "secret".to_string(),
+                    avatar_url: "".to_string(),
+                    bio: "".to_string(),
+                })
+                .await
+                .unwrap();
+            users.push(user.id);
+        }
+        let (requester, followed, public, private, stranger) =
+            (users[0], users[1], users[2], users[3], users[4]);
+        connection
+            .follow_user(&FollowEntityCreate {
+                follower_uuid: requester,
+                followed_uuid: followed,
+                followed_username: "followed".to_string(),
+                follower_username: "requester".to_string(),
+                followed_avatar_url: "".to_string(),
+                follower_avatar_url: "".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let item_uuid = Uuid::new_v4();
+        let add_to_list = |owner_uuid: Uuid, visibility: &str| {
+            let list = FavouriteListEntityCreate {
+                owner_uuid,
+                title: "list".to_string(),
+                visibility: visibility.to_string(),
+                created_at: 0,
+                updated_at: 0,
+            };
+            let connection = &connection;
+            async move {
+                let list = connection.add_list(list).await.unwrap();
+                connection
+                    .add_list_entry(owner_uuid, Some(list.uuid), item_uuid, "title".to_string())
+                    .await
+                    .unwrap();
+            }
+        };
+        add_to_list(followed, "followers").await;
+        add_to_list(private, "private").await;
+        add_to_list(stranger, "followers").await;
+        for user_uuid in [requester, public] {
+            connection
+                .add_list_entry(user_uuid, None, item_uuid, "title".to_string())
+                .await
+                .unwrap();
+        }
+
+        let result = connection
+            .get_favourite_users(
+                requester,
+                item_uuid,
+                vec!["public".to_string()],
+                vec!["public".to_string(), "followers".to_string()],
+                "".to_string(),
+                1,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.total, 2);
+        assert_eq!(result.result[0].uuid, followed);
+        assert!(result.result[0].is_following);
+        assert_eq!(result.result[1].uuid, public);
+        assert!(!result.result[1].is_following);
+    }
+
+    #[tokio::test]
+    async fn test_common_favourites_set_queries() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let (requester, user) = (Uuid::new_v4(), Uuid::new_v4());
+        let (only_mine, shared, only_theirs, hidden) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        for (user_uuid, favourite_uuid, title) in [
+            (requester, only_mine, "Alien"),
+            (requester, shared, "Brazil"),
+            (user, shared, "Brazil"),
+            (user, only_theirs, "Casablanca"),
+        ] {
+            connection
+                .add_list_entry(user_uuid, None, favourite_uuid, title.to_string())
+                .await
+                .unwrap();
+        }
+        let private_list = connection
+            .add_list(FavouriteListEntityCreate {
+                owner_uuid: user,
+                title: "private".to_string(),
+                visibility: "private".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        connection
+            .add_list_entry(user, Some(private_list.uuid), hidden, "Dune".to_string())
+            .await
+            .unwrap();
+
+        let visibilities = vec!["public".to_string()];
+        let counts = connection
+            .count_common_favourites(requester, user, visibilities.to_owned())
+            .await
+            .unwrap();
+        assert_eq!(
+            (counts.common_count, counts.mine_count, counts.theirs_count),
+            (1, 1, 1)
+        );
+
+        for (scope, expected) in [
+            ("common", shared),
+            ("mine", only_mine),
+            ("theirs", only_theirs),
+        ] {
+            let result = connection
+                .get_common_favourites(
+                    requester,
+                    user,
+                    visibilities.to_owned(),
+                    scope.to_string(),
+                    10,
+                    0,
+                )
+                .await
+                .unwrap();
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].favourite_uuid, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_favourite_status_transitions_and_counts() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let (watched, skipped) = (Uuid::new_v4(), Uuid::new_v4());
+        for (favourite_uuid, title) in [(watched, "Alien"), (skipped, "Brazil")] {
+            let added = connection
+                .add_list_entry(user, None, favourite_uuid, title.to_string())
+                .await
+                .unwrap();
+            assert_eq!(added.status, "want");
+        }
+
+        connection
+            .update_favourite_status(user, watched, "in_progress".to_string(), 10)
+            .await
+            .unwrap();
+        connection
+            .update_favourite_status(user, watched, "done".to_string(), 20)
+            .await
+            .unwrap();
+        connection
+            .update_favourite_status(user, watched, "done".to_string(), 30)
+            .await
+            .unwrap();
+        let transitions = connection
+            .get_favourite_status_transitions(user, watched)
+            .await
+            .unwrap();
+        assert_eq!(
+            transitions
+                .iter()
+                .map(|v| (v.from_status.as_str(), v.to_status.as_str(), v.created_at))
+                .collect::<Vec<_>>(),
+            vec![("want", "in_progress", 10), ("in_progress", "done", 20)]
+        );
+        assert_eq!(
+            connection
+                .get_favourite_status(user, watched)
+                .await
+                .unwrap(),
+            ("done".to_string(), 20)
+        );
+
+        // a list entry takes over the status of the favourite
+        let list = connection
+            .add_list(FavouriteListEntityCreate {
+                owner_uuid: user,
+                title: "rewatch".to_string(),
+                visibility: "public".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        let entry = connection
+            .add_list_entry(user, Some(list.uuid), watched, "Alien".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            (entry.status.as_str(), entry.status_updated_at),
+            ("done", 20)
+        );
+
+        let mut counts = connection.get_favourites_status_count(user).await.unwrap();
+        counts.sort();
+        assert_eq!(
+            counts,
+            vec![("done".to_string(), 1), ("want".to_string(), 1)]
+        );
+
+        let done = connection
+            .get_list_entries(
+                user,
+                None,
+                String::new(),
+                FavouriteEntityFilter {
+                    status: Some("done".to_string()),
+                    ..Default::default()
+                },
+                1,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(done.total, 1);
+        assert_eq!(done.result[0].favourite_uuid, watched);
+
+        let missing = connection
+            .update_favourite_status(user, Uuid::new_v4(), "done".to_string(), 40)
+            .await;
+        assert!(matches!(missing, Err(FavouriteDbError::EntryNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_status_history_removed_with_last_entry() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let favourite_uuid = Uuid::new_v4();
+        let list = connection
+            .add_list(FavouriteListEntityCreate {
+                owner_uuid: user,
+                title: "rewatch".to_string(),
+                visibility: "public".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        for list_uuid in [None, Some(list.uuid)] {
+            connection
+                .add_list_entry(user, list_uuid, favourite_uuid, "Alien".to_string())
+                .await
+                .unwrap();
+        }
+        connection
+            .update_favourite_status(user, favourite_uuid, "done".to_string(), 10)
+            .await
+            .unwrap();
+
+        // still kept in the named list
+        connection
+            .remove_list_entry(user, None, favourite_uuid)
+            .await
+            .unwrap();
+        let transitions = connection
+            .get_favourite_status_transitions(user, favourite_uuid)
+            .await
+            .unwrap();
+        assert_eq!(transitions.len(), 1);
+
+        connection.delete_list(list.uuid).await.unwrap();
+        let transitions = connection
+            .get_favourite_status_transitions(user, favourite_uuid)
+            .await
+            .unwrap();
+        assert!(transitions.is_empty());
+    }
 }
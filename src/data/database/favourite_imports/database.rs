@@ -0,0 +1,181 @@
+use super::{
+    objects::{
+        FavouriteImportDbError, FavouriteImportEntity, FavouriteImportEntityClaim,
+        FavouriteImportEntityCreate, FavouriteImportEntityFinish, FavouriteImportRowEntity,
+    },
+    FavouriteImportsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    schema::{favourite_import_rows, favourite_imports},
+    Conn,
+};
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+/// Every column of `favourite_imports` but the content.
+const IMPORT_COLUMNS: (
+    favourite_imports::uuid,
+    favourite_imports::user_uuid,
+    favourite_imports::list_uuid,
+    favourite_imports::format,
+    favourite_imports::status,
+    favourite_imports::rows_count,
+    favourite_imports::imported_count,
+    favourite_imports::duplicates_count,
+    favourite_imports::unmatched_count,
+    favourite_imports::created_at,
+    favourite_imports::updated_at,
+) = (
+    favourite_imports::uuid,
+    favourite_imports::user_uuid,
+    favourite_imports::list_uuid,
+    favourite_imports::format,
+    favourite_imports::status,
+    favourite_imports::rows_count,
+    favourite_imports::imported_count,
+    favourite_imports::duplicates_count,
+    favourite_imports::unmatched_count,
+    favourite_imports::created_at,
+    favourite_imports::updated_at,
+);
+
+#[async_trait]
+impl FavouriteImportsDatabase for Conn {
+    async fn add_import(
+        &self,
+        import: FavouriteImportEntityCreate,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError> {
+        self.0
+            .run(move |db| {
+                diesel::insert_into(favourite_imports::table)
+                    .values(&import)
+                    .returning(IMPORT_COLUMNS)
+                    .get_result::<FavouriteImportEntity>(db)
+            })
+            .await
+            .map_err(FavouriteImportDbError::from)
+    }
+
+    async fn get_import(
+        &self,
+        import_uuid: Uuid,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError> {
+        self.0
+            .run(move |db| {
+                favourite_imports::table
+                    .filter(favourite_imports::uuid.eq(import_uuid))
+                    .select(IMPORT_COLUMNS)
+                    .first::<FavouriteImportEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(FavouriteImportDbError::ImportNotFound)
+    }
+
+    async fn get_import_rows(
+        &self,
+        import_uuid: Uuid,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteImportRowEntity>, FavouriteImportDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let query_request = favourite_import_rows::table
+                    .filter(favourite_import_rows::import_uuid.eq(import_uuid));
+                let total: i64 = query_request.count().get_result(db)?;
+                let result = query_request
+                    .order(favourite_import_rows::row_number.asc())
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<FavouriteImportRowEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+
+    async fn claim_pending_import(
+        &self,
+        pending_status: String,
+        running_status: String,
+        stale_before: i64,
+        updated_at: i64,
+    ) -> Result<Option<FavouriteImportEntityClaim>, FavouriteImportDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let pending = favourite_imports::table
+                        .select(favourite_imports::uuid)
+                        .filter(
+                            favourite_imports::status.eq(pending_status).or(
+                                favourite_imports::status
+                                    .eq(&running_status)
+                                    .and(favourite_imports::updated_at.lt(stale_before)),
+                            ),
+                        )
+                        .order(favourite_imports::created_at.asc())
+                        .for_update()
+                        .skip_locked()
+                        .first::<Uuid>(db)
+                        .optional()?;
+                    let import_uuid = match pending {
+                        Some(import_uuid) => import_uuid,
+                        None => return Ok(None),
+                    };
+                    let (import, content) = diesel::update(favourite_imports::table)
+                        .filter(favourite_imports::uuid.eq(import_uuid))
+                        .set((
+                            favourite_imports::status.eq(running_status),
+                            favourite_imports::updated_at.eq(updated_at),
+                        ))
+                        .returning((IMPORT_COLUMNS, favourite_imports::content))
+                        .get_result::<(FavouriteImportEntity, String)>(db)?;
+                    Ok(Some(FavouriteImportEntityClaim { import, content }))
+                })
+            })
+            .await
+    }
+
+    async fn finish_import(
+        &self,
+        import_uuid: Uuid,
+        finish: FavouriteImportEntityFinish,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    diesel::insert_into(favourite_import_rows::table)
+                        .values(&finish.rows)
+                        .execute(db)?;
+                    diesel::update(favourite_imports::table)
+                        .filter(favourite_imports::uuid.eq(import_uuid))
+                        .set((
+                            favourite_imports::status.eq(finish.status),
+                            favourite_imports::content.eq(""),
+                            favourite_imports::imported_count.eq(finish.imported_count),
+                            favourite_imports::duplicates_count.eq(finish.duplicates_count),
+                            favourite_imports::unmatched_count.eq(finish.unmatched_count),
+                            favourite_imports::updated_at.eq(finish.updated_at),
+                        ))
+                        .returning(IMPORT_COLUMNS)
+                        .get_result::<FavouriteImportEntity>(db)
+                        .optional()?
+                        .ok_or(FavouriteImportDbError::ImportNotFound)
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{
+    FavouriteImportDbError, FavouriteImportEntity, FavouriteImportEntityClaim,
+    FavouriteImportEntityCreate, FavouriteImportEntityFinish, FavouriteImportRowEntity,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteImportsDatabase {
+    async fn add_import(
+        &self,
+        import: FavouriteImportEntityCreate,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError>;
+    async fn get_import(
+        &self,
+        import_uuid: Uuid,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError>;
+    /// Reported rows of the import in file order.
+    async fn get_import_rows(
+        &self,
+        import_uuid: Uuid,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteImportRowEntity>, FavouriteImportDbError>;
+    /// Moves the oldest import in `pending_status`, or left in `running_status` since before
+    /// `stale_before`, to `running_status` and hands out its content. Imports claimed by
+    /// another worker are skipped.
+    async fn claim_pending_import(
+        &self,
+        pending_status: String,
+        running_status: String,
+        stale_before: i64,
+        updated_at: i64,
+    ) -> Result<Option<FavouriteImportEntityClaim>, FavouriteImportDbError>;
+    /// Stores the outcome and reported rows of the import and clears its content.
+    async fn finish_import(
+        &self,
+        import_uuid: Uuid,
+        finish: FavouriteImportEntityFinish,
+    ) -> Result<FavouriteImportEntity, FavouriteImportDbError>;
+}
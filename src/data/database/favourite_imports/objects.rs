@@ -0,0 +1,83 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::{favourite_import_rows, favourite_imports};
+
+/// An uploaded favourites file and its progress, the content itself is left out.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteImportEntity {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub list_uuid: Option<Uuid>,
+    pub format: String,
+    pub status: String,
+    pub rows_count: i32,
+    pub imported_count: i32,
+    pub duplicates_count: i32,
+    pub unmatched_count: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_imports)]
+pub struct FavouriteImportEntityCreate {
+    pub user_uuid: Uuid,
+    pub list_uuid: Option<Uuid>,
+    pub format: String,
+    pub status: String,
+    pub content: String,
+    pub rows_count: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct FavouriteImportEntityClaim {
+    pub import: FavouriteImportEntity,
+    pub content: String,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct FavouriteImportEntityFinish {
+    pub status: String,
+    pub imported_count: i32,
+    pub duplicates_count: i32,
+    pub unmatched_count: i32,
+    pub rows: Vec<FavouriteImportRowEntityCreate>,
+    pub updated_at: i64,
+}
+
+/// A row of the file that was not imported, or only partly, and why.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteImportRowEntity {
+    pub uuid: Uuid,
+    pub import_uuid: Uuid,
+    pub row_number: i32,
+    pub title: Option<String>,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_import_rows)]
+pub struct FavouriteImportRowEntityCreate {
+    pub import_uuid: Uuid,
+    pub row_number: i32,
+    pub title: Option<String>,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug)]
+pub enum FavouriteImportDbError {
+    ImportNotFound,
+    InternalError,
+}
+
+impl From<Error> for FavouriteImportDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database favourite imports error: {:?}", err);
+        FavouriteImportDbError::InternalError
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        favourite_imports::{
+            objects::{
+                FavouriteImportDbError, FavouriteImportEntityCreate, FavouriteImportEntityFinish,
+                FavouriteImportRowEntityCreate,
+            },
+            FavouriteImportsDatabase,
+        },
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_claim_and_finish_import() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user_uuid = Uuid::new_v4();
+        // older than anything other tests queue, so it is claimed first
+        let created_at = 0;
+        let import = connection
+            .add_import(FavouriteImportEntityCreate {
+                user_uuid,
+                list_uuid: None,
+                format: "csv".to_string(),
+                status: "pending".to_string(),
+                content: "title\nHeat\n".to_string(),
+                rows_count: 1,
+                created_at,
+                updated_at: created_at,
+            })
+            .await
+            .unwrap();
+        assert_eq!(import.status, "pending");
+
+        let claim = connection
+            .claim_pending_import("pending".to_string(), "running".to_string(), 0, 5)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(claim.import.uuid, import.uuid);
+        assert_eq!(claim.import.status, "running");
+        assert_eq!(claim.content, "title\nHeat\n");
+
+        let finished = connection
+            .finish_import(
+                import.uuid,
+                FavouriteImportEntityFinish {
+                    status: "done".to_string(),
+                    imported_count: 0,
+                    duplicates_count: 0,
+                    unmatched_count: 1,
+                    rows: vec![FavouriteImportRowEntityCreate {
+                        import_uuid: import.uuid,
+                        row_number: 1,
+                        title: Some("Heat".to_string()),
+                        external_id: None,
+                        reason: "not_found".to_string(),
+                    }],
+                    updated_at: 6,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(finished.status, "done");
+        assert_eq!(finished.unmatched_count, 1);
+        assert_eq!(connection.get_import(import.uuid).await.unwrap(), finished);
+
+        let rows = connection
+            .get_import_rows(import.uuid, 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(rows.total, 1);
+        assert_eq!(rows.result[0].reason, "not_found");
+
+        let missing = connection.get_import(Uuid::new_v4()).await;
+        assert!(matches!(
+            missing,
+            Err(FavouriteImportDbError::ImportNotFound)
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use super::{
+    objects::{
+        FavouriteListDbError, FavouriteListEntity, FavouriteListEntityCreate,
+        FavouriteListEntityUpdate,
+    },
+    FavouriteListsDatabase,
+};
+use crate::{
+    data::{
+        database::{favourite::clear_removed_favourites, utils::paging::correct_page_number},
+        repository::objects::PagingDomainResponse,
+    },
+    schema::{favourite, favourite_lists},
+    Conn,
+};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl FavouriteListsDatabase for Conn {
+    async fn add_list(
+        &self,
+        list: FavouriteListEntityCreate,
+    ) -> Result<FavouriteListEntity, FavouriteListDbError> {
+        self.0
+            .run(move |db| {
+                diesel::insert_into(favourite_lists::table)
+                    .values(&list)
+                    .get_result::<FavouriteListEntity>(db)
+            })
+            .await
+            .map_err(FavouriteListDbError::from)
+    }
+
+    async fn get_list(&self, list_uuid: Uuid) -> Result<FavouriteListEntity, FavouriteListDbError> {
+        self.0
+            .run(move |db| {
+                favourite_lists::table
+                    .filter(favourite_lists::uuid.eq(list_uuid))
+                    .first::<FavouriteListEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(FavouriteListDbError::ListNotFound)
+    }
+
+    async fn get_lists(
+        &self,
+        owner_uuid: Uuid,
+        visibilities: Vec<String>,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteListEntity>, FavouriteListDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let query_request = favourite_lists::table
+                    .filter(favourite_lists::owner_uuid.eq(owner_uuid))
+                    .filter(favourite_lists::visibility.eq_any(visibilities))
+                    .filter(favourite_lists::title.ilike(format!("%{}%", query)));
+
+                let results = query_request
+                    .to_owned()
+                    .order((favourite_lists::title.asc(), favourite_lists::uuid.asc()))
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<FavouriteListEntity>(db)?;
+
+                let total_result = query_request.count().get_result(db)?;
+                let result_count = i64::try_from(results.len()).unwrap_or(i64::MAX);
+
+                Ok(PagingDomainResponse {
+                    total: total_result,
+                    result: results,
+                    page: page_number + 1,
+                    page_size,
+                    has_more: offset + result_count < total_result,
+                })
+            })
+            .await
+    }
+
+    async fn update_list(
+        &self,
+        list_uuid: Uuid,
+        update: FavouriteListEntityUpdate,
+    ) -> Result<FavouriteListEntity, FavouriteListDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(favourite_lists::table.filter(favourite_lists::uuid.eq(list_uuid)))
+                    .set(&update)
+                    .get_result::<FavouriteListEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(FavouriteListDbError::ListNotFound)
+    }
+
+    async fn delete_list(&self, list_uuid: Uuid) -> Result<(), FavouriteListDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let entries = favourite::table
+                        .select((favourite::user_uuid, favourite::favourite_uuid))
+                        .filter(favourite::list_uuid.eq(list_uuid))
+                        .load::<(Uuid, Uuid)>(db)?;
+                    // entries go along with the list
+                    let count = diesel::delete(
+                        favourite_lists::table.filter(favourite_lists::uuid.eq(list_uuid)),
+                    )
+                    .execute(db)?;
+                    if let Some((owner_uuid, _)) = entries.first() {
+                        let favourites_uuid = entries.iter().map(|(_, uuid)| *uuid).collect();
+                        clear_removed_favourites(db, *owner_uuid, favourites_uuid)?;
+                    }
+                    Ok::<usize, diesel::result::Error>(count)
+                })
+            })
+            .await
+            .map_err(FavouriteListDbError::from)
+            .and_then(|count| match count {
+                0 => Err(FavouriteListDbError::ListNotFound),
+                _ => Ok(()),
+            })
+    }
+}
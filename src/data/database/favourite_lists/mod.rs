@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{
+    FavouriteListDbError, FavouriteListEntity, FavouriteListEntityCreate, FavouriteListEntityUpdate,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteListsDatabase {
+    async fn add_list(
+        &self,
+        list: FavouriteListEntityCreate,
+    ) -> Result<FavouriteListEntity, FavouriteListDbError>;
+    async fn get_list(&self, list_uuid: Uuid) -> Result<FavouriteListEntity, FavouriteListDbError>;
+    /// Lists of `owner_uuid` with one of `visibilities` whose title matches `query`.
+    async fn get_lists(
+        &self,
+        owner_uuid: Uuid,
+        visibilities: Vec<String>,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteListEntity>, FavouriteListDbError>;
+    async fn update_list(
+        &self,
+        list_uuid: Uuid,
+        update: FavouriteListEntityUpdate,
+    ) -> Result<FavouriteListEntity, FavouriteListDbError>;
+    /// Deletes the list together with its entries.
+    async fn delete_list(&self, list_uuid: Uuid) -> Result<(), FavouriteListDbError>;
+}
@@ -0,0 +1,46 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::favourite_lists;
+
+/// A named, ordered list of favourites. Entries live in `favourite` with `list_uuid` set.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteListEntity {
+    pub uuid: Uuid,
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub visibility: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_lists)]
+pub struct FavouriteListEntityCreate {
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub visibility: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_lists)]
+pub struct FavouriteListEntityUpdate {
+    pub title: Option<String>,
+    pub visibility: Option<String>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteListDbError {
+    ListNotFound,
+    InternalError,
+}
+
+impl From<Error> for FavouriteListDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database favourite lists error: {:?}", err);
+        FavouriteListDbError::InternalError
+    }
+}
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        favourite::{objects::FavouriteDbError, UserFavouritesDatabase},
+        favourite_lists::{
+            objects::{FavouriteListDbError, FavouriteListEntityCreate, FavouriteListEntityUpdate},
+            FavouriteListsDatabase,
+        },
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_list_entries_order_move_and_pin() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let owner = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let list = |title: &str, visibility: &str| FavouriteListEntityCreate {
+            owner_uuid: owner,
+            title: title.to_string(),
+            visibility: visibility.to_string(),
+            created_at: current_time_ms,
+            updated_at: current_time_ms,
+        };
+        let watch_next = connection
+            .add_list(list("watch next", "public"))
+            .await
+            .unwrap();
+        let private = connection
+            .add_list(list("secret", "private"))
+            .await
+            .unwrap();
+
+        let favourites: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for (index, favourite_uuid) in favourites.iter().enumerate() {
+            let entry = connection
+                .add_list_entry(
+                    owner,
+                    Some(watch_next.uuid),
+                    *favourite_uuid,
+                    format!("title {}", index),
+                )
+                .await
+                .unwrap();
+            assert_eq!(entry.position, index as i64);
+        }
+        let conflict = connection
+            .add_list_entry(
+                owner,
+                Some(watch_next.uuid),
+                favourites[0],
+                "title".to_string(),
+            )
+            .await;
+        assert!(matches!(conflict, Err(FavouriteDbError::Conflict)));
+        // the default list is separate from named lists, and keeps an entry once too
+        let (owner_uuid, favourite_uuid) = (owner.to_string(), favourites[0].to_string());
+        let (first, second) = tokio::join!(
+            connection.add_favourite(&owner_uuid, &favourite_uuid, "title"),
+            connection.add_favourite(&owner_uuid, &favourite_uuid, "title"),
+        );
+        assert_eq!(
+            [&first, &second]
+                .iter()
+                .filter(|added| added.is_ok())
+                .count(),
+            1
+        );
+        assert!([first, second]
+            .into_iter()
+            .any(|added| matches!(added, Err(FavouriteDbError::Conflict))));
+        assert_eq!(
+            connection
+                .get_favourites_count(&owner.to_string())
+                .await
+                .unwrap(),
+            1
+        );
+
+        let order = |entries: Vec<Uuid>| {
+            entries
+                .iter()
+                .map(|uuid| favourites.iter().position(|v| v == uuid).unwrap())
+                .collect::<Vec<usize>>()
+        };
+
+        let invalid = connection
+            .reorder_list_entries(
+                owner,
+                Some(watch_next.uuid),
+                vec![favourites[2], favourites[1]],
+            )
+            .await;
+        assert!(matches!(invalid, Err(FavouriteDbError::OrderInvalid)));
+        connection
+            .reorder_list_entries(
+                owner,
+                Some(watch_next.uuid),
+                vec![favourites[2], favourites[0], favourites[1]],
+            )
+            .await
+            .unwrap();
+        let pinned = connection
+            .pin_list_entry(owner, Some(watch_next.uuid), favourites[1], true)
+            .await
+            .unwrap();
+        assert!(pinned.pinned);
+        let listed = connection
+            .get_list_entries(
+                owner,
+                Some(watch_next.uuid),
+                String::new(),
+                Default::default(),
+                1,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            order(listed.result.iter().map(|v| v.favourite_uuid).collect()),
+            vec![1, 2, 0]
+        );
+
+        let moved_conflict = connection
+            .move_list_entry(owner, Some(watch_next.uuid), favourites[0], None)
+            .await;
+        assert!(matches!(moved_conflict, Err(FavouriteDbError::Conflict)));
+        let moved = connection
+            .move_list_entry(
+                owner,
+                Some(watch_next.uuid),
+                favourites[2],
+                Some(private.uuid),
+            )
+            .await
+            .unwrap();
+        assert_eq!(moved.list_uuid, Some(private.uuid));
+        assert_eq!(moved.position, 0);
+        let missing = connection
+            .pin_list_entry(owner, Some(watch_next.uuid), favourites[2], true)
+            .await;
+        assert!(matches!(missing, Err(FavouriteDbError::EntryNotFound)));
+
+        let counts = connection
+            .get_lists_entries_count(vec![watch_next.uuid, private.uuid])
+            .await
+            .unwrap();
+        assert!(counts.contains(&(watch_next.uuid, 2)));
+        assert!(counts.contains(&(private.uuid, 1)));
+
+        // entries of private lists stay out of seeding
+        let shared = connection
+            .get_shared_favourites(vec![owner], "public".to_string(), 10)
+            .await
+            .unwrap();
+        let mut shared: Vec<Uuid> = shared.into_iter().map(|v| v.favourite_uuid).collect();
+        shared.sort();
+        let mut expected = vec![favourites[0], favourites[1]];
+        expected.sort();
+        assert_eq!(shared, expected);
+
+        let visible = connection
+            .get_lists(owner, vec!["public".to_string()], String::new(), 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(visible.result, vec![watch_next.clone()]);
+        let renamed = connection
+            .update_list(
+                watch_next.uuid,
+                FavouriteListEntityUpdate {
+                    title: Some("top".to_string()),
+                    visibility: None,
+                    updated_at: current_time_ms + 1,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(renamed.title, "top");
+        assert_eq!(renamed.visibility, "public");
+
+        connection.delete_list(watch_next.uuid).await.unwrap();
+        assert!(matches!(
+            connection.get_list(watch_next.uuid).await,
+            Err(FavouriteListDbError::ListNotFound)
+        ));
+        let remaining = connection
+            .get_list_entries(
+                owner,
+                Some(watch_next.uuid),
+                String::new(),
+                Default::default(),
+                1,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(remaining.total, 0);
+        connection
+            .remove_list_entry(owner, None, favourites[0])
+            .await
+            .unwrap();
+        let removed = connection
+            .remove_list_entry(owner, None, favourites[0])
+            .await;
+        assert!(matches!(removed, Err(FavouriteDbError::EntryNotFound)));
+    }
+}
@@ -0,0 +1,179 @@
+use diesel::{
+    dsl::{count_star, exists},
+    upsert::excluded,
+    Connection, ExpressionMethods, PgConnection, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+use super::{
+    objects::{
+        FavouriteNoteEntity, FavouriteNoteEntityCreate, FavouriteTagDbError, FavouriteTagEntity,
+        FavouriteTagEntityCreate,
+    },
+    FavouriteTagsDatabase,
+};
+use crate::{
+    schema::{favourite, favourite_notes, favourite_tags},
+    Conn,
+};
+
+#[async_trait]
+impl FavouriteTagsDatabase for Conn {
+    async fn set_favourite_tags(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+        tags: Vec<String>,
+        created_at: i64,
+    ) -> Result<Vec<FavouriteTagEntity>, FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    check_favourite(db, user_uuid, favourite_uuid)?;
+                    diesel::delete(
+                        favourite_tags::table
+                            .filter(favourite_tags::user_uuid.eq(user_uuid))
+                            .filter(favourite_tags::favourite_uuid.eq(favourite_uuid)),
+                    )
+                    .execute(db)?;
+                    let tags: Vec<FavouriteTagEntityCreate> = tags
+                        .into_iter()
+                        .map(|tag| FavouriteTagEntityCreate {
+                            user_uuid,
+                            favourite_uuid,
+                            tag,
+                            created_at,
+                        })
+                        .collect();
+                    diesel::insert_into(favourite_tags::table)
+                        .values(&tags)
+                        .execute(db)?;
+                    favourite_tags::table
+                        .filter(favourite_tags::user_uuid.eq(user_uuid))
+                        .filter(favourite_tags::favourite_uuid.eq(favourite_uuid))
+                        .order(favourite_tags::tag.asc())
+                        .load::<FavouriteTagEntity>(db)
+                        .map_err(FavouriteTagDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn get_favourites_tags(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteTagEntity>, FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                favourite_tags::table
+                    .filter(favourite_tags::user_uuid.eq(user_uuid))
+                    .filter(favourite_tags::favourite_uuid.eq_any(favourites_uuid))
+                    .order(favourite_tags::tag.asc())
+                    .load::<FavouriteTagEntity>(db)
+            })
+            .await
+            .map_err(FavouriteTagDbError::from)
+    }
+
+    async fn get_user_tags(
+        &self,
+        user_uuid: Uuid,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(String, i64)>, FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                let kept_favourites = favourite::table
+                    .select(favourite::favourite_uuid)
+                    .filter(favourite::user_uuid.eq(user_uuid));
+                favourite_tags::table
+                    .filter(favourite_tags::user_uuid.eq(user_uuid))
+                    .filter(favourite_tags::favourite_uuid.eq_any(kept_favourites))
+                    .filter(favourite_tags::tag.ilike(format!("{}%", query)))
+                    .group_by(favourite_tags::tag)
+                    .select((favourite_tags::tag, count_star()))
+                    .order((count_star().desc(), favourite_tags::tag.asc()))
+                    .limit(limit)
+                    .load::<(String, i64)>(db)
+            })
+            .await
+            .map_err(FavouriteTagDbError::from)
+    }
+
+    async fn set_favourite_note(
+        &self,
+        note: FavouriteNoteEntityCreate,
+    ) -> Result<FavouriteNoteEntity, FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    check_favourite(db, note.user_uuid, note.favourite_uuid)?;
+                    diesel::insert_into(favourite_notes::table)
+                        .values(&note)
+                        .on_conflict((favourite_notes::user_uuid, favourite_notes::favourite_uuid))
+                        .do_update()
+                        .set((
+                            favourite_notes::note.eq(excluded(favourite_notes::note)),
+                            favourite_notes::updated_at.eq(excluded(favourite_notes::updated_at)),
+                        ))
+                        .get_result::<FavouriteNoteEntity>(db)
+                        .map_err(FavouriteTagDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn remove_favourite_note(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<(), FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                diesel::delete(
+                    favourite_notes::table
+                        .filter(favourite_notes::user_uuid.eq(user_uuid))
+                        .filter(favourite_notes::favourite_uuid.eq(favourite_uuid)),
+                )
+                .execute(db)
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn get_favourites_notes(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteNoteEntity>, FavouriteTagDbError> {
+        self.0
+            .run(move |db| {
+                favourite_notes::table
+                    .filter(favourite_notes::user_uuid.eq(user_uuid))
+                    .filter(favourite_notes::favourite_uuid.eq_any(favourites_uuid))
+                    .load::<FavouriteNoteEntity>(db)
+            })
+            .await
+            .map_err(FavouriteTagDbError::from)
+    }
+}
+
+/// Tags and notes may only be set on favourites the user keeps in one of their lists.
+fn check_favourite(
+    db: &mut PgConnection,
+    user_uuid: Uuid,
+    favourite_uuid: Uuid,
+) -> Result<(), FavouriteTagDbError> {
+    let is_favourite = diesel::select(exists(
+        favourite::table
+            .filter(favourite::user_uuid.eq(user_uuid))
+            .filter(favourite::favourite_uuid.eq(favourite_uuid)),
+    ))
+    .get_result::<bool>(db)?;
+    if is_favourite {
+        Ok(())
+    } else {
+        Err(FavouriteTagDbError::EntryNotFound)
+    }
+}
@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use self::objects::{
+    FavouriteNoteEntity, FavouriteNoteEntityCreate, FavouriteTagDbError, FavouriteTagEntity,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteTagsDatabase {
+    /// Replaces the tags of the favourite, which has to be in one of the user's lists,
+    /// otherwise `EntryNotFound`.
+    async fn set_favourite_tags(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+        tags: Vec<String>,
+        created_at: i64,
+    ) -> Result<Vec<FavouriteTagEntity>, FavouriteTagDbError>;
+    /// Tags `user_uuid` attached to any of `favourites_uuid`.
+    async fn get_favourites_tags(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteTagEntity>, FavouriteTagDbError>;
+    /// Tags of the user starting with `query` and how many favourites carry them, the most
+    /// used first. Tags of favourites the user no longer has are left out.
+    async fn get_user_tags(
+        &self,
+        user_uuid: Uuid,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(String, i64)>, FavouriteTagDbError>;
+    /// Inserts or replaces the note, the favourite has to be in one of the user's lists,
+    /// otherwise `EntryNotFound`.
+    async fn set_favourite_note(
+        &self,
+        note: FavouriteNoteEntityCreate,
+    ) -> Result<FavouriteNoteEntity, FavouriteTagDbError>;
+    async fn remove_favourite_note(
+        &self,
+        user_uuid: Uuid,
+        favourite_uuid: Uuid,
+    ) -> Result<(), FavouriteTagDbError>;
+    /// Notes `user_uuid` wrote on any of `favourites_uuid`.
+    async fn get_favourites_notes(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteNoteEntity>, FavouriteTagDbError>;
+}
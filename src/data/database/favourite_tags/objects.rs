@@ -0,0 +1,57 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::{favourite_notes, favourite_tags};
+
+/// A free-form tag the user attached to a favourite, keyed by its uuid.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteTagEntity {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub tag: String,
+    pub created_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_tags)]
+pub struct FavouriteTagEntityCreate {
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub tag: String,
+    pub created_at: i64,
+}
+
+/// A note on a favourite, only ever shown to the user who wrote it.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteNoteEntity {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub note: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = favourite_notes)]
+pub struct FavouriteNoteEntityCreate {
+    pub user_uuid: Uuid,
+    pub favourite_uuid: Uuid,
+    pub note: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteTagDbError {
+    EntryNotFound,
+    InternalError,
+}
+
+impl From<Error> for FavouriteTagDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database favourite tags error: {:?}", err);
+        FavouriteTagDbError::InternalError
+    }
+}
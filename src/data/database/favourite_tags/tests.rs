@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        favourite::{objects::FavouriteEntityFilter, UserFavouritesDatabase},
+        favourite_tags::{
+            objects::{FavouriteNoteEntityCreate, FavouriteTagDbError},
+            FavouriteTagsDatabase,
+        },
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_favourite_tags_and_autocomplete() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let (alien, brazil, removed) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        for (favourite_uuid, title) in [(alien, "Alien"), (brazil, "Brazil"), (removed, "Dune")] {
+            connection
+                .add_list_entry(user, None, favourite_uuid, title.to_string())
+                .await
+                .unwrap();
+        }
+        let tags = |tags: &[&str]| tags.iter().map(|tag| tag.to_string()).collect();
+
+        connection
+            .set_favourite_tags(user, alien, tags(&["scary", "rainy day"]), 0)
+            .await
+            .unwrap();
+        let replaced = connection
+            .set_favourite_tags(user, alien, tags(&["scary", "space"]), 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            replaced.iter().map(|v| v.tag.as_str()).collect::<Vec<_>>(),
+            vec!["scary", "space"]
+        );
+        for favourite_uuid in [brazil, removed] {
+            connection
+                .set_favourite_tags(user, favourite_uuid, tags(&["satire"]), 0)
+                .await
+                .unwrap();
+        }
+        connection
+            .remove_list_entry(user, None, removed)
+            .await
+            .unwrap();
+
+        let suggested = connection
+            .get_user_tags(user, "s".to_string(), 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            suggested,
+            vec![
+                ("satire".to_string(), 1),
+                ("scary".to_string(), 1),
+                ("space".to_string(), 1)
+            ]
+        );
+        let tagged = connection
+            .get_list_entries(
+                user,
+                None,
+                String::new(),
+                FavouriteEntityFilter {
+                    tags: tags(&["scary", "space"]),
+                    ..Default::default()
+                },
+                1,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(tagged.total, 1);
+        assert_eq!(tagged.result[0].favourite_uuid, alien);
+
+        let favourites_tags = connection
+            .get_favourites_tags(user, vec![alien, brazil])
+            .await
+            .unwrap();
+        assert_eq!(favourites_tags.len(), 3);
+
+        let missing = connection
+            .set_favourite_tags(user, Uuid::new_v4(), tags(&["scary"]), 0)
+            .await;
+        assert!(matches!(missing, Err(FavouriteTagDbError::EntryNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_favourite_note_upsert_and_remove() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let favourite_uuid = Uuid::new_v4();
+        connection
+            .add_list_entry(user, None, favourite_uuid, "Alien".to_string())
+            .await
+            .unwrap();
+        let note = |note: &str, updated_at: i64| FavouriteNoteEntityCreate {
+            user_uuid: user,
+            favourite_uuid,
+            note: note.to_string(),
+            created_at: updated_at,
+            updated_at,
+        };
+
+        connection
+            .set_favourite_note(note("watch with Sam", 1))
+            .await
+            .unwrap();
+        let updated = connection
+            .set_favourite_note(note("watched with Sam", 2))
+            .await
+            .unwrap();
+        assert_eq!(
+            (
+                updated.note.as_str(),
+                updated.created_at,
+                updated.updated_at
+            ),
+            ("watched with Sam", 1, 2)
+        );
+
+        connection
+            .remove_favourite_note(user, favourite_uuid)
+            .await
+            .unwrap();
+        let notes = connection
+            .get_favourites_notes(user, vec![favourite_uuid])
+            .await
+            .unwrap();
+        assert!(notes.is_empty());
+
+        let missing = connection
+            .set_favourite_note(FavouriteNoteEntityCreate {
+                favourite_uuid: Uuid::new_v4(),
+                ..note("nothing", 3)
+            })
+            .await;
+        assert!(matches!(missing, Err(FavouriteTagDbError::EntryNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_tags_and_note_removed_with_last_entry() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let user = Uuid::new_v4();
+        let favourite_uuid = Uuid::new_v4();
+        connection
+            .add_list_entry(user, None, favourite_uuid, "Alien".to_string())
+            .await
+            .unwrap();
+        connection
+            .set_favourite_tags(user, favourite_uuid, vec!["scifi".to_string()], 1)
+            .await
+            .unwrap();
+        connection
+            .set_favourite_note(FavouriteNoteEntityCreate {
+                user_uuid: user,
+                favourite_uuid,
+                note: "watch with Sam".to_string(),
+                created_at: 1,
+                updated_at: 1,
+            })
+            .await
+            .unwrap();
+
+        connection
+            .remove_list_entry(user, None, favourite_uuid)
+            .await
+            .unwrap();
+        connection
+            .add_list_entry(user, None, favourite_uuid, "Alien".to_string())
+            .await
+            .unwrap();
+        let tags = connection
+            .get_favourites_tags(user, vec![favourite_uuid])
+            .await
+            .unwrap();
+        assert!(tags.is_empty());
+        let notes = connection
+            .get_favourites_notes(user, vec![favourite_uuid])
+            .await
+            .unwrap();
+        assert!(notes.is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use super::{
+    objects::{FavouriteTrendDbError, FavouriteTrendEntity},
+    FavouriteTrendsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    schema::{favourite_following_trends, favourite_trends},
+    Conn,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use uuid::Uuid;
+
+#[async_trait]
+impl FavouriteTrendsDatabase for Conn {
+    async fn refresh_trends(&self) -> Result<(), FavouriteTrendDbError> {
+        self.0
+            .run(move |db| {
+                diesel::sql_query("REFRESH MATERIALIZED VIEW CONCURRENTLY favourite_trends")
+                    .execute(db)?;
+                diesel::sql_query(
+                    "REFRESH MATERIALIZED VIEW CONCURRENTLY favourite_following_trends",
+                )
+                .execute(db)
+            })
+            .await
+            .map(|_| ())
+            .map_err(FavouriteTrendDbError::from)
+    }
+
+    async fn get_trends(
+        &self,
+        period: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteTrendEntity>, FavouriteTrendDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let filtered = || {
+                    let query_request = favourite_trends::table.into_boxed();
+                    match period.as_str() {
+                        "day" => query_request.filter(favourite_trends::day_count.gt(0)),
+                        "week" => query_request.filter(favourite_trends::week_count.gt(0)),
+                        _ => query_request,
+                    }
+                };
+                let total: i64 = filtered().count().get_result(db)?;
+                let result = match period.as_str() {
+                    "day" => filtered().order(favourite_trends::day_count.desc()),
+                    "week" => filtered().order(favourite_trends::week_count.desc()),
+                    _ => filtered().order(favourite_trends::all_count.desc()),
+                }
+                .then_order_by(favourite_trends::item_uuid.asc())
+                .limit(page_size)
+                .offset(offset)
+                .load::<FavouriteTrendEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+
+    async fn get_following_trends(
+        &self,
+        follower_uuid: Uuid,
+        period: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteTrendEntity>, FavouriteTrendDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let filtered = || {
+                    let query_request = favourite_following_trends::table
+                        .filter(favourite_following_trends::follower_uuid.eq(follower_uuid))
+                        .into_boxed();
+                    match period.as_str() {
+                        "day" => query_request.filter(favourite_following_trends::day_count.gt(0)),
+                        "week" => {
+                            query_request.filter(favourite_following_trends::week_count.gt(0))
+                        }
+                        _ => query_request,
+                    }
+                };
+                let total: i64 = filtered().count().get_result(db)?;
+                let result = match period.as_str() {
+                    "day" => filtered().order(favourite_following_trends::day_count.desc()),
+                    "week" => filtered().order(favourite_following_trends::week_count.desc()),
+                    _ => filtered().order(favourite_following_trends::all_count.desc()),
+                }
+                .then_order_by(favourite_following_trends::item_uuid.asc())
+                .select((
+                    favourite_following_trends::item_uuid,
+                    favourite_following_trends::title,
+                    favourite_following_trends::day_count,
+                    favourite_following_trends::week_count,
+                    favourite_following_trends::all_count,
+                    favourite_following_trends::refreshed_at,
+                ))
+                .limit(page_size)
+                .offset(offset)
+                .load::<FavouriteTrendEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{FavouriteTrendDbError, FavouriteTrendEntity};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteTrendsDatabase {
+    /// Recomputes both trend aggregates without blocking readers.
+    async fn refresh_trends(&self) -> Result<(), FavouriteTrendDbError>;
+    /// Items favourited within `period` (`day`, `week`, anything else meaning all time),
+    /// most favourited first.
+    async fn get_trends(
+        &self,
+        period: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteTrendEntity>, FavouriteTrendDbError>;
+    /// Same as `get_trends`, counting only the users `follower_uuid` follows.
+    async fn get_following_trends(
+        &self,
+        follower_uuid: Uuid,
+        period: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteTrendEntity>, FavouriteTrendDbError>;
+}
@@ -0,0 +1,25 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+/// A row of the `favourite_trends` aggregate, counts are distinct users.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct FavouriteTrendEntity {
+    pub item_uuid: Uuid,
+    pub title: String,
+    pub day_count: i64,
+    pub week_count: i64,
+    pub all_count: i64,
+    pub refreshed_at: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteTrendDbError {
+    InternalError,
+}
+
+impl From<Error> for FavouriteTrendDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database favourite trends error: {:?}", err);
+        FavouriteTrendDbError::InternalError
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        favourite::UserFavouritesDatabase,
+        favourite_trends::FavouriteTrendsDatabase,
+        follow::{objects::FollowEntityCreate, FollowDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_following_trends_count_followed_users() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let follower_uuid = Uuid::new_v4();
+        let followed = [Uuid::new_v4(), Uuid::new_v4()];
+        let shared_item = Uuid::new_v4().to_string();
+        let single_item = Uuid::new_v4().to_string();
+        for followed_uuid in followed {
+            connection
+                .follow_user(&FollowEntityCreate {
+                    follower_uuid,
+                    followed_uuid,
+                    followed_username: "followed".to_string(),
+                    follower_username: "follower".to_string(),
+                    followed_avatar_url: "".to_string(),
+                    follower_avatar_url: "".to_string(),
+                })
+                .await
+                .unwrap();
+            connection
+                .add_favourite(&followed_uuid.to_string(), &shared_item, "shared")
+                .await
+                .unwrap();
+        }
+        connection
+            .add_favourite(&followed[0].to_string(), &single_item, "single")
+            .await
+            .unwrap();
+
+        connection.refresh_trends().await.unwrap();
+        for period in ["day", "week", "all"] {
+            let trends = connection
+                .get_following_trends(follower_uuid, period.to_string(), 1, 10)
+                .await
+                .unwrap();
+            assert_eq!(trends.total, 2);
+            assert_eq!(trends.result[0].item_uuid.to_string(), shared_item);
+            assert_eq!(trends.result[0].week_count, 2);
+            assert_eq!(trends.result[1].item_uuid.to_string(), single_item);
+            assert_eq!(trends.result[1].all_count, 1);
+        }
+
+        let trends = connection
+            .get_trends("day".to_string(), 1, 10)
+            .await
+            .unwrap();
+        assert!(trends.total >= 2);
+        assert!(trends.result.iter().all(|trend| trend.day_count > 0));
+    }
+}
@@ -1,4 +1,7 @@
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
 use uuid::Uuid;
 
 use crate::{
@@ -51,22 +54,48 @@ impl FollowDatabase for Conn {
         record: &'a FollowEntityCreate,
     ) -> Result<FollowerEntity, FollowDataError> {
         let record = record.to_owned();
-        if self
-            .is_following_uuid(&record.follower_uuid, &record.followed_uuid)
-            .await?
-        {
-            return Result::Err(FollowDataError::Conflict);
-        };
         self.0
             .run(move |db| {
                 diesel::insert_into(follow::table)
                     .values(record)
-                    .get_result(db)
+                    .on_conflict((follow::follower_uuid, follow::followed_uuid))
+                    .do_nothing()
+                    .get_result::<FollowerEntity>(db)
+                    .optional()
             })
             .await
             .map_err(|err| {
                 eprintln!("Error following user: {}", err);
                 FollowDataError::InternalError
+            })?
+            .ok_or(FollowDataError::Conflict)
+    }
+
+    async fn follow_users<'a>(
+        &self,
+        records: &'a [FollowEntityCreate],
+    ) -> Result<Vec<Option<FollowerEntity>>, FollowDataError> {
+        let records = records.to_owned();
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    records
+                        .into_iter()
+                        .map(|record| {
+                            diesel::insert_into(follow::table)
+                                .values(record)
+                                .on_conflict((follow::follower_uuid, follow::followed_uuid))
+                                .do_nothing()
+                                .get_result::<FollowerEntity>(db)
+                                .optional()
+                        })
+                        .collect::<Result<Vec<_>, diesel::result::Error>>()
+                })
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error following users: {}", err);
+                FollowDataError::InternalError
             })
     }
 
@@ -96,6 +125,37 @@ impl FollowDatabase for Conn {
             .await
     }
 
+    async fn un_follow_users<'a>(
+        &self,
+        follower_uuid: &'a Uuid,
+        followed_uuids: &'a [Uuid],
+    ) -> Result<Vec<bool>, FollowDataError> {
+        let follower_uuid = *follower_uuid;
+        let followed_uuids = followed_uuids.to_owned();
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    followed_uuids
+                        .into_iter()
+                        .map(|followed_uuid| {
+                            diesel::delete(
+                                follow::table
+                                    .filter(follow::follower_uuid.eq(follower_uuid))
+                                    .filter(follow::followed_uuid.eq(followed_uuid)),
+                            )
+                            .execute(db)
+                            .map(|count| count > 0)
+                        })
+                        .collect::<Result<Vec<_>, diesel::result::Error>>()
+                })
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error unfollowing users: {}", err);
+                FollowDataError::InternalError
+            })
+    }
+
     async fn is_following<'a>(
         &self,
         follower_uuid: &'a str,
@@ -127,6 +187,77 @@ impl FollowDatabase for Conn {
             .await
     }
 
+    async fn get_followed_users<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+        uuids: &'a [Uuid],
+    ) -> Result<Vec<Uuid>, FollowDataError> {
+        let user_uuid = *user_uuid;
+        let uuids = uuids.to_owned();
+        self.0
+            .run(move |db| {
+                follow::table
+                    .select(follow::followed_uuid)
+                    .filter(follow::follower_uuid.eq(user_uuid))
+                    .filter(follow::followed_uuid.eq_any(&uuids))
+                    .load::<Uuid>(db)
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting followed users: {}", err);
+                FollowDataError::InternalError
+            })
+    }
+
+    async fn get_all_following<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+    ) -> Result<Vec<FollowerEntity>, FollowDataError> {
+        let user_uuid = *user_uuid;
+        self.0
+            .run(move |db| {
+                follow::table
+                    .filter(follow::follower_uuid.eq(user_uuid))
+                    .load::<FollowerEntity>(db)
+            })
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting all following: {}", err);
+                FollowDataError::InternalError
+            })
+    }
+
+    async fn get_connected_users<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+        uuids: &'a [Uuid],
+    ) -> Result<Vec<Uuid>, FollowDataError> {
+        let user_uuid = *user_uuid;
+        let uuids = uuids.to_owned();
+        self.0
+            .run(move |db| {
+                let mut followed = follow::table
+                    .select(follow::followed_uuid)
+                    .filter(follow::follower_uuid.eq(user_uuid))
+                    .filter(follow::followed_uuid.eq_any(&uuids))
+                    .load::<Uuid>(db)?;
+                let followers = follow::table
+                    .select(follow::follower_uuid)
+                    .filter(follow::followed_uuid.eq(user_uuid))
+                    .filter(follow::follower_uuid.eq_any(&uuids))
+                    .load::<Uuid>(db)?;
+                followed.extend(followers);
+                followed.sort();
+                followed.dedup();
+                Ok(followed)
+            })
+            .await
+            .map_err(|err: diesel::result::Error| {
+                eprintln!("Error getting connected users: {}", err);
+                FollowDataError::InternalError
+            })
+    }
+
     async fn get_user_following<'a>(
         &self,
         request: &'a PagingDomainRequest<'a>,
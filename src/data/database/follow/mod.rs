@@ -19,11 +19,20 @@ pub trait FollowDatabase {
         &self,
         record: &'a FollowEntityCreate,
     ) -> Result<FollowerEntity, FollowDataError>;
+    async fn follow_users<'a>(
+        &self,
+        records: &'a [FollowEntityCreate],
+    ) -> Result<Vec<Option<FollowerEntity>>, FollowDataError>;
     async fn un_follow_user<'a>(
         &self,
         follower_uuid: &'a str,
         followed_uuid: &'a str,
     ) -> Result<(), FollowDataError>;
+    async fn un_follow_users<'a>(
+        &self,
+        follower_uuid: &'a Uuid,
+        followed_uuids: &'a [Uuid],
+    ) -> Result<Vec<bool>, FollowDataError>;
     async fn is_following<'a>(
         &self,
         follower_uuid: &'a str,
@@ -34,6 +43,23 @@ pub trait FollowDatabase {
         follower_uuid: &'a Uuid,
         followed_uuid: &'a Uuid,
     ) -> Result<bool, FollowDataError>;
+    /// Returns those of `uuids` that `user_uuid` follows.
+    async fn get_followed_users<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+        uuids: &'a [Uuid],
+    ) -> Result<Vec<Uuid>, FollowDataError>;
+    /// Returns every user `user_uuid` follows.
+    async fn get_all_following<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+    ) -> Result<Vec<FollowerEntity>, FollowDataError>;
+    /// Returns those of `uuids` that `user_uuid` follows or is followed by.
+    async fn get_connected_users<'a>(
+        &self,
+        user_uuid: &'a Uuid,
+        uuids: &'a [Uuid],
+    ) -> Result<Vec<Uuid>, FollowDataError>;
     async fn get_user_followers<'a>(
         &self,
         request: &'a PagingDomainRequest<'a>,
@@ -97,4 +97,92 @@ mod tests {
 
         assert!(follow_get_result.eq(&(current_count + 1)));
     }
+
+    #[tokio::test]
+    async fn test_follow_users_batch() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let current_uuid = Uuid::new_v4();
+        let followed_uuids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+
+        let records: Vec<FollowEntityCreate> = followed_uuids
+            .iter()
+            .map(|followed_uuid| FollowEntityCreate {
+                follower_uuid: current_uuid,
+                followed_uuid: *followed_uuid,
+                follower_username: "follower_username".to_owned(),
+                followed_username: "followed_username".to_owned(),
+                follower_avatar_url: "follower_avatar_url".to_owned(),
+                followed_avatar_url: "followed_avatar_url".to_owned(),
+            })
+            .collect();
+
+        let first_result = connection.follow_users(&records[..1]).await;
+        assert!(first_result.is_ok());
+        assert!(first_result.unwrap()[0].is_some());
+
+        let batch_result = connection.follow_users(&records).await;
+        assert!(batch_result.is_ok());
+        let batch_result = batch_result.unwrap();
+        assert!(batch_result[0].is_none());
+        assert!(batch_result[1].is_some());
+        assert!(batch_result[2].is_some());
+
+        let count = connection
+            .get_following_count(&current_uuid.to_string())
+            .await;
+        assert_eq!(count.unwrap(), 3);
+
+        let single_result = connection.follow_user(&records[0]).await;
+        assert!(single_result.is_err());
+
+        let un_follow_result = connection
+            .un_follow_users(&current_uuid, &[followed_uuids[0], Uuid::new_v4()])
+            .await;
+        assert_eq!(un_follow_result.unwrap(), vec![true, false]);
+
+        let count = connection
+            .get_following_count(&current_uuid.to_string())
+            .await;
+        assert_eq!(count.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_connected_users() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let current_uuid = Uuid::new_v4();
+        let followed_uuid = Uuid::new_v4();
+        let follower_uuid = Uuid::new_v4();
+        let stranger_uuid = Uuid::new_v4();
+
+        let follow_entity = |follower_uuid: Uuid, followed_uuid: Uuid| FollowEntityCreate {
+            follower_uuid,
+            followed_uuid,
+            follower_username: "follower_username".to_owned(),
+            followed_username: "followed_username".to_owned(),
+            follower_avatar_url: "follower_avatar_url".to_owned(),
+            followed_avatar_url: "followed_avatar_url".to_owned(),
+        };
+        connection
+            .follow_user(&follow_entity(current_uuid, followed_uuid))
+            .await
+            .unwrap();
+        connection
+            .follow_user(&follow_entity(follower_uuid, current_uuid))
+            .await
+            .unwrap();
+
+        let mut connected = connection
+            .get_connected_users(
+                &current_uuid,
+                &[followed_uuid, follower_uuid, stranger_uuid],
+            )
+            .await
+            .unwrap();
+        connected.sort();
+        let mut expected = vec![followed_uuid, follower_uuid];
+        expected.sort();
+        assert_eq!(connected, expected);
+    }
 }
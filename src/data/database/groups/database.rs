@@ -0,0 +1,174 @@
+use super::{
+    objects::{
+        GroupDbError, GroupEntity, GroupEntityCreate, GroupEntityUpdate, GroupMemberEntity,
+        GroupMemberEntityCreate,
+    },
+    GroupsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::{correct_page_number, parce_uuid},
+        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+    },
+    schema::{group_members, groups},
+    Conn,
+};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, PgTextExpressionMethods,
+    QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl GroupsDatabase for Conn {
+    async fn add_group(
+        &self,
+        group: GroupEntityCreate,
+        members_uuid: Vec<Uuid>,
+    ) -> Result<GroupEntity, GroupDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let created = diesel::insert_into(groups::table)
+                        .values(&group)
+                        .get_result::<GroupEntity>(db)?;
+                    insert_members(db, created.uuid, members_uuid, created.created_at)?;
+                    Ok(created)
+                })
+            })
+            .await
+    }
+
+    async fn get_group(&self, group_uuid: Uuid) -> Result<GroupEntity, GroupDbError> {
+        self.0
+            .run(move |db| {
+                groups::table
+                    .filter(groups::uuid.eq(group_uuid))
+                    .first::<GroupEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(GroupDbError::GroupNotFound)
+    }
+
+    async fn get_groups<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<GroupEntity>, GroupDbError> {
+        let owner_uuid = parce_uuid(request.request_uuid).map_err(|_| GroupDbError::UuidInvalid)?;
+        let query = request.query.to_owned();
+        let page_number = correct_page_number(request.page);
+        let limit = request.page_size;
+        let offset = page_number * request.page_size;
+        let page_size = request.page_size;
+
+        self.0
+            .run(move |db| {
+                let query_request = groups::table
+                    .filter(groups::owner_uuid.eq(owner_uuid))
+                    .filter(groups::title.ilike(format!("%{}%", query)));
+
+                let results = query_request
+                    .to_owned()
+                    .order((groups::title.asc(), groups::uuid.asc()))
+                    .limit(limit)
+                    .offset(offset)
+                    .load::<GroupEntity>(db)?;
+
+                let total_result = query_request.count().get_result(db)?;
+
+                let result_count = i64::try_from(results.len()).map_err(|err| {
+                    eprintln!("Error converting result count: {}", err);
+                    GroupDbError::InternalError
+                })?;
+
+                Ok(PagingDomainResponse {
+                    total: total_result,
+                    result: results,
+                    page: page_number + 1,
+                    page_size,
+                    has_more: offset + result_count < total_result,
+                })
+            })
+            .await
+    }
+
+    async fn get_group_members(
+        &self,
+        groups_uuid: Vec<Uuid>,
+    ) -> Result<Vec<GroupMemberEntity>, GroupDbError> {
+        self.0
+            .run(move |db| {
+                group_members::table
+                    .filter(group_members::group_uuid.eq_any(groups_uuid))
+                    .order((
+                        group_members::created_at.asc(),
+                        group_members::user_uuid.asc(),
+                    ))
+                    .load::<GroupMemberEntity>(db)
+            })
+            .await
+            .map_err(GroupDbError::from)
+    }
+
+    async fn update_group(
+        &self,
+        group_uuid: Uuid,
+        update: GroupEntityUpdate,
+        members_uuid: Option<Vec<Uuid>>,
+    ) -> Result<GroupEntity, GroupDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let updated = diesel::update(groups::table.filter(groups::uuid.eq(group_uuid)))
+                        .set(&update)
+                        .get_result::<GroupEntity>(db)
+                        .optional()?
+                        .ok_or(GroupDbError::GroupNotFound)?;
+                    if let Some(members_uuid) = members_uuid {
+                        diesel::delete(
+                            group_members::table.filter(group_members::group_uuid.eq(group_uuid)),
+                        )
+                        .execute(db)?;
+                        insert_members(db, group_uuid, members_uuid, update.updated_at)?;
+                    }
+                    Ok(updated)
+                })
+            })
+            .await
+    }
+
+    async fn delete_group(&self, group_uuid: Uuid) -> Result<(), GroupDbError> {
+        self.0
+            .run(move |db| {
+                diesel::delete(groups::table.filter(groups::uuid.eq(group_uuid))).execute(db)
+            })
+            .await
+            .map_err(GroupDbError::from)
+            .and_then(|count| match count {
+                0 => Err(GroupDbError::GroupNotFound),
+                _ => Ok(()),
+            })
+    }
+}
+
+fn insert_members(
+    db: &mut PgConnection,
+    group_uuid: Uuid,
+    members_uuid: Vec<Uuid>,
+    created_at: i64,
+) -> Result<usize, GroupDbError> {
+    let members: Vec<GroupMemberEntityCreate> = members_uuid
+        .into_iter()
+        .map(|user_uuid| GroupMemberEntityCreate {
+            group_uuid,
+            user_uuid,
+            created_at,
+        })
+        .collect();
+    diesel::insert_into(group_members::table)
+        .values(&members)
+        .on_conflict_do_nothing()
+        .execute(db)
+        .map_err(GroupDbError::from)
+}
@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::{PagingDomainRequest, PagingDomainResponse};
+
+use self::objects::{
+    GroupDbError, GroupEntity, GroupEntityCreate, GroupEntityUpdate, GroupMemberEntity,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait GroupsDatabase {
+    /// Inserts the group together with its members in one transaction.
+    async fn add_group(
+        &self,
+        group: GroupEntityCreate,
+        members_uuid: Vec<Uuid>,
+    ) -> Result<GroupEntity, GroupDbError>;
+    async fn get_group(&self, group_uuid: Uuid) -> Result<GroupEntity, GroupDbError>;
+    /// Groups owned by `request.request_uuid` whose title matches `request.query`.
+    async fn get_groups<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<GroupEntity>, GroupDbError>;
+    async fn get_group_members(
+        &self,
+        groups_uuid: Vec<Uuid>,
+    ) -> Result<Vec<GroupMemberEntity>, GroupDbError>;
+    /// Updates the group and, when `members_uuid` is given, replaces its members.
+    async fn update_group(
+        &self,
+        group_uuid: Uuid,
+        update: GroupEntityUpdate,
+        members_uuid: Option<Vec<Uuid>>,
+    ) -> Result<GroupEntity, GroupDbError>;
+    async fn delete_group(&self, group_uuid: Uuid) -> Result<(), GroupDbError>;
+}
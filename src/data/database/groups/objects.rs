@@ -0,0 +1,60 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::{group_members, groups};
+
+/// A named set of users its owner follows, used to fill match participants.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct GroupEntity {
+    pub uuid: Uuid,
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = groups)]
+pub struct GroupEntityCreate {
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = groups)]
+pub struct GroupEntityUpdate {
+    pub title: Option<String>,
+    pub updated_at: i64,
+}
+
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct GroupMemberEntity {
+    pub uuid: Uuid,
+    pub group_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub created_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = group_members)]
+pub struct GroupMemberEntityCreate {
+    pub group_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum GroupDbError {
+    UuidInvalid,
+    GroupNotFound,
+    InternalError,
+}
+
+impl From<Error> for GroupDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database groups error: {:?}", err);
+        GroupDbError::InternalError
+    }
+}
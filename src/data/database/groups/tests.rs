@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::{
+        database::{
+            groups::{
+                objects::{GroupDbError, GroupEntityCreate, GroupEntityUpdate},
+                GroupsDatabase,
+            },
+            tests::database_test_utls::run_migration_get_conn,
+        },
+        repository::objects::PagingDomainRequest,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_group_members_update_and_delete() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let owner = Uuid::new_v4();
+        let members: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let group = connection
+            .add_group(
+                GroupEntityCreate {
+                    owner_uuid: owner,
+                    title: "friends".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                },
+                members[..2].to_vec(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(connection.get_group(group.uuid).await.unwrap(), group);
+
+        let stored: Vec<Uuid> = connection
+            .get_group_members(vec![group.uuid])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|member| member.user_uuid)
+            .collect();
+        assert_eq!(stored.len(), 2);
+        assert!(members[..2].iter().all(|uuid| stored.contains(uuid)));
+
+        let renamed = connection
+            .update_group(
+                group.uuid,
+                GroupEntityUpdate {
+                    title: Some("family".to_string()),
+                    updated_at: current_time_ms + 1,
+                },
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(renamed.title, "family");
+        assert_eq!(
+            connection
+                .get_group_members(vec![group.uuid])
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        connection
+            .update_group(
+                group.uuid,
+                GroupEntityUpdate {
+                    title: None,
+                    updated_at: current_time_ms + 2,
+                },
+                Some(vec![members[2]]),
+            )
+            .await
+            .unwrap();
+        let replaced: Vec<Uuid> = connection
+            .get_group_members(vec![group.uuid])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|member| member.user_uuid)
+            .collect();
+        assert_eq!(replaced, vec![members[2]]);
+
+        let owner_uuid = owner.to_string();
+        let request = PagingDomainRequest {
+            user_uuid: &owner_uuid,
+            request_uuid: &owner_uuid,
+            query: "fam",
+            page: 1,
+            page_size: 10,
+        };
+        let page = connection.get_groups(&request).await.unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.result[0].uuid, group.uuid);
+
+        connection.delete_group(group.uuid).await.unwrap();
+        assert!(matches!(
+            connection.get_group(group.uuid).await,
+            Err(GroupDbError::GroupNotFound)
+        ));
+        assert!(connection
+            .get_group_members(vec![group.uuid])
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            connection.delete_group(group.uuid).await,
+            Err(GroupDbError::GroupNotFound)
+        ));
+    }
+}
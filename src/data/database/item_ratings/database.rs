@@ -0,0 +1,148 @@
+use diesel::{
+    dsl::{count_star, exists},
+    upsert::excluded,
+    Connection, ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+use super::{
+    objects::{ItemRatingDbError, ItemRatingEntity, ItemRatingEntityCreate, ItemReviewEntity},
+    ItemRatingsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    schema::{favourite, follow, item_ratings, items},
+    Conn,
+};
+
+#[async_trait]
+impl ItemRatingsDatabase for Conn {
+    async fn set_rating(
+        &self,
+        rating: ItemRatingEntityCreate,
+    ) -> Result<ItemRatingEntity, ItemRatingDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let is_item = diesel::select(exists(items::table.find(rating.item_uuid)))
+                        .get_result::<bool>(db)?;
+                    let is_favourite = diesel::select(exists(
+                        favourite::table
+                            .filter(favourite::user_uuid.eq(rating.user_uuid))
+                            .filter(favourite::favourite_uuid.eq(rating.item_uuid)),
+                    ))
+                    .get_result::<bool>(db)?;
+                    if !is_item && !is_favourite {
+                        return Err(ItemRatingDbError::ItemNotFound);
+                    }
+                    diesel::insert_into(item_ratings::table)
+                        .values(&rating)
+                        .on_conflict((item_ratings::user_uuid, item_ratings::item_uuid))
+                        .do_update()
+                        .set((
+                            item_ratings::rating.eq(excluded(item_ratings::rating)),
+                            item_ratings::review.eq(excluded(item_ratings::review)),
+                            item_ratings::updated_at.eq(excluded(item_ratings::updated_at)),
+                        ))
+                        .get_result::<ItemRatingEntity>(db)
+                        .map_err(ItemRatingDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn remove_rating(
+        &self,
+        user_uuid: Uuid,
+        item_uuid: Uuid,
+    ) -> Result<(), ItemRatingDbError> {
+        self.0
+            .run(move |db| {
+                diesel::delete(
+                    item_ratings::table
+                        .filter(item_ratings::user_uuid.eq(user_uuid))
+                        .filter(item_ratings::item_uuid.eq(item_uuid)),
+                )
+                .execute(db)
+            })
+            .await
+            .map_err(ItemRatingDbError::from)
+            .and_then(|count| match count {
+                0 => Err(ItemRatingDbError::RatingNotFound),
+                _ => Ok(()),
+            })
+    }
+
+    async fn get_user_ratings(
+        &self,
+        user_uuid: Uuid,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemRatingEntity>, ItemRatingDbError> {
+        self.0
+            .run(move |db| {
+                item_ratings::table
+                    .filter(item_ratings::user_uuid.eq(user_uuid))
+                    .filter(item_ratings::item_uuid.eq_any(items_uuid))
+                    .load::<ItemRatingEntity>(db)
+            })
+            .await
+            .map_err(ItemRatingDbError::from)
+    }
+
+    async fn get_rating_distribution(
+        &self,
+        item_uuid: Uuid,
+    ) -> Result<Vec<(i16, i64)>, ItemRatingDbError> {
+        self.0
+            .run(move |db| {
+                item_ratings::table
+                    .filter(item_ratings::item_uuid.eq(item_uuid))
+                    .group_by(item_ratings::rating)
+                    .select((item_ratings::rating, count_star()))
+                    .order(item_ratings::rating.asc())
+                    .load::<(i16, i64)>(db)
+            })
+            .await
+            .map_err(ItemRatingDbError::from)
+    }
+
+    async fn get_followed_reviews(
+        &self,
+        request_uuid: Uuid,
+        item_uuid: Uuid,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemReviewEntity>, ItemRatingDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let query_request = item_ratings::table
+                    .inner_join(follow::table.on(follow::followed_uuid.eq(item_ratings::user_uuid)))
+                    .filter(follow::follower_uuid.eq(request_uuid))
+                    .filter(item_ratings::item_uuid.eq(item_uuid));
+                let total: i64 = query_request.count().get_result(db)?;
+                let result = query_request
+                    .select((
+                        item_ratings::all_columns,
+                        follow::followed_username,
+                        follow::followed_avatar_url,
+                    ))
+                    .order((item_ratings::updated_at.desc(), item_ratings::uuid.asc()))
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<ItemReviewEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+}
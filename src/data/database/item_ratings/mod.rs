@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{
+    ItemRatingDbError, ItemRatingEntity, ItemRatingEntityCreate, ItemReviewEntity,
+};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait ItemRatingsDatabase {
+    /// Inserts or replaces the rating of the user. The item has to be in the catalogue
+    /// or among the user's favourites, otherwise `ItemNotFound`.
+    async fn set_rating(
+        &self,
+        rating: ItemRatingEntityCreate,
+    ) -> Result<ItemRatingEntity, ItemRatingDbError>;
+    async fn remove_rating(
+        &self,
+        user_uuid: Uuid,
+        item_uuid: Uuid,
+    ) -> Result<(), ItemRatingDbError>;
+    /// Ratings `user_uuid` gave to any of `items_uuid`.
+    async fn get_user_ratings(
+        &self,
+        user_uuid: Uuid,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemRatingEntity>, ItemRatingDbError>;
+    /// Amount of ratings per rating value, values nobody gave are left out.
+    async fn get_rating_distribution(
+        &self,
+        item_uuid: Uuid,
+    ) -> Result<Vec<(i16, i64)>, ItemRatingDbError>;
+    /// Ratings of the item by users `request_uuid` follows, most recently updated first.
+    async fn get_followed_reviews(
+        &self,
+        request_uuid: Uuid,
+        item_uuid: Uuid,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemReviewEntity>, ItemRatingDbError>;
+}
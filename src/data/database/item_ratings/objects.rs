@@ -0,0 +1,49 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::item_ratings;
+
+/// Rating from 1 to 10 a user gave to a catalogue item or a favourite, keyed by its uuid.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct ItemRatingEntity {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub item_uuid: Uuid,
+    pub rating: i16,
+    pub review: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = item_ratings)]
+pub struct ItemRatingEntityCreate {
+    pub user_uuid: Uuid,
+    pub item_uuid: Uuid,
+    pub rating: i16,
+    pub review: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// A rating together with the author as stored on the follow relation.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct ItemReviewEntity {
+    pub rating: ItemRatingEntity,
+    pub username: String,
+    pub avatar_url: String,
+}
+
+#[derive(Debug)]
+pub enum ItemRatingDbError {
+    ItemNotFound,
+    RatingNotFound,
+    InternalError,
+}
+
+impl From<Error> for ItemRatingDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database item ratings error: {:?}", err);
+        ItemRatingDbError::InternalError
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        favourite::UserFavouritesDatabase,
+        follow::{objects::FollowEntityCreate, FollowDatabase},
+        item_ratings::{
+            objects::{ItemRatingDbError, ItemRatingEntityCreate},
+            ItemRatingsDatabase,
+        },
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_set_rating_and_followed_reviews() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let item_uuid = Uuid::new_v4();
+        let follower_uuid = Uuid::new_v4();
+        let followed_uuid = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let rating = |user_uuid: Uuid, rating: i16, review: Option<&str>| ItemRatingEntityCreate {
+            user_uuid,
+            item_uuid,
+            rating,
+            review: review.map(str::to_owned),
+            created_at: current_time_ms,
+            updated_at: current_time_ms,
+        };
+
+        // neither in the catalogue nor among the user's favourites
+        let not_found = connection.set_rating(rating(followed_uuid, 7, None)).await;
+        assert!(matches!(not_found, Err(ItemRatingDbError::ItemNotFound)));
+
+        for user_uuid in [follower_uuid, followed_uuid] {
+            connection
+                .add_favourite(&user_uuid.to_string(), &item_uuid.to_string(), "title")
+                .await
+                .unwrap();
+        }
+        connection
+            .set_rating(rating(followed_uuid, 7, None))
+            .await
+            .unwrap();
+        let mut updated = rating(followed_uuid, 9, Some("great"));
+        updated.updated_at += 1;
+        let updated = connection.set_rating(updated).await.unwrap();
+        assert_eq!(updated.rating, 9);
+        assert_eq!(updated.review, Some("great".to_string()));
+        assert_eq!(updated.created_at, current_time_ms);
+        assert_eq!(updated.updated_at, current_time_ms + 1);
+        connection
+            .set_rating(rating(follower_uuid, 4, None))
+            .await
+            .unwrap();
+
+        let user_ratings = connection
+            .get_user_ratings(followed_uuid, vec![item_uuid, Uuid::new_v4()])
+            .await
+            .unwrap();
+        assert_eq!(user_ratings, vec![updated.clone()]);
+        let distribution = connection.get_rating_distribution(item_uuid).await.unwrap();
+        assert_eq!(distribution, vec![(4, 1), (9, 1)]);
+
+        let reviews = connection
+            .get_followed_reviews(follower_uuid, item_uuid, 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(reviews.total, 0);
+        connection
+            .follow_user(&FollowEntityCreate {
+                follower_uuid,
+                followed_uuid,
+                followed_username: "followed".to_string(),
+                follower_username: "follower".to_string(),
+                followed_avatar_url: "".to_string(),
+                follower_avatar_url: "".to_string(),
+            })
+            .await
+            .unwrap();
+        let reviews = connection
+            .get_followed_reviews(follower_uuid, item_uuid, 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(reviews.total, 1);
+        assert_eq!(reviews.result[0].username, "followed");
+        assert_eq!(reviews.result[0].rating, updated);
+
+        connection
+            .remove_rating(followed_uuid, item_uuid)
+            .await
+            .unwrap();
+        let removed = connection.remove_rating(followed_uuid, item_uuid).await;
+        assert!(matches!(removed, Err(ItemRatingDbError::RatingNotFound)));
+    }
+}
@@ -0,0 +1,214 @@
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, PgTextExpressionMethods,
+    QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+use super::{
+    objects::{
+        ItemDbError, ItemEntity, ItemEntityCreate, ItemEntitySync, ItemExternalIdEntity,
+        ItemExternalIdEntityCreate,
+    },
+    ItemsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    schema::{item_external_ids, items},
+    Conn,
+};
+
+const LINK_FAVOURITES_QUERY: &str = "
+UPDATE favourite
+SET item_uuid = items.uuid, title = items.title
+FROM items
+WHERE favourite.favourite_uuid = items.uuid
+  AND (favourite.item_uuid IS NULL OR favourite.title <> items.title)
+";
+
+const LINK_CANDIDATES_QUERY: &str = "
+UPDATE match_candidates
+SET item_uuid = items.uuid
+FROM items
+WHERE match_candidates.reference_uuid = items.uuid
+  AND match_candidates.item_uuid IS NULL
+";
+
+#[async_trait]
+impl ItemsDatabase for Conn {
+    async fn sync_items(
+        &self,
+        provider: String,
+        items: Vec<ItemEntitySync>,
+        synced_at: i64,
+    ) -> Result<usize, ItemDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let count = items.len();
+                    for item in items {
+                        sync_item(db, &provider, item, synced_at)?;
+                    }
+                    diesel::sql_query(LINK_FAVOURITES_QUERY).execute(db)?;
+                    diesel::sql_query(LINK_CANDIDATES_QUERY).execute(db)?;
+                    Ok(count)
+                })
+            })
+            .await
+    }
+
+    async fn get_items(&self, items_uuid: Vec<Uuid>) -> Result<Vec<ItemEntity>, ItemDbError> {
+        self.0
+            .run(move |db| {
+                items::table
+                    .filter(items::uuid.eq_any(items_uuid))
+                    .load::<ItemEntity>(db)
+            })
+            .await
+            .map_err(ItemDbError::from)
+    }
+
+    async fn get_item_external_ids(
+        &self,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemExternalIdEntity>, ItemDbError> {
+        self.0
+            .run(move |db| {
+                item_external_ids::table
+                    .filter(item_external_ids::item_uuid.eq_any(items_uuid))
+                    .order(item_external_ids::source.asc())
+                    .load::<ItemExternalIdEntity>(db)
+            })
+            .await
+            .map_err(ItemDbError::from)
+    }
+
+    async fn find_external_ids(
+        &self,
+        external_ids: Vec<String>,
+    ) -> Result<Vec<ItemExternalIdEntity>, ItemDbError> {
+        self.0
+            .run(move |db| {
+                item_external_ids::table
+                    .filter(item_external_ids::external_id.eq_any(external_ids))
+                    .order(item_external_ids::source.asc())
+                    .load::<ItemExternalIdEntity>(db)
+            })
+            .await
+            .map_err(ItemDbError::from)
+    }
+
+    async fn search_items(
+        &self,
+        query: String,
+        kind: Option<String>,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemEntity>, ItemDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        self.0
+            .run(move |db| {
+                let filtered = || {
+                    let mut query_request = items::table
+                        .filter(items::title.ilike(format!("%{}%", query)))
+                        .into_boxed();
+                    if let Some(kind) = &kind {
+                        query_request = query_request.filter(items::kind.eq(kind.to_owned()));
+                    }
+                    query_request
+                };
+                let total: i64 = filtered().count().get_result(db)?;
+                let result = filtered()
+                    .order((items::title.asc(), items::uuid.asc()))
+                    .limit(page_size)
+                    .offset(offset)
+                    .load::<ItemEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+}
+
+/// Updates the stored item the sync item matches, or inserts it, then claims its external ids.
+fn sync_item(
+    db: &mut PgConnection,
+    provider: &str,
+    item: ItemEntitySync,
+    synced_at: i64,
+) -> Result<(), ItemDbError> {
+    let by_uuid = match item.uuid {
+        Some(uuid) => items::table
+            .select(items::uuid)
+            .find(uuid)
+            .first::<Uuid>(db)
+            .optional()?,
+        None => None,
+    };
+    let mut existing = by_uuid;
+    for (source, external_id) in &item.external_ids {
+        if existing.is_some() {
+            break;
+        }
+        existing = item_external_ids::table
+            .select(item_external_ids::item_uuid)
+            .filter(item_external_ids::source.eq(source))
+            .filter(item_external_ids::external_id.eq(external_id))
+            .first::<Uuid>(db)
+            .optional()?;
+    }
+
+    let item_uuid = match existing {
+        Some(uuid) => {
+            diesel::update(items::table.find(uuid))
+                .set((
+                    items::kind.eq(&item.kind),
+                    items::title.eq(&item.title),
+                    items::year.eq(item.year),
+                    items::image_url.eq(&item.image_url),
+                    items::provider.eq(provider),
+                    items::updated_at.eq(synced_at),
+                ))
+                .execute(db)?;
+            uuid
+        }
+        None => diesel::insert_into(items::table)
+            .values(&ItemEntityCreate {
+                uuid: item.uuid.unwrap_or_else(Uuid::new_v4),
+                kind: item.kind,
+                title: item.title,
+                year: item.year,
+                image_url: item.image_url,
+                provider: provider.to_owned(),
+                created_at: synced_at,
+                updated_at: synced_at,
+            })
+            .returning(items::uuid)
+            .get_result::<Uuid>(db)?,
+    };
+
+    let external_ids: Vec<ItemExternalIdEntityCreate> = item
+        .external_ids
+        .into_iter()
+        .map(|(source, external_id)| ItemExternalIdEntityCreate {
+            item_uuid,
+            source,
+            external_id,
+        })
+        .collect();
+    diesel::insert_into(item_external_ids::table)
+        .values(&external_ids)
+        .on_conflict((item_external_ids::source, item_external_ids::external_id))
+        .do_update()
+        .set(item_external_ids::item_uuid.eq(item_uuid))
+        .execute(db)?;
+    Ok(())
+}
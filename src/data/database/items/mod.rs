@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{ItemDbError, ItemEntity, ItemEntitySync, ItemExternalIdEntity};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait ItemsDatabase {
+    /// Inserts or refreshes the items in one transaction. An item matches a stored one by
+    /// uuid or by any of its external ids. Favourites and candidates referencing a synced
+    /// item are linked to it afterwards, favourites also take over its title.
+    async fn sync_items(
+        &self,
+        provider: String,
+        items: Vec<ItemEntitySync>,
+        synced_at: i64,
+    ) -> Result<usize, ItemDbError>;
+    async fn get_items(&self, items_uuid: Vec<Uuid>) -> Result<Vec<ItemEntity>, ItemDbError>;
+    async fn get_item_external_ids(
+        &self,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemExternalIdEntity>, ItemDbError>;
+    /// External ids of any source equal to one of `external_ids`.
+    async fn find_external_ids(
+        &self,
+        external_ids: Vec<String>,
+    ) -> Result<Vec<ItemExternalIdEntity>, ItemDbError>;
+    /// Items whose title contains `query`, ordered by title.
+    async fn search_items(
+        &self,
+        query: String,
+        kind: Option<String>,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemEntity>, ItemDbError>;
+}
@@ -0,0 +1,69 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::{item_external_ids, items};
+
+/// Catalogue entry favourites and match candidates refer to.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct ItemEntity {
+    pub uuid: Uuid,
+    pub kind: String,
+    pub title: String,
+    pub year: Option<i32>,
+    pub image_url: Option<String>,
+    pub provider: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = items)]
+pub struct ItemEntityCreate {
+    pub uuid: Uuid,
+    pub kind: String,
+    pub title: String,
+    pub year: Option<i32>,
+    pub image_url: Option<String>,
+    pub provider: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct ItemExternalIdEntity {
+    pub uuid: Uuid,
+    pub item_uuid: Uuid,
+    pub source: String,
+    pub external_id: String,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = item_external_ids)]
+pub struct ItemExternalIdEntityCreate {
+    pub item_uuid: Uuid,
+    pub source: String,
+    pub external_id: String,
+}
+
+/// An item coming from a catalogue provider, `external_ids` holds `(source, id)` pairs.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ItemEntitySync {
+    pub uuid: Option<Uuid>,
+    pub kind: String,
+    pub title: String,
+    pub year: Option<i32>,
+    pub image_url: Option<String>,
+    pub external_ids: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub enum ItemDbError {
+    InternalError,
+}
+
+impl From<Error> for ItemDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database items error: {:?}", err);
+        ItemDbError::InternalError
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::{
+        database::{
+            favourite::UserFavouritesDatabase,
+            items::{objects::ItemEntitySync, ItemsDatabase},
+            tests::database_test_utls::run_migration_get_conn,
+        },
+        repository::objects::PagingDomainRequest,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_sync_items_links_favourites_and_searches() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let suffix = Uuid::new_v4().to_simple().to_string();
+        let item_uuid = Uuid::new_v4();
+        let user_uuid = Uuid::new_v4().to_string();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        connection
+            .add_favourite(&user_uuid, &item_uuid.to_string(), "old title")
+            .await
+            .unwrap();
+
+        let sync_item = |uuid: Option<Uuid>, title: &str| ItemEntitySync {
+            uuid,
+            kind: "movie".to_string(),
+            title: format!("{} {}", title, suffix),
+            year: Some(1999),
+            image_url: None,
+            external_ids: vec![("imdb".to_string(), format!("tt{}", suffix))],
+        };
+        let synced = connection
+            .sync_items(
+                "fixture".to_string(),
+                vec![sync_item(Some(item_uuid), "Matrix")],
+                current_time_ms,
+            )
+            .await
+            .unwrap();
+        assert_eq!(synced, 1);
+
+        // no uuid this time, the external id points at the stored item
+        connection
+            .sync_items(
+                "fixture".to_string(),
+                vec![sync_item(None, "The Matrix")],
+                current_time_ms + 1,
+            )
+            .await
+            .unwrap();
+        let stored = connection.get_items(vec![item_uuid]).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].title, format!("The Matrix {}", suffix));
+        assert_eq!(stored[0].created_at, current_time_ms);
+        assert_eq!(stored[0].updated_at, current_time_ms + 1);
+        let external_ids = connection
+            .get_item_external_ids(vec![item_uuid])
+            .await
+            .unwrap();
+        assert_eq!(external_ids.len(), 1);
+        assert_eq!(external_ids[0].external_id, format!("tt{}", suffix));
+
+        let favourites = connection
+            .get_user_favourites(
+                PagingDomainRequest {
+                    user_uuid: &user_uuid,
+                    request_uuid: &user_uuid,
+                    query: "",
+                    page: 1,
+                    page_size: 10,
+                },
+                Default::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(favourites.result.len(), 1);
+        assert_eq!(favourites.result[0].item_uuid, Some(item_uuid));
+        assert_eq!(favourites.result[0].title, stored[0].title);
+
+        let found = connection
+            .search_items(suffix.clone(), Some("movie".to_string()), 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(found.total, 1);
+        assert_eq!(found.result, stored);
+        let other_kind = connection
+            .search_items(suffix, Some("book".to_string()), 1, 10)
+            .await
+            .unwrap();
+        assert!(other_kind.result.is_empty());
+    }
+}
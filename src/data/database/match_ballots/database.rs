@@ -0,0 +1,106 @@
+use super::{
+    objects::{MatchBallotDbError, MatchBallotEntity, MatchBallotEntityCreate},
+    MatchBallotsDatabase,
+};
+use crate::{
+    data::database::matches::objects::MatchesEntity,
+    schema::{match_ballots, match_candidates, match_votes, matches},
+    Conn,
+};
+use diesel::{
+    upsert::excluded, Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl MatchBallotsDatabase for Conn {
+    async fn add_ballot(
+        &self,
+        ballot: MatchBallotEntityCreate,
+        open_statuses: Vec<String>,
+    ) -> Result<MatchBallotEntity, MatchBallotDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // the match row is locked so a ballot can't land after completion
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(ballot.match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchBallotDbError::MatchesNotFound)?;
+                    if !open_statuses.contains(&match_entity.status)
+                        || match_entity.expires_at <= ballot.created_at
+                    {
+                        return Err(MatchBallotDbError::MatchClosed);
+                    }
+
+                    let known: i64 = match_candidates::table
+                        .filter(match_candidates::match_uuid.eq(ballot.match_uuid))
+                        .filter(match_candidates::uuid.eq_any(&ballot.candidate_uuids))
+                        .count()
+                        .get_result(db)?;
+                    if usize::try_from(known).ok() != Some(ballot.candidate_uuids.len()) {
+                        return Err(MatchBallotDbError::CandidateNotFound);
+                    }
+
+                    diesel::insert_into(match_ballots::table)
+                        .values(&ballot)
+                        .on_conflict((match_ballots::match_uuid, match_ballots::user_uuid))
+                        .do_update()
+                        .set((
+                            match_ballots::candidate_uuids
+                                .eq(excluded(match_ballots::candidate_uuids)),
+                            match_ballots::updated_at.eq(excluded(match_ballots::updated_at)),
+                        ))
+                        .get_result::<MatchBallotEntity>(db)
+                        .map_err(MatchBallotDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn get_ballots(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<MatchBallotEntity>, MatchBallotDbError> {
+        self.0
+            .run(move |db| {
+                match_ballots::table
+                    .filter(match_ballots::match_uuid.eq(match_uuid))
+                    .order(match_ballots::created_at.asc())
+                    .load::<MatchBallotEntity>(db)
+            })
+            .await
+            .map_err(MatchBallotDbError::from)
+    }
+
+    async fn get_candidate_order(&self, match_uuid: Uuid) -> Result<Vec<Uuid>, MatchBallotDbError> {
+        self.0
+            .run(move |db| {
+                match_candidates::table
+                    .select(match_candidates::uuid)
+                    .filter(match_candidates::match_uuid.eq(match_uuid))
+                    .order((
+                        match_candidates::created_at.asc(),
+                        match_candidates::uuid.asc(),
+                    ))
+                    .load::<Uuid>(db)
+            })
+            .await
+            .map_err(MatchBallotDbError::from)
+    }
+
+    async fn get_likes(&self, match_uuid: Uuid) -> Result<Vec<(Uuid, Uuid)>, MatchBallotDbError> {
+        self.0
+            .run(move |db| {
+                match_votes::table
+                    .select((match_votes::user_uuid, match_votes::candidate_uuid))
+                    .filter(match_votes::match_uuid.eq(match_uuid))
+                    .filter(match_votes::liked.eq(true))
+                    .load::<(Uuid, Uuid)>(db)
+            })
+            .await
+            .map_err(MatchBallotDbError::from)
+    }
+}
@@ -0,0 +1,25 @@
+use uuid::Uuid;
+
+use self::objects::{MatchBallotDbError, MatchBallotEntity, MatchBallotEntityCreate};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchBallotsDatabase {
+    /// Stores or replaces the user's ballot while the match is in one of `open_statuses`.
+    async fn add_ballot(
+        &self,
+        ballot: MatchBallotEntityCreate,
+        open_statuses: Vec<String>,
+    ) -> Result<MatchBallotEntity, MatchBallotDbError>;
+    async fn get_ballots(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<MatchBallotEntity>, MatchBallotDbError>;
+    /// Candidate uuids of the match in tie-break order, oldest first.
+    async fn get_candidate_order(&self, match_uuid: Uuid) -> Result<Vec<Uuid>, MatchBallotDbError>;
+    /// Liked `(user_uuid, candidate_uuid)` pairs of a unanimous match.
+    async fn get_likes(&self, match_uuid: Uuid) -> Result<Vec<(Uuid, Uuid)>, MatchBallotDbError>;
+}
@@ -0,0 +1,40 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_ballots;
+
+/// One participant's ballot, the candidates are ordered for ranked methods.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchBallotEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub candidate_uuids: Vec<Uuid>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_ballots)]
+pub struct MatchBallotEntityCreate {
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub candidate_uuids: Vec<Uuid>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchBallotDbError {
+    MatchesNotFound,
+    CandidateNotFound,
+    MatchClosed,
+    InternalError,
+}
+
+impl From<Error> for MatchBallotDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match ballots error: {:?}", err);
+        MatchBallotDbError::InternalError
+    }
+}
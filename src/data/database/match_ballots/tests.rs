@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        match_ballots::{
+            objects::{MatchBallotDbError, MatchBallotEntityCreate},
+            MatchBallotsDatabase,
+        },
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_ballots_upsert_and_validate_candidates() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let first_user = Uuid::new_v4();
+        let second_user = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: first_user,
+                    participants_uuid: vec![first_user, second_user],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "ranked".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut candidates = Vec::new();
+        for index in 0..2 {
+            let candidate = connection
+                .add_candidate(
+                    MatchCandidateEntityCreate {
+                        match_uuid: match_entity.uuid,
+                        reference_uuid: Uuid::new_v4(),
+                        title: format!("candidate {}", index),
+                        image_url: "image_url".to_string(),
+                        metadata: None,
+                        created_by: first_user,
+                        created_at: current_time_ms + index,
+                    },
+                    10,
+                )
+                .await
+                .unwrap();
+            candidates.push(candidate.uuid);
+        }
+
+        let order = connection
+            .get_candidate_order(match_entity.uuid)
+            .await
+            .unwrap();
+        assert_eq!(order, candidates);
+
+        let ballot = |candidate_uuids: Vec<Uuid>, time: i64| MatchBallotEntityCreate {
+            match_uuid: match_entity.uuid,
+            user_uuid: first_user,
+            candidate_uuids,
+            created_at: time,
+            updated_at: time,
+        };
+        let open_statuses = || vec!["pending".to_string(), "active".to_string()];
+
+        let first = connection
+            .add_ballot(ballot(candidates.clone(), current_time_ms), open_statuses())
+            .await
+            .unwrap();
+        let replaced = connection
+            .add_ballot(
+                ballot(vec![candidates[1], candidates[0]], current_time_ms + 1),
+                open_statuses(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(replaced.uuid, first.uuid);
+        assert_eq!(replaced.created_at, current_time_ms);
+        assert_eq!(replaced.updated_at, current_time_ms + 1);
+        assert_eq!(replaced.candidate_uuids, vec![candidates[1], candidates[0]]);
+
+        let ballots = connection.get_ballots(match_entity.uuid).await.unwrap();
+        assert_eq!(ballots, vec![replaced]);
+
+        let unknown = connection
+            .add_ballot(
+                ballot(vec![candidates[0], Uuid::new_v4()], current_time_ms),
+                open_statuses(),
+            )
+            .await;
+        assert!(matches!(
+            unknown,
+            Err(MatchBallotDbError::CandidateNotFound)
+        ));
+
+        let closed = connection
+            .add_ballot(
+                ballot(candidates.clone(), current_time_ms),
+                vec!["active".to_string()],
+            )
+            .await;
+        assert!(matches!(closed, Err(MatchBallotDbError::MatchClosed)));
+
+        let missing = connection
+            .add_ballot(
+                MatchBallotEntityCreate {
+                    match_uuid: Uuid::new_v4(),
+                    ..ballot(candidates.clone(), current_time_ms)
+                },
+                open_statuses(),
+            )
+            .await;
+        assert!(matches!(missing, Err(MatchBallotDbError::MatchesNotFound)));
+    }
+}
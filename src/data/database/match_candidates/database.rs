@@ -0,0 +1,133 @@
+use super::{
+    objects::{MatchCandidateDbError, MatchCandidateEntity, MatchCandidateEntityCreate},
+    MatchCandidatesDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::{correct_page_number, parce_uuid},
+        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+    },
+    schema::{items, match_candidates, matches},
+    Conn,
+};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl MatchCandidatesDatabase for Conn {
+    async fn add_candidate(
+        &self,
+        candidate: MatchCandidateEntityCreate,
+        limit: i64,
+    ) -> Result<MatchCandidateEntity, MatchCandidateDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // lock the match row so concurrent inserts can't overshoot the limit
+                    matches::table
+                        .select(matches::uuid)
+                        .filter(matches::uuid.eq(candidate.match_uuid))
+                        .for_update()
+                        .first::<Uuid>(db)
+                        .optional()?
+                        .ok_or(MatchCandidateDbError::MatchesNotFound)?;
+
+                    let count: i64 = match_candidates::table
+                        .filter(match_candidates::match_uuid.eq(candidate.match_uuid))
+                        .count()
+                        .get_result(db)?;
+                    if count >= limit {
+                        return Err(MatchCandidateDbError::LimitExceeded);
+                    }
+
+                    let item_uuid = items::table
+                        .select(items::uuid)
+                        .find(candidate.reference_uuid)
+                        .first::<Uuid>(db)
+                        .optional()?;
+                    diesel::insert_into(match_candidates::table)
+                        .values((&candidate, match_candidates::item_uuid.eq(item_uuid)))
+                        .on_conflict((
+                            match_candidates::match_uuid,
+                            match_candidates::reference_uuid,
+                        ))
+                        .do_nothing()
+                        .get_result::<MatchCandidateEntity>(db)
+                        .optional()?
+                        .ok_or(MatchCandidateDbError::Conflict)
+                })
+            })
+            .await
+    }
+
+    async fn get_candidates<'a>(
+        &self,
+        match_uuid: &'a str,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchCandidateEntity>, MatchCandidateDbError> {
+        let match_uuid = parce_uuid(match_uuid).map_err(|_| MatchCandidateDbError::UuidInvalid)?;
+        let query = request.query.to_owned();
+        let page_number = correct_page_number(request.page);
+        let limit = request.page_size;
+        let offset = page_number * request.page_size;
+        let page_size = request.page_size;
+
+        self.0
+            .run(move |db| {
+                let query_request = match_candidates::table
+                    .filter(match_candidates::match_uuid.eq(match_uuid))
+                    .filter(match_candidates::title.ilike(format!("%{}%", query)));
+
+                let results = query_request
+                    .to_owned()
+                    .order(match_candidates::created_at.asc())
+                    .limit(limit)
+                    .offset(offset)
+                    .load::<MatchCandidateEntity>(db)?;
+
+                let total_result = query_request.count().get_result(db)?;
+
+                let result_count = i64::try_from(results.len()).map_err(|err| {
+                    eprintln!("Error converting result count: {}", err);
+                    MatchCandidateDbError::InternalError
+                })?;
+
+                Ok(PagingDomainResponse {
+                    total: total_result,
+                    result: results,
+                    page: page_number + 1,
+                    page_size,
+                    has_more: offset + result_count < total_result,
+                })
+            })
+            .await
+    }
+
+    async fn remove_candidate<'a>(
+        &self,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), MatchCandidateDbError> {
+        let match_uuid = parce_uuid(match_uuid).map_err(|_| MatchCandidateDbError::UuidInvalid)?;
+        let candidate_uuid =
+            parce_uuid(candidate_uuid).map_err(|_| MatchCandidateDbError::UuidInvalid)?;
+        self.0
+            .run(move |db| {
+                diesel::delete(
+                    match_candidates::table
+                        .filter(match_candidates::match_uuid.eq(match_uuid))
+                        .filter(match_candidates::uuid.eq(candidate_uuid)),
+                )
+                .execute(db)
+            })
+            .await
+            .map_err(MatchCandidateDbError::from)
+            .and_then(|count| match count {
+                0 => Err(MatchCandidateDbError::CandidateNotFound),
+                _ => Ok(()),
+            })
+    }
+}
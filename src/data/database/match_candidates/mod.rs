@@ -0,0 +1,26 @@
+use crate::data::repository::objects::{PagingDomainRequest, PagingDomainResponse};
+
+use self::objects::{MatchCandidateDbError, MatchCandidateEntity, MatchCandidateEntityCreate};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchCandidatesDatabase {
+    async fn add_candidate(
+        &self,
+        candidate: MatchCandidateEntityCreate,
+        limit: i64,
+    ) -> Result<MatchCandidateEntity, MatchCandidateDbError>;
+    async fn get_candidates<'a>(
+        &self,
+        match_uuid: &'a str,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchCandidateEntity>, MatchCandidateDbError>;
+    async fn remove_candidate<'a>(
+        &self,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), MatchCandidateDbError>;
+}
@@ -0,0 +1,48 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_candidates;
+
+/// Represents an item attached to a match that participants choose between.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchCandidateEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub reference_uuid: Uuid,
+    pub title: String,
+    pub image_url: String,
+    pub metadata: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: i64,
+    /// Set when `reference_uuid` refers to a catalogue item.
+    pub item_uuid: Option<Uuid>,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_candidates)]
+pub struct MatchCandidateEntityCreate {
+    pub match_uuid: Uuid,
+    pub reference_uuid: Uuid,
+    pub title: String,
+    pub image_url: String,
+    pub metadata: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchCandidateDbError {
+    UuidInvalid,
+    MatchesNotFound,
+    CandidateNotFound,
+    Conflict,
+    LimitExceeded,
+    InternalError,
+}
+
+impl From<Error> for MatchCandidateDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match candidates error: {:?}", err);
+        MatchCandidateDbError::InternalError
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::{
+        database::{
+            match_candidates::{
+                objects::{MatchCandidateDbError, MatchCandidateEntityCreate},
+                MatchCandidatesDatabase,
+            },
+            matches::{objects::MatchesEntityCreate, MatchesDatabase},
+            tests::database_test_utls::run_migration_get_conn,
+        },
+        repository::objects::PagingDomainRequest,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_add_and_remove_candidates() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let creator_uuid = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid,
+                    participants_uuid: vec![creator_uuid],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let candidate = MatchCandidateEntityCreate {
+            match_uuid: match_entity.uuid,
+            reference_uuid: Uuid::new_v4(),
+            title: "candidate".to_string(),
+            image_url: "image_url".to_string(),
+            metadata: None,
+            created_by: creator_uuid,
+            created_at: current_time_ms,
+        };
+
+        let added = connection.add_candidate(candidate.to_owned(), 2).await;
+        assert!(added.is_ok());
+        let added = added.unwrap();
+        assert_eq!(added.reference_uuid, candidate.reference_uuid);
+
+        let duplicate = connection.add_candidate(candidate.to_owned(), 2).await;
+        assert!(matches!(duplicate, Err(MatchCandidateDbError::Conflict)));
+
+        let second = MatchCandidateEntityCreate {
+            reference_uuid: Uuid::new_v4(),
+            ..candidate.to_owned()
+        };
+        assert!(connection.add_candidate(second, 2).await.is_ok());
+
+        let over_limit = MatchCandidateEntityCreate {
+            reference_uuid: Uuid::new_v4(),
+            ..candidate.to_owned()
+        };
+        let over_limit = connection.add_candidate(over_limit, 2).await;
+        assert!(matches!(
+            over_limit,
+            Err(MatchCandidateDbError::LimitExceeded)
+        ));
+
+        let match_uuid = match_entity.uuid.to_string();
+        let request_uuid = creator_uuid.to_string();
+        let request = PagingDomainRequest {
+            user_uuid: &request_uuid,
+            request_uuid: &request_uuid,
+            query: "",
+            page: 1,
+            page_size: 10,
+        };
+        let candidates = connection.get_candidates(&match_uuid, &request).await;
+        assert!(candidates.is_ok());
+        let candidates = candidates.unwrap();
+        assert_eq!(candidates.total, 2);
+        assert!(!candidates.has_more);
+
+        let candidate_uuid = added.uuid.to_string();
+        let removed = connection
+            .remove_candidate(&match_uuid, &candidate_uuid)
+            .await;
+        assert!(removed.is_ok());
+
+        let removed_again = connection
+            .remove_candidate(&match_uuid, &candidate_uuid)
+            .await;
+        assert!(matches!(
+            removed_again,
+            Err(MatchCandidateDbError::CandidateNotFound)
+        ));
+
+        let candidates = connection.get_candidates(&match_uuid, &request).await;
+        assert_eq!(candidates.unwrap().total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_candidate_match_not_found() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let candidate = MatchCandidateEntityCreate {
+            match_uuid: Uuid::new_v4(),
+            reference_uuid: Uuid::new_v4(),
+            title: "candidate".to_string(),
+            image_url: "image_url".to_string(),
+            metadata: None,
+            created_by: Uuid::new_v4(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+
+        let result = connection.add_candidate(candidate, 10).await;
+        assert!(matches!(
+            result,
+            Err(MatchCandidateDbError::MatchesNotFound)
+        ));
+    }
+}
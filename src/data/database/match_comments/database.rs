@@ -0,0 +1,151 @@
+use super::{
+    objects::{MatchCommentDbError, MatchCommentEntity, MatchCommentEntityCreate},
+    MatchCommentsDatabase,
+};
+use crate::{
+    schema::{match_candidates, match_comments},
+    Conn,
+};
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl MatchCommentsDatabase for Conn {
+    async fn add_comment(
+        &self,
+        comment: MatchCommentEntityCreate,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    if let Some(candidate_uuid) = comment.candidate_uuid {
+                        match_candidates::table
+                            .select(match_candidates::uuid)
+                            .filter(match_candidates::match_uuid.eq(comment.match_uuid))
+                            .filter(match_candidates::uuid.eq(candidate_uuid))
+                            .first::<Uuid>(db)
+                            .optional()?
+                            .ok_or(MatchCommentDbError::CandidateNotFound)?;
+                    }
+                    if let Some(parent_uuid) = comment.parent_uuid {
+                        let parent = match_comments::table
+                            .filter(match_comments::match_uuid.eq(comment.match_uuid))
+                            .filter(match_comments::uuid.eq(parent_uuid))
+                            .filter(match_comments::deleted_at.is_null())
+                            .first::<MatchCommentEntity>(db)
+                            .optional()?
+                            .ok_or(MatchCommentDbError::CommentNotFound)?;
+                        if parent.candidate_uuid != comment.candidate_uuid {
+                            return Err(MatchCommentDbError::ParentMismatch);
+                        }
+                    }
+                    diesel::insert_into(match_comments::table)
+                        .values(&comment)
+                        .get_result::<MatchCommentEntity>(db)
+                        .map_err(MatchCommentDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn get_comments(
+        &self,
+        match_uuid: Uuid,
+        candidate_uuid: Option<Uuid>,
+        after: Option<(i64, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<MatchCommentEntity>, MatchCommentDbError> {
+        self.0
+            .run(move |db| {
+                let mut query = match_comments::table
+                    .filter(match_comments::match_uuid.eq(match_uuid))
+                    .into_boxed();
+                if let Some(candidate_uuid) = candidate_uuid {
+                    query = query.filter(match_comments::candidate_uuid.eq(candidate_uuid));
+                }
+                if let Some((created_at, uuid)) = after {
+                    query = query.filter(
+                        match_comments::created_at
+                            .gt(created_at)
+                            .or(match_comments::created_at
+                                .eq(created_at)
+                                .and(match_comments::uuid.gt(uuid))),
+                    );
+                }
+                query
+                    .order((match_comments::created_at.asc(), match_comments::uuid.asc()))
+                    .limit(limit)
+                    .load::<MatchCommentEntity>(db)
+            })
+            .await
+            .map_err(MatchCommentDbError::from)
+    }
+
+    async fn get_comment(
+        &self,
+        match_uuid: Uuid,
+        comment_uuid: Uuid,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError> {
+        self.0
+            .run(move |db| {
+                match_comments::table
+                    .filter(match_comments::match_uuid.eq(match_uuid))
+                    .filter(match_comments::uuid.eq(comment_uuid))
+                    .filter(match_comments::deleted_at.is_null())
+                    .first::<MatchCommentEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(MatchCommentDbError::CommentNotFound)
+    }
+
+    async fn update_comment(
+        &self,
+        comment_uuid: Uuid,
+        body: String,
+        updated_at: i64,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(
+                    match_comments::table
+                        .filter(match_comments::uuid.eq(comment_uuid))
+                        .filter(match_comments::deleted_at.is_null()),
+                )
+                .set((
+                    match_comments::body.eq(body),
+                    match_comments::updated_at.eq(updated_at),
+                ))
+                .get_result::<MatchCommentEntity>(db)
+                .optional()
+            })
+            .await?
+            .ok_or(MatchCommentDbError::CommentNotFound)
+    }
+
+    async fn delete_comment(
+        &self,
+        comment_uuid: Uuid,
+        deleted_at: i64,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(
+                    match_comments::table
+                        .filter(match_comments::uuid.eq(comment_uuid))
+                        .filter(match_comments::deleted_at.is_null()),
+                )
+                .set((
+                    match_comments::body.eq(""),
+                    match_comments::updated_at.eq(deleted_at),
+                    match_comments::deleted_at.eq(deleted_at),
+                ))
+                .get_result::<MatchCommentEntity>(db)
+                .optional()
+            })
+            .await?
+            .ok_or(MatchCommentDbError::CommentNotFound)
+    }
+}
@@ -0,0 +1,41 @@
+use uuid::Uuid;
+
+use self::objects::{MatchCommentDbError, MatchCommentEntity, MatchCommentEntityCreate};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchCommentsDatabase {
+    /// Replies must share the candidate scope of their parent, which can't be deleted.
+    async fn add_comment(
+        &self,
+        comment: MatchCommentEntityCreate,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError>;
+    /// Comments ordered oldest first, starting after the `(created_at, uuid)` cursor.
+    async fn get_comments(
+        &self,
+        match_uuid: Uuid,
+        candidate_uuid: Option<Uuid>,
+        after: Option<(i64, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<MatchCommentEntity>, MatchCommentDbError>;
+    async fn get_comment(
+        &self,
+        match_uuid: Uuid,
+        comment_uuid: Uuid,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError>;
+    async fn update_comment(
+        &self,
+        comment_uuid: Uuid,
+        body: String,
+        updated_at: i64,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError>;
+    /// Clears the body and marks the comment deleted, replies are kept.
+    async fn delete_comment(
+        &self,
+        comment_uuid: Uuid,
+        deleted_at: i64,
+    ) -> Result<MatchCommentEntity, MatchCommentDbError>;
+}
@@ -0,0 +1,46 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_comments;
+
+/// A comment in a match thread, scoped to a candidate when `candidate_uuid` is set.
+/// Deleted comments keep their row with an empty body so replies stay attached.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchCommentEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Option<Uuid>,
+    pub parent_uuid: Option<Uuid>,
+    pub author_uuid: Uuid,
+    pub body: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_comments)]
+pub struct MatchCommentEntityCreate {
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Option<Uuid>,
+    pub parent_uuid: Option<Uuid>,
+    pub author_uuid: Uuid,
+    pub body: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchCommentDbError {
+    CandidateNotFound,
+    CommentNotFound,
+    ParentMismatch,
+    InternalError,
+}
+
+impl From<Error> for MatchCommentDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match comments error: {:?}", err);
+        MatchCommentDbError::InternalError
+    }
+}
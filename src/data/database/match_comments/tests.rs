@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        match_comments::{
+            objects::{MatchCommentDbError, MatchCommentEntityCreate},
+            MatchCommentsDatabase,
+        },
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_comments_thread_paging_and_soft_delete() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let author = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: author,
+                    participants_uuid: vec![author],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+        let candidate = connection
+            .add_candidate(
+                MatchCandidateEntityCreate {
+                    match_uuid: match_entity.uuid,
+                    reference_uuid: Uuid::new_v4(),
+                    title: "candidate".to_string(),
+                    image_url: "image_url".to_string(),
+                    metadata: None,
+                    created_by: author,
+                    created_at: current_time_ms,
+                },
+                10,
+            )
+            .await
+            .unwrap();
+
+        let comment = |candidate_uuid: Option<Uuid>, parent_uuid: Option<Uuid>, time: i64| {
+            MatchCommentEntityCreate {
+                match_uuid: match_entity.uuid,
+                candidate_uuid,
+                parent_uuid,
+                author_uuid: author,
+                body: "body".to_string(),
+                created_at: time,
+                updated_at: time,
+            }
+        };
+
+        let general = connection
+            .add_comment(comment(None, None, current_time_ms))
+            .await
+            .unwrap();
+        let scoped = connection
+            .add_comment(comment(Some(candidate.uuid), None, current_time_ms + 1))
+            .await
+            .unwrap();
+        let reply = connection
+            .add_comment(comment(
+                Some(candidate.uuid),
+                Some(scoped.uuid),
+                current_time_ms + 2,
+            ))
+            .await
+            .unwrap();
+
+        let mismatch = connection
+            .add_comment(comment(None, Some(scoped.uuid), current_time_ms + 3))
+            .await;
+        assert!(matches!(mismatch, Err(MatchCommentDbError::ParentMismatch)));
+        let unknown = connection
+            .add_comment(comment(Some(Uuid::new_v4()), None, current_time_ms + 3))
+            .await;
+        assert!(matches!(
+            unknown,
+            Err(MatchCommentDbError::CandidateNotFound)
+        ));
+
+        let first_page = connection
+            .get_comments(match_entity.uuid, None, None, 2)
+            .await
+            .unwrap();
+        assert_eq!(first_page, vec![general.clone(), scoped.clone()]);
+        let second_page = connection
+            .get_comments(
+                match_entity.uuid,
+                None,
+                Some((scoped.created_at, scoped.uuid)),
+                2,
+            )
+            .await
+            .unwrap();
+        assert_eq!(second_page, vec![reply.clone()]);
+        let candidate_thread = connection
+            .get_comments(match_entity.uuid, Some(candidate.uuid), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(candidate_thread, vec![scoped.clone(), reply.clone()]);
+
+        let updated = connection
+            .update_comment(general.uuid, "edited".to_string(), current_time_ms + 5)
+            .await
+            .unwrap();
+        assert_eq!(updated.body, "edited");
+        assert_eq!(updated.updated_at, current_time_ms + 5);
+
+        let deleted = connection
+            .delete_comment(scoped.uuid, current_time_ms + 6)
+            .await
+            .unwrap();
+        assert_eq!(deleted.body, "");
+        assert_eq!(deleted.deleted_at, Some(current_time_ms + 6));
+
+        let thread = connection
+            .get_comments(match_entity.uuid, Some(candidate.uuid), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(thread, vec![deleted, reply]);
+
+        let missing = connection.get_comment(match_entity.uuid, scoped.uuid).await;
+        assert!(matches!(missing, Err(MatchCommentDbError::CommentNotFound)));
+        let edit_deleted = connection
+            .update_comment(scoped.uuid, "edited".to_string(), current_time_ms + 7)
+            .await;
+        assert!(matches!(
+            edit_deleted,
+            Err(MatchCommentDbError::CommentNotFound)
+        ));
+        let reply_deleted = connection
+            .add_comment(comment(
+                Some(candidate.uuid),
+                Some(scoped.uuid),
+                current_time_ms + 8,
+            ))
+            .await;
+        assert!(matches!(
+            reply_deleted,
+            Err(MatchCommentDbError::CommentNotFound)
+        ));
+    }
+}
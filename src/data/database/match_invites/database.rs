@@ -0,0 +1,185 @@
+use super::{
+    objects::{MatchInviteDbError, MatchInviteEntity, MatchInviteEntityCreate},
+    MatchInvitesDatabase,
+};
+use crate::{
+    data::{
+        database::{matches::objects::MatchesEntity, utils::paging::correct_page_number},
+        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+    },
+    schema::{match_invites, matches},
+    Conn,
+};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl MatchInvitesDatabase for Conn {
+    async fn add_invites(
+        &self,
+        match_uuid: Uuid,
+        invites: Vec<MatchInviteEntityCreate>,
+        open_statuses: Vec<String>,
+    ) -> Result<Vec<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchInviteDbError::MatchesNotFound)?;
+                    if !open_statuses.contains(&match_entity.status) {
+                        return Err(MatchInviteDbError::MatchClosed);
+                    }
+
+                    let invites: Vec<MatchInviteEntityCreate> = invites
+                        .into_iter()
+                        .filter(|invite| {
+                            !match_entity.participants_uuid.contains(&invite.user_uuid)
+                        })
+                        .map(|invite| MatchInviteEntityCreate {
+                            match_uuid,
+                            ..invite
+                        })
+                        .collect();
+                    let created = diesel::insert_into(match_invites::table)
+                        .values(&invites)
+                        .on_conflict((match_invites::match_uuid, match_invites::user_uuid))
+                        .do_nothing()
+                        .get_results::<MatchInviteEntity>(db)?;
+                    Ok(created
+                        .into_iter()
+                        .map(|invite| (invite, match_entity.clone()))
+                        .collect())
+                })
+            })
+            .await
+    }
+
+    async fn get_user_invites<'a>(
+        &self,
+        status: String,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError> {
+        let user_uuid =
+            Uuid::parse_str(request.request_uuid).map_err(|_| MatchInviteDbError::UuidInvalid)?;
+        let query = request.query.to_owned();
+        let page_number = correct_page_number(request.page);
+        let limit = request.page_size;
+        let offset = page_number * request.page_size;
+        let page_size = request.page_size;
+
+        self.0
+            .run(move |db| {
+                let query_request = match_invites::table
+                    .inner_join(matches::table)
+                    .filter(match_invites::user_uuid.eq(user_uuid))
+                    .filter(match_invites::status.eq(status))
+                    .filter(matches::title.ilike(format!("%{}%", query)));
+
+                let results = query_request
+                    .to_owned()
+                    .order(match_invites::created_at.desc())
+                    .limit(limit)
+                    .offset(offset)
+                    .load::<(MatchInviteEntity, MatchesEntity)>(db)?;
+
+                let total_result = query_request.count().get_result(db)?;
+
+                let result_count = i64::try_from(results.len()).map_err(|err| {
+                    eprintln!("Error converting result count: {}", err);
+                    MatchInviteDbError::InternalError
+                })?;
+
+                Ok(PagingDomainResponse {
+                    total: total_result,
+                    result: results,
+                    page: page_number + 1,
+                    page_size,
+                    has_more: offset + result_count < total_result,
+                })
+            })
+            .await
+    }
+
+    async fn get_match_invites(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError> {
+        self.0
+            .run(move |db| {
+                match_invites::table
+                    .inner_join(matches::table)
+                    .filter(match_invites::match_uuid.eq(match_uuid))
+                    .order(match_invites::created_at.asc())
+                    .load::<(MatchInviteEntity, MatchesEntity)>(db)
+            })
+            .await
+            .map_err(MatchInviteDbError::from)
+    }
+
+    async fn respond_invite(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        from_status: String,
+        status: String,
+        join_open_statuses: Option<Vec<String>>,
+        updated_at: i64,
+    ) -> Result<(MatchInviteEntity, MatchesEntity), MatchInviteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // lock the match row so concurrent accepts don't lose participants
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchInviteDbError::MatchesNotFound)?;
+
+                    let invite = diesel::update(match_invites::table)
+                        .filter(match_invites::match_uuid.eq(match_uuid))
+                        .filter(match_invites::user_uuid.eq(user_uuid))
+                        .filter(match_invites::status.eq(from_status))
+                        .set((
+                            match_invites::status.eq(status),
+                            match_invites::updated_at.eq(updated_at),
+                        ))
+                        .get_result::<MatchInviteEntity>(db)
+                        .optional()?
+                        .ok_or(MatchInviteDbError::InviteNotFound)?;
+
+                    let open_statuses = match join_open_statuses {
+                        Some(open_statuses) => open_statuses,
+                        None => return Ok((invite, match_entity)),
+                    };
+                    if !open_statuses.contains(&match_entity.status)
+                        || match_entity.expires_at <= updated_at
+                    {
+                        return Err(MatchInviteDbError::MatchClosed);
+                    }
+                    if match_entity.participants_uuid.contains(&user_uuid) {
+                        return Ok((invite, match_entity));
+                    }
+
+                    let mut participants_uuid = match_entity.participants_uuid;
+                    participants_uuid.push(user_uuid);
+                    let match_entity = diesel::update(matches::table)
+                        .filter(matches::uuid.eq(match_uuid))
+                        .set((
+                            matches::participants_uuid.eq(participants_uuid),
+                            matches::updated_at.eq(updated_at),
+                        ))
+                        .get_result::<MatchesEntity>(db)?;
+                    Ok((invite, match_entity))
+                })
+            })
+            .await
+    }
+}
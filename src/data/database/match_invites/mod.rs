@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::matches::objects::MatchesEntity,
+    repository::objects::{PagingDomainRequest, PagingDomainResponse},
+};
+
+use self::objects::{MatchInviteDbError, MatchInviteEntity, MatchInviteEntityCreate};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchInvitesDatabase {
+    /// Inserts invites for users that are neither participants nor already invited,
+    /// as long as the match is in one of `open_statuses`.
+    async fn add_invites(
+        &self,
+        match_uuid: Uuid,
+        invites: Vec<MatchInviteEntityCreate>,
+        open_statuses: Vec<String>,
+    ) -> Result<Vec<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError>;
+    async fn get_user_invites<'a>(
+        &self,
+        status: String,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError>;
+    async fn get_match_invites(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<(MatchInviteEntity, MatchesEntity)>, MatchInviteDbError>;
+    /// Moves an invite from `from_status` to `status`. When `join_open_statuses` is set the
+    /// invitee also joins the participants, as long as the match is still in one of them.
+    async fn respond_invite(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        from_status: String,
+        status: String,
+        join_open_statuses: Option<Vec<String>>,
+        updated_at: i64,
+    ) -> Result<(MatchInviteEntity, MatchesEntity), MatchInviteDbError>;
+}
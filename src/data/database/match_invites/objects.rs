@@ -0,0 +1,43 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_invites;
+
+/// Represents an invitation for a user to join a match.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchInviteEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub invited_by: Uuid,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_invites)]
+pub struct MatchInviteEntityCreate {
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub invited_by: Uuid,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchInviteDbError {
+    UuidInvalid,
+    MatchesNotFound,
+    InviteNotFound,
+    MatchClosed,
+    InternalError,
+}
+
+impl From<Error> for MatchInviteDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match invites error: {:?}", err);
+        MatchInviteDbError::InternalError
+    }
+}
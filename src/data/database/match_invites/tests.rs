@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::{
+        database::{
+            match_invites::{
+                objects::{MatchInviteDbError, MatchInviteEntityCreate},
+                MatchInvitesDatabase,
+            },
+            matches::{
+                objects::{MatchesEntity, MatchesEntityCreate},
+                MatchesDatabase,
+            },
+            tests::database_test_utls::run_migration_get_conn,
+        },
+        repository::objects::PagingDomainRequest,
+    };
+    use crate::Conn;
+    use uuid::Uuid;
+
+    fn open_statuses() -> Option<Vec<String>> {
+        Some(vec!["pending".to_string(), "active".to_string()])
+    }
+
+    async fn create_match(
+        connection: &Conn,
+        creator: Uuid,
+        invitees: &[Uuid],
+        status: &str,
+    ) -> MatchesEntity {
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let invites = invitees
+            .iter()
+            .map(|user_uuid| MatchInviteEntityCreate {
+                match_uuid: Uuid::nil(),
+                user_uuid: *user_uuid,
+                invited_by: creator,
+                status: "pending".to_string(),
+                created_at: current_time_ms,
+                updated_at: current_time_ms,
+            })
+            .collect();
+        connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: creator,
+                    participants_uuid: vec![creator],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: status.to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                invites,
+                Vec::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_accept_invite_joins_match() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let invitee = Uuid::new_v4();
+        let match_entity = create_match(&connection, creator, &[invitee], "pending").await;
+        assert_eq!(match_entity.participants_uuid, vec![creator]);
+
+        let invite_uuid = invitee.to_string();
+        let request = PagingDomainRequest {
+            user_uuid: &invite_uuid,
+            request_uuid: &invite_uuid,
+            query: "",
+            page: 1,
+            page_size: 10,
+        };
+        let pending = connection
+            .get_user_invites("pending".to_string(), &request)
+            .await
+            .unwrap();
+        assert_eq!(pending.total, 1);
+        assert_eq!(pending.result[0].0.match_uuid, match_entity.uuid);
+
+        let (invite, updated) = connection
+            .respond_invite(
+                match_entity.uuid,
+                invitee,
+                "pending".to_string(),
+                "accepted".to_string(),
+                open_statuses(),
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(invite.status, "accepted");
+        assert_eq!(updated.participants_uuid, vec![creator, invitee]);
+
+        let repeated = connection
+            .respond_invite(
+                match_entity.uuid,
+                invitee,
+                "pending".to_string(),
+                "declined".to_string(),
+                None,
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await;
+        assert!(matches!(repeated, Err(MatchInviteDbError::InviteNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_decline_invite_keeps_participants() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let invitee = Uuid::new_v4();
+        let match_entity = create_match(&connection, creator, &[invitee], "pending").await;
+
+        let (invite, updated) = connection
+            .respond_invite(
+                match_entity.uuid,
+                invitee,
+                "pending".to_string(),
+                "declined".to_string(),
+                None,
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(invite.status, "declined");
+        assert_eq!(updated.participants_uuid, vec![creator]);
+
+        let invites = connection
+            .get_match_invites(match_entity.uuid)
+            .await
+            .unwrap();
+        assert_eq!(invites.len(), 1);
+        assert_eq!(invites[0].0.status, "declined");
+    }
+
+    #[tokio::test]
+    async fn test_accept_invite_closed_match() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let invitee = Uuid::new_v4();
+        let match_entity = create_match(&connection, creator, &[invitee], "cancelled").await;
+
+        let result = connection
+            .respond_invite(
+                match_entity.uuid,
+                invitee,
+                "pending".to_string(),
+                "accepted".to_string(),
+                open_statuses(),
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await;
+        assert!(matches!(result, Err(MatchInviteDbError::MatchClosed)));
+    }
+
+    #[tokio::test]
+    async fn test_add_invites_skips_existing() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let invited = Uuid::new_v4();
+        let fresh = Uuid::new_v4();
+        let match_entity = create_match(&connection, creator, &[invited], "pending").await;
+
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let invites = [creator, invited, fresh]
+            .iter()
+            .map(|user_uuid| MatchInviteEntityCreate {
+                match_uuid: match_entity.uuid,
+                user_uuid: *user_uuid,
+                invited_by: creator,
+                status: "pending".to_string(),
+                created_at: current_time_ms,
+                updated_at: current_time_ms,
+            })
+            .collect();
+        let created = connection
+            .add_invites(match_entity.uuid, invites, open_statuses().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].0.user_uuid, fresh);
+    }
+}
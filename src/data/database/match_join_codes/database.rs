@@ -0,0 +1,135 @@
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+use super::{
+    objects::{MatchJoinCodeDbError, MatchJoinCodeEntity, MatchJoinCodeEntityCreate},
+    MatchJoinCodesDatabase,
+};
+use crate::{
+    data::database::matches::objects::MatchesEntity,
+    schema::{match_join_codes, matches},
+    Conn,
+};
+
+#[async_trait]
+impl MatchJoinCodesDatabase for Conn {
+    async fn add_join_code(
+        &self,
+        join_code: MatchJoinCodeEntityCreate,
+    ) -> Result<MatchJoinCodeEntity, MatchJoinCodeDbError> {
+        self.0
+            .run(move |db| {
+                diesel::insert_into(match_join_codes::table)
+                    .values(&join_code)
+                    .on_conflict_do_nothing()
+                    .get_result::<MatchJoinCodeEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(MatchJoinCodeDbError::CodeConflict)
+    }
+
+    async fn get_join_codes(
+        &self,
+        match_uuid: Uuid,
+        active_at: i64,
+    ) -> Result<Vec<MatchJoinCodeEntity>, MatchJoinCodeDbError> {
+        self.0
+            .run(move |db| {
+                match_join_codes::table
+                    .filter(match_join_codes::match_uuid.eq(match_uuid))
+                    .filter(match_join_codes::revoked_at.is_null())
+                    .filter(
+                        match_join_codes::expires_at
+                            .is_null()
+                            .or(match_join_codes::expires_at.gt(active_at)),
+                    )
+                    .order(match_join_codes::created_at.desc())
+                    .then_order_by(match_join_codes::uuid)
+                    .load::<MatchJoinCodeEntity>(db)
+            })
+            .await
+            .map_err(MatchJoinCodeDbError::from)
+    }
+
+    async fn revoke_join_code(
+        &self,
+        match_uuid: Uuid,
+        code_uuid: Uuid,
+        revoked_at: i64,
+    ) -> Result<MatchJoinCodeEntity, MatchJoinCodeDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(match_join_codes::table)
+                    .filter(match_join_codes::uuid.eq(code_uuid))
+                    .filter(match_join_codes::match_uuid.eq(match_uuid))
+                    .filter(match_join_codes::revoked_at.is_null())
+                    .set(match_join_codes::revoked_at.eq(revoked_at))
+                    .get_result::<MatchJoinCodeEntity>(db)
+                    .optional()
+            })
+            .await?
+            .ok_or(MatchJoinCodeDbError::JoinCodeNotFound)
+    }
+
+    async fn redeem_join_code(
+        &self,
+        code: String,
+        user_uuid: Uuid,
+        open_statuses: Vec<String>,
+        redeemed_at: i64,
+    ) -> Result<MatchesEntity, MatchJoinCodeDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let join_code = match_join_codes::table
+                        .filter(match_join_codes::code.eq(code))
+                        .filter(match_join_codes::revoked_at.is_null())
+                        .for_update()
+                        .first::<MatchJoinCodeEntity>(db)
+                        .optional()?
+                        .ok_or(MatchJoinCodeDbError::JoinCodeNotFound)?;
+                    if matches!(join_code.expires_at, Some(expires_at) if expires_at <= redeemed_at)
+                    {
+                        return Err(MatchJoinCodeDbError::JoinCodeExpired);
+                    }
+
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(join_code.match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchJoinCodeDbError::MatchesNotFound)?;
+                    if !open_statuses.contains(&match_entity.status)
+                        || match_entity.expires_at <= redeemed_at
+                    {
+                        return Err(MatchJoinCodeDbError::MatchClosed);
+                    }
+                    if match_entity.participants_uuid.contains(&user_uuid) {
+                        return Ok(match_entity);
+                    }
+                    if match_entity.participants_uuid.len() as i64 >= join_code.max_participants {
+                        return Err(MatchJoinCodeDbError::ParticipantsLimitReached);
+                    }
+
+                    let mut participants_uuid = match_entity.participants_uuid;
+                    participants_uuid.push(user_uuid);
+                    let match_entity = diesel::update(matches::table)
+                        .filter(matches::uuid.eq(join_code.match_uuid))
+                        .set((
+                            matches::participants_uuid.eq(participants_uuid),
+                            matches::updated_at.eq(redeemed_at),
+                        ))
+                        .get_result::<MatchesEntity>(db)?;
+                    diesel::update(match_join_codes::table)
+                        .filter(match_join_codes::uuid.eq(join_code.uuid))
+                        .set(match_join_codes::uses.eq(match_join_codes::uses + 1))
+                        .execute(db)?;
+                    Ok(match_entity)
+                })
+            })
+            .await
+    }
+}
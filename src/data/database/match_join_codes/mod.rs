@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::data::database::matches::objects::MatchesEntity;
+
+use self::objects::{MatchJoinCodeDbError, MatchJoinCodeEntity, MatchJoinCodeEntityCreate};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchJoinCodesDatabase {
+    /// Fails with `CodeConflict` when the generated code is already taken.
+    async fn add_join_code(
+        &self,
+        join_code: MatchJoinCodeEntityCreate,
+    ) -> Result<MatchJoinCodeEntity, MatchJoinCodeDbError>;
+    /// Codes of the match that are neither revoked nor expired at `active_at`.
+    async fn get_join_codes(
+        &self,
+        match_uuid: Uuid,
+        active_at: i64,
+    ) -> Result<Vec<MatchJoinCodeEntity>, MatchJoinCodeDbError>;
+    async fn revoke_join_code(
+        &self,
+        match_uuid: Uuid,
+        code_uuid: Uuid,
+        revoked_at: i64,
+    ) -> Result<MatchJoinCodeEntity, MatchJoinCodeDbError>;
+    /// Adds the user to the participants of the code's match while the code is active,
+    /// the match is in one of `open_statuses` and below the code's participant limit.
+    /// Users that already participate get the match back without using the code.
+    async fn redeem_join_code(
+        &self,
+        code: String,
+        user_uuid: Uuid,
+        open_statuses: Vec<String>,
+        redeemed_at: i64,
+    ) -> Result<MatchesEntity, MatchJoinCodeDbError>;
+}
@@ -0,0 +1,47 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_join_codes;
+
+/// Shareable code that lets any user join a match until it expires or is revoked.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchJoinCodeEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub code: String,
+    pub created_by: Uuid,
+    pub max_participants: i64,
+    pub uses: i64,
+    pub expires_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_join_codes)]
+pub struct MatchJoinCodeEntityCreate {
+    pub match_uuid: Uuid,
+    pub code: String,
+    pub created_by: Uuid,
+    pub max_participants: i64,
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchJoinCodeDbError {
+    CodeConflict,
+    JoinCodeNotFound,
+    JoinCodeExpired,
+    MatchesNotFound,
+    MatchClosed,
+    ParticipantsLimitReached,
+    InternalError,
+}
+
+impl From<Error> for MatchJoinCodeDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match join codes error: {:?}", err);
+        MatchJoinCodeDbError::InternalError
+    }
+}
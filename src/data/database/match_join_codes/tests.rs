@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        match_join_codes::{
+            objects::{MatchJoinCodeDbError, MatchJoinCodeEntityCreate},
+            MatchJoinCodesDatabase,
+        },
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_join_codes_redeem_limit_expiry_and_revoke() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let creator = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: creator,
+                    participants_uuid: vec![creator],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let join_code = |code: &str, expires_at: Option<i64>| MatchJoinCodeEntityCreate {
+            match_uuid: match_entity.uuid,
+            code: code.to_string(),
+            created_by: creator,
+            max_participants: 2,
+            expires_at,
+            created_at: current_time_ms,
+        };
+        let suffix = &Uuid::new_v4().to_simple().to_string()[..8];
+        let code = format!("A{}", suffix);
+        let expiring_code = format!("B{}", suffix);
+        let created = connection
+            .add_join_code(join_code(&code, None))
+            .await
+            .unwrap();
+        connection
+            .add_join_code(join_code(&expiring_code, Some(current_time_ms + 10)))
+            .await
+            .unwrap();
+        let conflict = connection.add_join_code(join_code(&code, None)).await;
+        assert!(matches!(conflict, Err(MatchJoinCodeDbError::CodeConflict)));
+
+        let active = connection
+            .get_join_codes(match_entity.uuid, current_time_ms + 10)
+            .await
+            .unwrap();
+        assert_eq!(active, vec![created.clone()]);
+
+        let open_statuses = || vec!["pending".to_string(), "active".to_string()];
+        let expired = connection
+            .redeem_join_code(
+                expiring_code,
+                Uuid::new_v4(),
+                open_statuses(),
+                current_time_ms + 10,
+            )
+            .await;
+        assert!(matches!(
+            expired,
+            Err(MatchJoinCodeDbError::JoinCodeExpired)
+        ));
+
+        let joined_user = Uuid::new_v4();
+        let joined = connection
+            .redeem_join_code(code.clone(), joined_user, open_statuses(), current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(joined.participants_uuid, vec![creator, joined_user]);
+        let repeated = connection
+            .redeem_join_code(code.clone(), joined_user, open_statuses(), current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(repeated.participants_uuid, joined.participants_uuid);
+
+        let limited = connection
+            .redeem_join_code(
+                code.clone(),
+                Uuid::new_v4(),
+                open_statuses(),
+                current_time_ms,
+            )
+            .await;
+        assert!(matches!(
+            limited,
+            Err(MatchJoinCodeDbError::ParticipantsLimitReached)
+        ));
+
+        let revoked = connection
+            .revoke_join_code(match_entity.uuid, created.uuid, current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(revoked.uses, 1);
+        assert_eq!(revoked.revoked_at, Some(current_time_ms));
+        let repeated = connection
+            .revoke_join_code(match_entity.uuid, created.uuid, current_time_ms)
+            .await;
+        assert!(matches!(
+            repeated,
+            Err(MatchJoinCodeDbError::JoinCodeNotFound)
+        ));
+        let redeemed = connection
+            .redeem_join_code(code, Uuid::new_v4(), open_statuses(), current_time_ms)
+            .await;
+        assert!(matches!(
+            redeemed,
+            Err(MatchJoinCodeDbError::JoinCodeNotFound)
+        ));
+    }
+}
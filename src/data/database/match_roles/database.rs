@@ -0,0 +1,144 @@
+use diesel::{Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+use uuid::Uuid;
+
+use super::{
+    objects::{MatchRoleDbError, MatchRoleEntity, MatchRoleEntityCreate},
+    MatchRolesDatabase,
+};
+use crate::{
+    data::database::matches::objects::MatchesEntity,
+    schema::{match_invites, match_roles, matches},
+    Conn,
+};
+
+#[async_trait]
+impl MatchRolesDatabase for Conn {
+    async fn get_match_roles(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<MatchRoleEntity>, MatchRoleDbError> {
+        self.0
+            .run(move |db| {
+                match_roles::table
+                    .filter(match_roles::match_uuid.eq(match_uuid))
+                    .order(match_roles::created_at.asc())
+                    .load::<MatchRoleEntity>(db)
+            })
+            .await
+            .map_err(MatchRoleDbError::from)
+    }
+
+    async fn set_match_role(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        role: Option<String>,
+        updated_at: i64,
+    ) -> Result<(), MatchRoleDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // the match row is locked so the participant can't leave meanwhile
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchRoleDbError::MatchesNotFound)?;
+                    if !match_entity.participants_uuid.contains(&user_uuid)
+                        || match_entity.creator_uuid == user_uuid
+                    {
+                        return Err(MatchRoleDbError::ParticipantNotFound);
+                    }
+
+                    let stored = match_roles::table
+                        .filter(match_roles::match_uuid.eq(match_uuid))
+                        .filter(match_roles::user_uuid.eq(user_uuid));
+                    match role {
+                        Some(role) => {
+                            let entity = MatchRoleEntityCreate {
+                                match_uuid,
+                                user_uuid,
+                                role: role.clone(),
+                                created_at: updated_at,
+                                updated_at,
+                            };
+                            diesel::insert_into(match_roles::table)
+                                .values(&entity)
+                                .on_conflict((match_roles::match_uuid, match_roles::user_uuid))
+                                .do_update()
+                                .set((
+                                    match_roles::role.eq(role),
+                                    match_roles::updated_at.eq(updated_at),
+                                ))
+                                .execute(db)?;
+                        }
+                        None => {
+                            diesel::delete(stored).execute(db)?;
+                        }
+                    }
+                    Ok(())
+                })
+            })
+            .await
+    }
+
+    async fn transfer_ownership(
+        &self,
+        match_uuid: Uuid,
+        owner_uuid: Uuid,
+        new_owner_uuid: Uuid,
+        leaving: bool,
+        updated_at: i64,
+    ) -> Result<MatchesEntity, MatchRoleDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchRoleDbError::MatchesNotFound)?;
+                    if match_entity.creator_uuid != owner_uuid {
+                        return Err(MatchRoleDbError::NotOwner);
+                    }
+                    if new_owner_uuid == owner_uuid
+                        || !match_entity.participants_uuid.contains(&new_owner_uuid)
+                    {
+                        return Err(MatchRoleDbError::ParticipantNotFound);
+                    }
+
+                    let participants_uuid: Vec<Uuid> = match_entity
+                        .participants_uuid
+                        .into_iter()
+                        .filter(|uuid| !leaving || *uuid != owner_uuid)
+                        .collect();
+                    diesel::delete(
+                        match_roles::table
+                            .filter(match_roles::match_uuid.eq(match_uuid))
+                            .filter(match_roles::user_uuid.eq(new_owner_uuid)),
+                    )
+                    .execute(db)?;
+                    if leaving {
+                        diesel::delete(
+                            match_invites::table
+                                .filter(match_invites::match_uuid.eq(match_uuid))
+                                .filter(match_invites::user_uuid.eq(owner_uuid)),
+                        )
+                        .execute(db)?;
+                    }
+                    diesel::update(matches::table)
+                        .filter(matches::uuid.eq(match_uuid))
+                        .set((
+                            matches::creator_uuid.eq(new_owner_uuid),
+                            matches::participants_uuid.eq(participants_uuid),
+                            matches::updated_at.eq(updated_at),
+                        ))
+                        .get_result::<MatchesEntity>(db)
+                        .map_err(MatchRoleDbError::from)
+                })
+            })
+            .await
+    }
+}
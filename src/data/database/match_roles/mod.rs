@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::data::database::matches::objects::MatchesEntity;
+
+use self::objects::{MatchRoleDbError, MatchRoleEntity};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchRolesDatabase {
+    async fn get_match_roles(
+        &self,
+        match_uuid: Uuid,
+    ) -> Result<Vec<MatchRoleEntity>, MatchRoleDbError>;
+    /// Stores the role of a participant, `None` drops it so they're a plain member again.
+    async fn set_match_role(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        role: Option<String>,
+        updated_at: i64,
+    ) -> Result<(), MatchRoleDbError>;
+    /// Hands the match from `owner_uuid` to another participant and drops the stored role
+    /// of the new owner. With `leaving` the previous owner is removed from the participants
+    /// in the same transaction.
+    async fn transfer_ownership(
+        &self,
+        match_uuid: Uuid,
+        owner_uuid: Uuid,
+        new_owner_uuid: Uuid,
+        leaving: bool,
+        updated_at: i64,
+    ) -> Result<MatchesEntity, MatchRoleDbError>;
+}
@@ -0,0 +1,40 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_roles;
+
+/// Role stored for a participant, participants without one are members.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchRoleEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub role: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_roles)]
+pub struct MatchRoleEntityCreate {
+    pub match_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub role: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchRoleDbError {
+    MatchesNotFound,
+    ParticipantNotFound,
+    NotOwner,
+    InternalError,
+}
+
+impl From<Error> for MatchRoleDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match roles error: {:?}", err);
+        MatchRoleDbError::InternalError
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        match_roles::{objects::MatchRoleDbError, MatchRolesDatabase},
+        match_votes::{objects::MatchVoteEntityCreate, MatchVotesDatabase},
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_match_roles_votes_and_transfer() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let owner = Uuid::new_v4();
+        let moderator = Uuid::new_v4();
+        let member = Uuid::new_v4();
+        let viewer = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: owner,
+                    participants_uuid: vec![owner, moderator, member, viewer],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let roles = [
+            (moderator, "moderator"),
+            (viewer, "viewer"),
+            (member, "viewer"),
+        ];
+        for (user_uuid, role) in roles {
+            connection
+                .set_match_role(
+                    match_entity.uuid,
+                    user_uuid,
+                    Some(role.to_string()),
+                    current_time_ms,
+                )
+                .await
+                .unwrap();
+        }
+        connection
+            .set_match_role(match_entity.uuid, member, None, current_time_ms)
+            .await
+            .unwrap();
+        let stranger = connection
+            .set_match_role(
+                match_entity.uuid,
+                Uuid::new_v4(),
+                Some("viewer".to_string()),
+                current_time_ms,
+            )
+            .await;
+        assert!(matches!(
+            stranger,
+            Err(MatchRoleDbError::ParticipantNotFound)
+        ));
+
+        let stored = connection.get_match_roles(match_entity.uuid).await.unwrap();
+        let mut stored: Vec<(Uuid, String)> = stored
+            .into_iter()
+            .map(|entity| (entity.user_uuid, entity.role))
+            .collect();
+        stored.sort();
+        let mut expected = vec![
+            (moderator, "moderator".to_string()),
+            (viewer, "viewer".to_string()),
+        ];
+        expected.sort();
+        assert_eq!(stored, expected);
+
+        let candidate = connection
+            .add_candidate(
+                MatchCandidateEntityCreate {
+                    match_uuid: match_entity.uuid,
+                    reference_uuid: Uuid::new_v4(),
+                    title: "candidate".to_string(),
+                    image_url: "image_url".to_string(),
+                    metadata: None,
+                    created_by: owner,
+                    created_at: current_time_ms,
+                },
+                10,
+            )
+            .await
+            .unwrap();
+        let mut result = None;
+        for user_uuid in [owner, moderator, member] {
+            result = Some(
+                connection
+                    .add_vote(
+                        MatchVoteEntityCreate {
+                            match_uuid: match_entity.uuid,
+                            candidate_uuid: candidate.uuid,
+                            user_uuid,
+                            liked: true,
+                            created_at: current_time_ms,
+                        },
+                        vec!["pending".to_string(), "active".to_string()],
+                        vec!["viewer".to_string()],
+                        "completed".to_string(),
+                    )
+                    .await
+                    .unwrap(),
+            );
+        }
+        let result = result.unwrap();
+        assert_eq!(result.status, "completed");
+        assert_eq!(result.winner_candidate_uuid, Some(candidate.uuid));
+
+        let not_owner = connection
+            .transfer_ownership(match_entity.uuid, moderator, member, false, current_time_ms)
+            .await;
+        assert!(matches!(not_owner, Err(MatchRoleDbError::NotOwner)));
+
+        let transferred = connection
+            .transfer_ownership(match_entity.uuid, owner, moderator, true, current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(transferred.creator_uuid, moderator);
+        assert_eq!(
+            transferred.participants_uuid,
+            vec![moderator, member, viewer]
+        );
+        let stored = connection.get_match_roles(match_entity.uuid).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].user_uuid, viewer);
+
+        let kept = connection
+            .transfer_ownership(match_entity.uuid, moderator, viewer, false, current_time_ms)
+            .await
+            .unwrap();
+        assert_eq!(kept.creator_uuid, viewer);
+        assert_eq!(kept.participants_uuid, vec![moderator, member, viewer]);
+        assert!(connection
+            .get_match_roles(match_entity.uuid)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use super::{
+    objects::{MatchVoteDbError, MatchVoteEntity, MatchVoteEntityCreate, MatchVoteResultEntity},
+    MatchVotesDatabase,
+};
+use crate::{
+    data::database::{
+        match_candidates::objects::MatchCandidateEntity, matches::objects::MatchesEntity,
+        utils::paging::parce_uuid,
+    },
+    schema::{match_candidates, match_roles, match_votes, matches},
+    Conn,
+};
+use diesel::{
+    dsl::{exists, not},
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl MatchVotesDatabase for Conn {
+    async fn add_vote(
+        &self,
+        vote: MatchVoteEntityCreate,
+        open_statuses: Vec<String>,
+        non_voting_roles: Vec<String>,
+        completed_status: String,
+    ) -> Result<MatchVoteResultEntity, MatchVoteDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // the match row is locked so two final likes can't both pick a winner
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(vote.match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchVoteDbError::MatchesNotFound)?;
+                    // overdue matches are closed even before the expiry job catches up
+                    if !open_statuses.contains(&match_entity.status)
+                        || match_entity.expires_at <= vote.created_at
+                    {
+                        return Err(MatchVoteDbError::MatchClosed);
+                    }
+
+                    match_candidates::table
+                        .select(match_candidates::uuid)
+                        .filter(match_candidates::uuid.eq(vote.candidate_uuid))
+                        .filter(match_candidates::match_uuid.eq(vote.match_uuid))
+                        .first::<Uuid>(db)
+                        .optional()?
+                        .ok_or(MatchVoteDbError::CandidateNotFound)?;
+
+                    let stored_vote = diesel::insert_into(match_votes::table)
+                        .values(&vote)
+                        .on_conflict((match_votes::candidate_uuid, match_votes::user_uuid))
+                        .do_update()
+                        .set(&vote)
+                        .get_result::<MatchVoteEntity>(db)?;
+
+                    let non_voting: Vec<Uuid> = match_roles::table
+                        .select(match_roles::user_uuid)
+                        .filter(match_roles::match_uuid.eq(vote.match_uuid))
+                        .filter(match_roles::role.eq_any(&non_voting_roles))
+                        .load(db)?;
+                    let participants: Vec<Uuid> = match_entity
+                        .participants_uuid
+                        .into_iter()
+                        .filter(|uuid| !non_voting.contains(uuid))
+                        .collect();
+                    let likes: i64 = match_votes::table
+                        .filter(match_votes::candidate_uuid.eq(vote.candidate_uuid))
+                        .filter(match_votes::liked.eq(true))
+                        .filter(match_votes::user_uuid.eq_any(&participants))
+                        .count()
+                        .get_result(db)?;
+                    let is_unanimous = stored_vote.liked
+                        && !participants.is_empty()
+                        && likes >= participants.len() as i64;
+
+                    if !is_unanimous {
+                        return Ok(MatchVoteResultEntity {
+                            vote: stored_vote,
+                            status: match_entity.status,
+                            winner_candidate_uuid: match_entity.winner_candidate_uuid,
+                        });
+                    }
+
+                    let completed = diesel::update(matches::table)
+                        .filter(matches::uuid.eq(vote.match_uuid))
+                        .set((
+                            matches::status.eq(completed_status),
+                            matches::winner_candidate_uuid.eq(Some(vote.candidate_uuid)),
+                            matches::updated_at.eq(vote.created_at),
+                        ))
+                        .get_result::<MatchesEntity>(db)?;
+                    Ok(MatchVoteResultEntity {
+                        vote: stored_vote,
+                        status: completed.status,
+                        winner_candidate_uuid: completed.winner_candidate_uuid,
+                    })
+                })
+            })
+            .await
+    }
+
+    async fn get_next_candidate<'a>(
+        &self,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<Option<MatchCandidateEntity>, MatchVoteDbError> {
+        let match_uuid = parce_uuid(match_uuid).map_err(|_| MatchVoteDbError::UuidInvalid)?;
+        let user_uuid = parce_uuid(user_uuid).map_err(|_| MatchVoteDbError::UuidInvalid)?;
+        self.0
+            .run(move |db| {
+                match_candidates::table
+                    .filter(match_candidates::match_uuid.eq(match_uuid))
+                    .filter(not(exists(
+                        match_votes::table
+                            .filter(match_votes::candidate_uuid.eq(match_candidates::uuid))
+                            .filter(match_votes::user_uuid.eq(user_uuid)),
+                    )))
+                    .order(match_candidates::created_at.asc())
+                    .first::<MatchCandidateEntity>(db)
+                    .optional()
+                    .map_err(|err| err.into())
+            })
+            .await
+    }
+}
@@ -0,0 +1,25 @@
+use super::match_candidates::objects::MatchCandidateEntity;
+
+use self::objects::{MatchVoteDbError, MatchVoteEntityCreate, MatchVoteResultEntity};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait MatchVotesDatabase {
+    /// Completes the match once every participant, except the ones holding one of
+    /// `non_voting_roles`, liked the candidate.
+    async fn add_vote(
+        &self,
+        vote: MatchVoteEntityCreate,
+        open_statuses: Vec<String>,
+        non_voting_roles: Vec<String>,
+        completed_status: String,
+    ) -> Result<MatchVoteResultEntity, MatchVoteDbError>;
+    async fn get_next_candidate<'a>(
+        &self,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<Option<MatchCandidateEntity>, MatchVoteDbError>;
+}
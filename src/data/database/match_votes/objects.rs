@@ -0,0 +1,49 @@
+use diesel::result::Error;
+use uuid::Uuid;
+
+use crate::schema::match_votes;
+
+/// Represents a like or dislike of a single participant on a match candidate.
+#[derive(Queryable, PartialEq, Debug, Clone)]
+pub struct MatchVoteEntity {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub liked: bool,
+    pub created_at: i64,
+}
+
+#[derive(Insertable, AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = match_votes)]
+pub struct MatchVoteEntityCreate {
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub liked: bool,
+    pub created_at: i64,
+}
+
+/// Stored vote together with the match state right after it was applied.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MatchVoteResultEntity {
+    pub vote: MatchVoteEntity,
+    pub status: String,
+    pub winner_candidate_uuid: Option<Uuid>,
+}
+
+#[derive(Debug)]
+pub enum MatchVoteDbError {
+    UuidInvalid,
+    MatchesNotFound,
+    CandidateNotFound,
+    MatchClosed,
+    InternalError,
+}
+
+impl From<Error> for MatchVoteDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database match votes error: {:?}", err);
+        MatchVoteDbError::InternalError
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::data::database::{
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        match_votes::{
+            objects::{MatchVoteDbError, MatchVoteEntityCreate},
+            MatchVotesDatabase,
+        },
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_votes_detect_unanimous_winner() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let first_user = Uuid::new_v4();
+        let second_user = Uuid::new_v4();
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_entity = connection
+            .add_match(
+                MatchesEntityCreate {
+                    creator_uuid: first_user,
+                    participants_uuid: vec![first_user, second_user],
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    cover_url: "url".to_string(),
+                    status: "pending".to_string(),
+                    created_at: current_time_ms,
+                    updated_at: current_time_ms,
+                    expires_at: current_time_ms + 60_000,
+                    decision_method: "unanimous".to_string(),
+                },
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut candidates = Vec::new();
+        for index in 0..2 {
+            let candidate = connection
+                .add_candidate(
+                    MatchCandidateEntityCreate {
+                        match_uuid: match_entity.uuid,
+                        reference_uuid: Uuid::new_v4(),
+                        title: format!("candidate {}", index),
+                        image_url: "image_url".to_string(),
+                        metadata: None,
+                        created_by: first_user,
+                        created_at: current_time_ms + index,
+                    },
+                    10,
+                )
+                .await
+                .unwrap();
+            candidates.push(candidate);
+        }
+
+        let vote = |user_uuid: Uuid, candidate_uuid: Uuid, liked: bool| MatchVoteEntityCreate {
+            match_uuid: match_entity.uuid,
+            candidate_uuid,
+            user_uuid,
+            liked,
+            created_at: current_time_ms,
+        };
+        let open_statuses = || vec!["pending".to_string(), "active".to_string()];
+
+        let result = connection
+            .add_vote(
+                vote(first_user, candidates[0].uuid, true),
+                open_statuses(),
+                vec!["viewer".to_string()],
+                "completed".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.winner_candidate_uuid, None);
+
+        let result = connection
+            .add_vote(
+                vote(second_user, candidates[0].uuid, false),
+                open_statuses(),
+                vec!["viewer".to_string()],
+                "completed".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.winner_candidate_uuid, None);
+        assert_eq!(result.status, "pending");
+
+        let next = connection
+            .get_next_candidate(&match_entity.uuid.to_string(), &second_user.to_string())
+            .await
+            .unwrap();
+        assert_eq!(next.unwrap().uuid, candidates[1].uuid);
+
+        connection
+            .add_vote(
+                vote(first_user, candidates[1].uuid, true),
+                open_statuses(),
+                vec!["viewer".to_string()],
+                "completed".to_string(),
+            )
+            .await
+            .unwrap();
+        let result = connection
+            .add_vote(
+                vote(second_user, candidates[1].uuid, true),
+                open_statuses(),
+                vec!["viewer".to_string()],
+                "completed".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.status, "completed");
+        assert_eq!(result.winner_candidate_uuid, Some(candidates[1].uuid));
+
+        let next = connection
+            .get_next_candidate(&match_entity.uuid.to_string(), &second_user.to_string())
+            .await
+            .unwrap();
+        assert!(next.is_none());
+
+        let closed = connection
+            .add_vote(
+                vote(second_user, candidates[0].uuid, true),
+                open_statuses(),
+                vec!["viewer".to_string()],
+                "completed".to_string(),
+            )
+            .await;
+        assert!(matches!(closed, Err(MatchVoteDbError::MatchClosed)));
+    }
+}
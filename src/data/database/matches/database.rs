@@ -1,14 +1,24 @@
 use super::{
-    objects::{MatchesDbError, MatchesEntity, MatchesEntityCreate},
+    objects::{
+        MatchesDbError, MatchesEntity, MatchesEntityCreate, MatchesEntityFilter,
+        MatchesEntityOrder, MatchesEntityUpdate,
+    },
     MatchesDatabase,
 };
 use crate::{
-    data::repository::objects::{PagingDomainRequest, PagingDomainResponse},
-    schema::matches,
+    data::{
+        database::{
+            match_candidates::objects::MatchCandidateEntityCreate,
+            match_invites::objects::MatchInviteEntityCreate,
+        },
+        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+    },
+    schema::{items, match_candidates, match_invites, match_roles, matches},
     Conn,
 };
 use diesel::{
-    ExpressionMethods, PgArrayExpressionMethods, QueryDsl, RunQueryDsl, TextExpressionMethods,
+    pg::Pg, Connection, ExpressionMethods, NullableExpressionMethods, OptionalExtension,
+    PgArrayExpressionMethods, QueryDsl, RunQueryDsl, TextExpressionMethods,
 };
 use uuid::Uuid;
 
@@ -34,26 +44,62 @@ impl MatchesDatabase for Conn {
     async fn add_match(
         &self,
         match_entity: MatchesEntityCreate,
+        invites: Vec<MatchInviteEntityCreate>,
+        candidates: Vec<MatchCandidateEntityCreate>,
     ) -> Result<MatchesEntity, MatchesDbError> {
         self.0
             .run(move |db| {
-                diesel::insert_into(matches::table)
-                    .values(&match_entity)
-                    .get_result::<MatchesEntity>(db)
+                db.transaction(|db| {
+                    let created = diesel::insert_into(matches::table)
+                        .values(&match_entity)
+                        .get_result::<MatchesEntity>(db)?;
+                    let invites: Vec<MatchInviteEntityCreate> = invites
+                        .into_iter()
+                        .map(|invite| MatchInviteEntityCreate {
+                            match_uuid: created.uuid,
+                            ..invite
+                        })
+                        .collect();
+                    diesel::insert_into(match_invites::table)
+                        .values(&invites)
+                        .execute(db)?;
+                    let candidates: Vec<MatchCandidateEntityCreate> = candidates
+                        .into_iter()
+                        .map(|candidate| MatchCandidateEntityCreate {
+                            match_uuid: created.uuid,
+                            ..candidate
+                        })
+                        .collect();
+                    diesel::insert_into(match_candidates::table)
+                        .values(&candidates)
+                        .on_conflict_do_nothing()
+                        .execute(db)?;
+                    diesel::update(
+                        match_candidates::table
+                            .filter(match_candidates::match_uuid.eq(created.uuid))
+                            .filter(
+                                match_candidates::reference_uuid
+                                    .eq_any(items::table.select(items::uuid)),
+                            ),
+                    )
+                    .set(
+                        match_candidates::item_uuid.eq(match_candidates::reference_uuid.nullable()),
+                    )
+                    .execute(db)?;
+                    Ok(created)
+                })
             })
             .await
-            .map_err(|err| {
+            .map_err(|err: diesel::result::Error| {
                 println!("Database add_match error: {:?}", err);
-                match err {
-                    diesel::result::Error::NotFound => MatchesDbError::MatchesNotCreated,
-                    _ => MatchesDbError::InternalError,
-                }
+                MatchesDbError::MatchesNotCreated
             })
     }
 
     async fn get_matches<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: MatchesEntityFilter,
     ) -> Result<PagingDomainResponse<MatchesEntity>, MatchesDbError> {
         let query = request.query.to_owned();
         let uuid = Uuid::parse_str(request.request_uuid).map_err(|e| {
@@ -70,19 +116,25 @@ impl MatchesDatabase for Conn {
 
         self.0
             .run(move |db| {
-                let request_uuid = uuid.to_owned();
-                let query_request = matches::table
-                    .filter(matches::title.like(format!("%{}%", query)))
-                    .filter(matches::participants_uuid.contains(vec![request_uuid]));
-
-                let data_request = query_request.to_owned();
+                let data_request = filtered_matches(uuid, &query, &filter);
+                let data_request = match filter.order {
+                    MatchesEntityOrder::RecentlyUpdated => {
+                        data_request.order(matches::updated_at.desc())
+                    }
+                    MatchesEntityOrder::ExpiringSoon => {
+                        data_request.order(matches::expires_at.asc())
+                    }
+                    MatchesEntityOrder::Newest => data_request.order(matches::created_at.desc()),
+                };
                 let data = data_request
+                    .then_order_by(matches::uuid)
                     .limit(limit)
                     .offset(offset)
                     .load::<MatchesEntity>(db)?;
 
-                let total_request = query_request.to_owned();
-                let total_result = total_request.count().get_result(db)?;
+                let total_result = filtered_matches(uuid, &query, &filter)
+                    .count()
+                    .get_result(db)?;
 
                 Ok(PagingDomainResponse {
                     total: total_result,
@@ -117,4 +169,218 @@ impl MatchesDatabase for Conn {
                 MatchesDbError::InternalError
             })
     }
+
+    async fn update_match_status(
+        &self,
+        match_uuid: Uuid,
+        from_statuses: Vec<String>,
+        status: String,
+        updated_at: i64,
+    ) -> Result<Option<MatchesEntity>, MatchesDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(matches::table)
+                    .filter(matches::uuid.eq(match_uuid))
+                    .filter(matches::status.eq_any(from_statuses))
+                    .set((
+                        matches::status.eq(status),
+                        matches::updated_at.eq(updated_at),
+                    ))
+                    .get_result::<MatchesEntity>(db)
+                    .optional()
+            })
+            .await
+            .map_err(|err| {
+                println!("Database update_match_status error: {:?}", err);
+                MatchesDbError::InternalError
+            })
+    }
+
+    async fn complete_match(
+        &self,
+        match_uuid: Uuid,
+        from_statuses: Vec<String>,
+        status: String,
+        winner_candidate_uuid: Option<Uuid>,
+        updated_at: i64,
+    ) -> Result<Option<MatchesEntity>, MatchesDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(matches::table)
+                    .filter(matches::uuid.eq(match_uuid))
+                    .filter(matches::status.eq_any(from_statuses))
+                    .set((
+                        matches::status.eq(status),
+                        matches::winner_candidate_uuid.eq(winner_candidate_uuid),
+                        matches::updated_at.eq(updated_at),
+                    ))
+                    .get_result::<MatchesEntity>(db)
+                    .optional()
+            })
+            .await
+            .map_err(MatchesDbError::from)
+    }
+
+    async fn expire_overdue_matches(
+        &self,
+        open_statuses: Vec<String>,
+        expired_status: String,
+        now: i64,
+    ) -> Result<usize, MatchesDbError> {
+        self.0
+            .run(move |db| {
+                diesel::update(matches::table)
+                    .filter(matches::status.eq_any(open_statuses))
+                    .filter(matches::expires_at.le(now))
+                    .set((
+                        matches::status.eq(expired_status),
+                        matches::updated_at.eq(now),
+                    ))
+                    .execute(db)
+            })
+            .await
+            .map_err(|err| {
+                println!("Database expire_overdue_matches error: {:?}", err);
+                MatchesDbError::InternalError
+            })
+    }
+
+    async fn update_match(
+        &self,
+        match_uuid: Uuid,
+        changes: MatchesEntityUpdate,
+        open_statuses: Vec<String>,
+    ) -> Result<MatchesEntity, MatchesDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let status = matches::table
+                        .select(matches::status)
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<String>(db)
+                        .optional()?
+                        .ok_or(MatchesDbError::MatchesNotFound)?;
+                    if !open_statuses.contains(&status) {
+                        return Err(MatchesDbError::MatchClosed);
+                    }
+                    diesel::update(matches::table)
+                        .filter(matches::uuid.eq(match_uuid))
+                        .set(&changes)
+                        .get_result::<MatchesEntity>(db)
+                        .map_err(MatchesDbError::from)
+                })
+            })
+            .await
+    }
+
+    async fn delete_match(&self, match_uuid: Uuid) -> Result<(), MatchesDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    // votes cascade from candidates, invites cascade from the match
+                    diesel::delete(
+                        match_candidates::table.filter(match_candidates::match_uuid.eq(match_uuid)),
+                    )
+                    .execute(db)?;
+                    let count = diesel::delete(matches::table.filter(matches::uuid.eq(match_uuid)))
+                        .execute(db)?;
+                    match count {
+                        0 => Err(MatchesDbError::MatchesNotFound),
+                        _ => Ok(()),
+                    }
+                })
+            })
+            .await
+    }
+
+    async fn remove_participant(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        open_statuses: Vec<String>,
+        updated_at: i64,
+    ) -> Result<MatchesEntity, MatchesDbError> {
+        self.0
+            .run(move |db| {
+                db.transaction(|db| {
+                    let match_entity = matches::table
+                        .filter(matches::uuid.eq(match_uuid))
+                        .for_update()
+                        .first::<MatchesEntity>(db)
+                        .optional()?
+                        .ok_or(MatchesDbError::MatchesNotFound)?;
+                    if !open_statuses.contains(&match_entity.status) {
+                        return Err(MatchesDbError::MatchClosed);
+                    }
+
+                    let invites = diesel::delete(
+                        match_invites::table
+                            .filter(match_invites::match_uuid.eq(match_uuid))
+                            .filter(match_invites::user_uuid.eq(user_uuid)),
+                    )
+                    .execute(db)?;
+                    if !match_entity.participants_uuid.contains(&user_uuid) {
+                        return match invites {
+                            0 => Err(MatchesDbError::ParticipantNotFound),
+                            _ => Ok(match_entity),
+                        };
+                    }
+
+                    diesel::delete(
+                        match_roles::table
+                            .filter(match_roles::match_uuid.eq(match_uuid))
+                            .filter(match_roles::user_uuid.eq(user_uuid)),
+                    )
+                    .execute(db)?;
+                    let participants_uuid: Vec<Uuid> = match_entity
+                        .participants_uuid
+                        .into_iter()
+                        .filter(|uuid| *uuid != user_uuid)
+                        .collect();
+                    diesel::update(matches::table)
+                        .filter(matches::uuid.eq(match_uuid))
+                        .set((
+                            matches::participants_uuid.eq(participants_uuid),
+                            matches::updated_at.eq(updated_at),
+                        ))
+                        .get_result::<MatchesEntity>(db)
+                        .map_err(MatchesDbError::from)
+                })
+            })
+            .await
+    }
+}
+
+/// Matches of `request_uuid` whose title contains `query`, narrowed by `filter`.
+fn filtered_matches<'a>(
+    request_uuid: Uuid,
+    query: &str,
+    filter: &MatchesEntityFilter,
+) -> matches::BoxedQuery<'a, Pg> {
+    let mut request = matches::table
+        .filter(matches::title.like(format!("%{}%", query)))
+        .filter(matches::participants_uuid.contains(vec![request_uuid]))
+        .into_boxed();
+    if !filter.statuses.is_empty() {
+        request = request.filter(matches::status.eq_any(filter.statuses.to_owned()));
+    }
+    request = match filter.created_by_requester {
+        Some(true) => request.filter(matches::creator_uuid.eq(request_uuid)),
+        Some(false) => request.filter(matches::creator_uuid.ne(request_uuid)),
+        None => request,
+    };
+    if let Some(created_from) = filter.created_from {
+        request = request.filter(matches::created_at.ge(created_from));
+    }
+    if let Some(created_to) = filter.created_to {
+        request = request.filter(matches::created_at.le(created_to));
+    }
+    if let Some(expires_from) = filter.expires_from {
+        request = request.filter(matches::expires_at.ge(expires_from));
+    }
+    if let Some(expires_to) = filter.expires_to {
+        request = request.filter(matches::expires_at.le(expires_to));
+    }
+    request
 }
@@ -1,6 +1,16 @@
-use crate::data::repository::objects::{PagingDomainRequest, PagingDomainResponse};
+use uuid::Uuid;
 
-use self::objects::{MatchesDbError, MatchesEntity, MatchesEntityCreate};
+use crate::data::{
+    database::{
+        match_candidates::objects::MatchCandidateEntityCreate,
+        match_invites::objects::MatchInviteEntityCreate,
+    },
+    repository::objects::{PagingDomainRequest, PagingDomainResponse},
+};
+
+use self::objects::{
+    MatchesDbError, MatchesEntity, MatchesEntityCreate, MatchesEntityFilter, MatchesEntityUpdate,
+};
 
 mod database;
 pub mod objects;
@@ -9,13 +19,57 @@ mod tests;
 #[async_trait]
 pub trait MatchesDatabase {
     async fn get_match(&self, id: String) -> Result<MatchesEntity, MatchesDbError>;
+    /// Inserts the match, an invite for every invitee and the seeded candidates in one
+    /// transaction. The `match_uuid` of the given invites and candidates is replaced with
+    /// the new match uuid.
     async fn add_match(
         &self,
         match_entity: MatchesEntityCreate,
+        invites: Vec<MatchInviteEntityCreate>,
+        candidates: Vec<MatchCandidateEntityCreate>,
     ) -> Result<MatchesEntity, MatchesDbError>;
     async fn get_matches<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: MatchesEntityFilter,
     ) -> Result<PagingDomainResponse<MatchesEntity>, MatchesDbError>;
     async fn get_match_count<'a>(&self, user_uuid: &'a str) -> Result<i64, MatchesDbError>;
+    async fn update_match_status(
+        &self,
+        match_uuid: Uuid,
+        from_statuses: Vec<String>,
+        status: String,
+        updated_at: i64,
+    ) -> Result<Option<MatchesEntity>, MatchesDbError>;
+    /// Like `update_match_status`, but also records the winning candidate.
+    async fn complete_match(
+        &self,
+        match_uuid: Uuid,
+        from_statuses: Vec<String>,
+        status: String,
+        winner_candidate_uuid: Option<Uuid>,
+        updated_at: i64,
+    ) -> Result<Option<MatchesEntity>, MatchesDbError>;
+    async fn expire_overdue_matches(
+        &self,
+        open_statuses: Vec<String>,
+        expired_status: String,
+        now: i64,
+    ) -> Result<usize, MatchesDbError>;
+    /// Applies `changes` only while the match is in one of `open_statuses`.
+    async fn update_match(
+        &self,
+        match_uuid: Uuid,
+        changes: MatchesEntityUpdate,
+        open_statuses: Vec<String>,
+    ) -> Result<MatchesEntity, MatchesDbError>;
+    async fn delete_match(&self, match_uuid: Uuid) -> Result<(), MatchesDbError>;
+    /// Drops the user from the participants and removes their invite and role.
+    async fn remove_participant(
+        &self,
+        match_uuid: Uuid,
+        user_uuid: Uuid,
+        open_statuses: Vec<String>,
+        updated_at: i64,
+    ) -> Result<MatchesEntity, MatchesDbError>;
 }
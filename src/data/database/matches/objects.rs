@@ -1,3 +1,4 @@
+use diesel::result::Error;
 use uuid::Uuid;
 
 use crate::schema::matches;
@@ -11,9 +12,11 @@ pub struct MatchesEntity {
     pub description: String,
     pub cover_url: String,
     pub status: String,
-    pub expires_at: i64,
     pub created_at: i64,
     pub updated_at: i64,
+    pub expires_at: i64,
+    pub winner_candidate_uuid: Option<Uuid>,
+    pub decision_method: String,
 }
 
 #[derive(Insertable, PartialEq, Debug, Clone)]
@@ -28,12 +31,57 @@ pub struct MatchesEntityCreate {
     pub created_at: i64,
     pub updated_at: i64,
     pub expires_at: i64,
+    pub decision_method: String,
+}
+
+/// Ordering of a match listing, ties are broken by uuid.
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+pub enum MatchesEntityOrder {
+    #[default]
+    RecentlyUpdated,
+    ExpiringSoon,
+    Newest,
+}
+
+/// Narrows a match listing, empty `statuses` and `None` bounds match everything.
+/// Date bounds are inclusive.
+#[derive(Default, PartialEq, Debug, Clone)]
+pub struct MatchesEntityFilter {
+    pub statuses: Vec<String>,
+    /// `Some(true)` keeps matches created by the requester, `Some(false)` the ones
+    /// they joined.
+    pub created_by_requester: Option<bool>,
+    pub created_from: Option<i64>,
+    pub created_to: Option<i64>,
+    pub expires_from: Option<i64>,
+    pub expires_to: Option<i64>,
+    pub order: MatchesEntityOrder,
+}
+
+/// Editable match fields, `None` leaves the column untouched.
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = matches)]
+pub struct MatchesEntityUpdate {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub cover_url: Option<String>,
+    pub expires_at: Option<i64>,
+    pub updated_at: i64,
 }
 
 #[derive(Debug)]
 pub enum MatchesDbError {
     MatchesNotFound,
     MatchesNotCreated,
+    MatchClosed,
+    ParticipantNotFound,
     UuidInvalid,
     InternalError,
 }
+
+impl From<Error> for MatchesDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database matches error: {:?}", err);
+        MatchesDbError::InternalError
+    }
+}
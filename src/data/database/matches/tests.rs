@@ -2,9 +2,16 @@
 mod tests {
 
     use crate::data::database::{
-        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        matches::{
+            objects::{
+                MatchesDbError, MatchesEntityCreate, MatchesEntityFilter, MatchesEntityOrder,
+                MatchesEntityUpdate,
+            },
+            MatchesDatabase,
+        },
         tests::database_test_utls::run_migration_get_conn,
     };
+    use crate::data::repository::objects::PagingDomainRequest;
     use std::env;
     use uuid::Uuid;
 
@@ -26,17 +33,21 @@ mod tests {
             title: "title".to_string(),
             description: "description".to_string(),
             cover_url: "url".to_string(),
-            status: "status".to_string(),
+            status: "pending".to_string(),
             created_at: current_time_ms,
             updated_at: current_time_ms,
             expires_at: current_time_ms,
+            decision_method: "unanimous".to_string(),
         };
         let match_create_send = match_create.to_owned();
 
         let connection = run_migration_get_conn().await.unwrap();
 
         // Add matches
-        let result = connection.add_match(match_create_send).await.unwrap();
+        let result = connection
+            .add_match(match_create_send, Vec::new(), Vec::new())
+            .await
+            .unwrap();
 
         // Check matches
         let actual = result.to_owned();
@@ -52,4 +63,330 @@ mod tests {
 
         assert!(is_valid);
     }
+
+    #[tokio::test]
+    async fn test_update_match_status_only_from_allowed() {
+        env::set_var("JWT_ACCESS_SECRET", "JWT_ACCESS_SECRET");
+        env::set_var("JWT_REFRESH_SECRET", "JWT_REFRESH_SECRET");
+
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_create = MatchesEntityCreate {
+            creator_uuid: Uuid::new_v4(),
+            participants_uuid: Vec::new(),
+            title: "title".to_string(),
+            description: "description".to_string(),
+            cover_url: "url".to_string(),
+            status: "pending".to_string(),
+            created_at: current_time_ms,
+            updated_at: current_time_ms,
+            expires_at: current_time_ms,
+            decision_method: "unanimous".to_string(),
+        };
+
+        let connection = run_migration_get_conn().await.unwrap();
+        let created = connection
+            .add_match(match_create, Vec::new(), Vec::new())
+            .await
+            .unwrap();
+
+        // Not allowed from pending
+        let skipped = connection
+            .update_match_status(
+                created.uuid,
+                vec!["active".to_string()],
+                "completed".to_string(),
+                current_time_ms + 1,
+            )
+            .await
+            .unwrap();
+        assert!(skipped.is_none());
+
+        // Allowed from pending
+        let updated = connection
+            .update_match_status(
+                created.uuid,
+                vec!["pending".to_string()],
+                "active".to_string(),
+                current_time_ms + 1,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.status, "active");
+        assert_eq!(updated.updated_at, current_time_ms + 1);
+    }
+
+    #[tokio::test]
+    async fn test_expire_overdue_matches() {
+        env::set_var("JWT_ACCESS_SECRET", "JWT_ACCESS_SECRET");
+        env::set_var("JWT_REFRESH_SECRET", "JWT_REFRESH_SECRET");
+
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let match_create = |expires_at: i64| MatchesEntityCreate {
+            creator_uuid: Uuid::new_v4(),
+            participants_uuid: Vec::new(),
+            title: "title".to_string(),
+            description: "description".to_string(),
+            cover_url: "url".to_string(),
+            status: "active".to_string(),
+            created_at: current_time_ms,
+            updated_at: current_time_ms,
+            expires_at,
+            decision_method: "unanimous".to_string(),
+        };
+
+        let connection = run_migration_get_conn().await.unwrap();
+        let overdue = connection
+            .add_match(match_create(current_time_ms - 1), Vec::new(), Vec::new())
+            .await
+            .unwrap();
+        let upcoming = connection
+            .add_match(
+                match_create(current_time_ms + 60_000),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let expired = connection
+            .expire_overdue_matches(
+                vec!["pending".to_string(), "active".to_string()],
+                "expired".to_string(),
+                current_time_ms,
+            )
+            .await
+            .unwrap();
+        assert!(expired >= 1);
+
+        let overdue = connection
+            .get_match(overdue.uuid.to_string())
+            .await
+            .unwrap();
+        assert_eq!(overdue.status, "expired");
+        assert_eq!(overdue.updated_at, current_time_ms);
+
+        let upcoming = connection
+            .get_match(upcoming.uuid.to_string())
+            .await
+            .unwrap();
+        assert_eq!(upcoming.status, "active");
+    }
+
+    fn open_statuses() -> Vec<String> {
+        vec!["pending".to_string(), "active".to_string()]
+    }
+
+    fn match_with(creator: Uuid, participants: Vec<Uuid>, status: &str) -> MatchesEntityCreate {
+        let current_time_ms = chrono::Utc::now().timestamp_millis();
+        MatchesEntityCreate {
+            creator_uuid: creator,
+            participants_uuid: participants,
+            title: "title".to_string(),
+            description: "description".to_string(),
+            cover_url: "url".to_string(),
+            status: status.to_string(),
+            created_at: current_time_ms,
+            updated_at: current_time_ms,
+            expires_at: current_time_ms + 60_000,
+            decision_method: "unanimous".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_match_only_open() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let open = connection
+            .add_match(
+                match_with(creator, vec![creator], "active"),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+        let completed = connection
+            .add_match(
+                match_with(creator, vec![creator], "completed"),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let changes = MatchesEntityUpdate {
+            title: Some("new title".to_string()),
+            description: None,
+            cover_url: None,
+            expires_at: None,
+            updated_at: open.updated_at + 1,
+        };
+        let updated = connection
+            .update_match(open.uuid, changes.to_owned(), open_statuses())
+            .await
+            .unwrap();
+        assert_eq!(updated.title, "new title");
+        assert_eq!(updated.description, open.description);
+        assert_eq!(updated.updated_at, open.updated_at + 1);
+
+        let rejected = connection
+            .update_match(completed.uuid, changes, open_statuses())
+            .await;
+        assert!(matches!(rejected, Err(MatchesDbError::MatchClosed)));
+    }
+
+    #[tokio::test]
+    async fn test_delete_match() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let created = connection
+            .add_match(
+                match_with(creator, vec![creator], "pending"),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        connection.delete_match(created.uuid).await.unwrap();
+        let missing = connection.get_match(created.uuid.to_string()).await;
+        assert!(matches!(missing, Err(MatchesDbError::MatchesNotFound)));
+
+        let repeated = connection.delete_match(created.uuid).await;
+        assert!(matches!(repeated, Err(MatchesDbError::MatchesNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_remove_participant() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let creator = Uuid::new_v4();
+        let participant = Uuid::new_v4();
+        let created = connection
+            .add_match(
+                match_with(creator, vec![creator, participant], "active"),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        let updated = connection
+            .remove_participant(
+                created.uuid,
+                participant,
+                open_statuses(),
+                created.updated_at + 1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.participants_uuid, vec![creator]);
+
+        let repeated = connection
+            .remove_participant(
+                created.uuid,
+                participant,
+                open_statuses(),
+                created.updated_at + 2,
+            )
+            .await;
+        assert!(matches!(repeated, Err(MatchesDbError::ParticipantNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_get_matches_filter_and_order() {
+        let connection = run_migration_get_conn().await.unwrap();
+        let user = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let base = match_with(user, vec![user], "active");
+        let created = [
+            base.to_owned(),
+            MatchesEntityCreate {
+                creator_uuid: other,
+                participants_uuid: vec![other, user],
+                status: "pending".to_string(),
+                created_at: base.created_at + 10,
+                updated_at: base.created_at + 30,
+                expires_at: base.created_at + 30_000,
+                ..base.to_owned()
+            },
+            MatchesEntityCreate {
+                status: "completed".to_string(),
+                created_at: base.created_at + 20,
+                updated_at: base.created_at + 20,
+                expires_at: base.created_at + 90_000,
+                ..base.to_owned()
+            },
+        ];
+        let mut matches_uuid = Vec::new();
+        for entity in created {
+            let created = connection
+                .add_match(entity, Vec::new(), Vec::new())
+                .await
+                .unwrap();
+            matches_uuid.push(created.uuid);
+        }
+
+        let user_uuid = user.to_string();
+        let listed = |filter: MatchesEntityFilter| {
+            let connection = &connection;
+            let user_uuid = &user_uuid;
+            async move {
+                let request = PagingDomainRequest {
+                    user_uuid,
+                    request_uuid: user_uuid,
+                    query: "",
+                    page: 1,
+                    page_size: 10,
+                };
+                let response = connection.get_matches(request, filter).await.unwrap();
+                assert_eq!(response.total, response.result.len() as i64);
+                response
+                    .result
+                    .into_iter()
+                    .map(|entity| entity.uuid)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(
+            listed(MatchesEntityFilter::default()).await,
+            vec![matches_uuid[1], matches_uuid[2], matches_uuid[0]]
+        );
+        assert_eq!(
+            listed(MatchesEntityFilter {
+                statuses: vec!["completed".to_string(), "pending".to_string()],
+                order: MatchesEntityOrder::Newest,
+                ..Default::default()
+            })
+            .await,
+            vec![matches_uuid[2], matches_uuid[1]]
+        );
+        assert_eq!(
+            listed(MatchesEntityFilter {
+                created_by_requester: Some(false),
+                ..Default::default()
+            })
+            .await,
+            vec![matches_uuid[1]]
+        );
+        assert_eq!(
+            listed(MatchesEntityFilter {
+                created_by_requester: Some(true),
+                created_from: Some(base.created_at),
+                created_to: Some(base.created_at + 15),
+                ..Default::default()
+            })
+            .await,
+            vec![matches_uuid[0]]
+        );
+        assert_eq!(
+            listed(MatchesEntityFilter {
+                expires_to: Some(base.expires_at),
+                order: MatchesEntityOrder::ExpiringSoon,
+                ..Default::default()
+            })
+            .await,
+            vec![matches_uuid[1], matches_uuid[0]]
+        );
+    }
 }
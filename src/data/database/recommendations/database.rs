@@ -0,0 +1,164 @@
+use super::{
+    objects::{RecommendationCountEntity, RecommendationDbError, RecommendationEntity},
+    RecommendationsDatabase,
+};
+use crate::{
+    data::{
+        database::utils::paging::correct_page_number, repository::objects::PagingDomainResponse,
+    },
+    Conn,
+};
+use diesel::{sql_types, RunQueryDsl};
+use uuid::Uuid;
+
+/// Scored items of the user (`$1`) not favourited yet, `$2` and `$3` pairing the weighted
+/// users with their weights, `$4` being the visible list visibilities, `$5` the completed
+/// match status and `$6` the title pattern. Winners of matches are keyed by their item, or
+/// by their reference when it is not one.
+const RECOMMENDATIONS_RANKED: &str = "
+WITH others AS (
+    SELECT * FROM unnest($2::uuid[], $3::float8[]) AS others (user_uuid, weight)
+),
+mine AS (
+    SELECT DISTINCT favourite_uuid FROM favourite WHERE user_uuid = $1
+),
+sources AS (
+    SELECT favourite.user_uuid, favourite.favourite_uuid AS item_uuid, favourite.title,
+        'favourite' AS source
+    FROM favourite
+    JOIN others ON others.user_uuid = favourite.user_uuid
+    WHERE favourite.list_uuid IS NULL OR favourite.list_uuid IN (
+        SELECT uuid FROM favourite_lists WHERE visibility = ANY ($4)
+    )
+    UNION ALL
+    SELECT others.user_uuid,
+        COALESCE(match_candidates.item_uuid, match_candidates.reference_uuid) AS item_uuid,
+        match_candidates.title, 'match' AS source
+    FROM others
+    JOIN matches ON matches.status = $5
+        AND others.user_uuid = ANY (matches.participants_uuid)
+    JOIN match_candidates ON match_candidates.uuid = matches.winner_candidate_uuid
+),
+candidates AS (
+    SELECT sources.item_uuid, MIN(sources.title) AS title,
+        COUNT(DISTINCT sources.user_uuid) FILTER (WHERE sources.source = 'favourite')
+            AS liked_count,
+        COUNT(DISTINCT sources.user_uuid) FILTER (WHERE sources.source = 'match')
+            AS picked_count
+    FROM sources
+    WHERE NOT EXISTS (SELECT 1 FROM mine WHERE mine.favourite_uuid = sources.item_uuid)
+    GROUP BY sources.item_uuid
+    HAVING MIN(sources.title) ILIKE $6
+),
+holders AS (
+    SELECT DISTINCT user_uuid, item_uuid FROM sources
+    UNION
+    SELECT $1, favourite_uuid FROM mine
+),
+holders_count AS (
+    SELECT item_uuid, COUNT(*) AS users_count FROM holders GROUP BY item_uuid
+),
+weighted AS (
+    SELECT holders.item_uuid, SUM(others.weight) AS weight
+    FROM holders
+    JOIN candidates ON candidates.item_uuid = holders.item_uuid
+    JOIN others ON others.user_uuid = holders.user_uuid
+    GROUP BY holders.item_uuid
+),
+similarities AS (
+    SELECT candidate.item_uuid, favourite.item_uuid AS favourite_uuid,
+        COUNT(*)::float8
+            / SQRT((candidate_count.users_count * favourite_count.users_count)::float8)
+            AS similarity
+    FROM holders candidate
+    JOIN candidates ON candidates.item_uuid = candidate.item_uuid
+    JOIN holders favourite ON favourite.user_uuid = candidate.user_uuid
+    JOIN mine ON mine.favourite_uuid = favourite.item_uuid
+    JOIN holders_count candidate_count ON candidate_count.item_uuid = candidate.item_uuid
+    JOIN holders_count favourite_count ON favourite_count.item_uuid = favourite.item_uuid
+    GROUP BY candidate.item_uuid, favourite.item_uuid,
+        candidate_count.users_count, favourite_count.users_count
+),
+ranked AS (
+    SELECT candidates.item_uuid, candidates.title, candidates.liked_count,
+        candidates.picked_count,
+        weighted.weight + COALESCE(
+            (SELECT SUM(similarity) FROM similarities
+                WHERE similarities.item_uuid = candidates.item_uuid),
+            0
+        ) AS score,
+        (SELECT MIN(favourite.title) FROM favourite
+            WHERE favourite.user_uuid = $1 AND favourite.favourite_uuid = (
+                SELECT favourite_uuid FROM similarities
+                WHERE similarities.item_uuid = candidates.item_uuid
+                ORDER BY similarity DESC, favourite_uuid ASC
+                LIMIT 1
+            )) AS similar_to
+    FROM candidates
+    JOIN weighted ON weighted.item_uuid = candidates.item_uuid
+)
+";
+
+const COUNT_RECOMMENDATIONS_QUERY: &str = "
+SELECT COUNT(*) AS total FROM ranked
+";
+
+const GET_RECOMMENDATIONS_QUERY: &str = "
+SELECT * FROM ranked
+ORDER BY score DESC, liked_count + picked_count DESC, title ASC, item_uuid ASC
+LIMIT $7 OFFSET $8
+";
+
+#[async_trait]
+impl RecommendationsDatabase for Conn {
+    async fn get_recommendations(
+        &self,
+        user_uuid: Uuid,
+        weights: Vec<(Uuid, f64)>,
+        visibilities: Vec<String>,
+        completed_status: String,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<RecommendationEntity>, RecommendationDbError> {
+        let page_number = correct_page_number(page);
+        let offset = page_number * page_size;
+        let (others_uuid, weights): (Vec<Uuid>, Vec<f64>) = weights.into_iter().unzip();
+        let pattern = format!("%{}%", query);
+        self.0
+            .run(move |db| {
+                let total = diesel::sql_query(
+                    RECOMMENDATIONS_RANKED.to_owned() + COUNT_RECOMMENDATIONS_QUERY,
+                )
+                .bind::<sql_types::Uuid, _>(user_uuid)
+                .bind::<sql_types::Array<sql_types::Uuid>, _>(&others_uuid)
+                .bind::<sql_types::Array<sql_types::Double>, _>(&weights)
+                .bind::<sql_types::Array<sql_types::Text>, _>(&visibilities)
+                .bind::<sql_types::Text, _>(&completed_status)
+                .bind::<sql_types::Text, _>(&pattern)
+                .get_result::<RecommendationCountEntity>(db)?
+                .total;
+                let result = diesel::sql_query(
+                    RECOMMENDATIONS_RANKED.to_owned() + GET_RECOMMENDATIONS_QUERY,
+                )
+                .bind::<sql_types::Uuid, _>(user_uuid)
+                .bind::<sql_types::Array<sql_types::Uuid>, _>(&others_uuid)
+                .bind::<sql_types::Array<sql_types::Double>, _>(&weights)
+                .bind::<sql_types::Array<sql_types::Text>, _>(&visibilities)
+                .bind::<sql_types::Text, _>(&completed_status)
+                .bind::<sql_types::Text, _>(&pattern)
+                .bind::<sql_types::BigInt, _>(page_size)
+                .bind::<sql_types::BigInt, _>(offset)
+                .load::<RecommendationEntity>(db)?;
+                let result_count = i64::try_from(result.len()).unwrap_or(i64::MAX);
+                Ok(PagingDomainResponse {
+                    page: page_number + 1,
+                    page_size,
+                    total,
+                    has_more: offset + result_count < total,
+                    result,
+                })
+            })
+            .await
+    }
+}
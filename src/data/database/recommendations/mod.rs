@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::data::repository::objects::PagingDomainResponse;
+
+use self::objects::{RecommendationDbError, RecommendationEntity};
+
+mod database;
+pub mod objects;
+mod tests;
+
+#[async_trait]
+pub trait RecommendationsDatabase {
+    /// Items `user_uuid` has not favourited but some of `weights` users have, either as a
+    /// favourite kept in a list with one of `visibilities` or as the winner of a match in
+    /// `completed_status` they took part in. Each item scores the summed weights of those
+    /// users plus its cosine similarity, over the users keeping both, to every favourite of
+    /// `user_uuid`. Best scored first, titles matching `query`.
+    #[allow(clippy::too_many_arguments)]
+    async fn get_recommendations(
+        &self,
+        user_uuid: Uuid,
+        weights: Vec<(Uuid, f64)>,
+        visibilities: Vec<String>,
+        completed_status: String,
+        query: String,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<RecommendationEntity>, RecommendationDbError>;
+}
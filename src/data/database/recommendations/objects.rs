@@ -0,0 +1,39 @@
+use diesel::{result::Error, sql_types};
+use uuid::Uuid;
+
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct RecommendationEntity {
+    #[diesel(sql_type = sql_types::Uuid)]
+    pub item_uuid: Uuid,
+    #[diesel(sql_type = sql_types::Text)]
+    pub title: String,
+    #[diesel(sql_type = sql_types::Double)]
+    pub score: f64,
+    /// Weighted users who favourited the item.
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub liked_count: i64,
+    /// Weighted users who picked the item in a completed match.
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub picked_count: i64,
+    /// Title of the favourite of the user the item is most similar to.
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Text>)]
+    pub similar_to: Option<String>,
+}
+
+#[derive(QueryableByName, PartialEq, Debug, Clone)]
+pub struct RecommendationCountEntity {
+    #[diesel(sql_type = sql_types::BigInt)]
+    pub total: i64,
+}
+
+#[derive(Debug)]
+pub enum RecommendationDbError {
+    InternalError,
+}
+
+impl From<Error> for RecommendationDbError {
+    fn from(err: Error) -> Self {
+        eprintln!("Database recommendations error: {:?}", err);
+        RecommendationDbError::InternalError
+    }
+}
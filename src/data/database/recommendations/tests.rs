@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::database::{
+        favourite::UserFavouritesDatabase,
+        favourite_lists::{objects::FavouriteListEntityCreate, FavouriteListsDatabase},
+        match_candidates::{objects::MatchCandidateEntityCreate, MatchCandidatesDatabase},
+        matches::{objects::MatchesEntityCreate, MatchesDatabase},
+        recommendations::RecommendationsDatabase,
+        tests::database_test_utls::run_migration_get_conn,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_get_recommendations() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let (requester, close, distant) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let (alien, brazil, casablanca, dune, hidden) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        for (user_uuid, favourite_uuid, title) in [
+            (requester, alien, "Alien"),
+            (close, alien, "Alien"),
+            (close, brazil, "Brazil"),
+            (distant, brazil, "Brazil"),
+            (distant, dune, "Dune"),
+        ] {
+            connection
+                .add_list_entry(user_uuid, None, favourite_uuid, title.to_string())
+                .await
+                .unwrap();
+        }
+        let private_list = connection
+            .add_list(FavouriteListEntityCreate {
+                owner_uuid: distant,
+                title: "private".to_string(),
+                visibility: "private".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        connection
+            .add_list_entry(
+                distant,
+                Some(private_list.uuid),
+                hidden,
+                "Eraserhead".to_string(),
+            )
+            .await
+            .unwrap();
+
+        for (user_uuid, reference_uuid, title) in [
+            (close, brazil, "Brazil"),
+            (distant, casablanca, "Casablanca"),
+        ] {
+            let match_entity = connection
+                .add_match(
+                    MatchesEntityCreate {
+                        creator_uuid: user_uuid,
+                        participants_uuid: vec![user_uuid],
+                        title: "title".to_string(),
+                        description: "description".to_string(),
+                        cover_url: "url".to_string(),
+                        status: "active".to_string(),
+                        created_at: 0,
+                        updated_at: 0,
+                        expires_at: 60_000,
+                        decision_method: "unanimous".to_string(),
+                    },
+                    Vec::new(),
+                    Vec::new(),
+                )
+                .await
+                .unwrap();
+            let candidate = connection
+                .add_candidate(
+                    MatchCandidateEntityCreate {
+                        match_uuid: match_entity.uuid,
+                        reference_uuid,
+                        title: title.to_string(),
+                        image_url: "image_url".to_string(),
+                        metadata: None,
+                        created_by: user_uuid,
+                        created_at: 0,
+                    },
+                    10,
+                )
+                .await
+                .unwrap();
+            connection
+                .complete_match(
+                    match_entity.uuid,
+                    vec!["active".to_string()],
+                    "completed".to_string(),
+                    Some(candidate.uuid),
+                    10,
+                )
+                .await
+                .unwrap();
+        }
+
+        let recommendations = |query: &str, page: i64, page_size: i64| {
+            connection.get_recommendations(
+                requester,
+                vec![(close, 1.0), (distant, 0.2)],
+                vec!["public".to_string(), "followers".to_string()],
+                "completed".to_string(),
+                query.to_string(),
+                page,
+                page_size,
+            )
+        };
+        let ranked = recommendations("", 1, 10).await.unwrap();
+        assert_eq!(ranked.total, 3);
+        let ordered: Vec<(Uuid, i64, i64, Option<&str>)> = ranked
+            .result
+            .iter()
+            .map(|v| {
+                (
+                    v.item_uuid,
+                    v.liked_count,
+                    v.picked_count,
+                    v.similar_to.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            ordered,
+            vec![
+                (brazil, 2, 1, Some("Alien")),
+                (casablanca, 0, 1, None),
+                (dune, 1, 0, None),
+            ]
+        );
+        // both followed users, plus half of Brazil's users keeping Alien as well
+        let expected = 1.2 + 1.0 / (2.0 * 2.0_f64).sqrt();
+        assert!((ranked.result[0].score - expected).abs() < 1e-9);
+        assert!((ranked.result[1].score - 0.2).abs() < 1e-9);
+
+        let paged = recommendations("", 2, 2).await.unwrap();
+        assert_eq!((paged.total, paged.has_more), (3, false));
+        assert_eq!(paged.result[0].item_uuid, dune);
+
+        let filtered = recommendations("casa", 1, 10).await.unwrap();
+        assert_eq!(filtered.total, 1);
+        assert_eq!(filtered.result[0].title, "Casablanca");
+    }
+}
@@ -2,6 +2,8 @@ use crate::data::repository::user::objects::{
     UserCreateDataError, UserDataError, UserSearchDataRequest, UserSearchError,
 };
 
+use uuid::Uuid;
+
 use self::objects::{UserEntity, UserEntityCreate};
 
 pub mod objects;
@@ -11,6 +13,7 @@ pub mod user_db;
 #[async_trait]
 pub trait UserDatabase {
     async fn get_user<'a>(&self, uuid: &'a str) -> Result<UserEntity, UserDataError>;
+    async fn get_users<'a>(&self, uuids: &'a [Uuid]) -> Result<Vec<UserEntity>, UserDataError>;
     async fn get_user_by_login<'a>(&self, login: &'a str) -> Result<UserEntity, UserDataError>;
     async fn insert_user<'a>(
         &self,
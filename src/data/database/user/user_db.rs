@@ -31,6 +31,21 @@ impl UserDatabase for Conn {
             .await
     }
 
+    async fn get_users<'a>(&self, uuids: &'a [Uuid]) -> Result<Vec<UserEntity>, UserDataError> {
+        let uuids = uuids.to_owned();
+        self.0
+            .run(move |db| {
+                users::table
+                    .filter(users::id.eq_any(uuids))
+                    .get_results::<UserEntity>(db)
+                    .map_err(|err| {
+                        eprintln!("Error getting users: {}", err);
+                        UserDataError::InternalError
+                    })
+            })
+            .await
+    }
+
     async fn get_user_by_login<'a>(&self, login: &'a str) -> Result<UserEntity, UserDataError> {
         let login = login.to_owned().to_lowercase();
         self.0
@@ -1,2 +1,3 @@
+pub mod catalog;
 mod database;
 pub mod repository;
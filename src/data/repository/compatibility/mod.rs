@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use self::objects::{CompatibilityData, CompatibilityDataError, CompatibilityFriendData};
+
+use super::objects::{PagingDomainRequest, PagingDomainResponse};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+/// Scores are cached per pair of users and recomputed once older than
+/// `config::compatibility_cache_ttl_ms`.
+#[async_trait]
+pub trait CompatibilityRepository {
+    async fn get_compatibility<'a>(
+        &self,
+        request_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<CompatibilityData, CompatibilityDataError>;
+    /// Ranks the users `request.request_uuid` follows, most compatible first.
+    async fn get_compatibility_ranking<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<CompatibilityFriendData>, CompatibilityDataError>;
+    /// Compatibility of `request_uuid` with each of `others_uuid`, in no particular order.
+    async fn get_compatibilities(
+        &self,
+        request_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+    ) -> Result<Vec<CompatibilityData>, CompatibilityDataError>;
+}
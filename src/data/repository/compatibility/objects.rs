@@ -0,0 +1,156 @@
+use uuid::Uuid;
+
+use crate::data::database::compatibility::objects::{
+    CompatibilityCountsEntity, CompatibilityDbError, CompatibilityEntity,
+    CompatibilityRankingEntity,
+};
+
+/// Share of the score given to agreement on votes in shared matches.
+pub const COMPATIBILITY_VOTES_WEIGHT: f64 = 0.7;
+/// Share of the score given to the overlap of favourites.
+pub const COMPATIBILITY_FAVOURITES_WEIGHT: f64 = 0.3;
+
+/// Compatibility of the requesting user with `user_uuid`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompatibilityData {
+    pub user_uuid: Uuid,
+    /// Score from 0 to 100, `None` while the users have nothing to compare.
+    pub score: Option<i64>,
+    pub shared_matches: i64,
+    pub compared_votes: i64,
+    pub agreed_votes: i64,
+    pub shared_favourites: i64,
+    pub union_favourites: i64,
+    pub computed_at: i64,
+}
+
+pub struct CompatibilityFriendData {
+    pub username: String,
+    pub avatar_url: String,
+    pub compatibility: CompatibilityData,
+}
+
+#[derive(Debug)]
+pub enum CompatibilityDataError {
+    UuidInvalid,
+    UserNotFound,
+    SelfCompatibility,
+    InternalError,
+}
+
+/// Weighs the vote agreement rate and the Jaccard index of favourites into a score
+/// from 0 to 100. A component without data is left out and the other one takes its
+/// weight, without any data there is no score.
+pub fn compatibility_score(
+    compared_votes: i64,
+    agreed_votes: i64,
+    shared_favourites: i64,
+    union_favourites: i64,
+) -> Option<i64> {
+    let components = [
+        (compared_votes, agreed_votes, COMPATIBILITY_VOTES_WEIGHT),
+        (
+            union_favourites,
+            shared_favourites,
+            COMPATIBILITY_FAVOURITES_WEIGHT,
+        ),
+    ];
+    let (weighted, weights) = components.iter().filter(|(total, _, _)| *total > 0).fold(
+        (0.0, 0.0),
+        |(weighted, weights), (total, part, weight)| {
+            (
+                weighted + weight * (*part as f64 / *total as f64),
+                weights + weight,
+            )
+        },
+    );
+    if weights > 0.0 {
+        Some((weighted / weights * 100.0).round() as i64)
+    } else {
+        None
+    }
+}
+
+impl CompatibilityData {
+    pub fn from_counts(counts: CompatibilityCountsEntity, computed_at: i64) -> Self {
+        CompatibilityData {
+            user_uuid: counts.other_uuid,
+            score: compatibility_score(
+                counts.compared_votes,
+                counts.agreed_votes,
+                counts.shared_favourites,
+                counts.union_favourites,
+            ),
+            shared_matches: counts.shared_matches,
+            compared_votes: counts.compared_votes,
+            agreed_votes: counts.agreed_votes,
+            shared_favourites: counts.shared_favourites,
+            union_favourites: counts.union_favourites,
+            computed_at,
+        }
+    }
+
+    /// Reads a cached pair from the side of `request_uuid`.
+    pub fn from_entity(entity: CompatibilityEntity, request_uuid: Uuid) -> Self {
+        let other_uuid = if entity.user_uuid == request_uuid {
+            entity.other_uuid
+        } else {
+            entity.user_uuid
+        };
+        let counts = CompatibilityCountsEntity {
+            other_uuid,
+            shared_matches: entity.shared_matches,
+            compared_votes: entity.compared_votes,
+            agreed_votes: entity.agreed_votes,
+            shared_favourites: entity.shared_favourites,
+            union_favourites: entity.union_favourites,
+        };
+        CompatibilityData::from_counts(counts, entity.computed_at)
+    }
+
+    /// Cache row of the pair, keyed with the smaller uuid first.
+    pub fn into_entity(self, request_uuid: Uuid) -> CompatibilityEntity {
+        let (user_uuid, other_uuid) = if request_uuid < self.user_uuid {
+            (request_uuid, self.user_uuid)
+        } else {
+            (self.user_uuid, request_uuid)
+        };
+        CompatibilityEntity {
+            user_uuid,
+            other_uuid,
+            shared_matches: self.shared_matches,
+            compared_votes: self.compared_votes,
+            agreed_votes: self.agreed_votes,
+            shared_favourites: self.shared_favourites,
+            union_favourites: self.union_favourites,
+            score: self.score,
+            computed_at: self.computed_at,
+        }
+    }
+}
+
+impl From<CompatibilityRankingEntity> for CompatibilityFriendData {
+    fn from(entity: CompatibilityRankingEntity) -> Self {
+        let counts = CompatibilityCountsEntity {
+            other_uuid: entity.other_uuid,
+            shared_matches: entity.shared_matches,
+            compared_votes: entity.compared_votes,
+            agreed_votes: entity.agreed_votes,
+            shared_favourites: entity.shared_favourites,
+            union_favourites: entity.union_favourites,
+        };
+        CompatibilityFriendData {
+            username: entity.username,
+            avatar_url: entity.avatar_url,
+            compatibility: CompatibilityData::from_counts(counts, entity.computed_at),
+        }
+    }
+}
+
+impl From<CompatibilityDbError> for CompatibilityDataError {
+    fn from(error: CompatibilityDbError) -> Self {
+        match error {
+            CompatibilityDbError::InternalError => CompatibilityDataError::InternalError,
+        }
+    }
+}
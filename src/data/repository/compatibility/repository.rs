@@ -0,0 +1,142 @@
+use uuid::Uuid;
+
+use crate::{
+    config,
+    data::{
+        database::{
+            compatibility::CompatibilityDatabase, follow::FollowDatabase, user::UserDatabase,
+        },
+        repository::{
+            favourite_lists::objects::FavouriteListVisibility,
+            matches::objects::MatchStatus,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{CompatibilityData, CompatibilityDataError, CompatibilityFriendData},
+    CompatibilityRepository,
+};
+
+#[async_trait]
+impl CompatibilityRepository for Conn {
+    async fn get_compatibility<'a>(
+        &self,
+        request_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<CompatibilityData, CompatibilityDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let other_uuid = parse_uuid(user_uuid)?;
+        if request_uuid == other_uuid {
+            return Result::Err(CompatibilityDataError::SelfCompatibility);
+        }
+        UserDatabase::get_user(self, user_uuid)
+            .await
+            .map_err(|_| CompatibilityDataError::UserNotFound)?;
+        load_compatibility(self, request_uuid, vec![other_uuid])
+            .await?
+            .pop()
+            .ok_or(CompatibilityDataError::InternalError)
+    }
+
+    async fn get_compatibility_ranking<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<CompatibilityFriendData>, CompatibilityDataError> {
+        let request_uuid = parse_uuid(request.request_uuid)?;
+        let others_uuid = self
+            .get_all_following(&request_uuid)
+            .await
+            .map_err(|_| CompatibilityDataError::InternalError)?
+            .into_iter()
+            .map(|follower| follower.followed_uuid)
+            .collect();
+        load_compatibility(self, request_uuid, others_uuid).await?;
+        let ranking = CompatibilityDatabase::get_compatibility_ranking(
+            self,
+            request_uuid,
+            request.query.to_string(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+        Ok(PagingDomainResponse {
+            page: ranking.page,
+            page_size: ranking.page_size,
+            total: ranking.total,
+            has_more: ranking.has_more,
+            result: ranking
+                .result
+                .into_iter()
+                .map(|entity| entity.into())
+                .collect(),
+        })
+    }
+
+    async fn get_compatibilities(
+        &self,
+        request_uuid: Uuid,
+        others_uuid: Vec<Uuid>,
+    ) -> Result<Vec<CompatibilityData>, CompatibilityDataError> {
+        load_compatibility(self, request_uuid, others_uuid).await
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, CompatibilityDataError> {
+    Uuid::parse_str(value).map_err(|_| CompatibilityDataError::UuidInvalid)
+}
+
+/// Reuses fresh cached pairs and computes the rest in a single query, caching them with
+/// their score so the ranking can order by it.
+async fn load_compatibility(
+    conn: &Conn,
+    request_uuid: Uuid,
+    others_uuid: Vec<Uuid>,
+) -> Result<Vec<CompatibilityData>, CompatibilityDataError> {
+    if others_uuid.is_empty() {
+        return Ok(Vec::new());
+    }
+    let now = chrono::Utc::now().timestamp_millis();
+    let fresh_after = now - config::compatibility_cache_ttl_ms();
+    let mut compatibility: Vec<CompatibilityData> = conn
+        .get_cached_compatibility(request_uuid, others_uuid.clone(), fresh_after)
+        .await?
+        .into_iter()
+        .map(|entity| CompatibilityData::from_entity(entity, request_uuid))
+        .collect();
+
+    let missing: Vec<Uuid> = others_uuid
+        .into_iter()
+        .filter(|uuid| !compatibility.iter().any(|data| data.user_uuid == *uuid))
+        .collect();
+    if missing.is_empty() {
+        return Ok(compatibility);
+    }
+    let visibilities = |is_follower: bool| -> Vec<String> {
+        FavouriteListVisibility::visible(false, is_follower)
+            .into_iter()
+            .map(|visibility| visibility.into())
+            .collect()
+    };
+    let computed: Vec<CompatibilityData> = conn
+        .compute_compatibility(
+            request_uuid,
+            missing,
+            MatchStatus::Completed.into(),
+            visibilities(false),
+            visibilities(true),
+        )
+        .await?
+        .into_iter()
+        .map(|counts| CompatibilityData::from_counts(counts, now))
+        .collect();
+    let entities = computed
+        .iter()
+        .map(|data| data.to_owned().into_entity(request_uuid))
+        .collect();
+    conn.store_compatibility(entities).await?;
+    compatibility.extend(computed);
+    Ok(compatibility)
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::repository::compatibility::objects::{compatibility_score, CompatibilityData};
+
+    #[test]
+    fn test_compatibility_score_weights_available_components() {
+        assert_eq!(compatibility_score(0, 0, 0, 0), None);
+        assert_eq!(compatibility_score(4, 3, 0, 0), Some(75));
+        assert_eq!(compatibility_score(0, 0, 1, 4), Some(25));
+        assert_eq!(compatibility_score(10, 10, 0, 5), Some(70));
+        assert_eq!(compatibility_score(2, 1, 1, 2), Some(50));
+    }
+
+    #[test]
+    fn test_compatibility_round_trips_cache_pair() {
+        let request_uuid = Uuid::new_v4();
+        let data = CompatibilityData {
+            user_uuid: Uuid::new_v4(),
+            score: Some(75),
+            shared_matches: 1,
+            compared_votes: 4,
+            agreed_votes: 3,
+            shared_favourites: 0,
+            union_favourites: 0,
+            computed_at: 10,
+        };
+        let entity = data.clone().into_entity(request_uuid);
+        assert!(entity.user_uuid < entity.other_uuid);
+        assert_eq!(CompatibilityData::from_entity(entity, request_uuid), data);
+    }
+}
@@ -1,4 +1,7 @@
-use self::objects::{FavouriteDataError, FavouriteDataResponse};
+use self::objects::{
+    FavouriteDataError, FavouriteDataFilter, FavouriteDataResponse, FavouriteStatusCountData,
+    FavouriteStatusData, FavouriteUserData,
+};
 
 use super::objects::{PagingDomainRequest, PagingDomainResponse};
 
@@ -25,8 +28,37 @@ pub trait FavouriteRepository {
         uuid: &'a str,
         favourite_uuid: &'a str,
     ) -> Result<bool, FavouriteDataError>;
+    /// Favourites of the default list matching `filter`. Notes are only loaded when the
+    /// requester owns the favourites.
     async fn get_user_favourites<'a>(
         &self,
         request: &'a PagingDomainRequest<'a>,
+        filter: &'a FavouriteDataFilter<'a>,
     ) -> Result<PagingDomainResponse<FavouriteDataResponse>, FavouriteDataError>;
+    /// Users other than `request_uuid` holding `favourite_uuid`, people the requester follows
+    /// first. Favourites kept in lists the requester cannot see are left out.
+    async fn get_favourite_users<'a>(
+        &self,
+        request_uuid: &'a str,
+        favourite_uuid: &'a str,
+        query: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteUserData>, FavouriteDataError>;
+    async fn get_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<FavouriteStatusData, FavouriteDataError>;
+    /// Moves the favourite to `status` in every list holding it.
+    async fn update_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        status: &'a str,
+    ) -> Result<FavouriteStatusData, FavouriteDataError>;
+    async fn get_favourites_status_count<'a>(
+        &self,
+        uuid: &'a str,
+    ) -> Result<FavouriteStatusCountData, FavouriteDataError>;
 }
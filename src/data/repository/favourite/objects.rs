@@ -1,8 +1,22 @@
 use uuid::Uuid;
 
-use crate::data::{
-    database::favourite::objects::{FavouriteDbError, FavouriteEntityResponse},
-    repository::objects::PagingDomainResponse,
+use crate::{
+    data::{
+        database::favourite::objects::{
+            FavouriteDbError, FavouriteEntityFilter, FavouriteEntityResponse,
+            FavouriteStatusTransitionEntity, FavouriteUserEntity,
+        },
+        repository::{
+            favourite_tags::{
+                objects::{normalize_tag, FavouriteNoteData, FavouriteTagData},
+                FavouriteTagsRepository,
+            },
+            item_ratings::{objects::ItemRatingData, ItemRatingsRepository},
+            items::{objects::ItemData, ItemsRepository},
+            objects::PagingDomainResponse,
+        },
+    },
+    Conn,
 };
 
 #[derive(Debug, Clone)]
@@ -10,6 +24,9 @@ pub enum FavouriteDataError {
     UuidInvalid,
     UserNotFound,
     Conflict,
+    EntryNotFound,
+    OrderInvalid,
+    StatusInvalid,
     InternalError,
 }
 
@@ -19,11 +36,49 @@ impl Into<FavouriteDataError> for FavouriteDbError {
             FavouriteDbError::UuidInvalid => FavouriteDataError::UuidInvalid,
             FavouriteDbError::UserNotFound => FavouriteDataError::UserNotFound,
             FavouriteDbError::Conflict => FavouriteDataError::Conflict,
+            FavouriteDbError::EntryNotFound => FavouriteDataError::EntryNotFound,
+            FavouriteDbError::OrderInvalid => FavouriteDataError::OrderInvalid,
             FavouriteDbError::InternalError => FavouriteDataError::InternalError,
         }
     }
 }
 
+/// Progress of the owner through a favourite, shared by every list holding it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FavouriteStatus {
+    #[default]
+    Want,
+    InProgress,
+    Done,
+    Dropped,
+}
+
+impl From<FavouriteStatus> for String {
+    fn from(status: FavouriteStatus) -> Self {
+        match status {
+            FavouriteStatus::Want => "want",
+            FavouriteStatus::InProgress => "in_progress",
+            FavouriteStatus::Done => "done",
+            FavouriteStatus::Dropped => "dropped",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for FavouriteStatus {
+    type Error = FavouriteDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "want" => Ok(FavouriteStatus::Want),
+            "in_progress" => Ok(FavouriteStatus::InProgress),
+            "done" => Ok(FavouriteStatus::Done),
+            "dropped" => Ok(FavouriteStatus::Dropped),
+            _ => Err(FavouriteDataError::StatusInvalid),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct FavouriteDataResponse {
@@ -31,6 +86,110 @@ pub struct FavouriteDataResponse {
     pub user_uuid: Uuid,
     pub favourite_uuid: Uuid,
     pub title: String,
+    pub item_uuid: Option<Uuid>,
+    /// Catalogue metadata, only loaded for favourite lists.
+    pub item: Option<ItemData>,
+    pub list_uuid: Option<Uuid>,
+    pub position: i64,
+    pub pinned: bool,
+    pub status: FavouriteStatus,
+    pub status_updated_at: i64,
+    /// The owner's rating of the favourite, only loaded for favourite listings.
+    pub rating: Option<ItemRatingData>,
+    /// The owner's tags, only loaded for favourite listings.
+    pub tags: Vec<String>,
+    /// The owner's private note, only loaded when the owner lists their own favourites.
+    pub note: Option<String>,
+}
+
+impl FavouriteDataResponse {
+    /// Sets `item` on every favourite whose item is among `items`.
+    pub fn attach_items(favourites: &mut [FavouriteDataResponse], items: &[ItemData]) {
+        for favourite in favourites.iter_mut() {
+            favourite.item = items
+                .iter()
+                .find(|item| Some(item.uuid) == favourite.item_uuid)
+                .cloned();
+        }
+    }
+
+    /// Sets `rating` on every favourite rated among `ratings`.
+    pub fn attach_ratings(favourites: &mut [FavouriteDataResponse], ratings: &[ItemRatingData]) {
+        for favourite in favourites.iter_mut() {
+            favourite.rating = ratings
+                .iter()
+                .find(|rating| {
+                    rating.user_uuid == favourite.user_uuid
+                        && rating.item_uuid == favourite.favourite_uuid
+                })
+                .cloned();
+        }
+    }
+
+    /// Sets `tags` on every favourite from the tags among `tags`.
+    pub fn attach_tags(favourites: &mut [FavouriteDataResponse], tags: &[FavouriteTagData]) {
+        for favourite in favourites.iter_mut() {
+            favourite.tags = tags
+                .iter()
+                .filter(|tag| tag.favourite_uuid == favourite.favourite_uuid)
+                .map(|tag| tag.tag.to_owned())
+                .collect();
+        }
+    }
+
+    /// Sets `note` on every favourite having one among `notes`.
+    pub fn attach_notes(favourites: &mut [FavouriteDataResponse], notes: &[FavouriteNoteData]) {
+        for favourite in favourites.iter_mut() {
+            favourite.note = notes
+                .iter()
+                .find(|note| note.favourite_uuid == favourite.favourite_uuid)
+                .map(|note| note.note.to_owned());
+        }
+    }
+
+    /// Loads the items, ratings and tags of favourites of `owner_uuid` listed to
+    /// `request_uuid`, and their notes when the requester is the owner.
+    pub async fn load_details(
+        conn: &Conn,
+        owner_uuid: Uuid,
+        request_uuid: Uuid,
+        favourites: &mut [FavouriteDataResponse],
+    ) -> Result<(), FavouriteDataError> {
+        let items_uuid = favourites
+            .iter()
+            .filter_map(|favourite| favourite.item_uuid)
+            .collect();
+        let items = ItemsRepository::get_items(conn, items_uuid)
+            .await
+            .map_err(|_| FavouriteDataError::InternalError)?;
+        FavouriteDataResponse::attach_items(favourites, &items);
+        let favourites_uuid: Vec<Uuid> = favourites
+            .iter()
+            .map(|favourite| favourite.favourite_uuid)
+            .collect();
+        let ratings =
+            ItemRatingsRepository::get_user_ratings(conn, owner_uuid, favourites_uuid.to_owned())
+                .await
+                .map_err(|_| FavouriteDataError::InternalError)?;
+        FavouriteDataResponse::attach_ratings(favourites, &ratings);
+        let tags = FavouriteTagsRepository::get_favourites_tags(
+            conn,
+            owner_uuid,
+            favourites_uuid.to_owned(),
+        )
+        .await
+        .map_err(|_| FavouriteDataError::InternalError)?;
+        FavouriteDataResponse::attach_tags(favourites, &tags);
+        // notes are private to the owner
+        if request_uuid == owner_uuid {
+            let notes =
+                FavouriteTagsRepository::get_favourites_notes(conn, owner_uuid, favourites_uuid)
+                    .await
+                    .map_err(|_| FavouriteDataError::InternalError)?;
+            FavouriteDataResponse::attach_notes(favourites, &notes);
+        }
+        Ok(())
+    }
 }
 
 impl Into<FavouriteDataResponse> for FavouriteEntityResponse {
@@ -40,6 +199,16 @@ impl Into<FavouriteDataResponse> for FavouriteEntityResponse {
             user_uuid: self.user_uuid,
             favourite_uuid: self.favourite_uuid,
             title: self.title.to_owned(),
+            item_uuid: self.item_uuid,
+            item: None,
+            list_uuid: self.list_uuid,
+            position: self.position,
+            pinned: self.pinned,
+            status: FavouriteStatus::try_from(self.status.as_str()).unwrap_or_default(),
+            status_updated_at: self.status_updated_at,
+            rating: None,
+            tags: Vec::new(),
+            note: None,
         }
     }
 }
@@ -57,3 +226,99 @@ impl Into<PagingDomainResponse<FavouriteDataResponse>>
         }
     }
 }
+
+/// A user holding a favourite, as seen by the requesting user.
+#[derive(Debug, Clone)]
+pub struct FavouriteUserData {
+    pub uuid: Uuid,
+    pub username: String,
+    pub avatar_url: String,
+    pub is_following: bool,
+}
+
+impl From<FavouriteUserEntity> for FavouriteUserData {
+    fn from(entity: FavouriteUserEntity) -> Self {
+        FavouriteUserData {
+            uuid: entity.uuid,
+            username: entity.username,
+            avatar_url: entity.avatar_url,
+            is_following: entity.is_following,
+        }
+    }
+}
+
+/// Optional narrowing of listed favourites, an entry has to carry every one of `tags`.
+#[derive(Debug, Clone, Default)]
+pub struct FavouriteDataFilter<'a> {
+    pub status: Option<&'a str>,
+    pub tags: Vec<&'a str>,
+}
+
+impl<'a> TryFrom<&FavouriteDataFilter<'a>> for FavouriteEntityFilter {
+    type Error = FavouriteDataError;
+
+    fn try_from(filter: &FavouriteDataFilter<'a>) -> Result<Self, Self::Error> {
+        let status = filter
+            .status
+            .map(FavouriteStatus::try_from)
+            .transpose()?
+            .map(|status| status.into());
+        Ok(FavouriteEntityFilter {
+            status,
+            tags: filter.tags.iter().map(|tag| normalize_tag(tag)).collect(),
+        })
+    }
+}
+
+/// A status change of a favourite.
+#[derive(Debug, Clone)]
+pub struct FavouriteStatusTransitionData {
+    pub from_status: FavouriteStatus,
+    pub to_status: FavouriteStatus,
+    pub created_at: i64,
+}
+
+impl From<FavouriteStatusTransitionEntity> for FavouriteStatusTransitionData {
+    fn from(entity: FavouriteStatusTransitionEntity) -> Self {
+        FavouriteStatusTransitionData {
+            from_status: FavouriteStatus::try_from(entity.from_status.as_str()).unwrap_or_default(),
+            to_status: FavouriteStatus::try_from(entity.to_status.as_str()).unwrap_or_default(),
+            created_at: entity.created_at,
+        }
+    }
+}
+
+/// Current status of a favourite with the changes that led to it, oldest first.
+#[derive(Debug, Clone)]
+pub struct FavouriteStatusData {
+    pub favourite_uuid: Uuid,
+    pub status: FavouriteStatus,
+    pub updated_at: i64,
+    pub transitions: Vec<FavouriteStatusTransitionData>,
+}
+
+/// Amount of favourites of the default list per status.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FavouriteStatusCountData {
+    pub want: i64,
+    pub in_progress: i64,
+    pub done: i64,
+    pub dropped: i64,
+}
+
+impl FavouriteStatusCountData {
+    /// Statuses missing from `counts` have no favourites, unknown ones are ignored.
+    pub fn from_counts(counts: Vec<(String, i64)>) -> Self {
+        let mut data = FavouriteStatusCountData::default();
+        for (status, count) in counts {
+            match FavouriteStatus::try_from(status.as_str()) {
+                Ok(FavouriteStatus::Want) => data.want = count,
+                Ok(FavouriteStatus::InProgress) => data.in_progress = count,
+                Ok(FavouriteStatus::Done) => data.done = count,
+                Ok(FavouriteStatus::Dropped) => data.dropped = count,
+                Err(_) => eprintln!("Unknown favourite status: {}", status),
+            }
+        }
+        data
+    }
+}
@@ -1,13 +1,21 @@
+use uuid::Uuid;
+
 use crate::{
     data::{
         database::favourite::UserFavouritesDatabase,
-        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+        repository::{
+            favourite_lists::objects::FavouriteListVisibility,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
     },
     Conn,
 };
 
 use super::{
-    objects::{FavouriteDataError, FavouriteDataResponse},
+    objects::{
+        FavouriteDataError, FavouriteDataFilter, FavouriteDataResponse, FavouriteStatus,
+        FavouriteStatusCountData, FavouriteStatusData, FavouriteUserData,
+    },
     FavouriteRepository,
 };
 
@@ -53,10 +61,127 @@ impl FavouriteRepository for Conn {
     async fn get_user_favourites<'a>(
         &self,
         request: &'a PagingDomainRequest<'a>,
+        filter: &'a FavouriteDataFilter<'a>,
     ) -> Result<PagingDomainResponse<FavouriteDataResponse>, FavouriteDataError> {
-        UserFavouritesDatabase::get_user_favourites(self, request.to_owned())
+        let filter = filter.try_into()?;
+        let mut favourites: PagingDomainResponse<FavouriteDataResponse> =
+            UserFavouritesDatabase::get_user_favourites(self, request.to_owned(), filter)
+                .await
+                .map(|res| res.into())
+                .map_err(|err| -> FavouriteDataError { err.into() })?;
+        let owner_uuid =
+            Uuid::parse_str(request.user_uuid).map_err(|_| FavouriteDataError::UuidInvalid)?;
+        let request_uuid =
+            Uuid::parse_str(request.request_uuid).map_err(|_| FavouriteDataError::UuidInvalid)?;
+        FavouriteDataResponse::load_details(self, owner_uuid, request_uuid, &mut favourites.result)
+            .await?;
+        Ok(favourites)
+    }
+
+    async fn get_favourite_users<'a>(
+        &self,
+        request_uuid: &'a str,
+        favourite_uuid: &'a str,
+        query: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteUserData>, FavouriteDataError> {
+        let request_uuid =
+            Uuid::parse_str(request_uuid).map_err(|_| FavouriteDataError::UuidInvalid)?;
+        let favourite_uuid =
+            Uuid::parse_str(favourite_uuid).map_err(|_| FavouriteDataError::UuidInvalid)?;
+        let visibilities = |is_follower: bool| -> Vec<String> {
+            FavouriteListVisibility::visible(false, is_follower)
+                .into_iter()
+                .map(|visibility| visibility.into())
+                .collect()
+        };
+        let users = UserFavouritesDatabase::get_favourite_users(
+            self,
+            request_uuid,
+            favourite_uuid,
+            visibilities(false),
+            visibilities(true),
+            query.to_string(),
+            page,
+            page_size,
+        )
+        .await
+        .map_err(|err| -> FavouriteDataError { err.into() })?;
+        Ok(PagingDomainResponse {
+            page: users.page,
+            page_size: users.page_size,
+            total: users.total,
+            has_more: users.has_more,
+            result: users.result.into_iter().map(|user| user.into()).collect(),
+        })
+    }
+    async fn get_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<FavouriteStatusData, FavouriteDataError> {
+        let uuid = parse_uuid(uuid)?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        get_status(self, uuid, favourite_uuid).await
+    }
+    async fn update_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        status: &'a str,
+    ) -> Result<FavouriteStatusData, FavouriteDataError> {
+        let uuid = parse_uuid(uuid)?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        let status = FavouriteStatus::try_from(status)?;
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        UserFavouritesDatabase::update_favourite_status(
+            self,
+            uuid,
+            favourite_uuid,
+            status.into(),
+            updated_at,
+        )
+        .await
+        .map_err(|err| -> FavouriteDataError { err.into() })?;
+        get_status(self, uuid, favourite_uuid).await
+    }
+    async fn get_favourites_status_count<'a>(
+        &self,
+        uuid: &'a str,
+    ) -> Result<FavouriteStatusCountData, FavouriteDataError> {
+        let uuid = parse_uuid(uuid)?;
+        UserFavouritesDatabase::get_favourites_status_count(self, uuid)
             .await
-            .map(|res| res.into())
+            .map(FavouriteStatusCountData::from_counts)
             .map_err(|err| err.into())
     }
 }
+
+async fn get_status(
+    db: &Conn,
+    uuid: Uuid,
+    favourite_uuid: Uuid,
+) -> Result<FavouriteStatusData, FavouriteDataError> {
+    let (status, updated_at) =
+        UserFavouritesDatabase::get_favourite_status(db, uuid, favourite_uuid)
+            .await
+            .map_err(|err| -> FavouriteDataError { err.into() })?;
+    let transitions =
+        UserFavouritesDatabase::get_favourite_status_transitions(db, uuid, favourite_uuid)
+            .await
+            .map_err(|err| -> FavouriteDataError { err.into() })?;
+    Ok(FavouriteStatusData {
+        favourite_uuid,
+        status: FavouriteStatus::try_from(status.as_str()).unwrap_or_default(),
+        updated_at,
+        transitions: transitions
+            .into_iter()
+            .map(|transition| transition.into())
+            .collect(),
+    })
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, FavouriteDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteDataError::UuidInvalid)
+}
@@ -3,7 +3,16 @@ mod tests {
 
     use crate::data::{
         database::tests::database_test_utls::run_migration_get_conn,
-        repository::{favourite::FavouriteRepository, objects::PagingDomainRequest},
+        repository::{
+            favourite::{
+                objects::{
+                    FavouriteDataError, FavouriteDataFilter, FavouriteStatus,
+                    FavouriteStatusCountData,
+                },
+                FavouriteRepository,
+            },
+            objects::PagingDomainRequest,
+        },
     };
     use tokio_test::assert_ok;
     use uuid::Uuid;
@@ -76,7 +85,9 @@ mod tests {
             page_size: 15,
         };
 
-        let get_favourite_result = connection.get_user_favourites(&paging_domain_request).await;
+        let get_favourite_result = connection
+            .get_user_favourites(&paging_domain_request, &FavouriteDataFilter::default())
+            .await;
         assert!(get_favourite_result.is_ok());
 
         let favourites = get_favourite_result.to_owned().unwrap();
@@ -91,4 +102,31 @@ mod tests {
         assert_eq!(favourites.page_size, paging_domain_request.page_size);
         assert_eq!(favourites.has_more, false);
     }
+
+    #[test]
+    fn test_favourite_status_count_from_counts() {
+        for status in ["want", "in_progress", "done", "dropped"] {
+            let parsed = FavouriteStatus::try_from(status).unwrap();
+            assert_eq!(String::from(parsed), status);
+        }
+        assert!(matches!(
+            FavouriteStatus::try_from("watching"),
+            Err(FavouriteDataError::StatusInvalid)
+        ));
+
+        let counts = FavouriteStatusCountData::from_counts(vec![
+            ("done".to_string(), 3),
+            ("in_progress".to_string(), 2),
+            ("watching".to_string(), 5),
+        ]);
+        assert_eq!(
+            counts,
+            FavouriteStatusCountData {
+                want: 0,
+                in_progress: 2,
+                done: 3,
+                dropped: 0,
+            }
+        );
+    }
 }
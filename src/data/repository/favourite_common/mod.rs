@@ -0,0 +1,16 @@
+use self::objects::{FavouriteCommonData, FavouriteCommonDataError, FavouriteCommonRequest};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteCommonRepository {
+    /// How the favourites of `request_uuid` and `user_uuid` overlap, with a page of those in
+    /// the requested scope. Favourites `user_uuid` keeps in lists the requester cannot see
+    /// are left out.
+    async fn get_common_favourites<'a>(
+        &self,
+        request: &'a FavouriteCommonRequest<'a>,
+    ) -> Result<FavouriteCommonData, FavouriteCommonDataError>;
+}
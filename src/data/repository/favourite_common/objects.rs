@@ -0,0 +1,105 @@
+use crate::data::{
+    database::favourite::objects::{FavouriteCommonCountsEntity, FavouriteDbError},
+    repository::{favourite::objects::FavouriteDataResponse, objects::PagingDomainResponse},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteCommonScope {
+    /// Favourites both users have.
+    Common,
+    /// Favourites only the requester has.
+    Mine,
+    /// Favourites only the other user has.
+    Theirs,
+}
+
+impl From<FavouriteCommonScope> for String {
+    fn from(scope: FavouriteCommonScope) -> Self {
+        match scope {
+            FavouriteCommonScope::Common => "common",
+            FavouriteCommonScope::Mine => "mine",
+            FavouriteCommonScope::Theirs => "theirs",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for FavouriteCommonScope {
+    type Error = FavouriteCommonDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "common" => Ok(FavouriteCommonScope::Common),
+            "mine" => Ok(FavouriteCommonScope::Mine),
+            "theirs" => Ok(FavouriteCommonScope::Theirs),
+            _ => Err(FavouriteCommonDataError::ScopeInvalid),
+        }
+    }
+}
+
+pub struct FavouriteCommonRequest<'a> {
+    pub request_uuid: &'a str,
+    pub user_uuid: &'a str,
+    pub scope: &'a str,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+pub struct FavouriteCommonCounts {
+    pub common_count: i64,
+    pub mine_count: i64,
+    pub theirs_count: i64,
+}
+
+impl FavouriteCommonCounts {
+    pub fn scope_count(&self, scope: FavouriteCommonScope) -> i64 {
+        match scope {
+            FavouriteCommonScope::Common => self.common_count,
+            FavouriteCommonScope::Mine => self.mine_count,
+            FavouriteCommonScope::Theirs => self.theirs_count,
+        }
+    }
+
+    /// Shared favourites over all favourites of both users, 0 when neither has any.
+    pub fn jaccard(&self) -> f64 {
+        let union = self.common_count + self.mine_count + self.theirs_count;
+        if union == 0 {
+            return 0.0;
+        }
+        self.common_count as f64 / union as f64
+    }
+}
+
+impl From<FavouriteCommonCountsEntity> for FavouriteCommonCounts {
+    fn from(entity: FavouriteCommonCountsEntity) -> Self {
+        FavouriteCommonCounts {
+            common_count: entity.common_count,
+            mine_count: entity.mine_count,
+            theirs_count: entity.theirs_count,
+        }
+    }
+}
+
+pub struct FavouriteCommonData {
+    pub counts: FavouriteCommonCounts,
+    pub favourites: PagingDomainResponse<FavouriteDataResponse>,
+}
+
+#[derive(Debug)]
+pub enum FavouriteCommonDataError {
+    UuidInvalid,
+    UserNotFound,
+    SelfCommon,
+    ScopeInvalid,
+    InternalError,
+}
+
+impl From<FavouriteDbError> for FavouriteCommonDataError {
+    fn from(error: FavouriteDbError) -> Self {
+        match error {
+            FavouriteDbError::UuidInvalid => FavouriteCommonDataError::UuidInvalid,
+            FavouriteDbError::UserNotFound => FavouriteCommonDataError::UserNotFound,
+            _ => FavouriteCommonDataError::InternalError,
+        }
+    }
+}
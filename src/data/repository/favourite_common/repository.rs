@@ -0,0 +1,97 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::{
+            favourite::UserFavouritesDatabase, follow::FollowDatabase, user::UserDatabase,
+            utils::paging::correct_page_number,
+        },
+        repository::{
+            favourite::objects::FavouriteDataResponse,
+            favourite_lists::objects::FavouriteListVisibility, items::ItemsRepository,
+            objects::PagingDomainResponse,
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteCommonCounts, FavouriteCommonData, FavouriteCommonDataError,
+        FavouriteCommonRequest, FavouriteCommonScope,
+    },
+    FavouriteCommonRepository,
+};
+
+#[async_trait]
+impl FavouriteCommonRepository for Conn {
+    async fn get_common_favourites<'a>(
+        &self,
+        request: &'a FavouriteCommonRequest<'a>,
+    ) -> Result<FavouriteCommonData, FavouriteCommonDataError> {
+        let request_uuid = parse_uuid(request.request_uuid)?;
+        let user_uuid = parse_uuid(request.user_uuid)?;
+        if request_uuid == user_uuid {
+            return Err(FavouriteCommonDataError::SelfCommon);
+        }
+        let scope = FavouriteCommonScope::try_from(request.scope)?;
+        UserDatabase::get_user(self, request.user_uuid)
+            .await
+            .map_err(|_| FavouriteCommonDataError::UserNotFound)?;
+        let is_follower = self
+            .is_following_uuid(&request_uuid, &user_uuid)
+            .await
+            .map_err(|_| FavouriteCommonDataError::InternalError)?;
+        let visibilities: Vec<String> = FavouriteListVisibility::visible(false, is_follower)
+            .into_iter()
+            .map(|visibility| visibility.into())
+            .collect();
+
+        let counts: FavouriteCommonCounts = self
+            .count_common_favourites(request_uuid, user_uuid, visibilities.to_owned())
+            .await?
+            .into();
+        let page_number = correct_page_number(request.page);
+        let page_size = request.page_size.max(0);
+        let offset = page_number.saturating_mul(page_size);
+        let mut result: Vec<FavouriteDataResponse> = UserFavouritesDatabase::get_common_favourites(
+            self,
+            request_uuid,
+            user_uuid,
+            visibilities,
+            scope.into(),
+            page_size,
+            offset,
+        )
+        .await?
+        .into_iter()
+        .map(|entity| entity.into())
+        .collect();
+
+        let items_uuid = result
+            .iter()
+            .filter_map(|favourite| favourite.item_uuid)
+            .collect();
+        let items = ItemsRepository::get_items(self, items_uuid)
+            .await
+            .map_err(|_| FavouriteCommonDataError::InternalError)?;
+        FavouriteDataResponse::attach_items(&mut result, &items);
+
+        let total = counts.scope_count(scope);
+        let has_more = offset + (result.len() as i64) < total;
+        Ok(FavouriteCommonData {
+            counts,
+            favourites: PagingDomainResponse {
+                page: page_number + 1,
+                page_size,
+                total,
+                has_more,
+                result,
+            },
+        })
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, FavouriteCommonDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteCommonDataError::UuidInvalid)
+}
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::repository::favourite_common::objects::{
+        FavouriteCommonCounts, FavouriteCommonDataError, FavouriteCommonScope,
+    };
+
+    #[test]
+    fn test_common_scope_round_trips() {
+        for scope in ["common", "mine", "theirs"] {
+            let parsed = FavouriteCommonScope::try_from(scope).unwrap();
+            assert_eq!(String::from(parsed), scope);
+        }
+        assert!(matches!(
+            FavouriteCommonScope::try_from("both"),
+            Err(FavouriteCommonDataError::ScopeInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_common_counts_jaccard() {
+        let counts = FavouriteCommonCounts {
+            common_count: 2,
+            mine_count: 3,
+            theirs_count: 3,
+        };
+        assert_eq!(counts.jaccard(), 0.25);
+        assert_eq!(counts.scope_count(FavouriteCommonScope::Mine), 3);
+
+        let empty = FavouriteCommonCounts {
+            common_count: 0,
+            mine_count: 0,
+            theirs_count: 0,
+        };
+        assert_eq!(empty.jaccard(), 0.0);
+    }
+}
@@ -0,0 +1,44 @@
+use self::objects::{
+    FavouriteImportData, FavouriteImportDataCreate, FavouriteImportDataError,
+    FavouriteImportRowData,
+};
+
+use super::objects::PagingDomainResponse;
+
+pub mod objects;
+pub mod parser;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteImportsRepository {
+    /// Validates the file and queues it, the rows are imported by the favourite import job.
+    async fn create_import<'a>(
+        &self,
+        request: FavouriteImportDataCreate<'a>,
+    ) -> Result<FavouriteImportData, FavouriteImportDataError>;
+    /// Imports are only visible to the user who uploaded them.
+    async fn get_import<'a>(
+        &self,
+        request_uuid: &'a str,
+        import_uuid: &'a str,
+    ) -> Result<FavouriteImportData, FavouriteImportDataError>;
+    async fn get_import_rows<'a>(
+        &self,
+        request_uuid: &'a str,
+        import_uuid: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteImportRowData>, FavouriteImportDataError>;
+    /// Imports the oldest queued file, `None` when the queue is empty. Rows are matched to
+    /// catalogue items by external id, then by the closest title, and added with the same
+    /// conflict handling as `FavouriteRepository::add_favourite`. Ratings in the file are only
+    /// kept for items the user hasn't rated yet. Imports left running for longer than
+    /// `config::favourite_import_timeout_ms` are picked up again from their first row, so
+    /// entries the stalled run already added are counted as duplicates. A failed import still
+    /// reports the rows handled before the failure.
+    async fn process_pending_import(
+        &self,
+        current_time_ms: i64,
+    ) -> Result<Option<FavouriteImportData>, FavouriteImportDataError>;
+}
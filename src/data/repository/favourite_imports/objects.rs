@@ -0,0 +1,178 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::favourite_imports::objects::{
+        FavouriteImportDbError, FavouriteImportEntity, FavouriteImportRowEntity,
+    },
+    repository::favourite_lists::objects::FavouriteListDataError,
+};
+
+/// Most rows a single file may hold.
+pub const FAVOURITE_IMPORT_MAX_ROWS: usize = 10_000;
+/// Lowest similarity of a row title to a catalogue title to count as the same item.
+pub const FAVOURITE_IMPORT_TITLE_MIN_SIMILARITY: f64 = 0.8;
+/// How many catalogue items are compared against a row title at most.
+pub const FAVOURITE_IMPORT_TITLE_CANDIDATES: i64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteImportFormat {
+    Csv,
+    Json,
+}
+
+impl From<FavouriteImportFormat> for String {
+    fn from(format: FavouriteImportFormat) -> Self {
+        match format {
+            FavouriteImportFormat::Csv => "csv",
+            FavouriteImportFormat::Json => "json",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for FavouriteImportFormat {
+    type Error = FavouriteImportDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "csv" => Ok(FavouriteImportFormat::Csv),
+            "json" => Ok(FavouriteImportFormat::Json),
+            _ => Err(FavouriteImportDataError::FormatInvalid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteImportStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+impl From<FavouriteImportStatus> for String {
+    fn from(status: FavouriteImportStatus) -> Self {
+        match status {
+            FavouriteImportStatus::Pending => "pending",
+            FavouriteImportStatus::Running => "running",
+            FavouriteImportStatus::Done => "done",
+            FavouriteImportStatus::Failed => "failed",
+        }
+        .to_string()
+    }
+}
+
+/// Why a row ended up in the import report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteImportRowReason {
+    /// Neither a title nor an external id, the row is skipped.
+    Empty,
+    /// No catalogue item matches the row, it is skipped.
+    NotFound,
+    /// The favourite is imported without the rating.
+    RatingInvalid,
+    /// The favourite and rating are imported, the rating dated to the import.
+    DateInvalid,
+}
+
+impl From<FavouriteImportRowReason> for String {
+    fn from(reason: FavouriteImportRowReason) -> Self {
+        match reason {
+            FavouriteImportRowReason::Empty => "empty",
+            FavouriteImportRowReason::NotFound => "not_found",
+            FavouriteImportRowReason::RatingInvalid => "rating_invalid",
+            FavouriteImportRowReason::DateInvalid => "date_invalid",
+        }
+        .to_string()
+    }
+}
+
+pub struct FavouriteImportData {
+    pub uuid: Uuid,
+    pub list_uuid: Option<Uuid>,
+    pub format: String,
+    pub status: String,
+    pub rows_count: i32,
+    pub imported_count: i32,
+    pub duplicates_count: i32,
+    pub unmatched_count: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub struct FavouriteImportDataCreate<'a> {
+    pub request_uuid: &'a str,
+    /// A list of the requesting user or `DEFAULT_FAVOURITE_LIST`.
+    pub list_uuid: &'a str,
+    pub format: &'a str,
+    pub content: String,
+    pub created_at: i64,
+}
+
+pub struct FavouriteImportRowData {
+    pub row_number: i32,
+    pub title: Option<String>,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug)]
+pub enum FavouriteImportDataError {
+    UuidInvalid,
+    ImportUuidInvalid,
+    ImportNotFound,
+    ListUuidInvalid,
+    ListNotFound,
+    FormatInvalid,
+    ContentInvalid,
+    ContentTooLarge,
+    InternalError,
+}
+
+impl From<FavouriteImportEntity> for FavouriteImportData {
+    fn from(entity: FavouriteImportEntity) -> Self {
+        FavouriteImportData {
+            uuid: entity.uuid,
+            list_uuid: entity.list_uuid,
+            format: entity.format,
+            status: entity.status,
+            rows_count: entity.rows_count,
+            imported_count: entity.imported_count,
+            duplicates_count: entity.duplicates_count,
+            unmatched_count: entity.unmatched_count,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<FavouriteImportRowEntity> for FavouriteImportRowData {
+    fn from(entity: FavouriteImportRowEntity) -> Self {
+        FavouriteImportRowData {
+            row_number: entity.row_number,
+            title: entity.title,
+            external_id: entity.external_id,
+            reason: entity.reason,
+        }
+    }
+}
+
+impl From<FavouriteImportDbError> for FavouriteImportDataError {
+    fn from(error: FavouriteImportDbError) -> Self {
+        match error {
+            FavouriteImportDbError::ImportNotFound => FavouriteImportDataError::ImportNotFound,
+            FavouriteImportDbError::InternalError => FavouriteImportDataError::InternalError,
+        }
+    }
+}
+
+impl From<FavouriteListDataError> for FavouriteImportDataError {
+    fn from(error: FavouriteListDataError) -> Self {
+        match error {
+            FavouriteListDataError::UuidInvalid => FavouriteImportDataError::UuidInvalid,
+            FavouriteListDataError::ListUuidInvalid => FavouriteImportDataError::ListUuidInvalid,
+            FavouriteListDataError::ListNotFound => FavouriteImportDataError::ListNotFound,
+            _ => FavouriteImportDataError::InternalError,
+        }
+    }
+}
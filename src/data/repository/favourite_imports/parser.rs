@@ -0,0 +1,142 @@
+use serde::Deserialize;
+
+use super::objects::{FavouriteImportDataError, FavouriteImportFormat, FAVOURITE_IMPORT_MAX_ROWS};
+
+/// A row of an uploaded file. Values are kept as written and validated while importing, so
+/// one bad cell only affects its own row.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+#[serde(default)]
+pub struct FavouriteImportRow {
+    pub title: Option<String>,
+    /// Either a bare id or `source=id`, e.g. `imdb=tt0133093`.
+    pub external_id: Option<String>,
+    pub rating: Option<String>,
+    pub date: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct FavouriteImportJsonRow {
+    title: Option<String>,
+    external_id: Option<String>,
+    rating: Option<serde_json::Value>,
+    date: Option<String>,
+}
+
+/// Reads the rows of a CSV file with a `title,external_id,rating,date` header, where any
+/// column may be left out, or of a JSON array of objects with the same fields.
+pub fn parse_import(
+    format: FavouriteImportFormat,
+    content: &str,
+) -> Result<Vec<FavouriteImportRow>, FavouriteImportDataError> {
+    let rows = match format {
+        FavouriteImportFormat::Csv => parse_csv(content)?,
+        FavouriteImportFormat::Json => parse_json(content)?,
+    };
+    if rows.len() > FAVOURITE_IMPORT_MAX_ROWS {
+        return Err(FavouriteImportDataError::ContentTooLarge);
+    }
+    Ok(rows
+        .into_iter()
+        .map(|row| FavouriteImportRow {
+            title: non_blank(row.title),
+            external_id: non_blank(row.external_id),
+            rating: non_blank(row.rating),
+            date: non_blank(row.date),
+        })
+        .collect())
+}
+
+fn parse_csv(content: &str) -> Result<Vec<FavouriteImportRow>, FavouriteImportDataError> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes())
+        .deserialize::<FavouriteImportRow>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| {
+            eprintln!("Favourite import csv error: {}", err);
+            FavouriteImportDataError::ContentInvalid
+        })
+}
+
+fn parse_json(content: &str) -> Result<Vec<FavouriteImportRow>, FavouriteImportDataError> {
+    let rows: Vec<FavouriteImportJsonRow> = serde_json::from_str(content).map_err(|err| {
+        eprintln!("Favourite import json error: {}", err);
+        FavouriteImportDataError::ContentInvalid
+    })?;
+    Ok(rows
+        .into_iter()
+        .map(|row| FavouriteImportRow {
+            title: row.title,
+            external_id: row.external_id,
+            rating: row.rating.and_then(|rating| match rating {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(rating) => Some(rating),
+                rating => Some(rating.to_string()),
+            }),
+            date: row.date,
+        })
+        .collect())
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+/// Splits `source=id` into its parts, a bare id has no source.
+pub fn split_external_id(value: &str) -> (Option<&str>, &str) {
+    match value.split_once('=') {
+        Some((source, id)) if !source.trim().is_empty() => (Some(source.trim()), id.trim()),
+        _ => (None, value.trim()),
+    }
+}
+
+/// Lowercases the title and keeps its words only, without a leading article, so
+/// punctuation, spacing and "The" don't matter when comparing titles.
+pub fn normalize_title(title: &str) -> String {
+    let title = title.to_lowercase();
+    let mut words: Vec<&str> = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.len() > 1 && TITLE_ARTICLES.contains(&words[0]) {
+        words.remove(0);
+    }
+    words.join(" ")
+}
+
+const TITLE_ARTICLES: [&str; 3] = ["the", "a", "an"];
+
+/// Similarity of two titles from 0 to 1, one minus their edit distance relative to the
+/// longer normalized title.
+pub fn title_similarity(left: &str, right: &str) -> f64 {
+    let left: Vec<char> = normalize_title(left).chars().collect();
+    let right: Vec<char> = normalize_title(right).chars().collect();
+    let longest = left.len().max(right.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    for (i, left_char) in left.iter().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    1.0 - previous[right.len()] as f64 / longest as f64
+}
+
+/// Parses `YYYY-MM-DD` or an RFC 3339 timestamp into milliseconds.
+pub fn parse_import_date(value: &str) -> Option<i64> {
+    if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.timestamp_millis());
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.and_utc().timestamp_millis())
+}
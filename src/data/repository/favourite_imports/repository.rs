@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::{
+    config,
+    data::{
+        database::{
+            favourite::{objects::FavouriteDbError, UserFavouritesDatabase},
+            favourite_imports::{
+                objects::{
+                    FavouriteImportEntity, FavouriteImportEntityCreate,
+                    FavouriteImportEntityFinish, FavouriteImportRowEntityCreate,
+                },
+                FavouriteImportsDatabase,
+            },
+            item_ratings::{objects::ItemRatingEntityCreate, ItemRatingsDatabase},
+            items::ItemsDatabase,
+        },
+        repository::{
+            favourite_lists::{get_owned_list_uuid, objects::FavouriteListDataError},
+            item_ratings::objects::validate_rating,
+            items::{objects::ItemData, ItemsRepository},
+            objects::PagingDomainResponse,
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteImportData, FavouriteImportDataCreate, FavouriteImportDataError,
+        FavouriteImportFormat, FavouriteImportRowData, FavouriteImportRowReason,
+        FavouriteImportStatus, FAVOURITE_IMPORT_TITLE_CANDIDATES,
+        FAVOURITE_IMPORT_TITLE_MIN_SIMILARITY,
+    },
+    parser::{
+        normalize_title, parse_import, parse_import_date, split_external_id, title_similarity,
+        FavouriteImportRow,
+    },
+    FavouriteImportsRepository,
+};
+
+#[async_trait]
+impl FavouriteImportsRepository for Conn {
+    async fn create_import<'a>(
+        &self,
+        request: FavouriteImportDataCreate<'a>,
+    ) -> Result<FavouriteImportData, FavouriteImportDataError> {
+        let request_uuid = parse_uuid(request.request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, request.list_uuid)
+            .await
+            .map_err(|err| match err {
+                FavouriteListDataError::ListUuidInvalid => {
+                    FavouriteImportDataError::ListUuidInvalid
+                }
+                FavouriteListDataError::ListNotFound => FavouriteImportDataError::ListNotFound,
+                _ => FavouriteImportDataError::InternalError,
+            })?;
+        let format = FavouriteImportFormat::try_from(request.format)?;
+        let rows = parse_import(format, &request.content)?;
+        let import = FavouriteImportEntityCreate {
+            user_uuid: request_uuid,
+            list_uuid,
+            format: format.into(),
+            status: FavouriteImportStatus::Pending.into(),
+            content: request.content,
+            rows_count: i32::try_from(rows.len()).unwrap_or(i32::MAX),
+            created_at: request.created_at,
+            updated_at: request.created_at,
+        };
+        FavouriteImportsDatabase::add_import(self, import)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_import<'a>(
+        &self,
+        request_uuid: &'a str,
+        import_uuid: &'a str,
+    ) -> Result<FavouriteImportData, FavouriteImportDataError> {
+        get_owned_import(self, request_uuid, import_uuid)
+            .await
+            .map(|v| v.into())
+    }
+
+    async fn get_import_rows<'a>(
+        &self,
+        request_uuid: &'a str,
+        import_uuid: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<FavouriteImportRowData>, FavouriteImportDataError> {
+        let import = get_owned_import(self, request_uuid, import_uuid).await?;
+        let response =
+            FavouriteImportsDatabase::get_import_rows(self, import.uuid, page, page_size).await?;
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        })
+    }
+
+    async fn process_pending_import(
+        &self,
+        current_time_ms: i64,
+    ) -> Result<Option<FavouriteImportData>, FavouriteImportDataError> {
+        let claim = match self
+            .claim_pending_import(
+                FavouriteImportStatus::Pending.into(),
+                FavouriteImportStatus::Running.into(),
+                current_time_ms - config::favourite_import_timeout_ms(),
+                current_time_ms,
+            )
+            .await?
+        {
+            Some(claim) => claim,
+            None => return Ok(None),
+        };
+        let import_uuid = claim.import.uuid;
+        let mut finish = FavouriteImportEntityFinish {
+            status: FavouriteImportStatus::Done.into(),
+            imported_count: 0,
+            duplicates_count: 0,
+            unmatched_count: 0,
+            rows: vec![],
+            updated_at: current_time_ms,
+        };
+        // entries added before a failure stay, so they are reported along with it
+        if let Err(err) = import_rows(
+            self,
+            &claim.import,
+            &claim.content,
+            current_time_ms,
+            &mut finish,
+        )
+        .await
+        {
+            eprintln!("Favourite import {} failed: {:?}", import_uuid, err);
+            finish.status = FavouriteImportStatus::Failed.into();
+        }
+        FavouriteImportsDatabase::finish_import(self, import_uuid, finish)
+            .await
+            .map(|import| Some(import.into()))
+            .map_err(|e| e.into())
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, FavouriteImportDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteImportDataError::UuidInvalid)
+}
+
+async fn get_owned_import(
+    conn: &Conn,
+    request_uuid: &str,
+    import_uuid: &str,
+) -> Result<FavouriteImportEntity, FavouriteImportDataError> {
+    let request_uuid = parse_uuid(request_uuid)?;
+    let import_uuid =
+        Uuid::parse_str(import_uuid).map_err(|_| FavouriteImportDataError::ImportUuidInvalid)?;
+    let import = FavouriteImportsDatabase::get_import(conn, import_uuid).await?;
+    if import.user_uuid != request_uuid {
+        return Err(FavouriteImportDataError::ImportNotFound);
+    }
+    Ok(import)
+}
+
+/// Matches and adds every row of the file, counting and collecting the rows to report into
+/// `finish` as it goes.
+async fn import_rows(
+    conn: &Conn,
+    import: &FavouriteImportEntity,
+    content: &str,
+    current_time_ms: i64,
+    finish: &mut FavouriteImportEntityFinish,
+) -> Result<(), FavouriteImportDataError> {
+    let format = FavouriteImportFormat::try_from(import.format.as_str())?;
+    let rows = parse_import(format, content)?;
+    let external_items = find_external_items(conn, &rows).await?;
+    for (index, row) in rows.into_iter().enumerate() {
+        let report = |reason: FavouriteImportRowReason| FavouriteImportRowEntityCreate {
+            import_uuid: import.uuid,
+            row_number: i32::try_from(index + 1).unwrap_or(i32::MAX),
+            title: row.title.clone(),
+            external_id: row.external_id.clone(),
+            reason: reason.into(),
+        };
+        if row.title.is_none() && row.external_id.is_none() {
+            finish.unmatched_count += 1;
+            finish.rows.push(report(FavouriteImportRowReason::Empty));
+            continue;
+        }
+        let external_item = row
+            .external_id
+            .as_deref()
+            .and_then(|external_id| external_items.get(external_id))
+            .cloned();
+        let item = match external_item {
+            Some(item) => Some(item),
+            None => match &row.title {
+                Some(title) => find_title_item(conn, title).await?,
+                None => None,
+            },
+        };
+        let item = match item {
+            Some(item) => item,
+            None => {
+                finish.unmatched_count += 1;
+                finish.rows.push(report(FavouriteImportRowReason::NotFound));
+                continue;
+            }
+        };
+        match UserFavouritesDatabase::add_list_entry(
+            conn,
+            import.user_uuid,
+            import.list_uuid,
+            item.uuid,
+            item.title.clone(),
+        )
+        .await
+        {
+            Ok(_) => finish.imported_count += 1,
+            Err(FavouriteDbError::Conflict) => finish.duplicates_count += 1,
+            Err(_) => return Err(FavouriteImportDataError::InternalError),
+        }
+        if let Some(rating) = &row.rating {
+            let rating = match rating.parse::<i16>().ok().map(validate_rating) {
+                Some(Ok(rating)) => rating,
+                _ => {
+                    finish
+                        .rows
+                        .push(report(FavouriteImportRowReason::RatingInvalid));
+                    continue;
+                }
+            };
+            let rated_at = match row.date.as_deref().map(parse_import_date) {
+                Some(Some(rated_at)) => rated_at,
+                Some(None) => {
+                    finish
+                        .rows
+                        .push(report(FavouriteImportRowReason::DateInvalid));
+                    current_time_ms
+                }
+                None => current_time_ms,
+            };
+            // the file never overrides a rating the user already gave
+            let rated =
+                ItemRatingsDatabase::get_user_ratings(conn, import.user_uuid, vec![item.uuid])
+                    .await
+                    .map_err(|_| FavouriteImportDataError::InternalError)?;
+            if !rated.is_empty() {
+                continue;
+            }
+            let rating = ItemRatingEntityCreate {
+                user_uuid: import.user_uuid,
+                item_uuid: item.uuid,
+                rating,
+                review: None,
+                created_at: rated_at,
+                updated_at: rated_at,
+            };
+            ItemRatingsDatabase::set_rating(conn, rating)
+                .await
+                .map_err(|_| FavouriteImportDataError::InternalError)?;
+        }
+    }
+    Ok(())
+}
+
+/// Catalogue items keyed by the external ids of the rows that point at them. An id given
+/// as `source=id` only matches that source.
+async fn find_external_items(
+    conn: &Conn,
+    rows: &[FavouriteImportRow],
+) -> Result<HashMap<String, ItemData>, FavouriteImportDataError> {
+    let external_ids: Vec<&str> = rows
+        .iter()
+        .filter_map(|row| row.external_id.as_deref())
+        .collect();
+    if external_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let stored = conn
+        .find_external_ids(
+            external_ids
+                .iter()
+                .map(|external_id| split_external_id(external_id).1.to_owned())
+                .collect(),
+        )
+        .await
+        .map_err(|_| FavouriteImportDataError::InternalError)?;
+    let items = ItemsRepository::get_items(
+        conn,
+        stored
+            .iter()
+            .map(|external_id| external_id.item_uuid)
+            .collect(),
+    )
+    .await
+    .map_err(|_| FavouriteImportDataError::InternalError)?;
+    let mut external_items = HashMap::new();
+    for external_id in external_ids {
+        let (source, id) = split_external_id(external_id);
+        let item = stored
+            .iter()
+            .find(|stored| {
+                stored.external_id == id && source.is_none_or(|source| stored.source == source)
+            })
+            .and_then(|stored| items.iter().find(|item| item.uuid == stored.item_uuid));
+        if let Some(item) = item {
+            external_items.insert(external_id.to_owned(), item.clone());
+        }
+    }
+    Ok(external_items)
+}
+
+/// The catalogue item with the most similar title, if similar enough. Candidates share the
+/// longest word of the title.
+async fn find_title_item(
+    conn: &Conn,
+    title: &str,
+) -> Result<Option<ItemData>, FavouriteImportDataError> {
+    let normalized = normalize_title(title);
+    let word = match normalized
+        .split(' ')
+        .max_by_key(|word| word.chars().count())
+    {
+        Some(word) if !word.is_empty() => word.to_owned(),
+        _ => return Ok(None),
+    };
+    let candidates =
+        ItemsRepository::search_items(conn, &word, None, 1, FAVOURITE_IMPORT_TITLE_CANDIDATES)
+            .await
+            .map_err(|_| FavouriteImportDataError::InternalError)?;
+    Ok(candidates
+        .result
+        .into_iter()
+        .map(|item| (title_similarity(title, &item.title), item))
+        .filter(|(similarity, _)| *similarity >= FAVOURITE_IMPORT_TITLE_MIN_SIMILARITY)
+        .max_by(|(left, _), (right, _)| left.total_cmp(right))
+        .map(|(_, item)| item))
+}
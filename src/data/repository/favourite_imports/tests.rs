@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        config,
+        data::{
+            database::{
+                favourite::UserFavouritesDatabase,
+                favourite_imports::{
+                    objects::FavouriteImportEntityCreate, FavouriteImportsDatabase,
+                },
+                item_ratings::{objects::ItemRatingEntityCreate, ItemRatingsDatabase},
+                items::{objects::ItemEntitySync, ItemsDatabase},
+                tests::database_test_utls::run_migration_get_conn,
+            },
+            repository::favourite_imports::{
+                objects::{FavouriteImportDataError, FavouriteImportFormat},
+                parser::{
+                    normalize_title, parse_import, parse_import_date, split_external_id,
+                    title_similarity, FavouriteImportRow,
+                },
+                FavouriteImportsRepository,
+            },
+        },
+    };
+
+    #[test]
+    fn test_parse_csv_with_missing_columns() {
+        let content = "title,rating\nThe Matrix, 9\n  ,\nAlien,\n";
+        let rows = parse_import(FavouriteImportFormat::Csv, content).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                FavouriteImportRow {
+                    title: Some("The Matrix".to_string()),
+                    rating: Some("9".to_string()),
+                    ..Default::default()
+                },
+                FavouriteImportRow::default(),
+                FavouriteImportRow {
+                    title: Some("Alien".to_string()),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_json_accepts_numeric_and_text_ratings() {
+        let content = r#"[
+            {"title": "Heat", "external_id": "imdb=tt0113277", "rating": 8, "date": "2020-01-02"},
+            {"external_id": "tt0133093", "rating": "seven"}
+        ]"#;
+        let rows = parse_import(FavouriteImportFormat::Json, content).unwrap();
+        assert_eq!(rows[0].rating, Some("8".to_string()));
+        assert_eq!(rows[0].external_id, Some("imdb=tt0113277".to_string()));
+        assert_eq!(rows[1].title, None);
+        assert_eq!(rows[1].rating, Some("seven".to_string()));
+        assert!(matches!(
+            parse_import(FavouriteImportFormat::Json, "{\"title\": \"Heat\"}"),
+            Err(FavouriteImportDataError::ContentInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_title_matching() {
+        assert_eq!(
+            normalize_title("  The  Lord of the Rings: "),
+            "lord of the rings"
+        );
+        assert_eq!(normalize_title("The"), "the");
+        assert_eq!(title_similarity("Matrix", "The Matrix"), 1.0);
+        assert!(title_similarity("The Godfather Part II", "Godfather: Part 2") > 0.8);
+        assert!(title_similarity("Alien", "Aliens vs Predator") < 0.5);
+        assert_eq!(title_similarity("", "..."), 0.0);
+    }
+
+    #[test]
+    fn test_external_id_and_date_parsing() {
+        assert_eq!(
+            split_external_id("imdb=tt0133093"),
+            (Some("imdb"), "tt0133093")
+        );
+        assert_eq!(split_external_id("tt0133093"), (None, "tt0133093"));
+        assert_eq!(parse_import_date("1970-01-02"), Some(86_400_000));
+        assert_eq!(parse_import_date("1970-01-01T00:00:01+00:00"), Some(1_000));
+        assert_eq!(parse_import_date("yesterday"), None);
+    }
+
+    #[tokio::test]
+    async fn test_replayed_import_keeps_existing_ratings() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        let suffix = Uuid::new_v4().to_simple().to_string();
+        let user_uuid = Uuid::new_v4();
+        let rated_item = Uuid::new_v4();
+        let added_item = Uuid::new_v4();
+        let sync_item = |uuid: Uuid, index: usize| ItemEntitySync {
+            uuid: Some(uuid),
+            kind: "movie".to_string(),
+            title: format!("Title {} {}", index, suffix),
+            year: None,
+            image_url: None,
+            external_ids: vec![("imdb".to_string(), format!("tt{}{}", suffix, index))],
+        };
+        connection
+            .sync_items(
+                "fixture".to_string(),
+                vec![sync_item(rated_item, 1), sync_item(added_item, 2)],
+                0,
+            )
+            .await
+            .unwrap();
+
+        // the user already keeps and rated the first item
+        connection
+            .add_list_entry(user_uuid, None, rated_item, "title".to_string())
+            .await
+            .unwrap();
+        connection
+            .set_rating(ItemRatingEntityCreate {
+                user_uuid,
+                item_uuid: rated_item,
+                rating: 9,
+                review: None,
+                created_at: 1,
+                updated_at: 1,
+            })
+            .await
+            .unwrap();
+        // a stalled run added the second item before it could rate it
+        connection
+            .add_list_entry(user_uuid, None, added_item, "title".to_string())
+            .await
+            .unwrap();
+        let stalled = connection
+            .add_import(FavouriteImportEntityCreate {
+                user_uuid,
+                list_uuid: None,
+                format: "csv".to_string(),
+                status: "running".to_string(),
+                content: format!("external_id,rating\ntt{0}1,3\ntt{0}2,7\n", suffix),
+                rows_count: 2,
+                created_at: 0,
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+
+        let processed = connection
+            .process_pending_import(config::favourite_import_timeout_ms() + 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(processed.uuid, stalled.uuid);
+        assert_eq!(processed.status, "done");
+        assert_eq!(processed.imported_count, 0);
+        assert_eq!(processed.duplicates_count, 2);
+
+        let mut ratings: Vec<(Uuid, i16)> = connection
+            .get_user_ratings(user_uuid, vec![rated_item, added_item])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|rating| (rating.item_uuid, rating.rating))
+            .collect();
+        ratings.sort();
+        let mut expected = vec![(rated_item, 9), (added_item, 7)];
+        expected.sort();
+        assert_eq!(ratings, expected);
+    }
+}
@@ -0,0 +1,85 @@
+use self::objects::{
+    FavouriteListData, FavouriteListDataCreate, FavouriteListDataError, FavouriteListDataUpdate,
+    FavouriteListEntriesRequest,
+};
+
+use super::{
+    favourite::objects::FavouriteDataResponse,
+    objects::{PagingDomainRequest, PagingDomainResponse},
+};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+pub(crate) use repository::get_owned_list_uuid;
+
+/// `list_uuid` is either a list uuid or `DEFAULT_FAVOURITE_LIST` for the favourites
+/// outside of any named list. Only owners change lists and their entries, other users
+/// see a list according to its visibility and get `ListNotFound` otherwise.
+#[async_trait]
+pub trait FavouriteListsRepository {
+    async fn create_list<'a>(
+        &self,
+        request: FavouriteListDataCreate<'a>,
+    ) -> Result<FavouriteListData, FavouriteListDataError>;
+    /// Lists of `request.user_uuid` visible to `request.request_uuid`.
+    async fn get_lists<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteListData>, FavouriteListDataError>;
+    async fn get_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<FavouriteListData, FavouriteListDataError>;
+    async fn update_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        update: FavouriteListDataUpdate<'a>,
+    ) -> Result<FavouriteListData, FavouriteListDataError>;
+    async fn delete_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<(), FavouriteListDataError>;
+    async fn get_list_entries<'a>(
+        &self,
+        request: &'a FavouriteListEntriesRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteDataResponse>, FavouriteListDataError>;
+    async fn add_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        title: &'a str,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError>;
+    async fn remove_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<(), FavouriteListDataError>;
+    /// `favourites_uuid` is the new order and has to hold every entry of the list once.
+    async fn reorder_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourites_uuid: Vec<&'a str>,
+    ) -> Result<(), FavouriteListDataError>;
+    async fn move_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        to_list_uuid: &'a str,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError>;
+    async fn pin_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        pinned: bool,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError>;
+}
@@ -0,0 +1,152 @@
+use uuid::Uuid;
+
+use crate::data::database::{
+    favourite::objects::FavouriteDbError,
+    favourite_lists::objects::{FavouriteListDbError, FavouriteListEntity},
+};
+
+/// Maximum length of a list title in characters.
+pub const FAVOURITE_LIST_TITLE_MAX_LENGTH: usize = 64;
+/// Stands in for a list uuid to address the favourites outside of any named list.
+pub const DEFAULT_FAVOURITE_LIST: &str = "default";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteListVisibility {
+    Public,
+    Followers,
+    Private,
+}
+
+impl From<FavouriteListVisibility> for String {
+    fn from(visibility: FavouriteListVisibility) -> Self {
+        match visibility {
+            FavouriteListVisibility::Public => "public",
+            FavouriteListVisibility::Followers => "followers",
+            FavouriteListVisibility::Private => "private",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for FavouriteListVisibility {
+    type Error = FavouriteListDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "public" => Ok(FavouriteListVisibility::Public),
+            "followers" => Ok(FavouriteListVisibility::Followers),
+            "private" => Ok(FavouriteListVisibility::Private),
+            _ => Err(FavouriteListDataError::VisibilityInvalid),
+        }
+    }
+}
+
+impl FavouriteListVisibility {
+    /// Visibilities of the lists a user may see, depending on whether they own the
+    /// lists or follow their owner.
+    pub fn visible(is_owner: bool, is_follower: bool) -> Vec<FavouriteListVisibility> {
+        match (is_owner, is_follower) {
+            (true, _) => vec![
+                FavouriteListVisibility::Public,
+                FavouriteListVisibility::Followers,
+                FavouriteListVisibility::Private,
+            ],
+            (false, true) => vec![
+                FavouriteListVisibility::Public,
+                FavouriteListVisibility::Followers,
+            ],
+            (false, false) => vec![FavouriteListVisibility::Public],
+        }
+    }
+}
+
+pub struct FavouriteListData {
+    pub uuid: Uuid,
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub visibility: String,
+    pub entries_count: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub struct FavouriteListDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub title: &'a str,
+    pub visibility: Option<&'a str>,
+    pub created_at: i64,
+}
+
+pub struct FavouriteListDataUpdate<'a> {
+    pub title: Option<&'a str>,
+    pub visibility: Option<&'a str>,
+    pub updated_at: i64,
+}
+
+pub struct FavouriteListEntriesRequest<'a> {
+    pub request_uuid: &'a str,
+    pub list_uuid: &'a str,
+    pub query: &'a str,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteListDataError {
+    UuidInvalid,
+    ListUuidInvalid,
+    ListNotFound,
+    TitleInvalid,
+    VisibilityInvalid,
+    EntryNotFound,
+    Conflict,
+    OrderInvalid,
+    InternalError,
+}
+
+/// Trims the title, which must not be blank or longer than `FAVOURITE_LIST_TITLE_MAX_LENGTH`.
+pub fn validate_list_title(title: &str) -> Result<String, FavouriteListDataError> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > FAVOURITE_LIST_TITLE_MAX_LENGTH {
+        Err(FavouriteListDataError::TitleInvalid)
+    } else {
+        Ok(title.to_owned())
+    }
+}
+
+impl FavouriteListData {
+    pub fn from_entity(entity: FavouriteListEntity, entries_count: i64) -> Self {
+        FavouriteListData {
+            uuid: entity.uuid,
+            owner_uuid: entity.owner_uuid,
+            title: entity.title,
+            visibility: entity.visibility,
+            entries_count,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<FavouriteListDbError> for FavouriteListDataError {
+    fn from(error: FavouriteListDbError) -> Self {
+        match error {
+            FavouriteListDbError::ListNotFound => FavouriteListDataError::ListNotFound,
+            FavouriteListDbError::InternalError => FavouriteListDataError::InternalError,
+        }
+    }
+}
+
+impl From<FavouriteDbError> for FavouriteListDataError {
+    fn from(error: FavouriteDbError) -> Self {
+        match error {
+            FavouriteDbError::UuidInvalid => FavouriteListDataError::UuidInvalid,
+            FavouriteDbError::Conflict => FavouriteListDataError::Conflict,
+            FavouriteDbError::EntryNotFound => FavouriteListDataError::EntryNotFound,
+            FavouriteDbError::OrderInvalid => FavouriteListDataError::OrderInvalid,
+            FavouriteDbError::UserNotFound | FavouriteDbError::InternalError => {
+                FavouriteListDataError::InternalError
+            }
+        }
+    }
+}
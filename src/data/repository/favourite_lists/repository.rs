@@ -0,0 +1,356 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::{
+            favourite::{objects::FavouriteEntityFilter, UserFavouritesDatabase},
+            favourite_lists::{
+                objects::{
+                    FavouriteListEntity, FavouriteListEntityCreate, FavouriteListEntityUpdate,
+                },
+                FavouriteListsDatabase,
+            },
+            follow::FollowDatabase,
+        },
+        repository::{
+            favourite::objects::FavouriteDataResponse,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        validate_list_title, FavouriteListData, FavouriteListDataCreate, FavouriteListDataError,
+        FavouriteListDataUpdate, FavouriteListEntriesRequest, FavouriteListVisibility,
+        DEFAULT_FAVOURITE_LIST,
+    },
+    FavouriteListsRepository,
+};
+
+#[async_trait]
+impl FavouriteListsRepository for Conn {
+    async fn create_list<'a>(
+        &self,
+        request: FavouriteListDataCreate<'a>,
+    ) -> Result<FavouriteListData, FavouriteListDataError> {
+        let owner_uuid = parse_uuid(request.request_uuid)?;
+        let title = validate_list_title(request.title)?;
+        let visibility = request
+            .visibility
+            .map(FavouriteListVisibility::try_from)
+            .transpose()?
+            .unwrap_or(FavouriteListVisibility::Public);
+        let list = FavouriteListEntityCreate {
+            owner_uuid,
+            title,
+            visibility: visibility.into(),
+            created_at: request.created_at,
+            updated_at: request.created_at,
+        };
+        let created = self.add_list(list).await?;
+        Ok(FavouriteListData::from_entity(created, 0))
+    }
+
+    async fn get_lists<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteListData>, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request.request_uuid)?;
+        let owner_uuid = parse_uuid(request.user_uuid)?;
+        let is_follower = request_uuid != owner_uuid
+            && self
+                .is_following_uuid(&request_uuid, &owner_uuid)
+                .await
+                .map_err(|_| FavouriteListDataError::InternalError)?;
+        let visibilities =
+            FavouriteListVisibility::visible(request_uuid == owner_uuid, is_follower)
+                .into_iter()
+                .map(String::from)
+                .collect();
+        let response = FavouriteListsDatabase::get_lists(
+            self,
+            owner_uuid,
+            visibilities,
+            request.query.to_owned(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+        let lists_uuid = response.result.iter().map(|list| list.uuid).collect();
+        let counts = self.get_lists_entries_count(lists_uuid).await?;
+        let result = response
+            .result
+            .into_iter()
+            .map(|list| {
+                let entries_count = counts
+                    .iter()
+                    .find(|(list_uuid, _)| *list_uuid == list.uuid)
+                    .map_or(0, |(_, count)| *count);
+                FavouriteListData::from_entity(list, entries_count)
+            })
+            .collect();
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result,
+        })
+    }
+
+    async fn get_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<FavouriteListData, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list = get_visible_list(self, request_uuid, parse_list_uuid(list_uuid)?).await?;
+        with_entries_count(self, list).await
+    }
+
+    async fn update_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        update: FavouriteListDataUpdate<'a>,
+    ) -> Result<FavouriteListData, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list = get_owned_list(self, request_uuid, parse_list_uuid(list_uuid)?).await?;
+        let update = FavouriteListEntityUpdate {
+            title: update.title.map(validate_list_title).transpose()?,
+            visibility: update
+                .visibility
+                .map(FavouriteListVisibility::try_from)
+                .transpose()?
+                .map(String::from),
+            updated_at: update.updated_at,
+        };
+        let updated = FavouriteListsDatabase::update_list(self, list.uuid, update).await?;
+        with_entries_count(self, updated).await
+    }
+
+    async fn delete_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<(), FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list = get_owned_list(self, request_uuid, parse_list_uuid(list_uuid)?).await?;
+        FavouriteListsDatabase::delete_list(self, list.uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_list_entries<'a>(
+        &self,
+        request: &'a FavouriteListEntriesRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteDataResponse>, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request.request_uuid)?;
+        let (owner_uuid, list_uuid) = match resolve_list_uuid(request.list_uuid)? {
+            Some(list_uuid) => {
+                let list = get_visible_list(self, request_uuid, list_uuid).await?;
+                (list.owner_uuid, Some(list.uuid))
+            }
+            None => (request_uuid, None),
+        };
+        let response = UserFavouritesDatabase::get_list_entries(
+            self,
+            owner_uuid,
+            list_uuid,
+            request.query.to_owned(),
+            FavouriteEntityFilter::default(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+        let mut result: Vec<FavouriteDataResponse> =
+            response.result.into_iter().map(|v| v.into()).collect();
+        FavouriteDataResponse::load_details(self, owner_uuid, request_uuid, &mut result)
+            .await
+            .map_err(|_| FavouriteListDataError::InternalError)?;
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result,
+        })
+    }
+
+    async fn add_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        title: &'a str,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, list_uuid).await?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        UserFavouritesDatabase::add_list_entry(
+            self,
+            request_uuid,
+            list_uuid,
+            favourite_uuid,
+            title.to_owned(),
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+
+    async fn remove_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<(), FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, list_uuid).await?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        UserFavouritesDatabase::remove_list_entry(self, request_uuid, list_uuid, favourite_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn reorder_list<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourites_uuid: Vec<&'a str>,
+    ) -> Result<(), FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, list_uuid).await?;
+        let favourites_uuid = favourites_uuid
+            .into_iter()
+            .map(parse_uuid)
+            .collect::<Result<Vec<Uuid>, FavouriteListDataError>>()?;
+        self.reorder_list_entries(request_uuid, list_uuid, favourites_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn move_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        to_list_uuid: &'a str,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, list_uuid).await?;
+        let to_list_uuid = get_owned_list_uuid(self, request_uuid, to_list_uuid).await?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        UserFavouritesDatabase::move_list_entry(
+            self,
+            request_uuid,
+            list_uuid,
+            favourite_uuid,
+            to_list_uuid,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+
+    async fn pin_list_entry<'a>(
+        &self,
+        request_uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        pinned: bool,
+    ) -> Result<FavouriteDataResponse, FavouriteListDataError> {
+        let request_uuid = parse_uuid(request_uuid)?;
+        let list_uuid = get_owned_list_uuid(self, request_uuid, list_uuid).await?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        UserFavouritesDatabase::pin_list_entry(
+            self,
+            request_uuid,
+            list_uuid,
+            favourite_uuid,
+            pinned,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, FavouriteListDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteListDataError::UuidInvalid)
+}
+
+fn parse_list_uuid(value: &str) -> Result<Uuid, FavouriteListDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteListDataError::ListUuidInvalid)
+}
+
+/// `None` for `DEFAULT_FAVOURITE_LIST`.
+fn resolve_list_uuid(value: &str) -> Result<Option<Uuid>, FavouriteListDataError> {
+    if value == DEFAULT_FAVOURITE_LIST {
+        Ok(None)
+    } else {
+        parse_list_uuid(value).map(Some)
+    }
+}
+
+async fn get_owned_list(
+    conn: &Conn,
+    request_uuid: Uuid,
+    list_uuid: Uuid,
+) -> Result<FavouriteListEntity, FavouriteListDataError> {
+    let list = FavouriteListsDatabase::get_list(conn, list_uuid).await?;
+    if list.owner_uuid != request_uuid {
+        return Err(FavouriteListDataError::ListNotFound);
+    }
+    Ok(list)
+}
+
+/// Resolves a list of the requesting user, `None` being their default list.
+pub(crate) async fn get_owned_list_uuid(
+    conn: &Conn,
+    request_uuid: Uuid,
+    list_uuid: &str,
+) -> Result<Option<Uuid>, FavouriteListDataError> {
+    match resolve_list_uuid(list_uuid)? {
+        Some(list_uuid) => Ok(Some(
+            get_owned_list(conn, request_uuid, list_uuid).await?.uuid,
+        )),
+        None => Ok(None),
+    }
+}
+
+async fn get_visible_list(
+    conn: &Conn,
+    request_uuid: Uuid,
+    list_uuid: Uuid,
+) -> Result<FavouriteListEntity, FavouriteListDataError> {
+    let list = FavouriteListsDatabase::get_list(conn, list_uuid).await?;
+    let is_owner = list.owner_uuid == request_uuid;
+    let visibility = FavouriteListVisibility::try_from(list.visibility.as_str())
+        .map_err(|_| FavouriteListDataError::InternalError)?;
+    let is_follower = !is_owner
+        && visibility == FavouriteListVisibility::Followers
+        && conn
+            .is_following_uuid(&request_uuid, &list.owner_uuid)
+            .await
+            .map_err(|_| FavouriteListDataError::InternalError)?;
+    if FavouriteListVisibility::visible(is_owner, is_follower).contains(&visibility) {
+        Ok(list)
+    } else {
+        Err(FavouriteListDataError::ListNotFound)
+    }
+}
+
+async fn with_entries_count(
+    conn: &Conn,
+    list: FavouriteListEntity,
+) -> Result<FavouriteListData, FavouriteListDataError> {
+    let entries_count = conn
+        .get_lists_entries_count(vec![list.uuid])
+        .await?
+        .first()
+        .map_or(0, |(_, count)| *count);
+    Ok(FavouriteListData::from_entity(list, entries_count))
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::repository::favourite_lists::objects::{
+        validate_list_title, FavouriteListDataError, FavouriteListVisibility,
+        FAVOURITE_LIST_TITLE_MAX_LENGTH,
+    };
+
+    #[test]
+    fn test_list_visibility_round_trips() {
+        for visibility in ["public", "followers", "private"] {
+            let parsed = FavouriteListVisibility::try_from(visibility).unwrap();
+            assert_eq!(String::from(parsed), visibility);
+        }
+        assert!(matches!(
+            FavouriteListVisibility::try_from("friends"),
+            Err(FavouriteListDataError::VisibilityInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_visible_lists_depend_on_relation() {
+        assert_eq!(FavouriteListVisibility::visible(true, false).len(), 3);
+        assert_eq!(
+            FavouriteListVisibility::visible(false, true),
+            vec![
+                FavouriteListVisibility::Public,
+                FavouriteListVisibility::Followers
+            ]
+        );
+        assert_eq!(
+            FavouriteListVisibility::visible(false, false),
+            vec![FavouriteListVisibility::Public]
+        );
+    }
+
+    #[test]
+    fn test_validate_list_title() {
+        assert_eq!(validate_list_title("  watch next ").unwrap(), "watch next");
+        assert!(matches!(
+            validate_list_title(" "),
+            Err(FavouriteListDataError::TitleInvalid)
+        ));
+        let long_title = "a".repeat(FAVOURITE_LIST_TITLE_MAX_LENGTH + 1);
+        assert!(matches!(
+            validate_list_title(&long_title),
+            Err(FavouriteListDataError::TitleInvalid)
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use self::objects::{
+    FavouriteNoteData, FavouriteTagCountData, FavouriteTagData, FavouriteTagDataError,
+    FavouriteTagsDataSet,
+};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteTagsRepository {
+    /// Replaces the tags of the requester's favourite, returning them in order.
+    async fn set_favourite_tags<'a>(
+        &self,
+        request: FavouriteTagsDataSet<'a>,
+    ) -> Result<Vec<String>, FavouriteTagDataError>;
+    /// Tags `user_uuid` attached to any of `favourites_uuid`.
+    async fn get_favourites_tags(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteTagData>, FavouriteTagDataError>;
+    /// The requester's own tags starting with `query`, the most used first.
+    async fn get_user_tags<'a>(
+        &self,
+        request_uuid: &'a str,
+        query: &'a str,
+        limit: Option<i64>,
+    ) -> Result<Vec<FavouriteTagCountData>, FavouriteTagDataError>;
+    /// Sets the requester's private note on the favourite, a blank note removes it.
+    async fn set_favourite_note<'a>(
+        &self,
+        request_uuid: &'a str,
+        favourite_uuid: &'a str,
+        note: Option<&'a str>,
+        updated_at: i64,
+    ) -> Result<Option<FavouriteNoteData>, FavouriteTagDataError>;
+    /// Notes `user_uuid` wrote on any of `favourites_uuid`.
+    async fn get_favourites_notes(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteNoteData>, FavouriteTagDataError>;
+}
@@ -0,0 +1,112 @@
+use uuid::Uuid;
+
+use crate::data::database::favourite_tags::objects::{
+    FavouriteNoteEntity, FavouriteTagDbError, FavouriteTagEntity,
+};
+
+/// Maximum length of a tag in characters.
+pub const FAVOURITE_TAG_MAX_LENGTH: usize = 32;
+/// Maximum amount of tags on a single favourite.
+pub const FAVOURITE_TAGS_MAX_COUNT: usize = 20;
+/// Maximum length of a note in characters.
+pub const FAVOURITE_NOTE_MAX_LENGTH: usize = 1000;
+pub const FAVOURITE_TAGS_SUGGEST_DEFAULT_LIMIT: i64 = 10;
+pub const FAVOURITE_TAGS_SUGGEST_MAX_LIMIT: i64 = 50;
+
+pub struct FavouriteTagsDataSet<'a> {
+    pub request_uuid: &'a str,
+    pub favourite_uuid: &'a str,
+    pub tags: Vec<&'a str>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FavouriteTagData {
+    pub favourite_uuid: Uuid,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FavouriteTagCountData {
+    pub tag: String,
+    pub favourites_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FavouriteNoteData {
+    pub favourite_uuid: Uuid,
+    pub note: String,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteTagDataError {
+    UuidInvalid,
+    EntryNotFound,
+    TagInvalid,
+    NoteInvalid,
+    InternalError,
+}
+
+impl From<FavouriteTagDbError> for FavouriteTagDataError {
+    fn from(error: FavouriteTagDbError) -> Self {
+        match error {
+            FavouriteTagDbError::EntryNotFound => FavouriteTagDataError::EntryNotFound,
+            FavouriteTagDbError::InternalError => FavouriteTagDataError::InternalError,
+        }
+    }
+}
+
+impl From<FavouriteTagEntity> for FavouriteTagData {
+    fn from(entity: FavouriteTagEntity) -> Self {
+        FavouriteTagData {
+            favourite_uuid: entity.favourite_uuid,
+            tag: entity.tag,
+        }
+    }
+}
+
+impl From<FavouriteNoteEntity> for FavouriteNoteData {
+    fn from(entity: FavouriteNoteEntity) -> Self {
+        FavouriteNoteData {
+            favourite_uuid: entity.favourite_uuid,
+            note: entity.note,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+/// Tags are trimmed and lowercased so `Rainy Day` and `rainy day ` are the same tag.
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// Normalizes the tags and drops repeated ones. Every tag has to be non blank and at
+/// most `FAVOURITE_TAG_MAX_LENGTH` long, and there may be no more than
+/// `FAVOURITE_TAGS_MAX_COUNT` of them.
+pub fn validate_tags(tags: &[&str]) -> Result<Vec<String>, FavouriteTagDataError> {
+    let mut validated: Vec<String> = Vec::new();
+    for tag in tags.iter().map(|tag| normalize_tag(tag)) {
+        if tag.is_empty() || tag.chars().count() > FAVOURITE_TAG_MAX_LENGTH {
+            return Err(FavouriteTagDataError::TagInvalid);
+        }
+        if !validated.contains(&tag) {
+            validated.push(tag);
+        }
+    }
+    if validated.len() > FAVOURITE_TAGS_MAX_COUNT {
+        return Err(FavouriteTagDataError::TagInvalid);
+    }
+    Ok(validated)
+}
+
+/// Trims the note, a blank one is dropped and it may not be longer than
+/// `FAVOURITE_NOTE_MAX_LENGTH`.
+pub fn validate_note(note: Option<&str>) -> Result<Option<String>, FavouriteTagDataError> {
+    match note.map(str::trim).filter(|note| !note.is_empty()) {
+        Some(note) if note.chars().count() > FAVOURITE_NOTE_MAX_LENGTH => {
+            Err(FavouriteTagDataError::NoteInvalid)
+        }
+        note => Ok(note.map(str::to_owned)),
+    }
+}
@@ -0,0 +1,120 @@
+use uuid::Uuid;
+
+use crate::{
+    data::database::favourite_tags::{objects::FavouriteNoteEntityCreate, FavouriteTagsDatabase},
+    Conn,
+};
+
+use super::{
+    objects::{
+        validate_note, validate_tags, FavouriteNoteData, FavouriteTagCountData, FavouriteTagData,
+        FavouriteTagDataError, FavouriteTagsDataSet, FAVOURITE_TAGS_SUGGEST_DEFAULT_LIMIT,
+        FAVOURITE_TAGS_SUGGEST_MAX_LIMIT,
+    },
+    FavouriteTagsRepository,
+};
+
+#[async_trait]
+impl FavouriteTagsRepository for Conn {
+    async fn set_favourite_tags<'a>(
+        &self,
+        request: FavouriteTagsDataSet<'a>,
+    ) -> Result<Vec<String>, FavouriteTagDataError> {
+        let user_uuid = parse_uuid(request.request_uuid)?;
+        let favourite_uuid = parse_uuid(request.favourite_uuid)?;
+        let tags = validate_tags(&request.tags)?;
+        FavouriteTagsDatabase::set_favourite_tags(
+            self,
+            user_uuid,
+            favourite_uuid,
+            tags,
+            request.updated_at,
+        )
+        .await
+        .map(|tags| tags.into_iter().map(|tag| tag.tag).collect())
+        .map_err(|e| e.into())
+    }
+
+    async fn get_favourites_tags(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteTagData>, FavouriteTagDataError> {
+        FavouriteTagsDatabase::get_favourites_tags(self, user_uuid, favourites_uuid)
+            .await
+            .map(|tags| tags.into_iter().map(|v| v.into()).collect())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_user_tags<'a>(
+        &self,
+        request_uuid: &'a str,
+        query: &'a str,
+        limit: Option<i64>,
+    ) -> Result<Vec<FavouriteTagCountData>, FavouriteTagDataError> {
+        let limit = limit
+            .unwrap_or(FAVOURITE_TAGS_SUGGEST_DEFAULT_LIMIT)
+            .clamp(1, FAVOURITE_TAGS_SUGGEST_MAX_LIMIT);
+        FavouriteTagsDatabase::get_user_tags(
+            self,
+            parse_uuid(request_uuid)?,
+            query.trim().to_lowercase(),
+            limit,
+        )
+        .await
+        .map(|tags| {
+            tags.into_iter()
+                .map(|(tag, favourites_count)| FavouriteTagCountData {
+                    tag,
+                    favourites_count,
+                })
+                .collect()
+        })
+        .map_err(|e| e.into())
+    }
+
+    async fn set_favourite_note<'a>(
+        &self,
+        request_uuid: &'a str,
+        favourite_uuid: &'a str,
+        note: Option<&'a str>,
+        updated_at: i64,
+    ) -> Result<Option<FavouriteNoteData>, FavouriteTagDataError> {
+        let user_uuid = parse_uuid(request_uuid)?;
+        let favourite_uuid = parse_uuid(favourite_uuid)?;
+        match validate_note(note)? {
+            Some(note) => {
+                let note = FavouriteNoteEntityCreate {
+                    user_uuid,
+                    favourite_uuid,
+                    note,
+                    created_at: updated_at,
+                    updated_at,
+                };
+                FavouriteTagsDatabase::set_favourite_note(self, note)
+                    .await
+                    .map(|note| Some(note.into()))
+                    .map_err(|e| e.into())
+            }
+            None => FavouriteTagsDatabase::remove_favourite_note(self, user_uuid, favourite_uuid)
+                .await
+                .map(|_| None)
+                .map_err(|e| e.into()),
+        }
+    }
+
+    async fn get_favourites_notes(
+        &self,
+        user_uuid: Uuid,
+        favourites_uuid: Vec<Uuid>,
+    ) -> Result<Vec<FavouriteNoteData>, FavouriteTagDataError> {
+        FavouriteTagsDatabase::get_favourites_notes(self, user_uuid, favourites_uuid)
+            .await
+            .map(|notes| notes.into_iter().map(|v| v.into()).collect())
+            .map_err(|e| e.into())
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, FavouriteTagDataError> {
+    Uuid::parse_str(value).map_err(|_| FavouriteTagDataError::UuidInvalid)
+}
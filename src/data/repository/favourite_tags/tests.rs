@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::repository::favourite_tags::objects::{
+        validate_note, validate_tags, FavouriteTagDataError, FAVOURITE_NOTE_MAX_LENGTH,
+        FAVOURITE_TAGS_MAX_COUNT, FAVOURITE_TAG_MAX_LENGTH,
+    };
+
+    #[test]
+    fn test_validate_tags_normalizes_and_dedupes() {
+        assert_eq!(
+            validate_tags(&[" Rainy Day", "rainy day ", "scary"]).unwrap(),
+            vec!["rainy day".to_string(), "scary".to_string()]
+        );
+        assert!(validate_tags(&[]).unwrap().is_empty());
+
+        let too_long = "a".repeat(FAVOURITE_TAG_MAX_LENGTH + 1);
+        for tags in [vec!["  "], vec![too_long.as_str()]] {
+            assert!(matches!(
+                validate_tags(&tags),
+                Err(FavouriteTagDataError::TagInvalid)
+            ));
+        }
+        let many: Vec<String> = (0..=FAVOURITE_TAGS_MAX_COUNT)
+            .map(|index| format!("tag {}", index))
+            .collect();
+        let many: Vec<&str> = many.iter().map(String::as_str).collect();
+        assert!(matches!(
+            validate_tags(&many),
+            Err(FavouriteTagDataError::TagInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_validate_note() {
+        assert_eq!(
+            validate_note(Some("  with Sam ")).unwrap(),
+            Some("with Sam".to_string())
+        );
+        assert_eq!(validate_note(Some("   ")).unwrap(), None);
+        assert_eq!(validate_note(None).unwrap(), None);
+        let too_long = "a".repeat(FAVOURITE_NOTE_MAX_LENGTH + 1);
+        assert!(matches!(
+            validate_note(Some(&too_long)),
+            Err(FavouriteTagDataError::NoteInvalid)
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+use self::objects::{FavouriteTrendData, FavouriteTrendDataError, FavouriteTrendsRequest};
+
+use super::objects::PagingDomainResponse;
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait FavouriteTrendsRepository {
+    async fn refresh_trends(&self) -> Result<(), FavouriteTrendDataError>;
+    /// Most favourited items of public lists, as of the last refresh.
+    async fn get_trends<'a>(
+        &self,
+        request: &'a FavouriteTrendsRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteTrendData>, FavouriteTrendDataError>;
+    /// Most favourited items among the users `request_uuid` follows.
+    async fn get_following_trends<'a>(
+        &self,
+        request: &'a FavouriteTrendsRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteTrendData>, FavouriteTrendDataError>;
+}
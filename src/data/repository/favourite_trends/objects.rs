@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::favourite_trends::objects::{FavouriteTrendDbError, FavouriteTrendEntity},
+    repository::items::objects::ItemData,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FavouriteTrendPeriod {
+    Day,
+    Week,
+    All,
+}
+
+impl From<FavouriteTrendPeriod> for String {
+    fn from(period: FavouriteTrendPeriod) -> Self {
+        match period {
+            FavouriteTrendPeriod::Day => "day",
+            FavouriteTrendPeriod::Week => "week",
+            FavouriteTrendPeriod::All => "all",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for FavouriteTrendPeriod {
+    type Error = FavouriteTrendDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "day" => Ok(FavouriteTrendPeriod::Day),
+            "week" => Ok(FavouriteTrendPeriod::Week),
+            "all" => Ok(FavouriteTrendPeriod::All),
+            _ => Err(FavouriteTrendDataError::PeriodInvalid),
+        }
+    }
+}
+
+pub struct FavouriteTrendsRequest<'a> {
+    pub request_uuid: &'a str,
+    pub period: &'a str,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+pub struct FavouriteTrendData {
+    pub item_uuid: Uuid,
+    pub title: String,
+    /// Users who favourited the item within the requested period.
+    pub favourites_count: i64,
+    pub item: Option<ItemData>,
+    pub refreshed_at: i64,
+}
+
+#[derive(Debug)]
+pub enum FavouriteTrendDataError {
+    UuidInvalid,
+    PeriodInvalid,
+    InternalError,
+}
+
+impl FavouriteTrendData {
+    pub fn from_entity(entity: FavouriteTrendEntity, period: FavouriteTrendPeriod) -> Self {
+        FavouriteTrendData {
+            item_uuid: entity.item_uuid,
+            title: entity.title,
+            favourites_count: match period {
+                FavouriteTrendPeriod::Day => entity.day_count,
+                FavouriteTrendPeriod::Week => entity.week_count,
+                FavouriteTrendPeriod::All => entity.all_count,
+            },
+            item: None,
+            refreshed_at: entity.refreshed_at,
+        }
+    }
+}
+
+impl From<FavouriteTrendDbError> for FavouriteTrendDataError {
+    fn from(error: FavouriteTrendDbError) -> Self {
+        match error {
+            FavouriteTrendDbError::InternalError => FavouriteTrendDataError::InternalError,
+        }
+    }
+}
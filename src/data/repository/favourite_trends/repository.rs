@@ -0,0 +1,94 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::favourite_trends::{objects::FavouriteTrendEntity, FavouriteTrendsDatabase},
+        repository::{items::ItemsRepository, objects::PagingDomainResponse},
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteTrendData, FavouriteTrendDataError, FavouriteTrendPeriod, FavouriteTrendsRequest,
+    },
+    FavouriteTrendsRepository,
+};
+
+#[async_trait]
+impl FavouriteTrendsRepository for Conn {
+    async fn refresh_trends(&self) -> Result<(), FavouriteTrendDataError> {
+        FavouriteTrendsDatabase::refresh_trends(self)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_trends<'a>(
+        &self,
+        request: &'a FavouriteTrendsRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteTrendData>, FavouriteTrendDataError> {
+        let period = FavouriteTrendPeriod::try_from(request.period)?;
+        let response = FavouriteTrendsDatabase::get_trends(
+            self,
+            period.into(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+        with_items(self, response, period).await
+    }
+
+    async fn get_following_trends<'a>(
+        &self,
+        request: &'a FavouriteTrendsRequest<'a>,
+    ) -> Result<PagingDomainResponse<FavouriteTrendData>, FavouriteTrendDataError> {
+        let request_uuid = Uuid::parse_str(request.request_uuid)
+            .map_err(|_| FavouriteTrendDataError::UuidInvalid)?;
+        let period = FavouriteTrendPeriod::try_from(request.period)?;
+        let response = FavouriteTrendsDatabase::get_following_trends(
+            self,
+            request_uuid,
+            period.into(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+        with_items(self, response, period).await
+    }
+}
+
+/// Converts the trends, attaching catalogue metadata where the item is known.
+async fn with_items(
+    conn: &Conn,
+    response: PagingDomainResponse<FavouriteTrendEntity>,
+    period: FavouriteTrendPeriod,
+) -> Result<PagingDomainResponse<FavouriteTrendData>, FavouriteTrendDataError> {
+    let items = ItemsRepository::get_items(
+        conn,
+        response
+            .result
+            .iter()
+            .map(|trend| trend.item_uuid)
+            .collect(),
+    )
+    .await
+    .map_err(|_| FavouriteTrendDataError::InternalError)?;
+    Ok(PagingDomainResponse {
+        page: response.page,
+        page_size: response.page_size,
+        total: response.total,
+        has_more: response.has_more,
+        result: response
+            .result
+            .into_iter()
+            .map(|entity| {
+                let mut trend = FavouriteTrendData::from_entity(entity, period);
+                trend.item = items
+                    .iter()
+                    .find(|item| item.uuid == trend.item_uuid)
+                    .cloned();
+                trend
+            })
+            .collect(),
+    })
+}
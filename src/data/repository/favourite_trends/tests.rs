@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::{
+        database::favourite_trends::objects::FavouriteTrendEntity,
+        repository::favourite_trends::objects::{
+            FavouriteTrendData, FavouriteTrendDataError, FavouriteTrendPeriod,
+        },
+    };
+
+    #[test]
+    fn test_trend_period_round_trips() {
+        for period in ["day", "week", "all"] {
+            let parsed = FavouriteTrendPeriod::try_from(period).unwrap();
+            assert_eq!(String::from(parsed), period);
+        }
+        assert!(matches!(
+            FavouriteTrendPeriod::try_from("month"),
+            Err(FavouriteTrendDataError::PeriodInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_trend_counts_follow_period() {
+        let entity = FavouriteTrendEntity {
+            item_uuid: Uuid::new_v4(),
+            title: "title".to_string(),
+            day_count: 1,
+            week_count: 3,
+            all_count: 7,
+            refreshed_at: 0,
+        };
+        let count =
+            |period| FavouriteTrendData::from_entity(entity.clone(), period).favourites_count;
+        assert_eq!(count(FavouriteTrendPeriod::Day), 1);
+        assert_eq!(count(FavouriteTrendPeriod::Week), 3);
+        assert_eq!(count(FavouriteTrendPeriod::All), 7);
+    }
+}
@@ -1,4 +1,4 @@
-use self::objects::{FollowDataError, FollowerDataResponse};
+use self::objects::{FollowBatchDataOutcome, FollowDataError, FollowerDataResponse};
 
 use super::objects::{PagingDomainRequest, PagingDomainResponse};
 
@@ -15,11 +15,21 @@ pub trait FollowRepository {
         follower_uuid: &'a str,
         followed_uuid: &'a str,
     ) -> Result<FollowerDataResponse, FollowDataError>;
+    async fn follow_users<'a>(
+        &self,
+        follower_uuid: &'a str,
+        followed_uuids: &'a [&'a str],
+    ) -> Result<Vec<FollowBatchDataOutcome>, FollowDataError>;
     async fn un_follow_user<'a>(
         &self,
         follower_uuid: &'a str,
         followed_uuid: &'a str,
     ) -> Result<(), FollowDataError>;
+    async fn un_follow_users<'a>(
+        &self,
+        follower_uuid: &'a str,
+        followed_uuids: &'a [&'a str],
+    ) -> Result<Vec<FollowBatchDataOutcome>, FollowDataError>;
     async fn is_following<'a>(
         &self,
         follower_uuid: &'a str,
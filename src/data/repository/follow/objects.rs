@@ -37,11 +37,45 @@ impl Mapper<Vec<FollowerDataResponse>> for Vec<FollowerEntity> {
     }
 }
 
+/// Maximum amount of users that can be followed or unfollowed in a single batch.
+pub const FOLLOW_BATCH_LIMIT: usize = 50;
+
+/// Outcome of a single uuid inside a batch follow or unfollow request.
+#[derive(Debug, Clone)]
+pub struct FollowBatchDataOutcome {
+    pub uuid: String,
+    pub status: FollowBatchStatus,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FollowBatchStatus {
+    Followed,
+    AlreadyFollowing,
+    Unfollowed,
+    NotFollowing,
+    UserNotFound,
+    UuidInvalid,
+}
+
+impl std::fmt::Display for FollowBatchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FollowBatchStatus::Followed => write!(f, "followed"),
+            FollowBatchStatus::AlreadyFollowing => write!(f, "already_following"),
+            FollowBatchStatus::Unfollowed => write!(f, "unfollowed"),
+            FollowBatchStatus::NotFollowing => write!(f, "not_following"),
+            FollowBatchStatus::UserNotFound => write!(f, "user_not_found"),
+            FollowBatchStatus::UuidInvalid => write!(f, "uuid_invalid"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FollowDataError {
     UuidInvalid,
     UserNotFound,
     Conflict,
+    BatchLimitExceeded,
     InternalError,
 }
 
@@ -51,6 +85,7 @@ impl std::fmt::Display for FollowDataError {
             FollowDataError::UuidInvalid => write!(f, "UuidInvalid"),
             FollowDataError::UserNotFound => write!(f, "UserNotFound"),
             FollowDataError::Conflict => write!(f, "Conflict"),
+            FollowDataError::BatchLimitExceeded => write!(f, "BatchLimitExceeded"),
             FollowDataError::InternalError => write!(f, "InternalError"),
         }
     }
@@ -1,3 +1,7 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
 use crate::{
     data::{
         database::{
@@ -11,7 +15,10 @@ use crate::{
 };
 
 use super::{
-    objects::{FollowDataError, FollowerDataResponse},
+    objects::{
+        FollowBatchDataOutcome, FollowBatchStatus, FollowDataError, FollowerDataResponse,
+        FOLLOW_BATCH_LIMIT,
+    },
     FollowRepository,
 };
 
@@ -54,6 +61,60 @@ impl FollowRepository for Conn {
             Err(err) => Err(err),
         }
     }
+    async fn follow_users<'a>(
+        &self,
+        follower_uuid: &'a str,
+        followed_uuids: &'a [&'a str],
+    ) -> Result<Vec<FollowBatchDataOutcome>, FollowDataError> {
+        let batch = parse_batch_uuids(followed_uuids)?;
+        let user = UserDatabase::get_user(self, follower_uuid)
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting user: {}", err);
+                FollowDataError::UserNotFound
+            })?;
+        let valid_uuids: Vec<Uuid> = batch.iter().filter_map(|(_, uuid)| *uuid).collect();
+        let followed_users = UserDatabase::get_users(self, &valid_uuids)
+            .await
+            .map_err(|err| {
+                eprintln!("Error getting users: {}", err);
+                FollowDataError::InternalError
+            })?;
+
+        let records: Vec<FollowEntityCreate> = valid_uuids
+            .iter()
+            .filter_map(|uuid| followed_users.iter().find(|followed| followed.id == *uuid))
+            .map(|followed_user| FollowEntityCreate {
+                follower_uuid: user.id,
+                followed_uuid: followed_user.id,
+                followed_username: followed_user.username.to_owned(),
+                follower_username: user.username.to_owned(),
+                followed_avatar_url: followed_user.avatar_url.to_owned(),
+                follower_avatar_url: user.avatar_url.to_owned(),
+            })
+            .collect();
+        let inserted = FollowDatabase::follow_users(self, &records).await?;
+
+        Ok(batch
+            .into_iter()
+            .map(|(raw_uuid, uuid)| {
+                let status = match uuid {
+                    None => FollowBatchStatus::UuidInvalid,
+                    Some(uuid) => match records.iter().position(|r| r.followed_uuid == uuid) {
+                        None => FollowBatchStatus::UserNotFound,
+                        Some(index) => match inserted[index] {
+                            Some(_) => FollowBatchStatus::Followed,
+                            None => FollowBatchStatus::AlreadyFollowing,
+                        },
+                    },
+                };
+                FollowBatchDataOutcome {
+                    uuid: raw_uuid.to_owned(),
+                    status,
+                }
+            })
+            .collect())
+    }
     async fn un_follow_user<'a>(
         &self,
         follower_uuid: &'a str,
@@ -61,6 +122,34 @@ impl FollowRepository for Conn {
     ) -> Result<(), FollowDataError> {
         FollowDatabase::un_follow_user(self, follower_uuid, followed_uuid).await
     }
+    async fn un_follow_users<'a>(
+        &self,
+        follower_uuid: &'a str,
+        followed_uuids: &'a [&'a str],
+    ) -> Result<Vec<FollowBatchDataOutcome>, FollowDataError> {
+        let batch = parse_batch_uuids(followed_uuids)?;
+        let follower_uuid =
+            Uuid::parse_str(follower_uuid).map_err(|_| FollowDataError::UuidInvalid)?;
+        let valid_uuids: Vec<Uuid> = batch.iter().filter_map(|(_, uuid)| *uuid).collect();
+        let removed = FollowDatabase::un_follow_users(self, &follower_uuid, &valid_uuids).await?;
+
+        Ok(batch
+            .into_iter()
+            .map(|(raw_uuid, uuid)| {
+                let status = match uuid {
+                    None => FollowBatchStatus::UuidInvalid,
+                    Some(uuid) => match valid_uuids.iter().position(|v| *v == uuid) {
+                        Some(index) if removed[index] => FollowBatchStatus::Unfollowed,
+                        _ => FollowBatchStatus::NotFollowing,
+                    },
+                };
+                FollowBatchDataOutcome {
+                    uuid: raw_uuid.to_owned(),
+                    status,
+                }
+            })
+            .collect())
+    }
     async fn is_following<'a>(
         &self,
         follower_uuid: &'a str,
@@ -99,3 +188,23 @@ impl FollowRepository for Conn {
         }
     }
 }
+
+/// Validates the batch size and parses every uuid once, keeping the request order and the
+/// first spelling of each uuid.
+fn parse_batch_uuids<'a>(
+    uuids: &'a [&'a str],
+) -> Result<Vec<(&'a str, Option<Uuid>)>, FollowDataError> {
+    if uuids.len() > FOLLOW_BATCH_LIMIT {
+        return Err(FollowDataError::BatchLimitExceeded);
+    }
+    let mut seen_uuids = HashSet::new();
+    let mut seen_invalid = HashSet::new();
+    Ok(uuids
+        .iter()
+        .map(|uuid| (*uuid, Uuid::parse_str(uuid).ok()))
+        .filter(|(raw, parsed)| match parsed {
+            Some(parsed) => seen_uuids.insert(*parsed),
+            None => seen_invalid.insert(*raw),
+        })
+        .collect())
+}
@@ -5,10 +5,14 @@ mod tests {
         database::tests::database_test_utls::run_migration_get_conn,
         repository::{
             auth::{objects::RegistrationData, AuthRepository},
-            follow::FollowRepository,
+            follow::{
+                objects::{FollowBatchStatus, FollowDataError, FOLLOW_BATCH_LIMIT},
+                FollowRepository,
+            },
         },
     };
     use std::env;
+    use uuid::Uuid;
 
     #[tokio::test]
     async fn test_get_follower_count() {
@@ -162,4 +166,79 @@ mod tests {
         assert!(is_follow.is_ok());
         assert!(!is_follow.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_follow_users_batch() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        env::set_var("JWT_ACCESS_SECRET", "JWT_ACCESS_SECRET");
+        env::set_var("JWT_REFRESH_SECRET", "JWT_REFRESH_SECRET");
+
+        let follower_data = RegistrationData {
+            login: "test_login_batch_follower",
+            username: "username_batch_follower",
+            password: "test_passwd_batch_follower",
+        };
+        let followed_data = RegistrationData {
+            login: "test_login_batch_followed",
+            username: "username_batch_followed",
+            password: "test_passwd_batch_followed",
+        };
+
+        let follower_uuid = connection.registration(&follower_data).await.unwrap().uuid;
+        let followed_uuid = connection.registration(&followed_data).await.unwrap().uuid;
+        let unknown_uuid = Uuid::new_v4().to_string();
+        let followed_upper = followed_uuid.to_uppercase();
+
+        let follow_result = connection.follow_user(&follower_uuid, &followed_uuid).await;
+        assert!(follow_result.is_ok());
+
+        let batch = vec![
+            followed_uuid.as_str(),
+            unknown_uuid.as_str(),
+            "invalid_uuid",
+            followed_uuid.as_str(),
+            followed_upper.as_str(),
+            "invalid_uuid",
+        ];
+        let outcomes = connection.follow_users(&follower_uuid, &batch).await;
+        assert!(outcomes.is_ok());
+
+        let statuses: Vec<FollowBatchStatus> = outcomes
+            .unwrap()
+            .into_iter()
+            .map(|outcome| outcome.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                FollowBatchStatus::AlreadyFollowing,
+                FollowBatchStatus::UserNotFound,
+                FollowBatchStatus::UuidInvalid,
+            ]
+        );
+
+        let outcomes = connection
+            .un_follow_users(
+                &follower_uuid,
+                &[followed_uuid.as_str(), unknown_uuid.as_str()],
+            )
+            .await;
+        let statuses: Vec<FollowBatchStatus> = outcomes
+            .unwrap()
+            .into_iter()
+            .map(|outcome| outcome.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                FollowBatchStatus::Unfollowed,
+                FollowBatchStatus::NotFollowing
+            ]
+        );
+
+        let too_large = vec![followed_uuid.as_str(); FOLLOW_BATCH_LIMIT + 1];
+        let outcomes = connection.follow_users(&follower_uuid, &too_large).await;
+        assert!(matches!(outcomes, Err(FollowDataError::BatchLimitExceeded)));
+    }
 }
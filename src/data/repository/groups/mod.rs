@@ -0,0 +1,37 @@
+use self::objects::{GroupData, GroupDataCreate, GroupDataError, GroupDataUpdate};
+
+use super::objects::{PagingDomainRequest, PagingDomainResponse};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+/// Groups are private to their owner, other users get `GroupNotFound`.
+#[async_trait]
+pub trait GroupsRepository {
+    async fn create_group<'a>(
+        &self,
+        request: GroupDataCreate<'a>,
+    ) -> Result<GroupData, GroupDataError>;
+    async fn get_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<GroupData, GroupDataError>;
+    async fn get_groups<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<GroupData>, GroupDataError>;
+    /// Renames the group and, when `members_uuid` is given, replaces its members.
+    async fn update_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+        update: GroupDataUpdate<'a>,
+    ) -> Result<GroupData, GroupDataError>;
+    async fn delete_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<(), GroupDataError>;
+}
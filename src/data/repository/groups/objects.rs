@@ -0,0 +1,85 @@
+use uuid::Uuid;
+
+use crate::data::database::groups::objects::{GroupDbError, GroupEntity};
+
+/// Maximum length of a group title in characters.
+pub const GROUP_TITLE_MAX_LENGTH: usize = 64;
+/// Maximum amount of members a single group can hold.
+pub const GROUP_MEMBERS_LIMIT: usize = 50;
+
+pub struct GroupData {
+    pub uuid: Uuid,
+    pub owner_uuid: Uuid,
+    pub title: String,
+    pub members_uuid: Vec<Uuid>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub struct GroupDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub title: &'a str,
+    pub members_uuid: Vec<&'a str>,
+    pub created_at: i64,
+}
+
+pub struct GroupDataUpdate<'a> {
+    pub title: Option<&'a str>,
+    pub members_uuid: Option<Vec<&'a str>>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum GroupDataError {
+    UuidInvalid,
+    GroupNotFound,
+    TitleInvalid,
+    MembersNotFollowed,
+    MembersLimitExceeded,
+    InternalError,
+}
+
+/// Trims the title, which must not be blank or longer than `GROUP_TITLE_MAX_LENGTH`.
+pub fn validate_group_title(title: &str) -> Result<String, GroupDataError> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > GROUP_TITLE_MAX_LENGTH {
+        Err(GroupDataError::TitleInvalid)
+    } else {
+        Ok(title.to_owned())
+    }
+}
+
+/// Drops the owner and duplicates, keeping the order members were given in.
+pub fn normalize_members(owner_uuid: Uuid, members_uuid: Vec<Uuid>) -> Vec<Uuid> {
+    members_uuid
+        .into_iter()
+        .fold(Vec::new(), |mut members, uuid| {
+            if uuid != owner_uuid && !members.contains(&uuid) {
+                members.push(uuid);
+            }
+            members
+        })
+}
+
+impl GroupData {
+    pub fn from_entity(entity: GroupEntity, members_uuid: Vec<Uuid>) -> Self {
+        GroupData {
+            uuid: entity.uuid,
+            owner_uuid: entity.owner_uuid,
+            title: entity.title,
+            members_uuid,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<GroupDbError> for GroupDataError {
+    fn from(error: GroupDbError) -> Self {
+        match error {
+            GroupDbError::UuidInvalid => GroupDataError::UuidInvalid,
+            GroupDbError::GroupNotFound => GroupDataError::GroupNotFound,
+            GroupDbError::InternalError => GroupDataError::InternalError,
+        }
+    }
+}
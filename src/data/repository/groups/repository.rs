@@ -0,0 +1,165 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::{
+            follow::FollowDatabase,
+            groups::{
+                objects::{GroupEntity, GroupEntityCreate, GroupEntityUpdate},
+                GroupsDatabase,
+            },
+        },
+        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        normalize_members, validate_group_title, GroupData, GroupDataCreate, GroupDataError,
+        GroupDataUpdate, GROUP_MEMBERS_LIMIT,
+    },
+    GroupsRepository,
+};
+
+#[async_trait]
+impl GroupsRepository for Conn {
+    async fn create_group<'a>(
+        &self,
+        request: GroupDataCreate<'a>,
+    ) -> Result<GroupData, GroupDataError> {
+        let owner_uuid = parse_uuid(request.request_uuid)?;
+        let title = validate_group_title(request.title)?;
+        let members_uuid = validate_members(self, owner_uuid, request.members_uuid).await?;
+        let group = GroupEntityCreate {
+            owner_uuid,
+            title,
+            created_at: request.created_at,
+            updated_at: request.created_at,
+        };
+        let created = self.add_group(group, members_uuid.clone()).await?;
+        Ok(GroupData::from_entity(created, members_uuid))
+    }
+
+    async fn get_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<GroupData, GroupDataError> {
+        let group = get_owned_group(self, request_uuid, group_uuid).await?;
+        with_members(self, group).await
+    }
+
+    async fn get_groups<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<GroupData>, GroupDataError> {
+        let response = GroupsDatabase::get_groups(self, request).await?;
+        let groups_uuid = response.result.iter().map(|group| group.uuid).collect();
+        let members = self.get_group_members(groups_uuid).await?;
+        let result = response
+            .result
+            .into_iter()
+            .map(|group| {
+                let members_uuid = members
+                    .iter()
+                    .filter(|member| member.group_uuid == group.uuid)
+                    .map(|member| member.user_uuid)
+                    .collect();
+                GroupData::from_entity(group, members_uuid)
+            })
+            .collect();
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result,
+        })
+    }
+
+    async fn update_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+        update: GroupDataUpdate<'a>,
+    ) -> Result<GroupData, GroupDataError> {
+        let group = get_owned_group(self, request_uuid, group_uuid).await?;
+        let title = update.title.map(validate_group_title).transpose()?;
+        let members_uuid = match update.members_uuid {
+            Some(members_uuid) => {
+                Some(validate_members(self, group.owner_uuid, members_uuid).await?)
+            }
+            None => None,
+        };
+        let update = GroupEntityUpdate {
+            title,
+            updated_at: update.updated_at,
+        };
+        let updated = GroupsDatabase::update_group(self, group.uuid, update, members_uuid).await?;
+        with_members(self, updated).await
+    }
+
+    async fn delete_group<'a>(
+        &self,
+        request_uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<(), GroupDataError> {
+        let group = get_owned_group(self, request_uuid, group_uuid).await?;
+        GroupsDatabase::delete_group(self, group.uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, GroupDataError> {
+    Uuid::parse_str(value).map_err(|_| GroupDataError::UuidInvalid)
+}
+
+/// Groups of other users are reported as missing rather than forbidden.
+async fn get_owned_group(
+    conn: &Conn,
+    request_uuid: &str,
+    group_uuid: &str,
+) -> Result<GroupEntity, GroupDataError> {
+    let owner_uuid = parse_uuid(request_uuid)?;
+    let group = GroupsDatabase::get_group(conn, parse_uuid(group_uuid)?).await?;
+    if group.owner_uuid != owner_uuid {
+        return Result::Err(GroupDataError::GroupNotFound);
+    }
+    Ok(group)
+}
+
+async fn with_members(conn: &Conn, group: GroupEntity) -> Result<GroupData, GroupDataError> {
+    let members_uuid = conn
+        .get_group_members(vec![group.uuid])
+        .await?
+        .into_iter()
+        .map(|member| member.user_uuid)
+        .collect();
+    Ok(GroupData::from_entity(group, members_uuid))
+}
+
+/// Members are limited to users the owner follows.
+async fn validate_members(
+    conn: &Conn,
+    owner_uuid: Uuid,
+    members_uuid: Vec<&str>,
+) -> Result<Vec<Uuid>, GroupDataError> {
+    let members_uuid = members_uuid
+        .into_iter()
+        .map(parse_uuid)
+        .collect::<Result<Vec<_>, _>>()?;
+    let members_uuid = normalize_members(owner_uuid, members_uuid);
+    if members_uuid.len() > GROUP_MEMBERS_LIMIT {
+        return Result::Err(GroupDataError::MembersLimitExceeded);
+    }
+    let followed = conn
+        .get_followed_users(&owner_uuid, &members_uuid)
+        .await
+        .map_err(|_| GroupDataError::InternalError)?;
+    if members_uuid.iter().any(|uuid| !followed.contains(uuid)) {
+        return Result::Err(GroupDataError::MembersNotFollowed);
+    }
+    Ok(members_uuid)
+}
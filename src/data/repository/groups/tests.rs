@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::repository::groups::objects::{
+        normalize_members, validate_group_title, GroupDataError, GROUP_TITLE_MAX_LENGTH,
+    };
+
+    #[test]
+    fn test_validate_group_title() {
+        assert_eq!(validate_group_title("  friends ").unwrap(), "friends");
+        assert!(validate_group_title(&"a".repeat(GROUP_TITLE_MAX_LENGTH)).is_ok());
+        for title in [
+            "".to_string(),
+            "   ".to_string(),
+            "a".repeat(GROUP_TITLE_MAX_LENGTH + 1),
+        ] {
+            assert!(matches!(
+                validate_group_title(&title),
+                Err(GroupDataError::TitleInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_normalize_members_drops_owner_and_duplicates() {
+        let owner = Uuid::new_v4();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        assert_eq!(
+            normalize_members(owner, vec![second, owner, first, second]),
+            vec![second, first]
+        );
+    }
+}
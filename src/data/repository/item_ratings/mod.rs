@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use self::objects::{
+    ItemRatingData, ItemRatingDataError, ItemRatingDataSet, ItemRatingStatsData, ItemReviewData,
+};
+
+use super::objects::PagingDomainResponse;
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait ItemRatingsRepository {
+    async fn set_rating<'a>(
+        &self,
+        request: ItemRatingDataSet<'a>,
+    ) -> Result<ItemRatingData, ItemRatingDataError>;
+    async fn remove_rating<'a>(
+        &self,
+        request_uuid: &'a str,
+        item_uuid: &'a str,
+    ) -> Result<(), ItemRatingDataError>;
+    /// Ratings `user_uuid` gave to any of `items_uuid`.
+    async fn get_user_ratings(
+        &self,
+        user_uuid: Uuid,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemRatingData>, ItemRatingDataError>;
+    async fn get_rating_stats<'a>(
+        &self,
+        item_uuid: &'a str,
+    ) -> Result<ItemRatingStatsData, ItemRatingDataError>;
+    /// Ratings and reviews of the item by users `request_uuid` follows.
+    async fn get_followed_reviews<'a>(
+        &self,
+        request_uuid: &'a str,
+        item_uuid: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemReviewData>, ItemRatingDataError>;
+}
@@ -0,0 +1,133 @@
+use uuid::Uuid;
+
+use crate::data::database::item_ratings::objects::{
+    ItemRatingDbError, ItemRatingEntity, ItemReviewEntity,
+};
+
+pub const ITEM_RATING_MIN: i16 = 1;
+pub const ITEM_RATING_MAX: i16 = 10;
+/// Maximum length of a review in characters.
+pub const ITEM_REVIEW_MAX_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemRatingData {
+    pub user_uuid: Uuid,
+    pub item_uuid: Uuid,
+    pub rating: i16,
+    pub review: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub struct ItemRatingDataSet<'a> {
+    pub request_uuid: &'a str,
+    pub item_uuid: &'a str,
+    pub rating: i16,
+    pub review: Option<&'a str>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ItemRatingStatsData {
+    pub item_uuid: Uuid,
+    pub ratings_count: i64,
+    /// `None` while nobody rated the item.
+    pub average: Option<f64>,
+    /// Amount of ratings per value, index 0 holding the ratings of 1.
+    pub distribution: Vec<i64>,
+}
+
+pub struct ItemReviewData {
+    pub username: String,
+    pub avatar_url: String,
+    pub rating: ItemRatingData,
+}
+
+#[derive(Debug)]
+pub enum ItemRatingDataError {
+    UuidInvalid,
+    ItemNotFound,
+    RatingNotFound,
+    RatingInvalid,
+    ReviewInvalid,
+    InternalError,
+}
+
+pub fn validate_rating(rating: i16) -> Result<i16, ItemRatingDataError> {
+    if (ITEM_RATING_MIN..=ITEM_RATING_MAX).contains(&rating) {
+        Ok(rating)
+    } else {
+        Err(ItemRatingDataError::RatingInvalid)
+    }
+}
+
+/// Trims the review, a blank one is dropped and it may not be longer than
+/// `ITEM_REVIEW_MAX_LENGTH`.
+pub fn validate_review(review: Option<&str>) -> Result<Option<String>, ItemRatingDataError> {
+    match review.map(str::trim).filter(|review| !review.is_empty()) {
+        Some(review) if review.chars().count() > ITEM_REVIEW_MAX_LENGTH => {
+            Err(ItemRatingDataError::ReviewInvalid)
+        }
+        review => Ok(review.map(str::to_owned)),
+    }
+}
+
+impl ItemRatingStatsData {
+    pub fn from_distribution(item_uuid: Uuid, counts: &[(i16, i64)]) -> Self {
+        let distribution: Vec<i64> = (ITEM_RATING_MIN..=ITEM_RATING_MAX)
+            .map(|value| {
+                counts
+                    .iter()
+                    .find(|(rating, _)| *rating == value)
+                    .map_or(0, |(_, count)| *count)
+            })
+            .collect();
+        let ratings_count: i64 = distribution.iter().sum();
+        let total: i64 = counts
+            .iter()
+            .map(|(rating, count)| i64::from(*rating) * count)
+            .sum();
+        ItemRatingStatsData {
+            item_uuid,
+            ratings_count,
+            average: match ratings_count {
+                0 => None,
+                _ => Some(total as f64 / ratings_count as f64),
+            },
+            distribution,
+        }
+    }
+}
+
+impl From<ItemRatingEntity> for ItemRatingData {
+    fn from(entity: ItemRatingEntity) -> Self {
+        ItemRatingData {
+            user_uuid: entity.user_uuid,
+            item_uuid: entity.item_uuid,
+            rating: entity.rating,
+            review: entity.review,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<ItemReviewEntity> for ItemReviewData {
+    fn from(entity: ItemReviewEntity) -> Self {
+        ItemReviewData {
+            username: entity.username,
+            avatar_url: entity.avatar_url,
+            rating: entity.rating.into(),
+        }
+    }
+}
+
+impl From<ItemRatingDbError> for ItemRatingDataError {
+    fn from(error: ItemRatingDbError) -> Self {
+        match error {
+            ItemRatingDbError::ItemNotFound => ItemRatingDataError::ItemNotFound,
+            ItemRatingDbError::RatingNotFound => ItemRatingDataError::RatingNotFound,
+            ItemRatingDbError::InternalError => ItemRatingDataError::InternalError,
+        }
+    }
+}
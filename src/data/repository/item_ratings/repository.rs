@@ -0,0 +1,96 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::item_ratings::{objects::ItemRatingEntityCreate, ItemRatingsDatabase},
+        repository::objects::PagingDomainResponse,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        validate_rating, validate_review, ItemRatingData, ItemRatingDataError, ItemRatingDataSet,
+        ItemRatingStatsData, ItemReviewData,
+    },
+    ItemRatingsRepository,
+};
+
+#[async_trait]
+impl ItemRatingsRepository for Conn {
+    async fn set_rating<'a>(
+        &self,
+        request: ItemRatingDataSet<'a>,
+    ) -> Result<ItemRatingData, ItemRatingDataError> {
+        let rating = ItemRatingEntityCreate {
+            user_uuid: parse_uuid(request.request_uuid)?,
+            item_uuid: parse_uuid(request.item_uuid)?,
+            rating: validate_rating(request.rating)?,
+            review: validate_review(request.review)?,
+            created_at: request.updated_at,
+            updated_at: request.updated_at,
+        };
+        ItemRatingsDatabase::set_rating(self, rating)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn remove_rating<'a>(
+        &self,
+        request_uuid: &'a str,
+        item_uuid: &'a str,
+    ) -> Result<(), ItemRatingDataError> {
+        ItemRatingsDatabase::remove_rating(self, parse_uuid(request_uuid)?, parse_uuid(item_uuid)?)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_user_ratings(
+        &self,
+        user_uuid: Uuid,
+        items_uuid: Vec<Uuid>,
+    ) -> Result<Vec<ItemRatingData>, ItemRatingDataError> {
+        ItemRatingsDatabase::get_user_ratings(self, user_uuid, items_uuid)
+            .await
+            .map(|ratings| ratings.into_iter().map(|v| v.into()).collect())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_rating_stats<'a>(
+        &self,
+        item_uuid: &'a str,
+    ) -> Result<ItemRatingStatsData, ItemRatingDataError> {
+        let item_uuid = parse_uuid(item_uuid)?;
+        let counts = self.get_rating_distribution(item_uuid).await?;
+        Ok(ItemRatingStatsData::from_distribution(item_uuid, &counts))
+    }
+
+    async fn get_followed_reviews<'a>(
+        &self,
+        request_uuid: &'a str,
+        item_uuid: &'a str,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemReviewData>, ItemRatingDataError> {
+        let response = ItemRatingsDatabase::get_followed_reviews(
+            self,
+            parse_uuid(request_uuid)?,
+            parse_uuid(item_uuid)?,
+            page,
+            page_size,
+        )
+        .await?;
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        })
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, ItemRatingDataError> {
+    Uuid::parse_str(value).map_err(|_| ItemRatingDataError::UuidInvalid)
+}
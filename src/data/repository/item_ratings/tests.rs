@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::repository::item_ratings::objects::{
+        validate_rating, validate_review, ItemRatingDataError, ItemRatingStatsData,
+        ITEM_REVIEW_MAX_LENGTH,
+    };
+
+    #[test]
+    fn test_validate_rating_bounds() {
+        assert_eq!(validate_rating(1).unwrap(), 1);
+        assert_eq!(validate_rating(10).unwrap(), 10);
+        for rating in [0, 11, -3] {
+            assert!(matches!(
+                validate_rating(rating),
+                Err(ItemRatingDataError::RatingInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_review_trims_and_limits() {
+        assert_eq!(validate_review(None).unwrap(), None);
+        assert_eq!(validate_review(Some("   ")).unwrap(), None);
+        assert_eq!(
+            validate_review(Some("  worth it ")).unwrap(),
+            Some("worth it".to_string())
+        );
+        let longest = "é".repeat(ITEM_REVIEW_MAX_LENGTH);
+        assert_eq!(
+            validate_review(Some(&longest)).unwrap(),
+            Some(longest.clone())
+        );
+        assert!(matches!(
+            validate_review(Some(&format!("{}a", longest))),
+            Err(ItemRatingDataError::ReviewInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_rating_stats_from_distribution() {
+        let item_uuid = Uuid::new_v4();
+        let stats = ItemRatingStatsData::from_distribution(item_uuid, &[(2, 1), (8, 2), (10, 1)]);
+        assert_eq!(stats.ratings_count, 4);
+        assert_eq!(stats.average, Some(7.0));
+        assert_eq!(stats.distribution, vec![0, 1, 0, 0, 0, 0, 0, 2, 0, 1]);
+
+        let empty = ItemRatingStatsData::from_distribution(item_uuid, &[]);
+        assert_eq!(empty.ratings_count, 0);
+        assert_eq!(empty.average, None);
+        assert_eq!(empty.distribution, vec![0; 10]);
+    }
+}
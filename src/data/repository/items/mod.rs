@@ -0,0 +1,30 @@
+use uuid::Uuid;
+
+use crate::data::catalog::CatalogProvider;
+
+use self::objects::{CatalogSyncData, ItemData, ItemDataError};
+
+use super::objects::PagingDomainResponse;
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait ItemsRepository {
+    /// Stores every valid item the provider returns, invalid ones are skipped.
+    async fn sync_catalog(
+        &self,
+        provider: &dyn CatalogProvider,
+    ) -> Result<CatalogSyncData, ItemDataError>;
+    async fn get_item<'a>(&self, item_uuid: &'a str) -> Result<ItemData, ItemDataError>;
+    /// Items found for `items_uuid`, unknown uuids are left out.
+    async fn get_items(&self, items_uuid: Vec<Uuid>) -> Result<Vec<ItemData>, ItemDataError>;
+    async fn search_items<'a>(
+        &self,
+        query: &'a str,
+        kind: Option<&'a str>,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemData>, ItemDataError>;
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use crate::data::{
+    catalog::{CatalogError, CatalogItem},
+    database::items::objects::{ItemDbError, ItemEntity, ItemEntitySync, ItemExternalIdEntity},
+};
+
+pub const ITEM_TITLE_MAX_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Movie,
+    Series,
+    Book,
+    Game,
+    Music,
+    Other,
+}
+
+impl From<ItemKind> for String {
+    fn from(kind: ItemKind) -> Self {
+        match kind {
+            ItemKind::Movie => "movie",
+            ItemKind::Series => "series",
+            ItemKind::Book => "book",
+            ItemKind::Game => "game",
+            ItemKind::Music => "music",
+            ItemKind::Other => "other",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<&str> for ItemKind {
+    type Error = ItemDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "movie" => Ok(ItemKind::Movie),
+            "series" => Ok(ItemKind::Series),
+            "book" => Ok(ItemKind::Book),
+            "game" => Ok(ItemKind::Game),
+            "music" => Ok(ItemKind::Music),
+            "other" => Ok(ItemKind::Other),
+            _ => Err(ItemDataError::KindInvalid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemData {
+    pub uuid: Uuid,
+    pub kind: String,
+    pub title: String,
+    pub year: Option<i32>,
+    pub image_url: Option<String>,
+    pub external_ids: BTreeMap<String, String>,
+}
+
+impl ItemData {
+    /// Picks the external ids of the entity out of `external_ids`.
+    pub fn from_entity(entity: ItemEntity, external_ids: &[ItemExternalIdEntity]) -> Self {
+        ItemData {
+            external_ids: external_ids
+                .iter()
+                .filter(|external_id| external_id.item_uuid == entity.uuid)
+                .map(|external_id| {
+                    (
+                        external_id.source.to_owned(),
+                        external_id.external_id.to_owned(),
+                    )
+                })
+                .collect(),
+            uuid: entity.uuid,
+            kind: entity.kind,
+            title: entity.title,
+            year: entity.year,
+            image_url: entity.image_url,
+        }
+    }
+}
+
+pub struct CatalogSyncData {
+    pub synced: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug)]
+pub enum ItemDataError {
+    UuidInvalid,
+    ItemNotFound,
+    KindInvalid,
+    TitleInvalid,
+    CatalogUnavailable,
+    CatalogInvalid,
+    InternalError,
+}
+
+impl TryFrom<CatalogItem> for ItemEntitySync {
+    type Error = ItemDataError;
+
+    fn try_from(item: CatalogItem) -> Result<Self, Self::Error> {
+        let kind = ItemKind::try_from(item.kind.trim())?;
+        let title = item.title.trim();
+        if title.is_empty() || title.chars().count() > ITEM_TITLE_MAX_LENGTH {
+            return Err(ItemDataError::TitleInvalid);
+        }
+        Ok(ItemEntitySync {
+            uuid: item.uuid,
+            kind: kind.into(),
+            title: title.to_string(),
+            year: item.year,
+            image_url: item.image_url.filter(|image_url| !image_url.is_empty()),
+            external_ids: item.external_ids.into_iter().collect(),
+        })
+    }
+}
+
+impl From<ItemDbError> for ItemDataError {
+    fn from(error: ItemDbError) -> Self {
+        match error {
+            ItemDbError::InternalError => ItemDataError::InternalError,
+        }
+    }
+}
+
+impl From<CatalogError> for ItemDataError {
+    fn from(error: CatalogError) -> Self {
+        match error {
+            CatalogError::Unavailable => ItemDataError::CatalogUnavailable,
+            CatalogError::FormatInvalid => ItemDataError::CatalogInvalid,
+        }
+    }
+}
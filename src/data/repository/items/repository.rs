@@ -0,0 +1,89 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        catalog::CatalogProvider,
+        database::items::{objects::ItemEntitySync, ItemsDatabase},
+        repository::objects::PagingDomainResponse,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{CatalogSyncData, ItemData, ItemDataError, ItemKind},
+    ItemsRepository,
+};
+
+#[async_trait]
+impl ItemsRepository for Conn {
+    async fn sync_catalog(
+        &self,
+        provider: &dyn CatalogProvider,
+    ) -> Result<CatalogSyncData, ItemDataError> {
+        let fetched = provider.fetch_items().await?;
+        let fetched_count = fetched.len();
+        let items: Vec<ItemEntitySync> = fetched
+            .into_iter()
+            .filter_map(|item| {
+                let title = item.title.to_owned();
+                ItemEntitySync::try_from(item)
+                    .map_err(|err| {
+                        eprintln!("Skipping catalogue item {:?}: {:?}", title, err);
+                    })
+                    .ok()
+            })
+            .collect();
+        let synced_at = chrono::Utc::now().timestamp_millis();
+        let synced =
+            ItemsDatabase::sync_items(self, provider.name().to_owned(), items, synced_at).await?;
+        Ok(CatalogSyncData {
+            synced,
+            skipped: fetched_count - synced,
+        })
+    }
+
+    async fn get_item<'a>(&self, item_uuid: &'a str) -> Result<ItemData, ItemDataError> {
+        let item_uuid = Uuid::parse_str(item_uuid).map_err(|_| ItemDataError::UuidInvalid)?;
+        ItemsRepository::get_items(self, vec![item_uuid])
+            .await?
+            .pop()
+            .ok_or(ItemDataError::ItemNotFound)
+    }
+
+    async fn get_items(&self, items_uuid: Vec<Uuid>) -> Result<Vec<ItemData>, ItemDataError> {
+        if items_uuid.is_empty() {
+            return Ok(Vec::new());
+        }
+        let entities = ItemsDatabase::get_items(self, items_uuid.clone()).await?;
+        let external_ids = self.get_item_external_ids(items_uuid).await?;
+        Ok(entities
+            .into_iter()
+            .map(|entity| ItemData::from_entity(entity, &external_ids))
+            .collect())
+    }
+
+    async fn search_items<'a>(
+        &self,
+        query: &'a str,
+        kind: Option<&'a str>,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PagingDomainResponse<ItemData>, ItemDataError> {
+        let kind = kind.map(ItemKind::try_from).transpose()?.map(String::from);
+        let found =
+            ItemsDatabase::search_items(self, query.to_owned(), kind, page, page_size).await?;
+        let items_uuid = found.result.iter().map(|entity| entity.uuid).collect();
+        let external_ids = self.get_item_external_ids(items_uuid).await?;
+        Ok(PagingDomainResponse {
+            page: found.page,
+            page_size: found.page_size,
+            total: found.total,
+            has_more: found.has_more,
+            result: found
+                .result
+                .into_iter()
+                .map(|entity| ItemData::from_entity(entity, &external_ids))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::data::{
+        catalog::CatalogItem,
+        database::items::objects::ItemEntitySync,
+        repository::items::objects::{ItemDataError, ItemKind, ITEM_TITLE_MAX_LENGTH},
+    };
+
+    fn catalog_item(kind: &str, title: &str) -> CatalogItem {
+        CatalogItem {
+            uuid: None,
+            kind: kind.to_string(),
+            title: title.to_string(),
+            year: Some(2001),
+            image_url: Some(String::new()),
+            external_ids: BTreeMap::from([("imdb".to_string(), "tt0245429".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_item_kind_round_trips() {
+        for kind in ["movie", "series", "book", "game", "music", "other"] {
+            let parsed = ItemKind::try_from(kind).unwrap();
+            assert_eq!(String::from(parsed), kind);
+        }
+        assert!(matches!(
+            ItemKind::try_from("podcast"),
+            Err(ItemDataError::KindInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_catalog_item_validation() {
+        let item = ItemEntitySync::try_from(catalog_item(" movie ", "  Spirited Away ")).unwrap();
+        assert_eq!(item.kind, "movie");
+        assert_eq!(item.title, "Spirited Away");
+        assert_eq!(item.image_url, None);
+        assert_eq!(
+            item.external_ids,
+            vec![("imdb".to_string(), "tt0245429".to_string())]
+        );
+
+        assert!(matches!(
+            ItemEntitySync::try_from(catalog_item("podcast", "Title")),
+            Err(ItemDataError::KindInvalid)
+        ));
+        assert!(matches!(
+            ItemEntitySync::try_from(catalog_item("movie", "   ")),
+            Err(ItemDataError::TitleInvalid)
+        ));
+        let long_title = "a".repeat(ITEM_TITLE_MAX_LENGTH + 1);
+        assert!(matches!(
+            ItemEntitySync::try_from(catalog_item("movie", &long_title)),
+            Err(ItemDataError::TitleInvalid)
+        ));
+    }
+}
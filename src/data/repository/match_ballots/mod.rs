@@ -0,0 +1,30 @@
+use self::{
+    objects::{MatchBallotData, MatchBallotDataCreate, MatchBallotDataError, MatchResultsData},
+    tally::TallyResult,
+};
+
+use super::matches::objects::MatchesData;
+
+pub mod objects;
+mod repository;
+pub mod tally;
+mod tests;
+
+#[async_trait]
+pub trait MatchBallotsRepository {
+    /// Stores the ballot; once every participant has one the match is tallied and completed.
+    async fn submit_ballot<'a>(
+        &self,
+        request: MatchBallotDataCreate<'a>,
+    ) -> Result<MatchBallotData, MatchBallotDataError>;
+    async fn get_results<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchResultsData, MatchBallotDataError>;
+    /// Tallies the current ballots, or likes for unanimous matches, of the participants.
+    async fn tally_match(
+        &self,
+        match_data: &MatchesData,
+    ) -> Result<(TallyResult, usize), MatchBallotDataError>;
+}
@@ -0,0 +1,121 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::{
+        match_ballots::objects::{MatchBallotDbError, MatchBallotEntity},
+        matches::objects::MatchesDbError,
+    },
+    repository::matches::objects::{MatchDecisionMethod, MatchStatus, MatchesDataError},
+};
+
+use super::tally::TallyRound;
+
+pub struct MatchBallotData {
+    pub match_uuid: Uuid,
+    pub candidate_uuids: Vec<Uuid>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub status: MatchStatus,
+    pub winner_candidate_uuid: Option<Uuid>,
+}
+
+pub struct MatchBallotDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub candidate_uuids: Vec<&'a str>,
+    pub created_at: i64,
+}
+
+pub struct MatchResultsData {
+    pub match_uuid: Uuid,
+    pub decision_method: MatchDecisionMethod,
+    pub status: MatchStatus,
+    pub ballots_count: usize,
+    pub winner_candidate_uuid: Option<Uuid>,
+    pub rounds: Vec<TallyRound>,
+}
+
+#[derive(Debug)]
+pub enum MatchBallotDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    CandidateNotFound,
+    MatchClosed,
+    DecisionMethodMismatch,
+    BallotInvalid,
+    InternalError,
+}
+
+/// Ballots name each candidate at most once and at least one of them.
+pub fn validate_ballot(candidate_uuids: &[Uuid]) -> Result<(), MatchBallotDataError> {
+    let has_repeats = candidate_uuids
+        .iter()
+        .enumerate()
+        .any(|(index, uuid)| candidate_uuids[..index].contains(uuid));
+    if candidate_uuids.is_empty() || has_repeats {
+        Err(MatchBallotDataError::BallotInvalid)
+    } else {
+        Ok(())
+    }
+}
+
+impl MatchBallotData {
+    pub fn from_entity(
+        entity: MatchBallotEntity,
+        status: MatchStatus,
+        winner_candidate_uuid: Option<Uuid>,
+    ) -> Self {
+        MatchBallotData {
+            match_uuid: entity.match_uuid,
+            candidate_uuids: entity.candidate_uuids,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+            status,
+            winner_candidate_uuid,
+        }
+    }
+}
+
+impl From<MatchBallotDbError> for MatchBallotDataError {
+    fn from(error: MatchBallotDbError) -> Self {
+        match error {
+            MatchBallotDbError::MatchesNotFound => MatchBallotDataError::MatchesNotFound,
+            MatchBallotDbError::CandidateNotFound => MatchBallotDataError::CandidateNotFound,
+            MatchBallotDbError::MatchClosed => MatchBallotDataError::MatchClosed,
+            MatchBallotDbError::InternalError => MatchBallotDataError::InternalError,
+        }
+    }
+}
+
+impl From<MatchesDbError> for MatchBallotDataError {
+    fn from(error: MatchesDbError) -> Self {
+        let error: MatchesDataError = error.into();
+        error.into()
+    }
+}
+
+impl From<MatchesDataError> for MatchBallotDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchBallotDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchBallotDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchBallotDataError::NoPermission,
+            MatchesDataError::MatchClosed => MatchBallotDataError::MatchClosed,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantsNotFound
+            | MatchesDataError::InviteNotAllowed
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchBallotDataError::InternalError,
+        }
+    }
+}
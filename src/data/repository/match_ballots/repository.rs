@@ -0,0 +1,156 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::{
+            match_ballots::{objects::MatchBallotEntityCreate, MatchBallotsDatabase},
+            matches::MatchesDatabase,
+        },
+        repository::matches::{
+            objects::{MatchDecisionMethod, MatchStatus, MatchesData},
+            MatchesRepository,
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        validate_ballot, MatchBallotData, MatchBallotDataCreate, MatchBallotDataError,
+        MatchResultsData,
+    },
+    tally::{tally, TallyResult},
+    MatchBallotsRepository,
+};
+
+#[async_trait]
+impl MatchBallotsRepository for Conn {
+    async fn submit_ballot<'a>(
+        &self,
+        request: MatchBallotDataCreate<'a>,
+    ) -> Result<MatchBallotData, MatchBallotDataError> {
+        let match_data = self
+            .get_current_match(request.request_uuid, request.match_uuid)
+            .await?;
+        if !match_data.decision_method.uses_ballots() {
+            return Result::Err(MatchBallotDataError::DecisionMethodMismatch);
+        }
+        let candidate_uuids = request
+            .candidate_uuids
+            .iter()
+            .map(|uuid| Uuid::parse_str(uuid))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| MatchBallotDataError::UuidInvalid)?;
+        validate_ballot(&candidate_uuids)?;
+        let user_uuid =
+            Uuid::parse_str(request.request_uuid).map_err(|_| MatchBallotDataError::UuidInvalid)?;
+        if !self
+            .get_participant_role(&match_data, user_uuid)
+            .await?
+            .can_vote()
+        {
+            return Result::Err(MatchBallotDataError::NoPermission);
+        }
+        let ballot = MatchBallotEntityCreate {
+            match_uuid: match_data.uuid,
+            user_uuid,
+            candidate_uuids,
+            created_at: request.created_at,
+            updated_at: request.created_at,
+        };
+        let ballot = self
+            .add_ballot(ballot, MatchStatus::open_status_names())
+            .await?;
+
+        let (result, ballots_count) = self.tally_match(&match_data).await?;
+        if ballots_count < self.get_voters(&match_data).await?.len() {
+            return Ok(MatchBallotData::from_entity(
+                ballot,
+                match_data.status,
+                None,
+            ));
+        }
+        let completed = self
+            .complete_match(
+                match_data.uuid,
+                MatchStatus::open_status_names(),
+                MatchStatus::Completed.into(),
+                result.winner,
+                request.created_at,
+            )
+            .await?;
+        match completed {
+            Some(completed) => Ok(MatchBallotData::from_entity(
+                ballot,
+                MatchStatus::Completed,
+                completed.winner_candidate_uuid,
+            )),
+            None => Ok(MatchBallotData::from_entity(
+                ballot,
+                match_data.status,
+                None,
+            )),
+        }
+    }
+
+    async fn get_results<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchResultsData, MatchBallotDataError> {
+        let match_data = self.get_current_match(request_uuid, match_uuid).await?;
+        let (result, ballots_count) = self.tally_match(&match_data).await?;
+        // a completed match keeps the winner it was closed with
+        let winner_candidate_uuid = match match_data.status {
+            MatchStatus::Completed => match_data.winner_candidate_uuid,
+            _ => result.winner,
+        };
+        Ok(MatchResultsData {
+            match_uuid: match_data.uuid,
+            decision_method: match_data.decision_method,
+            status: match_data.status,
+            ballots_count,
+            winner_candidate_uuid,
+            rounds: result.rounds,
+        })
+    }
+
+    async fn tally_match(
+        &self,
+        match_data: &MatchesData,
+    ) -> Result<(TallyResult, usize), MatchBallotDataError> {
+        let candidates = self.get_candidate_order(match_data.uuid).await?;
+        // viewers don't vote, so neither their likes nor ballots count
+        let participants = &self.get_voters(match_data).await?;
+        let ballots: Vec<Vec<Uuid>> = match match_data.decision_method {
+            MatchDecisionMethod::Unanimous => {
+                let likes = self.get_likes(match_data.uuid).await?;
+                participants
+                    .iter()
+                    .map(|participant| {
+                        likes
+                            .iter()
+                            .filter(|(user_uuid, _)| user_uuid == participant)
+                            .map(|(_, candidate_uuid)| *candidate_uuid)
+                            .collect::<Vec<_>>()
+                    })
+                    .filter(|liked| !liked.is_empty())
+                    .collect()
+            }
+            _ => self
+                .get_ballots(match_data.uuid)
+                .await?
+                .into_iter()
+                .filter(|ballot| participants.contains(&ballot.user_uuid))
+                .map(|ballot| ballot.candidate_uuids)
+                .collect(),
+        };
+        let result = tally(
+            match_data.decision_method,
+            &candidates,
+            &ballots,
+            participants.len(),
+        );
+        Ok((result, ballots.len()))
+    }
+}
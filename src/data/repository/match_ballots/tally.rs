@@ -0,0 +1,193 @@
+//! Deterministic tallying of match ballots.
+//!
+//! `candidates` are always given in tie-break order: the order they were added to the match,
+//! oldest first. Whenever scores tie the candidate earlier in that order is preferred, so it
+//! takes a tied top score and survives a tied elimination. Ballot entries that are not in
+//! `candidates`, and repeats within one ballot, are ignored.
+
+use uuid::Uuid;
+
+use crate::data::repository::matches::objects::MatchDecisionMethod;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateScore {
+    pub candidate_uuid: Uuid,
+    pub score: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TallyRound {
+    pub scores: Vec<CandidateScore>,
+    pub eliminated: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TallyResult {
+    pub winner: Option<Uuid>,
+    pub rounds: Vec<TallyRound>,
+}
+
+/// Tallies `ballots` with `method`. For unanimous matches every ballot is the set of
+/// candidates one participant liked and `voters` is the number of participants.
+pub fn tally(
+    method: MatchDecisionMethod,
+    candidates: &[Uuid],
+    ballots: &[Vec<Uuid>],
+    voters: usize,
+) -> TallyResult {
+    let ballots: Vec<Vec<Uuid>> = ballots
+        .iter()
+        .map(|ballot| clean_ballot(candidates, ballot))
+        .collect();
+    match method {
+        MatchDecisionMethod::Unanimous => unanimous(candidates, &ballots, voters),
+        MatchDecisionMethod::Approval => approval(candidates, &ballots),
+        MatchDecisionMethod::Ranked => instant_runoff(candidates, &ballots),
+        MatchDecisionMethod::Borda => borda(candidates, &ballots),
+    }
+}
+
+/// A candidate wins only when every voter liked it; the earliest such candidate wins.
+fn unanimous(candidates: &[Uuid], ballots: &[Vec<Uuid>], voters: usize) -> TallyResult {
+    let scores = approval_scores(candidates, ballots);
+    let required = i64::try_from(voters).unwrap_or(i64::MAX);
+    let winner = scores
+        .iter()
+        .find(|score| voters > 0 && score.score >= required)
+        .map(|score| score.candidate_uuid);
+    single_round(winner, scores)
+}
+
+/// Most approvals wins, nobody wins without a single approval.
+fn approval(candidates: &[Uuid], ballots: &[Vec<Uuid>]) -> TallyResult {
+    let scores = approval_scores(candidates, ballots);
+    let winner = leader(&scores)
+        .filter(|score| score.score > 0)
+        .map(|score| score.candidate_uuid);
+    single_round(winner, scores)
+}
+
+/// With `n` candidates the first choice of a ballot gets `n - 1` points, the second `n - 2`
+/// and so on; unranked candidates get nothing. Nobody wins without a non-empty ballot.
+fn borda(candidates: &[Uuid], ballots: &[Vec<Uuid>]) -> TallyResult {
+    let count = i64::try_from(candidates.len()).unwrap_or(i64::MAX);
+    let scores = candidates
+        .iter()
+        .map(|candidate| CandidateScore {
+            candidate_uuid: *candidate,
+            score: ballots
+                .iter()
+                .filter_map(|ballot| ballot.iter().position(|uuid| uuid == candidate))
+                .map(|position| count - 1 - i64::try_from(position).unwrap_or(count - 1))
+                .sum(),
+        })
+        .collect::<Vec<_>>();
+    let winner = match ballots.iter().any(|ballot| !ballot.is_empty()) {
+        true => leader(&scores).map(|score| score.candidate_uuid),
+        false => None,
+    };
+    single_round(winner, scores)
+}
+
+/// Instant-runoff: each round counts every ballot for its highest ranked remaining candidate.
+/// A candidate with more than half of those votes, or the last one standing, wins; otherwise
+/// the candidate with the fewest votes is eliminated. Ballots with no remaining candidate are
+/// exhausted; when every ballot is exhausted nobody wins.
+fn instant_runoff(candidates: &[Uuid], ballots: &[Vec<Uuid>]) -> TallyResult {
+    let mut remaining = candidates.to_vec();
+    let mut rounds = Vec::new();
+    loop {
+        let scores: Vec<CandidateScore> = remaining
+            .iter()
+            .map(|candidate| CandidateScore {
+                candidate_uuid: *candidate,
+                score: 0,
+            })
+            .collect();
+        let scores = ballots.iter().fold(scores, |mut scores, ballot| {
+            let first = ballot.iter().find(|uuid| remaining.contains(uuid));
+            if let Some(score) = first.and_then(|first| {
+                scores
+                    .iter_mut()
+                    .find(|score| score.candidate_uuid == *first)
+            }) {
+                score.score += 1;
+            }
+            scores
+        });
+        let active: i64 = scores.iter().map(|score| score.score).sum();
+        let top = match leader(&scores) {
+            Some(top) if active > 0 => top.to_owned(),
+            _ => {
+                rounds.push(TallyRound {
+                    scores,
+                    eliminated: None,
+                });
+                return TallyResult {
+                    winner: None,
+                    rounds,
+                };
+            }
+        };
+        if top.score * 2 > active || remaining.len() == 1 {
+            rounds.push(TallyRound {
+                scores,
+                eliminated: None,
+            });
+            return TallyResult {
+                winner: Some(top.candidate_uuid),
+                rounds,
+            };
+        }
+        // iterating from the back makes the latest candidate lose a tied elimination
+        let eliminated = scores
+            .iter()
+            .rev()
+            .min_by_key(|score| score.score)
+            .map(|score| score.candidate_uuid);
+        remaining.retain(|candidate| Some(*candidate) != eliminated);
+        rounds.push(TallyRound { scores, eliminated });
+    }
+}
+
+fn approval_scores(candidates: &[Uuid], ballots: &[Vec<Uuid>]) -> Vec<CandidateScore> {
+    candidates
+        .iter()
+        .map(|candidate| CandidateScore {
+            candidate_uuid: *candidate,
+            score: ballots
+                .iter()
+                .filter(|ballot| ballot.contains(candidate))
+                .count()
+                .try_into()
+                .unwrap_or(i64::MAX),
+        })
+        .collect()
+}
+
+/// Highest score, the earliest candidate wins a tie.
+fn leader(scores: &[CandidateScore]) -> Option<&CandidateScore> {
+    scores.iter().fold(None, |best, score| match best {
+        Some(best) if best.score >= score.score => Some(best),
+        _ => Some(score),
+    })
+}
+
+fn single_round(winner: Option<Uuid>, scores: Vec<CandidateScore>) -> TallyResult {
+    TallyResult {
+        winner,
+        rounds: vec![TallyRound {
+            scores,
+            eliminated: None,
+        }],
+    }
+}
+
+fn clean_ballot(candidates: &[Uuid], ballot: &[Uuid]) -> Vec<Uuid> {
+    ballot.iter().fold(Vec::new(), |mut cleaned, uuid| {
+        if candidates.contains(uuid) && !cleaned.contains(uuid) {
+            cleaned.push(*uuid);
+        }
+        cleaned
+    })
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::repository::{
+        match_ballots::{
+            objects::{validate_ballot, MatchBallotDataError},
+            tally::{tally, CandidateScore},
+        },
+        matches::objects::MatchDecisionMethod,
+    };
+
+    fn candidates(count: usize) -> Vec<Uuid> {
+        (0..count).map(|_| Uuid::new_v4()).collect()
+    }
+
+    fn scores(result_scores: &[CandidateScore]) -> Vec<i64> {
+        result_scores.iter().map(|score| score.score).collect()
+    }
+
+    #[test]
+    fn test_unanimous_requires_every_voter() {
+        let c = candidates(3);
+        let result = tally(
+            MatchDecisionMethod::Unanimous,
+            &c,
+            &[vec![c[1], c[2]], vec![c[2], c[1]]],
+            2,
+        );
+        assert_eq!(result.winner, Some(c[1]));
+
+        let result = tally(
+            MatchDecisionMethod::Unanimous,
+            &c,
+            &[vec![c[1], c[2]], vec![c[2], c[1]]],
+            3,
+        );
+        assert_eq!(result.winner, None);
+        assert_eq!(scores(&result.rounds[0].scores), vec![0, 2, 2]);
+    }
+
+    #[test]
+    fn test_approval_most_approvals_with_earliest_tie_break() {
+        let c = candidates(3);
+        let result = tally(
+            MatchDecisionMethod::Approval,
+            &c,
+            &[vec![c[2]], vec![c[1], c[2]], vec![c[1]]],
+            3,
+        );
+        assert_eq!(result.winner, Some(c[1]));
+        assert_eq!(scores(&result.rounds[0].scores), vec![0, 2, 2]);
+
+        let result = tally(MatchDecisionMethod::Approval, &c, &[vec![], vec![]], 2);
+        assert_eq!(result.winner, None);
+    }
+
+    #[test]
+    fn test_borda_points_by_position() {
+        let c = candidates(3);
+        let result = tally(
+            MatchDecisionMethod::Borda,
+            &c,
+            &[vec![c[0], c[1], c[2]], vec![c[2], c[1]], vec![c[1]]],
+            3,
+        );
+        assert_eq!(scores(&result.rounds[0].scores), vec![2, 4, 2]);
+        assert_eq!(result.winner, Some(c[1]));
+
+        let result = tally(MatchDecisionMethod::Borda, &c, &[], 2);
+        assert_eq!(result.winner, None);
+    }
+
+    #[test]
+    fn test_ranked_eliminates_until_majority() {
+        let c = candidates(3);
+        let ballots = vec![
+            vec![c[0], c[1]],
+            vec![c[0]],
+            vec![c[1], c[0]],
+            vec![c[2], c[1]],
+            vec![c[2], c[1]],
+        ];
+        let result = tally(MatchDecisionMethod::Ranked, &c, &ballots, 5);
+        assert_eq!(result.rounds.len(), 2);
+        assert_eq!(scores(&result.rounds[0].scores), vec![2, 1, 2]);
+        assert_eq!(result.rounds[0].eliminated, Some(c[1]));
+        assert_eq!(scores(&result.rounds[1].scores), vec![3, 2]);
+        assert_eq!(result.winner, Some(c[0]));
+    }
+
+    #[test]
+    fn test_ranked_tied_elimination_drops_latest_candidate() {
+        let c = candidates(2);
+        let result = tally(
+            MatchDecisionMethod::Ranked,
+            &c,
+            &[vec![c[0]], vec![c[1]]],
+            2,
+        );
+        assert_eq!(result.rounds[0].eliminated, Some(c[1]));
+        assert_eq!(result.winner, Some(c[0]));
+    }
+
+    #[test]
+    fn test_ranked_exhausted_ballots_have_no_winner() {
+        let c = candidates(2);
+        let result = tally(
+            MatchDecisionMethod::Ranked,
+            &c,
+            &[vec![Uuid::new_v4()], vec![]],
+            2,
+        );
+        assert_eq!(result.winner, None);
+        assert_eq!(result.rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_tally_ignores_unknown_and_repeated_entries() {
+        let c = candidates(2);
+        let result = tally(
+            MatchDecisionMethod::Borda,
+            &c,
+            &[vec![Uuid::new_v4(), c[1], c[1], c[0]]],
+            1,
+        );
+        assert_eq!(scores(&result.rounds[0].scores), vec![0, 1]);
+        assert_eq!(result.winner, Some(c[1]));
+    }
+
+    #[test]
+    fn test_validate_ballot() {
+        let c = candidates(2);
+        assert!(validate_ballot(&c).is_ok());
+        assert!(matches!(
+            validate_ballot(&[]),
+            Err(MatchBallotDataError::BallotInvalid)
+        ));
+        assert!(matches!(
+            validate_ballot(&[c[0], c[1], c[0]]),
+            Err(MatchBallotDataError::BallotInvalid)
+        ));
+    }
+}
@@ -0,0 +1,25 @@
+use self::objects::{MatchCandidateData, MatchCandidateDataCreate, MatchCandidateDataError};
+
+use super::objects::{PagingDomainRequest, PagingDomainResponse};
+
+pub mod objects;
+mod repository;
+
+#[async_trait]
+pub trait MatchCandidatesRepository {
+    async fn add_candidate<'a>(
+        &self,
+        request: MatchCandidateDataCreate<'a>,
+    ) -> Result<MatchCandidateData, MatchCandidateDataError>;
+    async fn get_candidates<'a>(
+        &self,
+        match_uuid: &'a str,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchCandidateData>, MatchCandidateDataError>;
+    async fn remove_candidate<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), MatchCandidateDataError>;
+}
@@ -0,0 +1,106 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::match_candidates::objects::{MatchCandidateDbError, MatchCandidateEntity},
+    repository::{matches::objects::MatchesDataError, objects::PagingDomainResponse},
+};
+
+/// Maximum amount of candidates a single match can hold.
+pub const MATCH_CANDIDATES_LIMIT: i64 = 100;
+
+pub struct MatchCandidateData {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub reference_uuid: Uuid,
+    pub title: String,
+    pub image_url: String,
+    pub metadata: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: i64,
+}
+
+pub struct MatchCandidateDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub reference_uuid: &'a str,
+    pub title: &'a str,
+    pub image_url: &'a str,
+    pub metadata: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchCandidateDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    CandidateNotFound,
+    Conflict,
+    LimitExceeded,
+    InternalError,
+}
+
+impl From<MatchCandidateEntity> for MatchCandidateData {
+    fn from(entity: MatchCandidateEntity) -> Self {
+        MatchCandidateData {
+            uuid: entity.uuid,
+            match_uuid: entity.match_uuid,
+            reference_uuid: entity.reference_uuid,
+            title: entity.title,
+            image_url: entity.image_url,
+            metadata: entity.metadata,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<PagingDomainResponse<MatchCandidateEntity>> for PagingDomainResponse<MatchCandidateData> {
+    fn from(response: PagingDomainResponse<MatchCandidateEntity>) -> Self {
+        PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<MatchCandidateDbError> for MatchCandidateDataError {
+    fn from(error: MatchCandidateDbError) -> Self {
+        match error {
+            MatchCandidateDbError::UuidInvalid => MatchCandidateDataError::UuidInvalid,
+            MatchCandidateDbError::MatchesNotFound => MatchCandidateDataError::MatchesNotFound,
+            MatchCandidateDbError::CandidateNotFound => MatchCandidateDataError::CandidateNotFound,
+            MatchCandidateDbError::Conflict => MatchCandidateDataError::Conflict,
+            MatchCandidateDbError::LimitExceeded => MatchCandidateDataError::LimitExceeded,
+            MatchCandidateDbError::InternalError => MatchCandidateDataError::InternalError,
+        }
+    }
+}
+
+impl From<MatchesDataError> for MatchCandidateDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchCandidateDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchCandidateDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchCandidateDataError::NoPermission,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantsNotFound
+            | MatchesDataError::InviteNotAllowed
+            | MatchesDataError::MatchClosed
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchCandidateDataError::InternalError,
+        }
+    }
+}
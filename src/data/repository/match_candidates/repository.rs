@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::match_candidates::{
+            objects::MatchCandidateEntityCreate, MatchCandidatesDatabase,
+        },
+        repository::{
+            matches::MatchesRepository,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        MatchCandidateData, MatchCandidateDataCreate, MatchCandidateDataError,
+        MATCH_CANDIDATES_LIMIT,
+    },
+    MatchCandidatesRepository,
+};
+
+#[async_trait]
+impl MatchCandidatesRepository for Conn {
+    async fn add_candidate<'a>(
+        &self,
+        request: MatchCandidateDataCreate<'a>,
+    ) -> Result<MatchCandidateData, MatchCandidateDataError> {
+        let match_data = self
+            .get_current_match(request.request_uuid, request.match_uuid)
+            .await?;
+        let reference_uuid = Uuid::parse_str(request.reference_uuid)
+            .map_err(|_| MatchCandidateDataError::UuidInvalid)?;
+        let created_by = Uuid::parse_str(request.request_uuid)
+            .map_err(|_| MatchCandidateDataError::UuidInvalid)?;
+        // viewers only watch the match
+        if !self
+            .get_participant_role(&match_data, created_by)
+            .await?
+            .can_vote()
+        {
+            return Result::Err(MatchCandidateDataError::NoPermission);
+        }
+        let candidate = MatchCandidateEntityCreate {
+            match_uuid: match_data.uuid,
+            reference_uuid,
+            title: request.title.to_owned(),
+            image_url: request.image_url.to_owned(),
+            metadata: request.metadata,
+            created_by,
+            created_at: request.created_at,
+        };
+        MatchCandidatesDatabase::add_candidate(self, candidate, MATCH_CANDIDATES_LIMIT)
+            .await
+            .map(|value| value.into())
+            .map_err(|err| err.into())
+    }
+
+    async fn get_candidates<'a>(
+        &self,
+        match_uuid: &'a str,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchCandidateData>, MatchCandidateDataError> {
+        self.get_current_match(request.request_uuid, match_uuid)
+            .await?;
+        MatchCandidatesDatabase::get_candidates(self, match_uuid, request)
+            .await
+            .map(|value| value.into())
+            .map_err(|err| err.into())
+    }
+
+    async fn remove_candidate<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), MatchCandidateDataError> {
+        self.get_moderated_match(request_uuid, match_uuid).await?;
+        MatchCandidatesDatabase::remove_candidate(self, match_uuid, candidate_uuid)
+            .await
+            .map_err(|err| err.into())
+    }
+}
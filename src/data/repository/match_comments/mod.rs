@@ -0,0 +1,37 @@
+use self::objects::{
+    MatchCommentData, MatchCommentDataCreate, MatchCommentDataError, MatchCommentsDataRequest,
+    MatchCommentsPageData,
+};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait MatchCommentsRepository {
+    async fn add_comment<'a>(
+        &self,
+        request: MatchCommentDataCreate<'a>,
+    ) -> Result<MatchCommentData, MatchCommentDataError>;
+    async fn get_comments<'a>(
+        &self,
+        request: MatchCommentsDataRequest<'a>,
+    ) -> Result<MatchCommentsPageData, MatchCommentDataError>;
+    /// Only the author can edit a comment.
+    async fn update_comment<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        body: &'a str,
+        updated_at: i64,
+    ) -> Result<MatchCommentData, MatchCommentDataError>;
+    /// The author or the match creator can delete a comment.
+    async fn delete_comment<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        deleted_at: i64,
+    ) -> Result<MatchCommentData, MatchCommentDataError>;
+}
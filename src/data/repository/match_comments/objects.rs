@@ -0,0 +1,158 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::match_comments::objects::{MatchCommentDbError, MatchCommentEntity},
+    repository::matches::objects::MatchesDataError,
+};
+
+/// Maximum length of a comment body in characters.
+pub const MATCH_COMMENT_MAX_LENGTH: usize = 2000;
+pub const MATCH_COMMENTS_DEFAULT_LIMIT: i64 = 20;
+pub const MATCH_COMMENTS_MAX_LIMIT: i64 = 100;
+
+pub struct MatchCommentData {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Option<Uuid>,
+    pub parent_uuid: Option<Uuid>,
+    pub author_uuid: Uuid,
+    pub body: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub is_deleted: bool,
+}
+
+pub struct MatchCommentDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub candidate_uuid: Option<&'a str>,
+    pub parent_uuid: Option<&'a str>,
+    pub body: &'a str,
+    pub created_at: i64,
+}
+
+pub struct MatchCommentsDataRequest<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub candidate_uuid: Option<&'a str>,
+    pub cursor: Option<&'a str>,
+    pub limit: i64,
+}
+
+/// A page of comments, `next_cursor` is set while older pages remain.
+pub struct MatchCommentsPageData {
+    pub result: Vec<MatchCommentData>,
+    pub next_cursor: Option<String>,
+}
+
+/// Position of the last comment of a page, encoded as `<created_at>_<uuid>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchCommentCursor {
+    pub created_at: i64,
+    pub uuid: Uuid,
+}
+
+#[derive(Debug)]
+pub enum MatchCommentDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    CandidateNotFound,
+    CommentNotFound,
+    CommentInvalid,
+    CursorInvalid,
+    InternalError,
+}
+
+/// Trims the body, which must not be blank or longer than `MATCH_COMMENT_MAX_LENGTH`.
+pub fn validate_comment_body(body: &str) -> Result<String, MatchCommentDataError> {
+    let body = body.trim();
+    if body.is_empty() || body.chars().count() > MATCH_COMMENT_MAX_LENGTH {
+        Err(MatchCommentDataError::CommentInvalid)
+    } else {
+        Ok(body.to_owned())
+    }
+}
+
+impl TryFrom<&str> for MatchCommentCursor {
+    type Error = MatchCommentDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (created_at, uuid) = value
+            .split_once('_')
+            .ok_or(MatchCommentDataError::CursorInvalid)?;
+        Ok(MatchCommentCursor {
+            created_at: created_at
+                .parse()
+                .map_err(|_| MatchCommentDataError::CursorInvalid)?,
+            uuid: Uuid::parse_str(uuid).map_err(|_| MatchCommentDataError::CursorInvalid)?,
+        })
+    }
+}
+
+impl From<MatchCommentCursor> for String {
+    fn from(cursor: MatchCommentCursor) -> Self {
+        format!("{}_{}", cursor.created_at, cursor.uuid)
+    }
+}
+
+impl From<&MatchCommentData> for MatchCommentCursor {
+    fn from(comment: &MatchCommentData) -> Self {
+        MatchCommentCursor {
+            created_at: comment.created_at,
+            uuid: comment.uuid,
+        }
+    }
+}
+
+impl From<MatchCommentEntity> for MatchCommentData {
+    fn from(entity: MatchCommentEntity) -> Self {
+        MatchCommentData {
+            uuid: entity.uuid,
+            match_uuid: entity.match_uuid,
+            candidate_uuid: entity.candidate_uuid,
+            parent_uuid: entity.parent_uuid,
+            author_uuid: entity.author_uuid,
+            body: entity.body,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+            is_deleted: entity.deleted_at.is_some(),
+        }
+    }
+}
+
+impl From<MatchCommentDbError> for MatchCommentDataError {
+    fn from(error: MatchCommentDbError) -> Self {
+        match error {
+            MatchCommentDbError::CandidateNotFound => MatchCommentDataError::CandidateNotFound,
+            MatchCommentDbError::CommentNotFound => MatchCommentDataError::CommentNotFound,
+            MatchCommentDbError::ParentMismatch => MatchCommentDataError::CommentInvalid,
+            MatchCommentDbError::InternalError => MatchCommentDataError::InternalError,
+        }
+    }
+}
+
+impl From<MatchesDataError> for MatchCommentDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchCommentDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchCommentDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchCommentDataError::NoPermission,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantsNotFound
+            | MatchesDataError::InviteNotAllowed
+            | MatchesDataError::MatchClosed
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchCommentDataError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::match_comments::{objects::MatchCommentEntityCreate, MatchCommentsDatabase},
+        repository::matches::MatchesRepository,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        validate_comment_body, MatchCommentCursor, MatchCommentData, MatchCommentDataCreate,
+        MatchCommentDataError, MatchCommentsDataRequest, MatchCommentsPageData,
+    },
+    MatchCommentsRepository,
+};
+
+#[async_trait]
+impl MatchCommentsRepository for Conn {
+    async fn add_comment<'a>(
+        &self,
+        request: MatchCommentDataCreate<'a>,
+    ) -> Result<MatchCommentData, MatchCommentDataError> {
+        let match_data = self
+            .get_current_match(request.request_uuid, request.match_uuid)
+            .await?;
+        let body = validate_comment_body(request.body)?;
+        let comment = MatchCommentEntityCreate {
+            match_uuid: match_data.uuid,
+            candidate_uuid: parse_optional_uuid(request.candidate_uuid)?,
+            parent_uuid: parse_optional_uuid(request.parent_uuid)?,
+            author_uuid: parse_uuid(request.request_uuid)?,
+            body,
+            created_at: request.created_at,
+            updated_at: request.created_at,
+        };
+        MatchCommentsDatabase::add_comment(self, comment)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_comments<'a>(
+        &self,
+        request: MatchCommentsDataRequest<'a>,
+    ) -> Result<MatchCommentsPageData, MatchCommentDataError> {
+        let match_data = self
+            .get_current_match(request.request_uuid, request.match_uuid)
+            .await?;
+        let candidate_uuid = parse_optional_uuid(request.candidate_uuid)?;
+        let after = request
+            .cursor
+            .map(MatchCommentCursor::try_from)
+            .transpose()?
+            .map(|cursor| (cursor.created_at, cursor.uuid));
+        // one extra row tells whether another page follows
+        let mut result: Vec<MatchCommentData> = MatchCommentsDatabase::get_comments(
+            self,
+            match_data.uuid,
+            candidate_uuid,
+            after,
+            request.limit + 1,
+        )
+        .await?
+        .into_iter()
+        .map(|v| v.into())
+        .collect();
+        let has_more = i64::try_from(result.len()).unwrap_or(i64::MAX) > request.limit;
+        result.truncate(usize::try_from(request.limit).unwrap_or(0));
+        let next_cursor = match has_more {
+            true => result
+                .last()
+                .map(|comment| MatchCommentCursor::from(comment).into()),
+            false => None,
+        };
+        Ok(MatchCommentsPageData {
+            result,
+            next_cursor,
+        })
+    }
+
+    async fn update_comment<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        body: &'a str,
+        updated_at: i64,
+    ) -> Result<MatchCommentData, MatchCommentDataError> {
+        let match_data = self.get_current_match(request_uuid, match_uuid).await?;
+        let comment = self
+            .get_comment(match_data.uuid, parse_uuid(comment_uuid)?)
+            .await?;
+        if comment.author_uuid != parse_uuid(request_uuid)? {
+            return Result::Err(MatchCommentDataError::NoPermission);
+        }
+        let body = validate_comment_body(body)?;
+        MatchCommentsDatabase::update_comment(self, comment.uuid, body, updated_at)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn delete_comment<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        deleted_at: i64,
+    ) -> Result<MatchCommentData, MatchCommentDataError> {
+        let match_data = self.get_current_match(request_uuid, match_uuid).await?;
+        let comment = self
+            .get_comment(match_data.uuid, parse_uuid(comment_uuid)?)
+            .await?;
+        let user_uuid = parse_uuid(request_uuid)?;
+        if comment.author_uuid != user_uuid
+            && !self
+                .get_participant_role(&match_data, user_uuid)
+                .await?
+                .can_moderate()
+        {
+            return Result::Err(MatchCommentDataError::NoPermission);
+        }
+        MatchCommentsDatabase::delete_comment(self, comment.uuid, deleted_at)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, MatchCommentDataError> {
+    Uuid::parse_str(value).map_err(|_| MatchCommentDataError::UuidInvalid)
+}
+
+fn parse_optional_uuid(value: Option<&str>) -> Result<Option<Uuid>, MatchCommentDataError> {
+    value.map(parse_uuid).transpose()
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::data::repository::match_comments::objects::{
+        validate_comment_body, MatchCommentCursor, MatchCommentDataError, MATCH_COMMENT_MAX_LENGTH,
+    };
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = MatchCommentCursor {
+            created_at: 1_700_000_000_000,
+            uuid: Uuid::new_v4(),
+        };
+        let value: String = cursor.into();
+        assert_eq!(
+            MatchCommentCursor::try_from(value.as_str()).unwrap(),
+            cursor
+        );
+    }
+
+    #[test]
+    fn test_cursor_invalid() {
+        let uuid = Uuid::new_v4();
+        for value in [
+            "".to_string(),
+            "123".to_string(),
+            format!("abc_{}", uuid),
+            "123_uuid".to_string(),
+        ] {
+            assert!(matches!(
+                MatchCommentCursor::try_from(value.as_str()),
+                Err(MatchCommentDataError::CursorInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_comment_body() {
+        assert_eq!(validate_comment_body("  text \n").unwrap(), "text");
+        assert!(validate_comment_body(&"a".repeat(MATCH_COMMENT_MAX_LENGTH)).is_ok());
+        for body in [
+            "".to_string(),
+            " \n ".to_string(),
+            "a".repeat(MATCH_COMMENT_MAX_LENGTH + 1),
+        ] {
+            assert!(matches!(
+                validate_comment_body(&body),
+                Err(MatchCommentDataError::CommentInvalid)
+            ));
+        }
+    }
+}
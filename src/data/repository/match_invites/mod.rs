@@ -0,0 +1,37 @@
+use self::objects::{MatchInviteData, MatchInviteDataError};
+
+use super::objects::{PagingDomainRequest, PagingDomainResponse};
+
+pub mod objects;
+mod repository;
+
+#[async_trait]
+pub trait MatchInvitesRepository {
+    /// Creator-only, invites more users into an open match.
+    async fn invite_participants<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        participants_uuid: Vec<&'a str>,
+    ) -> Result<Vec<MatchInviteData>, MatchInviteDataError>;
+    /// Pending invites addressed to the requesting user.
+    async fn get_user_invites<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchInviteData>, MatchInviteDataError>;
+    async fn get_match_invites<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchInviteData>, MatchInviteDataError>;
+    async fn accept_invite<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchInviteData, MatchInviteDataError>;
+    async fn decline_invite<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchInviteData, MatchInviteDataError>;
+}
@@ -0,0 +1,142 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::{
+        match_invites::objects::{MatchInviteDbError, MatchInviteEntity},
+        matches::objects::MatchesEntity,
+    },
+    repository::{matches::objects::MatchesDataError, objects::PagingDomainResponse},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchInviteStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl From<MatchInviteStatus> for String {
+    fn from(status: MatchInviteStatus) -> Self {
+        match status {
+            MatchInviteStatus::Pending => "pending".to_string(),
+            MatchInviteStatus::Accepted => "accepted".to_string(),
+            MatchInviteStatus::Declined => "declined".to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for MatchInviteStatus {
+    type Error = MatchInviteDataError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "pending" => Ok(MatchInviteStatus::Pending),
+            "accepted" => Ok(MatchInviteStatus::Accepted),
+            "declined" => Ok(MatchInviteStatus::Declined),
+            _ => {
+                eprintln!("Unknown match invite status: {}", value);
+                Err(MatchInviteDataError::InternalError)
+            }
+        }
+    }
+}
+
+pub struct MatchInviteData {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub match_title: String,
+    pub user_uuid: Uuid,
+    pub invited_by: Uuid,
+    pub status: MatchInviteStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchInviteDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    InviteNotFound,
+    MatchClosed,
+    ParticipantsNotFound,
+    InviteNotAllowed,
+    InternalError,
+}
+
+impl TryFrom<(MatchInviteEntity, MatchesEntity)> for MatchInviteData {
+    type Error = MatchInviteDataError;
+
+    fn try_from(value: (MatchInviteEntity, MatchesEntity)) -> Result<Self, Self::Error> {
+        let (invite, match_entity) = value;
+        Ok(MatchInviteData {
+            uuid: invite.uuid,
+            match_uuid: invite.match_uuid,
+            match_title: match_entity.title,
+            user_uuid: invite.user_uuid,
+            invited_by: invite.invited_by,
+            status: invite.status.try_into()?,
+            created_at: invite.created_at,
+            updated_at: invite.updated_at,
+        })
+    }
+}
+
+impl TryFrom<PagingDomainResponse<(MatchInviteEntity, MatchesEntity)>>
+    for PagingDomainResponse<MatchInviteData>
+{
+    type Error = MatchInviteDataError;
+
+    fn try_from(
+        response: PagingDomainResponse<(MatchInviteEntity, MatchesEntity)>,
+    ) -> Result<Self, Self::Error> {
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result: response
+                .result
+                .into_iter()
+                .map(MatchInviteData::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<MatchInviteDbError> for MatchInviteDataError {
+    fn from(error: MatchInviteDbError) -> Self {
+        match error {
+            MatchInviteDbError::UuidInvalid => MatchInviteDataError::UuidInvalid,
+            MatchInviteDbError::MatchesNotFound => MatchInviteDataError::MatchesNotFound,
+            MatchInviteDbError::InviteNotFound => MatchInviteDataError::InviteNotFound,
+            MatchInviteDbError::MatchClosed => MatchInviteDataError::MatchClosed,
+            MatchInviteDbError::InternalError => MatchInviteDataError::InternalError,
+        }
+    }
+}
+
+impl From<MatchesDataError> for MatchInviteDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchInviteDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchInviteDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchInviteDataError::NoPermission,
+            MatchesDataError::MatchClosed => MatchInviteDataError::MatchClosed,
+            MatchesDataError::ParticipantsNotFound => MatchInviteDataError::ParticipantsNotFound,
+            MatchesDataError::InviteNotAllowed => MatchInviteDataError::InviteNotAllowed,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchInviteDataError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::match_invites::{objects::MatchInviteEntityCreate, MatchInvitesDatabase},
+        repository::{
+            matches::{objects::MatchStatus, MatchesRepository},
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
+    },
+    utils::Mapper,
+    Conn,
+};
+
+use super::{
+    objects::{MatchInviteData, MatchInviteDataError, MatchInviteStatus},
+    MatchInvitesRepository,
+};
+
+#[async_trait]
+impl MatchInvitesRepository for Conn {
+    async fn invite_participants<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        participants_uuid: Vec<&'a str>,
+    ) -> Result<Vec<MatchInviteData>, MatchInviteDataError> {
+        let match_data = self.get_moderated_match(request_uuid, match_uuid).await?;
+        let invited_by: Uuid = request_uuid.map().await?;
+        let participants_uuid: Vec<Uuid> = participants_uuid.map().await?;
+        let invitees = self
+            .validate_invitees(match_data.creator_uuid, participants_uuid)
+            .await?;
+        let created_at = chrono::Utc::now().timestamp_millis();
+        let invites = invitees
+            .into_iter()
+            .map(|user_uuid| MatchInviteEntityCreate {
+                match_uuid: match_data.uuid,
+                user_uuid,
+                invited_by,
+                status: MatchInviteStatus::Pending.into(),
+                created_at,
+                updated_at: created_at,
+            })
+            .collect();
+        let open_statuses = MatchStatus::open_status_names();
+        MatchInvitesDatabase::add_invites(self, match_data.uuid, invites, open_statuses)
+            .await?
+            .into_iter()
+            .map(MatchInviteData::try_from)
+            .collect()
+    }
+
+    async fn get_user_invites<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<MatchInviteData>, MatchInviteDataError> {
+        MatchInvitesDatabase::get_user_invites(self, MatchInviteStatus::Pending.into(), request)
+            .await?
+            .try_into()
+    }
+
+    async fn get_match_invites<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchInviteData>, MatchInviteDataError> {
+        let match_data = self.get_current_match(request_uuid, match_uuid).await?;
+        MatchInvitesDatabase::get_match_invites(self, match_data.uuid)
+            .await?
+            .into_iter()
+            .map(MatchInviteData::try_from)
+            .collect()
+    }
+
+    async fn accept_invite<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchInviteData, MatchInviteDataError> {
+        let open_statuses = MatchStatus::open_status_names();
+        respond_invite(
+            self,
+            request_uuid,
+            match_uuid,
+            MatchInviteStatus::Accepted,
+            Some(open_statuses),
+        )
+        .await
+    }
+
+    async fn decline_invite<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchInviteData, MatchInviteDataError> {
+        respond_invite(
+            self,
+            request_uuid,
+            match_uuid,
+            MatchInviteStatus::Declined,
+            None,
+        )
+        .await
+    }
+}
+
+async fn respond_invite(
+    conn: &Conn,
+    request_uuid: &str,
+    match_uuid: &str,
+    status: MatchInviteStatus,
+    join_open_statuses: Option<Vec<String>>,
+) -> Result<MatchInviteData, MatchInviteDataError> {
+    let user_uuid = Uuid::parse_str(request_uuid).map_err(|_| MatchInviteDataError::UuidInvalid)?;
+    let match_uuid = Uuid::parse_str(match_uuid).map_err(|_| MatchInviteDataError::UuidInvalid)?;
+    let updated_at = chrono::Utc::now().timestamp_millis();
+    conn.respond_invite(
+        match_uuid,
+        user_uuid,
+        MatchInviteStatus::Pending.into(),
+        status.into(),
+        join_open_statuses,
+        updated_at,
+    )
+    .await?
+    .try_into()
+}
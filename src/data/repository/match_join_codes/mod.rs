@@ -0,0 +1,35 @@
+use self::objects::{MatchJoinCodeData, MatchJoinCodeDataCreate, MatchJoinCodeDataError};
+
+use super::matches::objects::MatchesData;
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait MatchJoinCodesRepository {
+    /// Creator-only, generates a new code for an open match.
+    async fn create_join_code<'a>(
+        &self,
+        request: MatchJoinCodeDataCreate<'a>,
+    ) -> Result<MatchJoinCodeData, MatchJoinCodeDataError>;
+    /// Creator-only, codes that can still be redeemed.
+    async fn get_join_codes<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchJoinCodeData>, MatchJoinCodeDataError>;
+    /// Creator-only, the code stops working immediately.
+    async fn revoke_join_code<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        code_uuid: &'a str,
+    ) -> Result<MatchJoinCodeData, MatchJoinCodeDataError>;
+    /// Joins the requesting user to the match of the code.
+    async fn redeem_join_code<'a>(
+        &self,
+        request_uuid: &'a str,
+        code: &'a str,
+    ) -> Result<MatchesData, MatchJoinCodeDataError>;
+}
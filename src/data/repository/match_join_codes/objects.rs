@@ -0,0 +1,152 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::match_join_codes::objects::{MatchJoinCodeDbError, MatchJoinCodeEntity},
+    repository::matches::objects::MatchesDataError,
+};
+
+/// Characters of a join code, without the easily confused `0`, `O`, `1` and `I`.
+pub const MATCH_JOIN_CODE_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+pub const MATCH_JOIN_CODE_LENGTH: usize = 8;
+/// How often a new code is generated when the previous one is already taken.
+pub const MATCH_JOIN_CODE_ATTEMPTS: usize = 3;
+/// Highest participant count a code may allow, also used when none is given.
+pub const MATCH_JOIN_CODE_PARTICIPANTS_LIMIT: i64 = 50;
+
+pub struct MatchJoinCodeData {
+    pub uuid: Uuid,
+    pub match_uuid: Uuid,
+    pub code: String,
+    pub created_by: Uuid,
+    pub max_participants: i64,
+    pub uses: i64,
+    pub expires_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+    pub created_at: i64,
+}
+
+pub struct MatchJoinCodeDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub max_participants: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchJoinCodeDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    MatchClosed,
+    JoinCodeNotFound,
+    JoinCodeExpired,
+    ExpiresAtInvalid,
+    MaxParticipantsInvalid,
+    ParticipantsLimitReached,
+    InternalError,
+}
+
+/// Maps every byte onto the alphabet, 256 being a multiple of its 32 characters keeps
+/// the characters equally likely.
+pub fn join_code_from_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take(MATCH_JOIN_CODE_LENGTH)
+        .map(|byte| {
+            MATCH_JOIN_CODE_ALPHABET[(*byte as usize) % MATCH_JOIN_CODE_ALPHABET.len()] as char
+        })
+        .collect()
+}
+
+/// Codes are case insensitive, anything that can't be a code is reported as missing.
+pub fn normalize_join_code(code: &str) -> Result<String, MatchJoinCodeDataError> {
+    let code = code.trim().to_uppercase();
+    let is_valid = code.len() == MATCH_JOIN_CODE_LENGTH
+        && code
+            .bytes()
+            .all(|byte| MATCH_JOIN_CODE_ALPHABET.contains(&byte));
+    if is_valid {
+        Ok(code)
+    } else {
+        Err(MatchJoinCodeDataError::JoinCodeNotFound)
+    }
+}
+
+impl<'a> MatchJoinCodeDataCreate<'a> {
+    /// The limit counts the creator too, so a code has to allow at least two participants.
+    pub fn validate_max_participants(&self) -> Result<i64, MatchJoinCodeDataError> {
+        match self.max_participants {
+            None => Ok(MATCH_JOIN_CODE_PARTICIPANTS_LIMIT),
+            Some(value) if (2..=MATCH_JOIN_CODE_PARTICIPANTS_LIMIT).contains(&value) => Ok(value),
+            Some(_) => Err(MatchJoinCodeDataError::MaxParticipantsInvalid),
+        }
+    }
+
+    pub fn validate_expiry(&self) -> Result<(), MatchJoinCodeDataError> {
+        match self.expires_at {
+            Some(expires_at) if expires_at <= self.created_at => {
+                Err(MatchJoinCodeDataError::ExpiresAtInvalid)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<MatchJoinCodeEntity> for MatchJoinCodeData {
+    fn from(entity: MatchJoinCodeEntity) -> Self {
+        MatchJoinCodeData {
+            uuid: entity.uuid,
+            match_uuid: entity.match_uuid,
+            code: entity.code,
+            created_by: entity.created_by,
+            max_participants: entity.max_participants,
+            uses: entity.uses,
+            expires_at: entity.expires_at,
+            revoked_at: entity.revoked_at,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<MatchJoinCodeDbError> for MatchJoinCodeDataError {
+    fn from(error: MatchJoinCodeDbError) -> Self {
+        match error {
+            MatchJoinCodeDbError::JoinCodeNotFound => MatchJoinCodeDataError::JoinCodeNotFound,
+            MatchJoinCodeDbError::JoinCodeExpired => MatchJoinCodeDataError::JoinCodeExpired,
+            MatchJoinCodeDbError::MatchesNotFound => MatchJoinCodeDataError::MatchesNotFound,
+            MatchJoinCodeDbError::MatchClosed => MatchJoinCodeDataError::MatchClosed,
+            MatchJoinCodeDbError::ParticipantsLimitReached => {
+                MatchJoinCodeDataError::ParticipantsLimitReached
+            }
+            MatchJoinCodeDbError::CodeConflict | MatchJoinCodeDbError::InternalError => {
+                MatchJoinCodeDataError::InternalError
+            }
+        }
+    }
+}
+
+impl From<MatchesDataError> for MatchJoinCodeDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchJoinCodeDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchJoinCodeDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchJoinCodeDataError::NoPermission,
+            MatchesDataError::MatchClosed => MatchJoinCodeDataError::MatchClosed,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantsNotFound
+            | MatchesDataError::InviteNotAllowed
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchJoinCodeDataError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::match_join_codes::{
+            objects::{MatchJoinCodeDbError, MatchJoinCodeEntityCreate},
+            MatchJoinCodesDatabase,
+        },
+        repository::matches::{
+            objects::{MatchStatus, MatchesData},
+            MatchesRepository,
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        join_code_from_bytes, normalize_join_code, MatchJoinCodeData, MatchJoinCodeDataCreate,
+        MatchJoinCodeDataError, MATCH_JOIN_CODE_ATTEMPTS,
+    },
+    MatchJoinCodesRepository,
+};
+
+#[async_trait]
+impl MatchJoinCodesRepository for Conn {
+    async fn create_join_code<'a>(
+        &self,
+        request: MatchJoinCodeDataCreate<'a>,
+    ) -> Result<MatchJoinCodeData, MatchJoinCodeDataError> {
+        let max_participants = request.validate_max_participants()?;
+        request.validate_expiry()?;
+        let match_data = self
+            .get_moderated_match(request.request_uuid, request.match_uuid)
+            .await?;
+        if !MatchStatus::open_statuses().contains(&match_data.status) {
+            return Result::Err(MatchJoinCodeDataError::MatchClosed);
+        }
+        let created_by = Uuid::parse_str(request.request_uuid)
+            .map_err(|_| MatchJoinCodeDataError::UuidInvalid)?;
+        for _ in 0..MATCH_JOIN_CODE_ATTEMPTS {
+            let join_code = MatchJoinCodeEntityCreate {
+                match_uuid: match_data.uuid,
+                code: join_code_from_bytes(Uuid::new_v4().as_bytes()),
+                created_by,
+                max_participants,
+                expires_at: request.expires_at,
+                created_at: request.created_at,
+            };
+            match self.add_join_code(join_code).await {
+                Err(MatchJoinCodeDbError::CodeConflict) => continue,
+                result => return result.map(|v| v.into()).map_err(|e| e.into()),
+            }
+        }
+        Result::Err(MatchJoinCodeDataError::InternalError)
+    }
+
+    async fn get_join_codes<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchJoinCodeData>, MatchJoinCodeDataError> {
+        let match_data = self.get_moderated_match(request_uuid, match_uuid).await?;
+        let active_at = chrono::Utc::now().timestamp_millis();
+        Ok(
+            MatchJoinCodesDatabase::get_join_codes(self, match_data.uuid, active_at)
+                .await?
+                .into_iter()
+                .map(MatchJoinCodeData::from)
+                .collect(),
+        )
+    }
+
+    async fn revoke_join_code<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        code_uuid: &'a str,
+    ) -> Result<MatchJoinCodeData, MatchJoinCodeDataError> {
+        let code_uuid =
+            Uuid::parse_str(code_uuid).map_err(|_| MatchJoinCodeDataError::UuidInvalid)?;
+        let match_data = self.get_moderated_match(request_uuid, match_uuid).await?;
+        let revoked_at = chrono::Utc::now().timestamp_millis();
+        MatchJoinCodesDatabase::revoke_join_code(self, match_data.uuid, code_uuid, revoked_at)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn redeem_join_code<'a>(
+        &self,
+        request_uuid: &'a str,
+        code: &'a str,
+    ) -> Result<MatchesData, MatchJoinCodeDataError> {
+        let user_uuid =
+            Uuid::parse_str(request_uuid).map_err(|_| MatchJoinCodeDataError::UuidInvalid)?;
+        let code = normalize_join_code(code)?;
+        let open_statuses = MatchStatus::open_status_names();
+        let redeemed_at = chrono::Utc::now().timestamp_millis();
+        let match_entity = MatchJoinCodesDatabase::redeem_join_code(
+            self,
+            code,
+            user_uuid,
+            open_statuses,
+            redeemed_at,
+        )
+        .await?;
+        MatchesData::try_from(match_entity).map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::repository::match_join_codes::objects::{
+        join_code_from_bytes, normalize_join_code, MatchJoinCodeDataCreate, MatchJoinCodeDataError,
+        MATCH_JOIN_CODE_LENGTH, MATCH_JOIN_CODE_PARTICIPANTS_LIMIT,
+    };
+
+    #[test]
+    fn test_join_code_from_bytes() {
+        let code = join_code_from_bytes(&[0, 31, 32, 255, 8, 9, 10, 11, 12, 13]);
+        assert_eq!(code, "A9A9JKLM");
+        assert_eq!(code.len(), MATCH_JOIN_CODE_LENGTH);
+        assert_eq!(normalize_join_code(&code).unwrap(), code);
+    }
+
+    #[test]
+    fn test_normalize_join_code() {
+        assert_eq!(normalize_join_code(" a9a9jklm ").unwrap(), "A9A9JKLM");
+        for code in ["", "A9A9JKL", "A9A9JKLMN", "A9A9JKL0", "A9A9JKL-"] {
+            assert!(matches!(
+                normalize_join_code(code),
+                Err(MatchJoinCodeDataError::JoinCodeNotFound)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_join_code_create_validation() {
+        let request =
+            |max_participants: Option<i64>, expires_at: Option<i64>| MatchJoinCodeDataCreate {
+                request_uuid: "",
+                match_uuid: "",
+                max_participants,
+                expires_at,
+                created_at: 100,
+            };
+        assert_eq!(
+            request(None, None).validate_max_participants().unwrap(),
+            MATCH_JOIN_CODE_PARTICIPANTS_LIMIT
+        );
+        assert_eq!(
+            request(Some(2), None).validate_max_participants().unwrap(),
+            2
+        );
+        for max_participants in [1, MATCH_JOIN_CODE_PARTICIPANTS_LIMIT + 1] {
+            assert!(matches!(
+                request(Some(max_participants), None).validate_max_participants(),
+                Err(MatchJoinCodeDataError::MaxParticipantsInvalid)
+            ));
+        }
+        assert!(request(None, Some(101)).validate_expiry().is_ok());
+        assert!(matches!(
+            request(None, Some(100)).validate_expiry(),
+            Err(MatchJoinCodeDataError::ExpiresAtInvalid)
+        ));
+    }
+}
@@ -0,0 +1,19 @@
+use self::objects::{MatchVoteData, MatchVoteDataCreate, MatchVoteDataError};
+
+use super::match_candidates::objects::MatchCandidateData;
+
+pub mod objects;
+mod repository;
+
+#[async_trait]
+pub trait MatchVotesRepository {
+    async fn vote<'a>(
+        &self,
+        request: MatchVoteDataCreate<'a>,
+    ) -> Result<MatchVoteData, MatchVoteDataError>;
+    async fn get_next_candidate<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchCandidateData, MatchVoteDataError>;
+}
@@ -0,0 +1,87 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::match_votes::objects::{MatchVoteDbError, MatchVoteResultEntity},
+    repository::matches::objects::{MatchStatus, MatchesDataError},
+};
+
+pub struct MatchVoteData {
+    pub match_uuid: Uuid,
+    pub candidate_uuid: Uuid,
+    pub liked: bool,
+    pub created_at: i64,
+    pub status: MatchStatus,
+    pub winner_candidate_uuid: Option<Uuid>,
+}
+
+pub struct MatchVoteDataCreate<'a> {
+    pub request_uuid: &'a str,
+    pub match_uuid: &'a str,
+    pub candidate_uuid: &'a str,
+    pub liked: bool,
+    pub created_at: i64,
+}
+
+#[derive(Debug)]
+pub enum MatchVoteDataError {
+    UuidInvalid,
+    MatchesNotFound,
+    NoPermission,
+    CandidateNotFound,
+    MatchClosed,
+    NoCandidatesLeft,
+    DecisionMethodMismatch,
+    InternalError,
+}
+
+impl TryFrom<MatchVoteResultEntity> for MatchVoteData {
+    type Error = MatchesDataError;
+
+    fn try_from(entity: MatchVoteResultEntity) -> Result<Self, Self::Error> {
+        Ok(MatchVoteData {
+            match_uuid: entity.vote.match_uuid,
+            candidate_uuid: entity.vote.candidate_uuid,
+            liked: entity.vote.liked,
+            created_at: entity.vote.created_at,
+            status: entity.status.try_into()?,
+            winner_candidate_uuid: entity.winner_candidate_uuid,
+        })
+    }
+}
+
+impl From<MatchVoteDbError> for MatchVoteDataError {
+    fn from(error: MatchVoteDbError) -> Self {
+        match error {
+            MatchVoteDbError::UuidInvalid => MatchVoteDataError::UuidInvalid,
+            MatchVoteDbError::MatchesNotFound => MatchVoteDataError::MatchesNotFound,
+            MatchVoteDbError::CandidateNotFound => MatchVoteDataError::CandidateNotFound,
+            MatchVoteDbError::MatchClosed => MatchVoteDataError::MatchClosed,
+            MatchVoteDbError::InternalError => MatchVoteDataError::InternalError,
+        }
+    }
+}
+
+impl From<MatchesDataError> for MatchVoteDataError {
+    fn from(error: MatchesDataError) -> Self {
+        match error {
+            MatchesDataError::UuidInvalid => MatchVoteDataError::UuidInvalid,
+            MatchesDataError::MatchesNotFound => MatchVoteDataError::MatchesNotFound,
+            MatchesDataError::NoPermission => MatchVoteDataError::NoPermission,
+            MatchesDataError::MatchClosed => MatchVoteDataError::MatchClosed,
+            MatchesDataError::MatchesNotCreated
+            | MatchesDataError::StatusInvalid
+            | MatchesDataError::InvalidTransition
+            | MatchesDataError::ExpiresAtInvalid
+            | MatchesDataError::ParticipantsNotFound
+            | MatchesDataError::InviteNotAllowed
+            | MatchesDataError::ParticipantNotFound
+            | MatchesDataError::CreatorCannotLeave
+            | MatchesDataError::DecisionMethodInvalid
+            | MatchesDataError::SeedLimitInvalid
+            | MatchesDataError::GroupNotFound
+            | MatchesDataError::FilterInvalid
+            | MatchesDataError::RoleInvalid
+            | MatchesDataError::InternalError => MatchVoteDataError::InternalError,
+        }
+    }
+}
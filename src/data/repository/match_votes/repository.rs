@@ -0,0 +1,78 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::match_votes::{objects::MatchVoteEntityCreate, MatchVotesDatabase},
+        repository::{
+            match_candidates::objects::MatchCandidateData,
+            matches::{
+                objects::{MatchParticipantRole, MatchStatus},
+                MatchesRepository,
+            },
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{MatchVoteData, MatchVoteDataCreate, MatchVoteDataError},
+    MatchVotesRepository,
+};
+
+#[async_trait]
+impl MatchVotesRepository for Conn {
+    async fn vote<'a>(
+        &self,
+        request: MatchVoteDataCreate<'a>,
+    ) -> Result<MatchVoteData, MatchVoteDataError> {
+        let match_data = self
+            .get_current_match(request.request_uuid, request.match_uuid)
+            .await?;
+        if match_data.decision_method.uses_ballots() {
+            return Result::Err(MatchVoteDataError::DecisionMethodMismatch);
+        }
+        let user_uuid =
+            Uuid::parse_str(request.request_uuid).map_err(|_| MatchVoteDataError::UuidInvalid)?;
+        if !self
+            .get_participant_role(&match_data, user_uuid)
+            .await?
+            .can_vote()
+        {
+            return Result::Err(MatchVoteDataError::NoPermission);
+        }
+        let vote = MatchVoteEntityCreate {
+            match_uuid: match_data.uuid,
+            candidate_uuid: Uuid::parse_str(request.candidate_uuid)
+                .map_err(|_| MatchVoteDataError::UuidInvalid)?,
+            user_uuid,
+            liked: request.liked,
+            created_at: request.created_at,
+        };
+        let open_statuses = MatchStatus::open_status_names();
+        let non_voting_roles = MatchParticipantRole::non_voting_roles()
+            .into_iter()
+            .map(|role| role.into())
+            .collect();
+        let result = self
+            .add_vote(
+                vote,
+                open_statuses,
+                non_voting_roles,
+                MatchStatus::Completed.into(),
+            )
+            .await?;
+        MatchVoteData::try_from(result).map_err(|err| err.into())
+    }
+
+    async fn get_next_candidate<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchCandidateData, MatchVoteDataError> {
+        self.get_current_match(request_uuid, match_uuid).await?;
+        MatchVotesDatabase::get_next_candidate(self, match_uuid, request_uuid)
+            .await?
+            .map(|value| value.into())
+            .ok_or(MatchVoteDataError::NoCandidatesLeft)
+    }
+}
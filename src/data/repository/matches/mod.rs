@@ -1,9 +1,16 @@
-use self::objects::{MatchesData, MatchesDataCreate, MatchesDataError};
+use uuid::Uuid;
+
+use self::objects::{
+    MatchParticipantData, MatchParticipantRole, MatchSeedCandidateData, MatchSeedPreviewData,
+    MatchTransition, MatchesData, MatchesDataCreate, MatchesDataError, MatchesDataFilter,
+    MatchesDataUpdate,
+};
 
 use super::objects::{PagingDomainRequest, PagingDomainResponse};
 
 pub mod objects;
 mod repository;
+mod tests;
 
 #[async_trait]
 pub trait MatchesRepository {
@@ -19,10 +26,97 @@ pub trait MatchesRepository {
     async fn get_matches<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: MatchesDataFilter,
     ) -> Result<PagingDomainResponse<MatchesData>, MatchesDataError>;
     async fn get_match_count<'a>(
         &self,
         user_uuid: &'a str,
         request_uuid: &'a str,
     ) -> Result<i64, MatchesDataError>;
+    async fn transition_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        transition: MatchTransition,
+    ) -> Result<MatchesData, MatchesDataError>;
+    async fn expire_overdue_matches(&self) -> Result<usize, MatchesDataError>;
+    /// Drops the creator and duplicates, then checks every invitee exists
+    /// and follows or is followed by the creator.
+    async fn validate_invitees(
+        &self,
+        creator_uuid: Uuid,
+        participants_uuid: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, MatchesDataError>;
+    /// Favourites of `users_uuid` ranked by how many of them share each one,
+    /// items everyone favourited come first.
+    async fn get_seed_candidates(
+        &self,
+        users_uuid: Vec<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<MatchSeedCandidateData>, MatchesDataError>;
+    /// Shows the candidates `create_matches` would seed for the same participants.
+    async fn preview_seed_candidates<'a>(
+        &self,
+        request_uuid: &'a str,
+        participants_uuid: Vec<&'a str>,
+        group_uuid: Option<&'a str>,
+        limit: i64,
+    ) -> Result<MatchSeedPreviewData, MatchesDataError>;
+    async fn update_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        update: MatchesDataUpdate<'a>,
+    ) -> Result<MatchesData, MatchesDataError>;
+    async fn delete_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), MatchesDataError>;
+    async fn remove_participant<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError>;
+    /// The owner hands the match to the next participant in line when they leave, see
+    /// `pick_successor`. An owner without other participants can't leave.
+    async fn leave_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), MatchesDataError>;
+    /// Same as `get_current_match`, but only the owner and moderators pass.
+    async fn get_moderated_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError>;
+    async fn get_participant_role(
+        &self,
+        match_data: &MatchesData,
+        user_uuid: Uuid,
+    ) -> Result<MatchParticipantRole, MatchesDataError>;
+    /// Participants whose role lets them vote, in joining order.
+    async fn get_voters(&self, match_data: &MatchesData) -> Result<Vec<Uuid>, MatchesDataError>;
+    async fn get_match_roles<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchParticipantData>, MatchesDataError>;
+    /// Only the owner assigns roles, ownership itself moves through `transfer_ownership`.
+    async fn set_participant_role<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+        role: MatchParticipantRole,
+    ) -> Result<Vec<MatchParticipantData>, MatchesDataError>;
+    /// The previous owner stays in the match as a member.
+    async fn transfer_ownership<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError>;
 }
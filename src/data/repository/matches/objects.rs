@@ -2,7 +2,20 @@ use rocket::futures;
 use uuid::Uuid;
 
 use crate::{
-    data::database::matches::objects::{MatchesDbError, MatchesEntity},
+    data::{
+        database::{
+            favourite::objects::FavouriteOverlapEntity,
+            match_candidates::objects::MatchCandidateEntityCreate,
+            match_roles::objects::{MatchRoleDbError, MatchRoleEntity},
+            matches::objects::{
+                MatchesDbError, MatchesEntity, MatchesEntityFilter, MatchesEntityOrder,
+                MatchesEntityUpdate,
+            },
+        },
+        repository::{
+            groups::objects::GroupDataError, match_candidates::objects::MATCH_CANDIDATES_LIMIT,
+        },
+    },
     utils::Mapper,
 };
 
@@ -17,8 +30,11 @@ pub struct MatchesData {
     pub expires_at: i64,
     pub updated_at: i64,
     pub created_at: i64,
+    pub winner_candidate_uuid: Option<Uuid>,
+    pub decision_method: MatchDecisionMethod,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MatchStatus {
     Pending,
     Active,
@@ -27,6 +43,21 @@ pub enum MatchStatus {
     Cancelled,
 }
 
+impl MatchStatus {
+    /// Open matches still accept candidates and votes.
+    pub fn open_statuses() -> Vec<MatchStatus> {
+        vec![MatchStatus::Pending, MatchStatus::Active]
+    }
+
+    /// The open statuses as stored in the database.
+    pub fn open_status_names() -> Vec<String> {
+        MatchStatus::open_statuses()
+            .into_iter()
+            .map(|status| status.into())
+            .collect()
+    }
+}
+
 impl Into<String> for MatchStatus {
     fn into(self) -> String {
         match self {
@@ -39,15 +70,108 @@ impl Into<String> for MatchStatus {
     }
 }
 
-impl Into<MatchStatus> for String {
-    fn into(self) -> MatchStatus {
-        match self.as_str() {
-            "pending" => MatchStatus::Pending,
-            "active" => MatchStatus::Active,
-            "expired" => MatchStatus::Expired,
-            "completed" => MatchStatus::Completed,
-            "cancelled" => MatchStatus::Cancelled,
-            _ => MatchStatus::Pending,
+impl TryFrom<String> for MatchStatus {
+    type Error = MatchesDataError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "pending" => Ok(MatchStatus::Pending),
+            "active" => Ok(MatchStatus::Active),
+            "expired" => Ok(MatchStatus::Expired),
+            "completed" => Ok(MatchStatus::Completed),
+            "cancelled" => Ok(MatchStatus::Cancelled),
+            _ => {
+                eprintln!("Unknown match status: {}", value);
+                Err(MatchesDataError::StatusInvalid)
+            }
+        }
+    }
+}
+
+/// How participants pick the winner of a match, chosen at creation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchDecisionMethod {
+    /// Like/dislike swipes, the first candidate liked by every participant wins.
+    Unanimous,
+    /// Each ballot approves any number of candidates, most approvals wins.
+    Approval,
+    /// Ranked ballots resolved with instant-runoff.
+    Ranked,
+    /// Ranked ballots scored with the Borda count.
+    Borda,
+}
+
+impl MatchDecisionMethod {
+    /// Ballot based methods are decided through `/ballot` instead of swipes.
+    pub fn uses_ballots(&self) -> bool {
+        !matches!(self, MatchDecisionMethod::Unanimous)
+    }
+}
+
+impl From<MatchDecisionMethod> for String {
+    fn from(method: MatchDecisionMethod) -> Self {
+        match method {
+            MatchDecisionMethod::Unanimous => "unanimous".to_string(),
+            MatchDecisionMethod::Approval => "approval".to_string(),
+            MatchDecisionMethod::Ranked => "ranked".to_string(),
+            MatchDecisionMethod::Borda => "borda".to_string(),
+        }
+    }
+}
+
+impl TryFrom<&str> for MatchDecisionMethod {
+    type Error = MatchesDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "unanimous" => Ok(MatchDecisionMethod::Unanimous),
+            "approval" => Ok(MatchDecisionMethod::Approval),
+            "ranked" => Ok(MatchDecisionMethod::Ranked),
+            "borda" => Ok(MatchDecisionMethod::Borda),
+            _ => Err(MatchesDataError::DecisionMethodInvalid),
+        }
+    }
+}
+
+/// Explicit actions that move a match between statuses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchTransition {
+    Start,
+    Complete,
+    Cancel,
+    Expire,
+}
+
+impl MatchTransition {
+    pub fn allowed_from(&self) -> Vec<MatchStatus> {
+        match self {
+            MatchTransition::Start => vec![MatchStatus::Pending],
+            MatchTransition::Complete | MatchTransition::Cancel | MatchTransition::Expire => {
+                MatchStatus::open_statuses()
+            }
+        }
+    }
+
+    pub fn target(&self) -> MatchStatus {
+        match self {
+            MatchTransition::Start => MatchStatus::Active,
+            MatchTransition::Complete => MatchStatus::Completed,
+            MatchTransition::Cancel => MatchStatus::Cancelled,
+            MatchTransition::Expire => MatchStatus::Expired,
+        }
+    }
+
+    /// Only the creator can start, complete or cancel a match. Any participant can expire it
+    /// once its expiry time has passed.
+    pub fn is_creator_only(&self) -> bool {
+        !matches!(self, MatchTransition::Expire)
+    }
+
+    pub fn apply(&self, status: MatchStatus) -> Result<MatchStatus, MatchesDataError> {
+        if self.allowed_from().contains(&status) {
+            Ok(self.target())
+        } else {
+            Err(MatchesDataError::InvalidTransition)
         }
     }
 }
@@ -55,12 +179,321 @@ impl Into<MatchStatus> for String {
 pub struct MatchesDataCreate<'a> {
     pub creator_uuid: &'a str,
     pub participants_uuid: Vec<&'a str>,
+    /// Members of this group, owned by the creator, are invited as well.
+    pub group_uuid: Option<&'a str>,
     pub title: &'a str,
     pub description: &'a str,
     pub cover_url: &'a str,
     pub created_at: i64,
     pub expires_at: i64,
     pub updated_at: i64,
+    pub decision_method: MatchDecisionMethod,
+    /// Seeds up to this many candidates from the participants' favourites, `None` skips seeding.
+    pub seed_limit: Option<i64>,
+}
+
+impl<'a> MatchesDataCreate<'a> {
+    /// A match must expire in the future, but no later than `max_expiry_ms` after creation.
+    pub fn validate_expiry(&self, max_expiry_ms: i64) -> Result<(), MatchesDataError> {
+        validate_expiry(self.created_at, self.expires_at, max_expiry_ms)
+    }
+}
+
+/// Amount of candidates seeded from favourites when the request doesn't give a limit.
+pub const MATCH_SEED_DEFAULT_LIMIT: i64 = 20;
+
+/// The seed limit can't exceed the amount of candidates a match can hold.
+pub fn validate_seed_limit(limit: i64) -> Result<(), MatchesDataError> {
+    if (1..=MATCH_CANDIDATES_LIMIT).contains(&limit) {
+        Ok(())
+    } else {
+        Err(MatchesDataError::SeedLimitInvalid)
+    }
+}
+
+/// A favourite proposed as a candidate, `overlap` is how many participants favourited it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchSeedCandidateData {
+    pub reference_uuid: Uuid,
+    pub title: String,
+    pub overlap: i64,
+}
+
+pub struct MatchSeedPreviewData {
+    pub participants_count: i64,
+    pub candidates: Vec<MatchSeedCandidateData>,
+}
+
+impl From<FavouriteOverlapEntity> for MatchSeedCandidateData {
+    fn from(entity: FavouriteOverlapEntity) -> Self {
+        MatchSeedCandidateData {
+            reference_uuid: entity.favourite_uuid,
+            title: entity.title.unwrap_or_default(),
+            overlap: entity.overlap,
+        }
+    }
+}
+
+impl MatchSeedCandidateData {
+    /// Seeded candidates are created a millisecond apart so the candidate order, which also
+    /// breaks ties when tallying, follows the overlap ranking.
+    pub fn into_candidate(
+        self,
+        created_by: Uuid,
+        created_at: i64,
+        position: usize,
+    ) -> MatchCandidateEntityCreate {
+        MatchCandidateEntityCreate {
+            match_uuid: Uuid::nil(),
+            reference_uuid: self.reference_uuid,
+            title: self.title,
+            image_url: String::new(),
+            metadata: Some(serde_json::json!({ "overlap": self.overlap }).to_string()),
+            created_by,
+            created_at: created_at + i64::try_from(position).unwrap_or(i64::MAX - created_at),
+        }
+    }
+}
+
+pub struct MatchesDataUpdate<'a> {
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub cover_url: Option<&'a str>,
+    pub expires_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+impl<'a> MatchesDataUpdate<'a> {
+    /// A new expiry follows the creation rules, counted from the moment of the update.
+    pub fn validate_expiry(&self, max_expiry_ms: i64) -> Result<(), MatchesDataError> {
+        match self.expires_at {
+            Some(expires_at) => validate_expiry(self.updated_at, expires_at, max_expiry_ms),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> From<MatchesDataUpdate<'a>> for MatchesEntityUpdate {
+    fn from(update: MatchesDataUpdate<'a>) -> Self {
+        MatchesEntityUpdate {
+            title: update.title.map(|value| value.to_owned()),
+            description: update.description.map(|value| value.to_owned()),
+            cover_url: update.cover_url.map(|value| value.to_owned()),
+            expires_at: update.expires_at,
+            updated_at: update.updated_at,
+        }
+    }
+}
+
+/// Side of the requester in a listed match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchRole {
+    Creator,
+    Invited,
+}
+
+impl TryFrom<&str> for MatchRole {
+    type Error = MatchesDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "creator" => Ok(MatchRole::Creator),
+            "invited" => Ok(MatchRole::Invited),
+            _ => Err(MatchesDataError::FilterInvalid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MatchSort {
+    #[default]
+    RecentlyUpdated,
+    ExpiringSoon,
+    Newest,
+}
+
+impl TryFrom<&str> for MatchSort {
+    type Error = MatchesDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "updated" => Ok(MatchSort::RecentlyUpdated),
+            "expiring" => Ok(MatchSort::ExpiringSoon),
+            "newest" => Ok(MatchSort::Newest),
+            _ => Err(MatchesDataError::FilterInvalid),
+        }
+    }
+}
+
+impl From<MatchSort> for MatchesEntityOrder {
+    fn from(sort: MatchSort) -> Self {
+        match sort {
+            MatchSort::RecentlyUpdated => MatchesEntityOrder::RecentlyUpdated,
+            MatchSort::ExpiringSoon => MatchesEntityOrder::ExpiringSoon,
+            MatchSort::Newest => MatchesEntityOrder::Newest,
+        }
+    }
+}
+
+/// Optional narrowing of the match list, an empty `statuses` keeps every status.
+/// Date bounds are inclusive milliseconds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MatchesDataFilter {
+    pub statuses: Vec<MatchStatus>,
+    pub role: Option<MatchRole>,
+    pub created_from: Option<i64>,
+    pub created_to: Option<i64>,
+    pub expires_from: Option<i64>,
+    pub expires_to: Option<i64>,
+    pub sort: MatchSort,
+}
+
+impl MatchesDataFilter {
+    /// Every range has to start no later than it ends.
+    pub fn validate(&self) -> Result<(), MatchesDataError> {
+        let ranges = [
+            (self.created_from, self.created_to),
+            (self.expires_from, self.expires_to),
+        ];
+        let is_reversed = ranges
+            .iter()
+            .any(|range| matches!(range, (Some(from), Some(to)) if from > to));
+        if is_reversed {
+            Err(MatchesDataError::FilterInvalid)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<MatchesDataFilter> for MatchesEntityFilter {
+    fn from(filter: MatchesDataFilter) -> Self {
+        MatchesEntityFilter {
+            statuses: filter
+                .statuses
+                .into_iter()
+                .map(|status| status.into())
+                .collect(),
+            created_by_requester: filter.role.map(|role| role == MatchRole::Creator),
+            created_from: filter.created_from,
+            created_to: filter.created_to,
+            expires_from: filter.expires_from,
+            expires_to: filter.expires_to,
+            order: filter.sort.into(),
+        }
+    }
+}
+
+/// Role of a participant inside a match. The creator is the owner, participants without a
+/// stored role are members.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchParticipantRole {
+    Owner,
+    Moderator,
+    Member,
+    Viewer,
+}
+
+impl MatchParticipantRole {
+    /// Owners and moderators manage candidates, invites, join codes and participants.
+    pub fn can_moderate(&self) -> bool {
+        matches!(
+            self,
+            MatchParticipantRole::Owner | MatchParticipantRole::Moderator
+        )
+    }
+
+    /// Viewers follow the match and its results without voting.
+    pub fn can_vote(&self) -> bool {
+        !matches!(self, MatchParticipantRole::Viewer)
+    }
+
+    pub fn non_voting_roles() -> Vec<MatchParticipantRole> {
+        vec![MatchParticipantRole::Viewer]
+    }
+}
+
+impl From<MatchParticipantRole> for String {
+    fn from(role: MatchParticipantRole) -> Self {
+        match role {
+            MatchParticipantRole::Owner => "owner".to_string(),
+            MatchParticipantRole::Moderator => "moderator".to_string(),
+            MatchParticipantRole::Member => "member".to_string(),
+            MatchParticipantRole::Viewer => "viewer".to_string(),
+        }
+    }
+}
+
+impl TryFrom<&str> for MatchParticipantRole {
+    type Error = MatchesDataError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "owner" => Ok(MatchParticipantRole::Owner),
+            "moderator" => Ok(MatchParticipantRole::Moderator),
+            "member" => Ok(MatchParticipantRole::Member),
+            "viewer" => Ok(MatchParticipantRole::Viewer),
+            _ => Err(MatchesDataError::RoleInvalid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchParticipantData {
+    pub user_uuid: Uuid,
+    pub role: MatchParticipantRole,
+}
+
+impl MatchesData {
+    /// Every participant in joining order with the role resolved from `stored`.
+    pub fn participant_roles(
+        &self,
+        stored: Vec<MatchRoleEntity>,
+    ) -> Result<Vec<MatchParticipantData>, MatchesDataError> {
+        self.participants_uuid
+            .iter()
+            .map(|user_uuid| {
+                let role = if *user_uuid == self.creator_uuid {
+                    MatchParticipantRole::Owner
+                } else {
+                    match stored.iter().find(|entity| entity.user_uuid == *user_uuid) {
+                        Some(entity) => entity.role.as_str().try_into()?,
+                        None => MatchParticipantRole::Member,
+                    }
+                };
+                Ok(MatchParticipantData {
+                    user_uuid: *user_uuid,
+                    role,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Who inherits the match when the owner leaves: the first moderator, else the first member,
+/// else the first viewer, in joining order.
+pub fn pick_successor(participants: &[MatchParticipantData]) -> Option<Uuid> {
+    [
+        MatchParticipantRole::Moderator,
+        MatchParticipantRole::Member,
+        MatchParticipantRole::Viewer,
+    ]
+    .iter()
+    .find_map(|role| {
+        participants
+            .iter()
+            .find(|participant| participant.role == *role)
+            .map(|participant| participant.user_uuid)
+    })
+}
+
+fn validate_expiry(from: i64, expires_at: i64, max_expiry_ms: i64) -> Result<(), MatchesDataError> {
+    let expires_in = expires_at - from;
+    if expires_in <= 0 || expires_in > max_expiry_ms {
+        Err(MatchesDataError::ExpiresAtInvalid)
+    } else {
+        Ok(())
+    }
 }
 
 impl Into<MatchesDataError> for MatchesDbError {
@@ -69,25 +502,31 @@ impl Into<MatchesDataError> for MatchesDbError {
             MatchesDbError::UuidInvalid => MatchesDataError::UuidInvalid,
             MatchesDbError::MatchesNotFound => MatchesDataError::MatchesNotFound,
             MatchesDbError::MatchesNotCreated => MatchesDataError::MatchesNotCreated,
+            MatchesDbError::MatchClosed => MatchesDataError::MatchClosed,
+            MatchesDbError::ParticipantNotFound => MatchesDataError::ParticipantNotFound,
             MatchesDbError::InternalError => MatchesDataError::InternalError,
         }
     }
 }
 
-impl Into<MatchesData> for MatchesEntity {
-    fn into(self) -> MatchesData {
-        MatchesData {
-            uuid: self.uuid,
-            creator_uuid: self.creator_uuid,
-            participants_uuid: self.participants_uuid,
-            title: self.title,
-            description: self.description,
-            cover_url: self.cover_url,
-            status: self.status.into(),
-            expires_at: self.expires_at,
-            updated_at: self.updated_at,
-            created_at: self.created_at,
-        }
+impl TryFrom<MatchesEntity> for MatchesData {
+    type Error = MatchesDataError;
+
+    fn try_from(entity: MatchesEntity) -> Result<Self, Self::Error> {
+        Ok(MatchesData {
+            uuid: entity.uuid,
+            creator_uuid: entity.creator_uuid,
+            participants_uuid: entity.participants_uuid,
+            title: entity.title,
+            description: entity.description,
+            cover_url: entity.cover_url,
+            status: entity.status.try_into()?,
+            expires_at: entity.expires_at,
+            updated_at: entity.updated_at,
+            created_at: entity.created_at,
+            winner_candidate_uuid: entity.winner_candidate_uuid,
+            decision_method: entity.decision_method.as_str().try_into()?,
+        })
     }
 }
 
@@ -108,10 +547,48 @@ impl Mapper<Result<Vec<Uuid>, MatchesDataError>> for Vec<&str> {
     }
 }
 
+#[derive(Debug)]
 pub enum MatchesDataError {
     UuidInvalid,
     MatchesNotFound,
     InternalError,
     MatchesNotCreated,
     NoPermission,
+    StatusInvalid,
+    InvalidTransition,
+    ExpiresAtInvalid,
+    ParticipantsNotFound,
+    InviteNotAllowed,
+    MatchClosed,
+    ParticipantNotFound,
+    CreatorCannotLeave,
+    DecisionMethodInvalid,
+    SeedLimitInvalid,
+    GroupNotFound,
+    FilterInvalid,
+    RoleInvalid,
+}
+
+impl From<MatchRoleDbError> for MatchesDataError {
+    fn from(error: MatchRoleDbError) -> Self {
+        match error {
+            MatchRoleDbError::MatchesNotFound => MatchesDataError::MatchesNotFound,
+            MatchRoleDbError::ParticipantNotFound => MatchesDataError::ParticipantNotFound,
+            MatchRoleDbError::NotOwner => MatchesDataError::NoPermission,
+            MatchRoleDbError::InternalError => MatchesDataError::InternalError,
+        }
+    }
+}
+
+impl From<GroupDataError> for MatchesDataError {
+    fn from(error: GroupDataError) -> Self {
+        match error {
+            GroupDataError::UuidInvalid => MatchesDataError::UuidInvalid,
+            GroupDataError::GroupNotFound => MatchesDataError::GroupNotFound,
+            GroupDataError::TitleInvalid
+            | GroupDataError::MembersNotFollowed
+            | GroupDataError::MembersLimitExceeded
+            | GroupDataError::InternalError => MatchesDataError::InternalError,
+        }
+    }
 }
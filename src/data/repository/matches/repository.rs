@@ -1,20 +1,36 @@
 use std::ops::Not;
 
+use uuid::Uuid;
+
 use crate::{
+    config,
     data::{
         database::{
+            favourite::UserFavouritesDatabase,
             follow::FollowDatabase,
+            match_invites::objects::MatchInviteEntityCreate,
+            match_roles::MatchRolesDatabase,
             matches::{objects::MatchesEntityCreate, MatchesDatabase},
             user::UserDatabase,
         },
-        repository::objects::{PagingDomainRequest, PagingDomainResponse},
+        repository::{
+            favourite_lists::objects::FavouriteListVisibility,
+            groups::GroupsRepository,
+            match_ballots::MatchBallotsRepository,
+            match_invites::objects::MatchInviteStatus,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
     },
     utils::Mapper,
     Conn,
 };
 
 use super::{
-    objects::{MatchStatus, MatchesData, MatchesDataCreate, MatchesDataError},
+    objects::{
+        pick_successor, validate_seed_limit, MatchParticipantData, MatchParticipantRole,
+        MatchSeedCandidateData, MatchSeedPreviewData, MatchStatus, MatchTransition, MatchesData,
+        MatchesDataCreate, MatchesDataError, MatchesDataFilter, MatchesDataUpdate,
+    },
     MatchesRepository,
 };
 
@@ -24,9 +40,48 @@ impl MatchesRepository for Conn {
         &self,
         request: MatchesDataCreate<'a>,
     ) -> Result<MatchesData, MatchesDataError> {
+        request.validate_expiry(config::match_max_expiry_ms())?;
+        let creator_uuid: Uuid = request.creator_uuid.map().await?;
+        let participants_uuid = expand_participants(
+            self,
+            request.creator_uuid,
+            request.participants_uuid,
+            request.group_uuid,
+        )
+        .await?;
+        let invitees = self
+            .validate_invitees(creator_uuid, participants_uuid)
+            .await?;
+
+        let invites = invitees
+            .iter()
+            .map(|user_uuid| MatchInviteEntityCreate {
+                match_uuid: Uuid::nil(),
+                user_uuid: *user_uuid,
+                invited_by: creator_uuid,
+                status: MatchInviteStatus::Pending.into(),
+                created_at: request.created_at,
+                updated_at: request.created_at,
+            })
+            .collect();
+        let candidates = match request.seed_limit {
+            Some(limit) => {
+                validate_seed_limit(limit)?;
+                let users_uuid = [vec![creator_uuid], invitees.clone()].concat();
+                self.get_seed_candidates(users_uuid, limit)
+                    .await?
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, candidate)| {
+                        candidate.into_candidate(creator_uuid, request.created_at, position)
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
         let match_entity = MatchesEntityCreate {
-            creator_uuid: request.creator_uuid.map().await?,
-            participants_uuid: request.participants_uuid.map().await?,
+            creator_uuid,
+            participants_uuid: vec![creator_uuid],
             title: request.title.to_owned(),
             description: request.description.to_owned(),
             cover_url: request.cover_url.to_owned(),
@@ -34,11 +89,12 @@ impl MatchesRepository for Conn {
             created_at: request.created_at,
             updated_at: request.updated_at,
             expires_at: request.expires_at,
+            decision_method: request.decision_method.into(),
         };
-        self.add_match(match_entity)
+        self.add_match(match_entity, invites, candidates)
             .await
-            .map_err(|value| value.into())
-            .map(|value| value.into())
+            .map_err(|value| -> MatchesDataError { value.into() })?
+            .try_into()
     }
     async fn get_current_match<'a>(
         &self,
@@ -53,18 +109,22 @@ impl MatchesRepository for Conn {
             .get_match(match_uuid.to_string())
             .await
             .map_err(|_| MatchesDataError::MatchesNotFound)?;
-        if match_entity.participants_uuid.contains(&user.id).not() {
+        let is_member = match_entity.creator_uuid == user.id
+            || match_entity.participants_uuid.contains(&user.id);
+        if is_member.not() {
             println!("User not found in match");
             Result::Err(MatchesDataError::NoPermission)
         } else {
-            Result::Ok(match_entity.into())
+            match_entity.try_into()
         }
     }
 
     async fn get_matches<'a>(
         &self,
         request: PagingDomainRequest<'a>,
+        filter: MatchesDataFilter,
     ) -> Result<PagingDomainResponse<MatchesData>, MatchesDataError> {
+        filter.validate()?;
         let is_permitted = if request.user_uuid == request.request_uuid {
             true
         } else {
@@ -78,16 +138,20 @@ impl MatchesRepository for Conn {
             return Result::Err(MatchesDataError::NoPermission);
         }
 
-        MatchesDatabase::get_matches(self, request)
+        let response = MatchesDatabase::get_matches(self, request, filter.into())
             .await
-            .map(|response| PagingDomainResponse {
-                page: response.page,
-                page_size: response.page_size,
-                total: response.total,
-                has_more: response.has_more,
-                result: response.result.into_iter().map(|v| v.into()).collect(),
-            })
-            .map_err(|value| value.into())
+            .map_err(|value| -> MatchesDataError { value.into() })?;
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result: response
+                .result
+                .into_iter()
+                .map(MatchesData::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
     }
 
     async fn get_match_count<'a>(
@@ -113,4 +177,369 @@ impl MatchesRepository for Conn {
             .map(|value| value)
             .map_err(|value| value.into())
     }
+
+    async fn transition_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        transition: MatchTransition,
+    ) -> Result<MatchesData, MatchesDataError> {
+        let current = self.get_current_match(request_uuid, match_uuid).await?;
+        if transition.is_creator_only() && current.creator_uuid.to_string() != request_uuid {
+            return Result::Err(MatchesDataError::NoPermission);
+        }
+        transition.apply(current.status)?;
+
+        let now = chrono::Utc::now().timestamp_millis();
+        if transition == MatchTransition::Expire && current.expires_at > now {
+            return Result::Err(MatchesDataError::InvalidTransition);
+        }
+
+        let from_statuses = transition
+            .allowed_from()
+            .into_iter()
+            .map(|status| status.into())
+            .collect();
+        if transition == MatchTransition::Complete && current.decision_method.uses_ballots() {
+            // completing a ballot match early settles it with the ballots cast so far
+            let (result, _) = self.tally_match(&current).await.map_err(|err| {
+                println!("Match tally error: {:?}", err);
+                MatchesDataError::InternalError
+            })?;
+            return self
+                .complete_match(
+                    current.uuid,
+                    from_statuses,
+                    transition.target().into(),
+                    result.winner,
+                    now,
+                )
+                .await
+                .map_err(|value| -> MatchesDataError { value.into() })?
+                .ok_or(MatchesDataError::InvalidTransition)?
+                .try_into();
+        }
+        self.update_match_status(current.uuid, from_statuses, transition.target().into(), now)
+            .await
+            .map_err(|value| -> MatchesDataError { value.into() })?
+            .ok_or(MatchesDataError::InvalidTransition)?
+            .try_into()
+    }
+
+    async fn expire_overdue_matches(&self) -> Result<usize, MatchesDataError> {
+        let now = chrono::Utc::now().timestamp_millis();
+        MatchesDatabase::expire_overdue_matches(
+            self,
+            MatchStatus::open_status_names(),
+            MatchStatus::Expired.into(),
+            now,
+        )
+        .await
+        .map_err(|value| value.into())
+    }
+
+    async fn validate_invitees(
+        &self,
+        creator_uuid: Uuid,
+        participants_uuid: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, MatchesDataError> {
+        let mut invitees = participants_uuid;
+        invitees.retain(|uuid| *uuid != creator_uuid);
+        invitees.sort();
+        invitees.dedup();
+
+        let users = self
+            .get_users(&invitees)
+            .await
+            .map_err(|_| MatchesDataError::InternalError)?;
+        if users.len() != invitees.len() {
+            return Result::Err(MatchesDataError::ParticipantsNotFound);
+        }
+        let connected = self
+            .get_connected_users(&creator_uuid, &invitees)
+            .await
+            .map_err(|_| MatchesDataError::InternalError)?;
+        if invitees.iter().any(|uuid| !connected.contains(uuid)) {
+            return Result::Err(MatchesDataError::InviteNotAllowed);
+        }
+        Ok(invitees)
+    }
+
+    async fn get_seed_candidates(
+        &self,
+        users_uuid: Vec<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<MatchSeedCandidateData>, MatchesDataError> {
+        self.get_shared_favourites(users_uuid, FavouriteListVisibility::Public.into(), limit)
+            .await
+            .map(|favourites| favourites.into_iter().map(|v| v.into()).collect())
+            .map_err(|_| MatchesDataError::InternalError)
+    }
+
+    async fn preview_seed_candidates<'a>(
+        &self,
+        request_uuid: &'a str,
+        participants_uuid: Vec<&'a str>,
+        group_uuid: Option<&'a str>,
+        limit: i64,
+    ) -> Result<MatchSeedPreviewData, MatchesDataError> {
+        validate_seed_limit(limit)?;
+        let creator_uuid: Uuid = request_uuid.map().await?;
+        let participants_uuid =
+            expand_participants(self, request_uuid, participants_uuid, group_uuid).await?;
+        let invitees = self
+            .validate_invitees(creator_uuid, participants_uuid)
+            .await?;
+        let users_uuid = [vec![creator_uuid], invitees].concat();
+        let participants_count =
+            i64::try_from(users_uuid.len()).map_err(|_| MatchesDataError::InternalError)?;
+        let candidates = self.get_seed_candidates(users_uuid, limit).await?;
+        Ok(MatchSeedPreviewData {
+            participants_count,
+            candidates,
+        })
+    }
+
+    async fn update_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        update: MatchesDataUpdate<'a>,
+    ) -> Result<MatchesData, MatchesDataError> {
+        let current = get_created_match(self, request_uuid, match_uuid).await?;
+        update.validate_expiry(config::match_max_expiry_ms())?;
+        MatchesDatabase::update_match(
+            self,
+            current.uuid,
+            update.into(),
+            MatchStatus::open_status_names(),
+        )
+        .await
+        .map_err(|value| -> MatchesDataError { value.into() })?
+        .try_into()
+    }
+
+    async fn delete_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), MatchesDataError> {
+        let current = get_created_match(self, request_uuid, match_uuid).await?;
+        if !MatchStatus::open_statuses().contains(&current.status) {
+            return Result::Err(MatchesDataError::MatchClosed);
+        }
+        MatchesDatabase::delete_match(self, current.uuid)
+            .await
+            .map_err(|value| value.into())
+    }
+
+    async fn remove_participant<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError> {
+        let current = self.get_moderated_match(request_uuid, match_uuid).await?;
+        let user_uuid = Uuid::parse_str(user_uuid).map_err(|_| MatchesDataError::UuidInvalid)?;
+        if user_uuid == current.creator_uuid {
+            return Result::Err(MatchesDataError::CreatorCannotLeave);
+        }
+        // moderators remove members and viewers, other moderators are up to the owner
+        let is_owner = current.creator_uuid.to_string() == request_uuid;
+        if !is_owner
+            && user_uuid.to_string() != request_uuid
+            && self.get_participant_role(&current, user_uuid).await?
+                == MatchParticipantRole::Moderator
+        {
+            return Result::Err(MatchesDataError::NoPermission);
+        }
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        MatchesDatabase::remove_participant(
+            self,
+            current.uuid,
+            user_uuid,
+            MatchStatus::open_status_names(),
+            updated_at,
+        )
+        .await
+        .map_err(|value| -> MatchesDataError { value.into() })?
+        .try_into()
+    }
+
+    async fn leave_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), MatchesDataError> {
+        let current = self.get_current_match(request_uuid, match_uuid).await?;
+        let user_uuid = Uuid::parse_str(request_uuid).map_err(|_| MatchesDataError::UuidInvalid)?;
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        if user_uuid == current.creator_uuid {
+            if !MatchStatus::open_statuses().contains(&current.status) {
+                return Result::Err(MatchesDataError::MatchClosed);
+            }
+            let participants: Vec<MatchParticipantData> = load_participant_roles(self, &current)
+                .await?
+                .into_iter()
+                .filter(|participant| participant.user_uuid != user_uuid)
+                .collect();
+            let successor =
+                pick_successor(&participants).ok_or(MatchesDataError::CreatorCannotLeave)?;
+            return MatchRolesDatabase::transfer_ownership(
+                self,
+                current.uuid,
+                user_uuid,
+                successor,
+                true,
+                updated_at,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|value| value.into());
+        }
+        MatchesDatabase::remove_participant(
+            self,
+            current.uuid,
+            user_uuid,
+            MatchStatus::open_status_names(),
+            updated_at,
+        )
+        .await
+        .map(|_| ())
+        .map_err(|value| value.into())
+    }
+
+    async fn get_moderated_match<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError> {
+        let current = self.get_current_match(request_uuid, match_uuid).await?;
+        let user_uuid = Uuid::parse_str(request_uuid).map_err(|_| MatchesDataError::UuidInvalid)?;
+        if !self
+            .get_participant_role(&current, user_uuid)
+            .await?
+            .can_moderate()
+        {
+            return Result::Err(MatchesDataError::NoPermission);
+        }
+        Ok(current)
+    }
+
+    async fn get_participant_role(
+        &self,
+        match_data: &MatchesData,
+        user_uuid: Uuid,
+    ) -> Result<MatchParticipantRole, MatchesDataError> {
+        if user_uuid == match_data.creator_uuid {
+            return Ok(MatchParticipantRole::Owner);
+        }
+        load_participant_roles(self, match_data)
+            .await?
+            .into_iter()
+            .find(|participant| participant.user_uuid == user_uuid)
+            .map(|participant| participant.role)
+            .ok_or(MatchesDataError::ParticipantNotFound)
+    }
+
+    async fn get_voters(&self, match_data: &MatchesData) -> Result<Vec<Uuid>, MatchesDataError> {
+        Ok(load_participant_roles(self, match_data)
+            .await?
+            .into_iter()
+            .filter(|participant| participant.role.can_vote())
+            .map(|participant| participant.user_uuid)
+            .collect())
+    }
+
+    async fn get_match_roles<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<MatchParticipantData>, MatchesDataError> {
+        let current = self.get_current_match(request_uuid, match_uuid).await?;
+        load_participant_roles(self, &current).await
+    }
+
+    async fn set_participant_role<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+        role: MatchParticipantRole,
+    ) -> Result<Vec<MatchParticipantData>, MatchesDataError> {
+        let current = get_created_match(self, request_uuid, match_uuid).await?;
+        let user_uuid = Uuid::parse_str(user_uuid).map_err(|_| MatchesDataError::UuidInvalid)?;
+        if role == MatchParticipantRole::Owner || user_uuid == current.creator_uuid {
+            return Result::Err(MatchesDataError::RoleInvalid);
+        }
+        let stored = match role {
+            MatchParticipantRole::Member => None,
+            role => Some(role.into()),
+        };
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        self.set_match_role(current.uuid, user_uuid, stored, updated_at)
+            .await?;
+        load_participant_roles(self, &current).await
+    }
+
+    async fn transfer_ownership<'a>(
+        &self,
+        request_uuid: &'a str,
+        match_uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<MatchesData, MatchesDataError> {
+        let current = get_created_match(self, request_uuid, match_uuid).await?;
+        let user_uuid = Uuid::parse_str(user_uuid).map_err(|_| MatchesDataError::UuidInvalid)?;
+        if user_uuid == current.creator_uuid {
+            return Result::Err(MatchesDataError::RoleInvalid);
+        }
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        MatchRolesDatabase::transfer_ownership(
+            self,
+            current.uuid,
+            current.creator_uuid,
+            user_uuid,
+            false,
+            updated_at,
+        )
+        .await?
+        .try_into()
+    }
+}
+
+/// Participants of the match with their resolved roles.
+async fn load_participant_roles(
+    conn: &Conn,
+    match_data: &MatchesData,
+) -> Result<Vec<MatchParticipantData>, MatchesDataError> {
+    let stored = MatchRolesDatabase::get_match_roles(conn, match_data.uuid).await?;
+    match_data.participant_roles(stored)
+}
+
+/// Adds the members of the creator's group, when given, to the requested participants.
+async fn expand_participants(
+    conn: &Conn,
+    creator_uuid: &str,
+    participants_uuid: Vec<&str>,
+    group_uuid: Option<&str>,
+) -> Result<Vec<Uuid>, MatchesDataError> {
+    let mut participants_uuid: Vec<Uuid> = participants_uuid.map().await?;
+    if let Some(group_uuid) = group_uuid {
+        let group = GroupsRepository::get_group(conn, creator_uuid, group_uuid).await?;
+        participants_uuid.extend(group.members_uuid);
+    }
+    Ok(participants_uuid)
+}
+
+/// Same as `get_current_match`, but only the creator passes.
+async fn get_created_match(
+    conn: &Conn,
+    request_uuid: &str,
+    match_uuid: &str,
+) -> Result<MatchesData, MatchesDataError> {
+    let current = conn.get_current_match(request_uuid, match_uuid).await?;
+    if current.creator_uuid.to_string() != request_uuid {
+        return Result::Err(MatchesDataError::NoPermission);
+    }
+    Ok(current)
 }
@@ -0,0 +1,336 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use uuid::Uuid;
+
+    use crate::data::{
+        database::{
+            match_roles::objects::MatchRoleEntity,
+            matches::{objects::MatchesEntityCreate, MatchesDatabase},
+            tests::database_test_utls::run_migration_get_conn,
+        },
+        repository::{
+            auth::{objects::RegistrationData, AuthRepository},
+            matches::{
+                objects::{
+                    pick_successor, validate_seed_limit, MatchDecisionMethod, MatchParticipantData,
+                    MatchParticipantRole, MatchRole, MatchSeedCandidateData, MatchSort,
+                    MatchStatus, MatchTransition, MatchesData, MatchesDataCreate, MatchesDataError,
+                    MatchesDataFilter, MatchesDataUpdate,
+                },
+                MatchesRepository,
+            },
+        },
+    };
+
+    #[test]
+    fn test_status_round_trip() {
+        let statuses = [
+            MatchStatus::Pending,
+            MatchStatus::Active,
+            MatchStatus::Expired,
+            MatchStatus::Completed,
+            MatchStatus::Cancelled,
+        ];
+        for status in statuses {
+            let value: String = status.into();
+            assert_eq!(MatchStatus::try_from(value).unwrap(), status);
+        }
+    }
+
+    #[test]
+    fn test_status_unknown_is_error() {
+        let result = MatchStatus::try_from("status".to_string());
+        assert!(matches!(result, Err(MatchesDataError::StatusInvalid)));
+    }
+
+    #[test]
+    fn test_transition_allowed() {
+        assert_eq!(
+            MatchTransition::Start.apply(MatchStatus::Pending).unwrap(),
+            MatchStatus::Active
+        );
+        assert_eq!(
+            MatchTransition::Complete
+                .apply(MatchStatus::Active)
+                .unwrap(),
+            MatchStatus::Completed
+        );
+        assert_eq!(
+            MatchTransition::Cancel.apply(MatchStatus::Pending).unwrap(),
+            MatchStatus::Cancelled
+        );
+        assert_eq!(
+            MatchTransition::Expire.apply(MatchStatus::Active).unwrap(),
+            MatchStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_transition_rejected() {
+        let rejected = [
+            (MatchTransition::Start, MatchStatus::Active),
+            (MatchTransition::Start, MatchStatus::Completed),
+            (MatchTransition::Complete, MatchStatus::Cancelled),
+            (MatchTransition::Cancel, MatchStatus::Expired),
+            (MatchTransition::Expire, MatchStatus::Completed),
+        ];
+        for (transition, status) in rejected {
+            assert!(matches!(
+                transition.apply(status),
+                Err(MatchesDataError::InvalidTransition)
+            ));
+        }
+    }
+
+    fn match_create(created_at: i64, expires_at: i64) -> MatchesDataCreate<'static> {
+        MatchesDataCreate {
+            creator_uuid: "creator",
+            participants_uuid: Vec::new(),
+            group_uuid: None,
+            title: "title",
+            description: "description",
+            cover_url: "url",
+            created_at,
+            expires_at,
+            updated_at: created_at,
+            decision_method: MatchDecisionMethod::Unanimous,
+            seed_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_expiry() {
+        let max_expiry_ms = 1_000;
+        assert!(match_create(100, 101)
+            .validate_expiry(max_expiry_ms)
+            .is_ok());
+        assert!(match_create(100, 1_100)
+            .validate_expiry(max_expiry_ms)
+            .is_ok());
+        for expires_at in [0, 100, 1_101] {
+            assert!(matches!(
+                match_create(100, expires_at).validate_expiry(max_expiry_ms),
+                Err(MatchesDataError::ExpiresAtInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_update_expiry() {
+        let update = |expires_at: Option<i64>| MatchesDataUpdate {
+            title: None,
+            description: None,
+            cover_url: None,
+            expires_at,
+            updated_at: 100,
+        };
+        assert!(update(None).validate_expiry(1_000).is_ok());
+        assert!(update(Some(500)).validate_expiry(1_000).is_ok());
+        assert!(matches!(
+            update(Some(50)).validate_expiry(1_000),
+            Err(MatchesDataError::ExpiresAtInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_validate_seed_limit() {
+        assert!(validate_seed_limit(1).is_ok());
+        assert!(validate_seed_limit(100).is_ok());
+        for limit in [0, -1, 101] {
+            assert!(matches!(
+                validate_seed_limit(limit),
+                Err(MatchesDataError::SeedLimitInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_seed_candidate_keeps_rank_order() {
+        let creator = Uuid::new_v4();
+        let seed = MatchSeedCandidateData {
+            reference_uuid: Uuid::new_v4(),
+            title: "title".to_string(),
+            overlap: 2,
+        };
+        let candidate = seed.clone().into_candidate(creator, 1_000, 3);
+        assert_eq!(candidate.reference_uuid, seed.reference_uuid);
+        assert_eq!(candidate.created_by, creator);
+        assert_eq!(candidate.created_at, 1_003);
+        assert_eq!(candidate.metadata, Some("{\"overlap\":2}".to_string()));
+    }
+
+    #[test]
+    fn test_filter_rejects_reversed_ranges() {
+        assert!(MatchesDataFilter::default().validate().is_ok());
+        let bounded = MatchesDataFilter {
+            created_from: Some(10),
+            created_to: Some(10),
+            expires_from: Some(5),
+            ..Default::default()
+        };
+        assert!(bounded.validate().is_ok());
+        for filter in [
+            MatchesDataFilter {
+                created_from: Some(11),
+                created_to: Some(10),
+                ..Default::default()
+            },
+            MatchesDataFilter {
+                expires_from: Some(11),
+                expires_to: Some(10),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                filter.validate(),
+                Err(MatchesDataError::FilterInvalid)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_filter_role_and_sort_values() {
+        assert_eq!(MatchRole::try_from("creator").unwrap(), MatchRole::Creator);
+        assert_eq!(MatchRole::try_from("invited").unwrap(), MatchRole::Invited);
+        assert_eq!(
+            MatchSort::try_from("expiring").unwrap(),
+            MatchSort::ExpiringSoon
+        );
+        assert_eq!(MatchSort::try_from("newest").unwrap(), MatchSort::Newest);
+        assert_eq!(MatchSort::default(), MatchSort::RecentlyUpdated);
+        assert!(MatchRole::try_from("owner").is_err());
+        assert!(MatchSort::try_from("oldest").is_err());
+    }
+
+    #[test]
+    fn test_participant_role_values() {
+        let roles = [
+            MatchParticipantRole::Owner,
+            MatchParticipantRole::Moderator,
+            MatchParticipantRole::Member,
+            MatchParticipantRole::Viewer,
+        ];
+        for role in roles {
+            let value: String = role.into();
+            assert_eq!(
+                MatchParticipantRole::try_from(value.as_str()).unwrap(),
+                role
+            );
+        }
+        assert!(matches!(
+            MatchParticipantRole::try_from("admin"),
+            Err(MatchesDataError::RoleInvalid)
+        ));
+        assert!(MatchParticipantRole::Moderator.can_moderate());
+        assert!(!MatchParticipantRole::Member.can_moderate());
+        assert!(MatchParticipantRole::Moderator.can_vote());
+        assert!(!MatchParticipantRole::Viewer.can_vote());
+    }
+
+    #[test]
+    fn test_participant_roles_and_successor() {
+        let owner = Uuid::new_v4();
+        let member = Uuid::new_v4();
+        let viewer = Uuid::new_v4();
+        let moderator = Uuid::new_v4();
+        let match_data = MatchesData {
+            uuid: Uuid::new_v4(),
+            creator_uuid: owner,
+            participants_uuid: vec![owner, member, viewer, moderator],
+            title: "title".to_string(),
+            description: "description".to_string(),
+            status: MatchStatus::Pending,
+            cover_url: "url".to_string(),
+            expires_at: 2,
+            updated_at: 1,
+            created_at: 1,
+            winner_candidate_uuid: None,
+            decision_method: MatchDecisionMethod::Unanimous,
+        };
+        let stored = |user_uuid: Uuid, role: &str| MatchRoleEntity {
+            uuid: Uuid::new_v4(),
+            match_uuid: match_data.uuid,
+            user_uuid,
+            role: role.to_string(),
+            created_at: 1,
+            updated_at: 1,
+        };
+        let participants = match_data
+            .participant_roles(vec![
+                stored(moderator, "moderator"),
+                stored(viewer, "viewer"),
+            ])
+            .unwrap();
+        let roles: Vec<MatchParticipantRole> = participants.iter().map(|p| p.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                MatchParticipantRole::Owner,
+                MatchParticipantRole::Member,
+                MatchParticipantRole::Viewer,
+                MatchParticipantRole::Moderator,
+            ]
+        );
+
+        let others: Vec<MatchParticipantData> = participants
+            .into_iter()
+            .filter(|participant| participant.user_uuid != owner)
+            .collect();
+        assert_eq!(pick_successor(&others), Some(moderator));
+        assert_eq!(pick_successor(&others[..2]), Some(member));
+        assert_eq!(pick_successor(&others[1..2]), Some(viewer));
+        assert_eq!(pick_successor(&[]), None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_match_only_open() {
+        let connection = run_migration_get_conn().await.unwrap();
+
+        env::set_var("JWT_ACCESS_SECRET", "JWT_ACCESS_SECRET");
+        env::set_var("JWT_REFRESH_SECRET", "JWT_REFRESH_SECRET");
+
+        let creator_data = RegistrationData {
+            login: "test_login_delete_match",
+            username: "username_delete_match",
+            password: "test_passwd_delete_match",
+        };
+        let creator = connection.registration(&creator_data).await.unwrap().uuid;
+        let creator_uuid = Uuid::parse_str(&creator).unwrap();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let create = |status: &str| MatchesEntityCreate {
+            creator_uuid,
+            participants_uuid: vec![creator_uuid],
+            title: "title".to_string(),
+            description: "description".to_string(),
+            cover_url: "url".to_string(),
+            status: status.to_string(),
+            created_at: now,
+            updated_at: now,
+            expires_at: now + 60_000,
+            decision_method: "unanimous".to_string(),
+        };
+        let open = connection
+            .add_match(create("active"), Vec::new(), Vec::new())
+            .await
+            .unwrap();
+        let completed = connection
+            .add_match(create("completed"), Vec::new(), Vec::new())
+            .await
+            .unwrap();
+
+        let rejected =
+            MatchesRepository::delete_match(&connection, &creator, &completed.uuid.to_string())
+                .await;
+        assert!(matches!(rejected, Err(MatchesDataError::MatchClosed)));
+        assert!(connection
+            .get_match(completed.uuid.to_string())
+            .await
+            .is_ok());
+
+        let deleted =
+            MatchesRepository::delete_match(&connection, &creator, &open.uuid.to_string()).await;
+        assert!(deleted.is_ok());
+    }
+}
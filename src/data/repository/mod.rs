@@ -1,6 +1,22 @@
 pub mod auth;
+pub mod compatibility;
 pub mod favourite;
+pub mod favourite_common;
+pub mod favourite_imports;
+pub mod favourite_lists;
+pub mod favourite_tags;
+pub mod favourite_trends;
 pub mod follow;
+pub mod groups;
+pub mod item_ratings;
+pub mod items;
+pub mod match_ballots;
+pub mod match_candidates;
+pub mod match_comments;
+pub mod match_invites;
+pub mod match_join_codes;
+pub mod match_votes;
 pub mod matches;
 pub mod objects;
+pub mod recommendations;
 pub mod user;
@@ -0,0 +1,19 @@
+use self::objects::{RecommendationData, RecommendationDataError};
+
+use super::objects::{PagingDomainRequest, PagingDomainResponse};
+
+pub mod objects;
+mod repository;
+mod tests;
+
+#[async_trait]
+pub trait RecommendationsRepository {
+    /// Items `request.request_uuid` has not favourited, ranked by how many of the users
+    /// they follow favourited or picked them in completed matches, weighted by
+    /// compatibility, and by how close they are to the requester's own favourites.
+    /// `request.query` filters by title.
+    async fn get_recommendations<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<RecommendationData>, RecommendationDataError>;
+}
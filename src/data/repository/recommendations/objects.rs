@@ -0,0 +1,57 @@
+use uuid::Uuid;
+
+use crate::data::{
+    database::recommendations::objects::{RecommendationDbError, RecommendationEntity},
+    repository::items::objects::ItemData,
+};
+
+/// Weight of a followed user the requester has nothing to compare with yet.
+pub const RECOMMENDATION_UNSCORED_WEIGHT: f64 = 0.5;
+
+pub struct RecommendationData {
+    pub item_uuid: Uuid,
+    pub title: String,
+    pub score: f64,
+    /// Followed users who favourited the item.
+    pub liked_count: i64,
+    /// Followed users who picked the item in a completed match.
+    pub picked_count: i64,
+    /// Title of the requester's favourite the item is most often kept along with.
+    pub similar_to: Option<String>,
+    pub item: Option<ItemData>,
+}
+
+#[derive(Debug)]
+pub enum RecommendationDataError {
+    UuidInvalid,
+    InternalError,
+}
+
+/// Compatibility score from 0 to 100 as a weight from 0 to 1.
+pub fn compatibility_weight(score: Option<i64>) -> f64 {
+    score
+        .map(|score| score as f64 / 100.0)
+        .unwrap_or(RECOMMENDATION_UNSCORED_WEIGHT)
+}
+
+impl From<RecommendationEntity> for RecommendationData {
+    fn from(entity: RecommendationEntity) -> Self {
+        RecommendationData {
+            item_uuid: entity.item_uuid,
+            title: entity.title,
+            score: entity.score,
+            liked_count: entity.liked_count,
+            picked_count: entity.picked_count,
+            similar_to: entity.similar_to,
+            item: None,
+        }
+    }
+}
+
+impl From<RecommendationDbError> for RecommendationDataError {
+    fn from(error: RecommendationDbError) -> Self {
+        match error {
+            RecommendationDbError::InternalError => RecommendationDataError::InternalError,
+        }
+    }
+}
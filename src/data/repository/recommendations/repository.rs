@@ -0,0 +1,93 @@
+use uuid::Uuid;
+
+use crate::{
+    data::{
+        database::{follow::FollowDatabase, recommendations::RecommendationsDatabase},
+        repository::{
+            compatibility::CompatibilityRepository,
+            favourite_lists::objects::FavouriteListVisibility,
+            items::ItemsRepository,
+            matches::objects::MatchStatus,
+            objects::{PagingDomainRequest, PagingDomainResponse},
+        },
+    },
+    Conn,
+};
+
+use super::{
+    objects::{compatibility_weight, RecommendationData, RecommendationDataError},
+    RecommendationsRepository,
+};
+
+#[async_trait]
+impl RecommendationsRepository for Conn {
+    async fn get_recommendations<'a>(
+        &self,
+        request: &'a PagingDomainRequest<'a>,
+    ) -> Result<PagingDomainResponse<RecommendationData>, RecommendationDataError> {
+        let request_uuid = Uuid::parse_str(request.request_uuid)
+            .map_err(|_| RecommendationDataError::UuidInvalid)?;
+        let following: Vec<Uuid> = self
+            .get_all_following(&request_uuid)
+            .await
+            .map_err(|_| RecommendationDataError::InternalError)?
+            .into_iter()
+            .map(|follower| follower.followed_uuid)
+            .collect();
+        let compatibilities = self
+            .get_compatibilities(request_uuid, following.to_owned())
+            .await
+            .map_err(|_| RecommendationDataError::InternalError)?;
+        let weights: Vec<(Uuid, f64)> = following
+            .into_iter()
+            .map(|user_uuid| {
+                let score = compatibilities
+                    .iter()
+                    .find(|compatibility| compatibility.user_uuid == user_uuid)
+                    .and_then(|compatibility| compatibility.score);
+                (user_uuid, compatibility_weight(score))
+            })
+            .collect();
+        let visibilities: Vec<String> = FavouriteListVisibility::visible(false, true)
+            .into_iter()
+            .map(|visibility| visibility.into())
+            .collect();
+        let response = RecommendationsDatabase::get_recommendations(
+            self,
+            request_uuid,
+            weights,
+            visibilities,
+            MatchStatus::Completed.into(),
+            request.query.to_owned(),
+            request.page,
+            request.page_size,
+        )
+        .await?;
+
+        let mut result: Vec<RecommendationData> =
+            response.result.into_iter().map(|v| v.into()).collect();
+        let items = ItemsRepository::get_items(
+            self,
+            result
+                .iter()
+                .map(|recommendation| recommendation.item_uuid)
+                .collect(),
+        )
+        .await
+        .map_err(|_| RecommendationDataError::InternalError)?;
+        for recommendation in result.iter_mut() {
+            recommendation.item = items
+                .iter()
+                .find(|item| item.uuid == recommendation.item_uuid)
+                .cloned();
+        }
+
+        Ok(PagingDomainResponse {
+            page: response.page,
+            page_size: response.page_size,
+            total: response.total,
+            has_more: response.has_more,
+            result,
+        })
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(test)]
+mod tests {
+    use crate::data::repository::recommendations::objects::{
+        compatibility_weight, RECOMMENDATION_UNSCORED_WEIGHT,
+    };
+
+    #[test]
+    fn test_compatibility_weight() {
+        assert_eq!(compatibility_weight(Some(80)), 0.8);
+        assert_eq!(compatibility_weight(Some(0)), 0.0);
+        assert_eq!(compatibility_weight(None), RECOMMENDATION_UNSCORED_WEIGHT);
+    }
+}
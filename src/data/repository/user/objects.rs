@@ -60,6 +60,7 @@ pub enum UserCreateDataError {
 #[derive(Debug)]
 pub enum UserSearchError {
     UuidInvalid,
+    FilterInvalid,
     InternalError,
 }
 
@@ -0,0 +1,48 @@
+use rocket::{futures::future::BoxFuture, Orbit, Rocket};
+
+use crate::{
+    config,
+    data::{catalog::fixture::FixtureCatalogProvider, repository::items::ItemsRepository},
+    Conn,
+};
+
+/// Periodically syncs the item catalogue from the fixture at `CATALOG_FIXTURE_PATH`.
+pub fn run_catalog_sync(rocket: &Rocket<Orbit>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let provider = match config::catalog_fixture_path() {
+            Some(path) => FixtureCatalogProvider::new(path),
+            None => {
+                println!("Catalog sync not started: no catalog fixture configured");
+                return;
+            }
+        };
+        let pool = match Conn::pool(rocket) {
+            Some(pool) => pool.clone(),
+            None => {
+                println!("Catalog sync not started: database pool is unavailable");
+                return;
+            }
+        };
+        let period = config::catalog_sync_interval();
+        rocket::tokio::spawn(async move {
+            let mut interval = rocket::tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                let conn = match pool.get().await {
+                    Some(connection) => Conn(connection),
+                    None => {
+                        println!("Catalog sync skipped: no database connection");
+                        continue;
+                    }
+                };
+                match conn.sync_catalog(&provider).await {
+                    Ok(sync) => println!(
+                        "Synced {} catalog items, skipped {}",
+                        sync.synced, sync.skipped
+                    ),
+                    Err(err) => println!("Catalog sync error: {:?}", err),
+                }
+            }
+        });
+    })
+}
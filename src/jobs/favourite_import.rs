@@ -0,0 +1,47 @@
+use rocket::{futures::future::BoxFuture, Orbit, Rocket};
+
+use crate::{config, data::repository::favourite_imports::FavouriteImportsRepository, Conn};
+
+/// Periodically imports the queued favourite files, oldest first.
+pub fn run_favourite_import(rocket: &Rocket<Orbit>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let pool = match Conn::pool(rocket) {
+            Some(pool) => pool.clone(),
+            None => {
+                println!("Favourite import not started: database pool is unavailable");
+                return;
+            }
+        };
+        let period = config::favourite_import_interval();
+        rocket::tokio::spawn(async move {
+            let mut interval = rocket::tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                let conn = match pool.get().await {
+                    Some(connection) => Conn(connection),
+                    None => {
+                        println!("Favourite import skipped: no database connection");
+                        continue;
+                    }
+                };
+                loop {
+                    let current_time_ms = chrono::Utc::now().timestamp_millis();
+                    match conn.process_pending_import(current_time_ms).await {
+                        Ok(Some(import)) => println!(
+                            "Imported favourites {}: {} imported, {} duplicates, {} unmatched",
+                            import.uuid,
+                            import.imported_count,
+                            import.duplicates_count,
+                            import.unmatched_count
+                        ),
+                        Ok(None) => break,
+                        Err(err) => {
+                            println!("Favourite import error: {:?}", err);
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    })
+}
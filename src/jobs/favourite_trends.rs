@@ -0,0 +1,33 @@
+use rocket::{futures::future::BoxFuture, Orbit, Rocket};
+
+use crate::{config, data::repository::favourite_trends::FavouriteTrendsRepository, Conn};
+
+/// Periodically refreshes the trending favourites aggregate.
+pub fn run_favourite_trends(rocket: &Rocket<Orbit>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let pool = match Conn::pool(rocket) {
+            Some(pool) => pool.clone(),
+            None => {
+                println!("Favourite trends not started: database pool is unavailable");
+                return;
+            }
+        };
+        let period = config::favourite_trends_interval();
+        rocket::tokio::spawn(async move {
+            let mut interval = rocket::tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                let conn = match pool.get().await {
+                    Some(connection) => Conn(connection),
+                    None => {
+                        println!("Favourite trends skipped: no database connection");
+                        continue;
+                    }
+                };
+                if let Err(err) = conn.refresh_trends().await {
+                    println!("Favourite trends error: {:?}", err);
+                }
+            }
+        });
+    })
+}
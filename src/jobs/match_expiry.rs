@@ -0,0 +1,35 @@
+use rocket::{futures::future::BoxFuture, Orbit, Rocket};
+
+use crate::{config, data::repository::matches::MatchesRepository, Conn};
+
+/// Periodically moves overdue `Pending`/`Active` matches to `Expired`.
+pub fn run_match_expiry(rocket: &Rocket<Orbit>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let pool = match Conn::pool(rocket) {
+            Some(pool) => pool.clone(),
+            None => {
+                println!("Match expiry not started: database pool is unavailable");
+                return;
+            }
+        };
+        let period = config::match_expiry_interval();
+        rocket::tokio::spawn(async move {
+            let mut interval = rocket::tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                let conn = match pool.get().await {
+                    Some(connection) => Conn(connection),
+                    None => {
+                        println!("Match expiry skipped: no database connection");
+                        continue;
+                    }
+                };
+                match conn.expire_overdue_matches().await {
+                    Ok(0) => {}
+                    Ok(count) => println!("Expired {} overdue matches", count),
+                    Err(err) => println!("Match expiry error: {:?}", err),
+                }
+            }
+        });
+    })
+}
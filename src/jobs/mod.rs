@@ -0,0 +1,4 @@
+pub mod catalog_sync;
+pub mod favourite_import;
+pub mod favourite_trends;
+pub mod match_expiry;
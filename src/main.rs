@@ -5,12 +5,17 @@ extern crate rocket;
 
 use crate::presenter::routes::RoutesInitialized;
 use config::run_db_migrations;
+use jobs::{
+    catalog_sync::run_catalog_sync, favourite_import::run_favourite_import,
+    favourite_trends::run_favourite_trends, match_expiry::run_match_expiry,
+};
 use presenter::catcher::AppCatcher;
 use rocket_sync_db_pools::database;
 
 use rocket::{fairing::AdHoc, Build, Rocket};
 mod config;
 mod data;
+mod jobs;
 mod presenter;
 mod schema;
 pub mod utils;
@@ -20,6 +25,10 @@ fn launch() -> Rocket<Build> {
     rocket::custom(config::from_env())
         .attach(Conn::fairing())
         .attach(AdHoc::on_ignite("Database Migrations", run_db_migrations))
+        .attach(AdHoc::on_liftoff("Match Expiry", run_match_expiry))
+        .attach(AdHoc::on_liftoff("Catalog Sync", run_catalog_sync))
+        .attach(AdHoc::on_liftoff("Favourite Import", run_favourite_import))
+        .attach(AdHoc::on_liftoff("Favourite Trends", run_favourite_trends))
         .mount_catcher()
         .mount_routes()
 }
@@ -0,0 +1,48 @@
+use crate::{
+    data::repository::{compatibility::CompatibilityRepository, objects::PagingDomainRequest},
+    presenter::handlers::objects::{request::PagingRequest, response::PagingResponse},
+    Conn,
+};
+
+use super::{
+    objects::{UserCompatibilityError, UserCompatibilityFriendResponse, UserCompatibilityResponse},
+    CompatibilityHandler,
+};
+
+#[async_trait]
+impl CompatibilityHandler for Conn {
+    async fn get_compatibility<'a>(
+        &self,
+        uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<UserCompatibilityResponse, UserCompatibilityError> {
+        CompatibilityRepository::get_compatibility(self, uuid, user_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_compatibility_ranking<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserCompatibilityFriendResponse>, UserCompatibilityError> {
+        let request = PagingDomainRequest {
+            user_uuid: uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        CompatibilityRepository::get_compatibility_ranking(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+}
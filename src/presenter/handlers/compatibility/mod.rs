@@ -0,0 +1,21 @@
+use self::objects::{
+    UserCompatibilityError, UserCompatibilityFriendResponse, UserCompatibilityResponse,
+};
+use super::objects::{request::PagingRequest, response::PagingResponse};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait CompatibilityHandler {
+    async fn get_compatibility<'a>(
+        &self,
+        uuid: &'a str,
+        user_uuid: &'a str,
+    ) -> Result<UserCompatibilityResponse, UserCompatibilityError>;
+
+    async fn get_compatibility_ranking<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserCompatibilityFriendResponse>, UserCompatibilityError>;
+}
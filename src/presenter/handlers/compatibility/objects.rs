@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+use crate::data::repository::compatibility::objects::{
+    CompatibilityData, CompatibilityDataError, CompatibilityFriendData,
+};
+
+/// `score` is 0 to 100 and `null` until the users voted in a shared completed match
+/// or saved any favourites.
+#[derive(Serialize)]
+pub struct UserCompatibilityResponse {
+    pub user_uuid: String,
+    pub score: Option<i64>,
+    pub shared_matches: i64,
+    pub compared_votes: i64,
+    pub agreed_votes: i64,
+    pub shared_favourites: i64,
+    pub union_favourites: i64,
+    pub computed_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct UserCompatibilityFriendResponse {
+    pub username: String,
+    pub avatar_url: String,
+    #[serde(flatten)]
+    pub compatibility: UserCompatibilityResponse,
+}
+
+#[derive(Debug)]
+pub enum UserCompatibilityError {
+    UuidInvalid,
+    UserNotFound,
+    SelfCompatibility,
+    InternalError,
+}
+
+impl From<CompatibilityData> for UserCompatibilityResponse {
+    fn from(data: CompatibilityData) -> Self {
+        UserCompatibilityResponse {
+            user_uuid: data.user_uuid.to_string(),
+            score: data.score,
+            shared_matches: data.shared_matches,
+            compared_votes: data.compared_votes,
+            agreed_votes: data.agreed_votes,
+            shared_favourites: data.shared_favourites,
+            union_favourites: data.union_favourites,
+            computed_at: data.computed_at,
+        }
+    }
+}
+
+impl From<CompatibilityFriendData> for UserCompatibilityFriendResponse {
+    fn from(data: CompatibilityFriendData) -> Self {
+        UserCompatibilityFriendResponse {
+            username: data.username,
+            avatar_url: data.avatar_url,
+            compatibility: data.compatibility.into(),
+        }
+    }
+}
+
+impl From<CompatibilityDataError> for UserCompatibilityError {
+    fn from(error: CompatibilityDataError) -> Self {
+        match error {
+            CompatibilityDataError::UuidInvalid => UserCompatibilityError::UuidInvalid,
+            CompatibilityDataError::UserNotFound => UserCompatibilityError::UserNotFound,
+            CompatibilityDataError::SelfCompatibility => UserCompatibilityError::SelfCompatibility,
+            CompatibilityDataError::InternalError => UserCompatibilityError::InternalError,
+        }
+    }
+}
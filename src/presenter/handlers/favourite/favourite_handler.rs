@@ -7,7 +7,7 @@ use crate::{
     Conn,
 };
 
-use super::FavouriteHandler;
+use super::{response::FavouriteStatusResponse, FavouriteHandler};
 
 #[async_trait]
 impl FavouriteHandler for Conn {
@@ -46,4 +46,28 @@ impl FavouriteHandler for Conn {
             Err(err) => ApiResponse::Err(Mapper::map(&err).await),
         }
     }
+
+    async fn get_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> ApiResponse<'static, Json<FavouriteStatusResponse>> {
+        match FavouriteRepository::get_favourite_status(self, uuid, favourite_uuid).await {
+            Ok(status) => ApiResponse::Ok(Json(status.into())),
+            Err(err) => ApiResponse::Err(Mapper::map(&err).await),
+        }
+    }
+
+    async fn update_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        status: &'a str,
+    ) -> ApiResponse<'static, Json<FavouriteStatusResponse>> {
+        match FavouriteRepository::update_favourite_status(self, uuid, favourite_uuid, status).await
+        {
+            Ok(status) => ApiResponse::Ok(Json(status.into())),
+            Err(err) => ApiResponse::Err(Mapper::map(&err).await),
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use rocket::serde::json::Json;
 
+use self::response::FavouriteStatusResponse;
+
 use super::objects::response::{ApiMessageResponse, ApiResponse, BooleanResponse};
 
 mod favourite_handler;
@@ -26,4 +28,17 @@ pub trait FavouriteHandler {
         uuid: &'a str,
         favourite_uuid: &'a str,
     ) -> ApiResponse<'static, Json<BooleanResponse>>;
+
+    async fn get_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> ApiResponse<'static, Json<FavouriteStatusResponse>>;
+
+    async fn update_favourite_status<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        status: &'a str,
+    ) -> ApiResponse<'static, Json<FavouriteStatusResponse>>;
 }
@@ -10,3 +10,21 @@ pub struct FavouriteAddBody<'a> {
     pub favourite_uuid: &'a str,
     pub title: &'a str,
 }
+
+/// Paging of a user's favourites, `status` keeps only the ones with that status and
+/// `tag`, which may repeat, the ones carrying every given tag.
+#[derive(FromForm)]
+pub struct FavouritesRequest<'a> {
+    pub uuid: &'a str,
+    pub query: &'a str,
+    pub status: Option<&'a str>,
+    pub tag: Vec<&'a str>,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+/// `status` is one of `want`, `in_progress`, `done` or `dropped`.
+#[derive(Deserialize)]
+pub struct FavouriteStatusBody<'a> {
+    pub status: &'a str,
+}
@@ -1,8 +1,14 @@
+use serde::Serialize;
+
 use crate::{
-    data::repository::favourite::objects::FavouriteDataError,
+    data::repository::favourite::objects::{
+        FavouriteDataError, FavouriteStatusCountData, FavouriteStatusData,
+        FavouriteStatusTransitionData,
+    },
     presenter::handlers::objects::response::{
-        ErrorResponse, ERROR_FAVOURITE_CONFLICT, ERROR_FAVOURITE_USER_NOT_FOUND,
-        ERROR_FAVOURITE_UUID_INVALID, ERROR_UNKNOWN,
+        ErrorResponse, ERROR_FAVOURITE_CONFLICT, ERROR_FAVOURITE_NOT_FOUND,
+        ERROR_FAVOURITE_ORDER_INVALID, ERROR_FAVOURITE_STATUS_INVALID,
+        ERROR_FAVOURITE_USER_NOT_FOUND, ERROR_FAVOURITE_UUID_INVALID, ERROR_UNKNOWN,
     },
     utils::Mapper,
 };
@@ -15,7 +21,67 @@ impl Mapper<&'static ErrorResponse<'static>> for FavouriteDataError {
             FavouriteDataError::UuidInvalid => ERROR_FAVOURITE_UUID_INVALID,
             FavouriteDataError::UserNotFound => ERROR_FAVOURITE_USER_NOT_FOUND,
             FavouriteDataError::Conflict => ERROR_FAVOURITE_CONFLICT,
+            FavouriteDataError::EntryNotFound => ERROR_FAVOURITE_NOT_FOUND,
+            FavouriteDataError::OrderInvalid => ERROR_FAVOURITE_ORDER_INVALID,
+            FavouriteDataError::StatusInvalid => ERROR_FAVOURITE_STATUS_INVALID,
             FavouriteDataError::InternalError => ERROR_UNKNOWN,
         }
     }
 }
+
+#[derive(Serialize)]
+pub struct FavouriteStatusResponse {
+    pub uuid: String,
+    pub status: String,
+    pub updated_at: i64,
+    /// Status changes, oldest first.
+    pub transitions: Vec<FavouriteStatusTransitionResponse>,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteStatusTransitionResponse {
+    pub from_status: String,
+    pub to_status: String,
+    pub created_at: i64,
+}
+
+/// Amount of favourites per status.
+#[derive(Serialize, Default)]
+pub struct FavouriteStatusCountResponse {
+    pub want: i64,
+    pub in_progress: i64,
+    pub done: i64,
+    pub dropped: i64,
+}
+
+impl From<FavouriteStatusTransitionData> for FavouriteStatusTransitionResponse {
+    fn from(data: FavouriteStatusTransitionData) -> Self {
+        FavouriteStatusTransitionResponse {
+            from_status: data.from_status.into(),
+            to_status: data.to_status.into(),
+            created_at: data.created_at,
+        }
+    }
+}
+
+impl From<FavouriteStatusData> for FavouriteStatusResponse {
+    fn from(data: FavouriteStatusData) -> Self {
+        FavouriteStatusResponse {
+            uuid: data.favourite_uuid.to_string(),
+            status: data.status.into(),
+            updated_at: data.updated_at,
+            transitions: data.transitions.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<FavouriteStatusCountData> for FavouriteStatusCountResponse {
+    fn from(data: FavouriteStatusCountData) -> Self {
+        FavouriteStatusCountResponse {
+            want: data.want,
+            in_progress: data.in_progress,
+            done: data.done,
+            dropped: data.dropped,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    data::repository::favourite_common::{
+        objects::FavouriteCommonRequest, FavouriteCommonRepository,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteCommonResponse, UserFavouriteCommonError, UserFavouriteCommonRequest,
+        FAVOURITE_COMMON_SCOPE_DEFAULT,
+    },
+    FavouriteCommonHandler,
+};
+
+#[async_trait]
+impl FavouriteCommonHandler for Conn {
+    async fn get_common_favourites<'a>(
+        &self,
+        uuid: &'a str,
+        user_uuid: &'a str,
+        params: UserFavouriteCommonRequest<'a>,
+    ) -> Result<FavouriteCommonResponse, UserFavouriteCommonError> {
+        let request = FavouriteCommonRequest {
+            request_uuid: uuid,
+            user_uuid,
+            scope: params.scope.unwrap_or(FAVOURITE_COMMON_SCOPE_DEFAULT),
+            page: params.page,
+            page_size: params.page_size,
+        };
+        FavouriteCommonRepository::get_common_favourites(self, &request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
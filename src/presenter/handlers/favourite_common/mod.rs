@@ -0,0 +1,15 @@
+use self::objects::{
+    FavouriteCommonResponse, UserFavouriteCommonError, UserFavouriteCommonRequest,
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait FavouriteCommonHandler {
+    async fn get_common_favourites<'a>(
+        &self,
+        uuid: &'a str,
+        user_uuid: &'a str,
+        params: UserFavouriteCommonRequest<'a>,
+    ) -> Result<FavouriteCommonResponse, UserFavouriteCommonError>;
+}
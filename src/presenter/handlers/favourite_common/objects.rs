@@ -0,0 +1,92 @@
+use serde::Serialize;
+
+use crate::{
+    data::repository::{
+        favourite::objects::FavouriteDataResponse,
+        favourite_common::objects::{FavouriteCommonData, FavouriteCommonDataError},
+    },
+    presenter::handlers::{items::objects::ItemResponse, objects::response::PagingResponse},
+};
+
+pub const FAVOURITE_COMMON_SCOPE_DEFAULT: &str = "common";
+
+/// `scope` is one of `common` (default), `mine` or `theirs`.
+#[derive(FromForm)]
+pub struct UserFavouriteCommonRequest<'a> {
+    pub scope: Option<&'a str>,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteCommonResponse {
+    /// Favourites both users have.
+    pub common_count: i64,
+    /// Favourites only the requester has.
+    pub mine_count: i64,
+    /// Favourites only the other user has.
+    pub theirs_count: i64,
+    pub jaccard: f64,
+    pub favourites: PagingResponse<FavouriteCommonItemResponse>,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteCommonItemResponse {
+    pub uuid: String,
+    pub title: String,
+    pub item: Option<ItemResponse>,
+}
+
+#[derive(Debug)]
+pub enum UserFavouriteCommonError {
+    UuidInvalid,
+    UserNotFound,
+    SelfCommon,
+    ScopeInvalid,
+    InternalError,
+}
+
+impl From<FavouriteDataResponse> for FavouriteCommonItemResponse {
+    fn from(data: FavouriteDataResponse) -> Self {
+        FavouriteCommonItemResponse {
+            uuid: data.favourite_uuid.to_string(),
+            title: data.title,
+            item: data.item.map(|item| item.into()),
+        }
+    }
+}
+
+impl From<FavouriteCommonData> for FavouriteCommonResponse {
+    fn from(data: FavouriteCommonData) -> Self {
+        FavouriteCommonResponse {
+            common_count: data.counts.common_count,
+            mine_count: data.counts.mine_count,
+            theirs_count: data.counts.theirs_count,
+            jaccard: data.counts.jaccard(),
+            favourites: PagingResponse {
+                page: data.favourites.page,
+                page_size: data.favourites.page_size,
+                total: data.favourites.total,
+                has_more: data.favourites.has_more,
+                result: data
+                    .favourites
+                    .result
+                    .into_iter()
+                    .map(|v| v.into())
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<FavouriteCommonDataError> for UserFavouriteCommonError {
+    fn from(error: FavouriteCommonDataError) -> Self {
+        match error {
+            FavouriteCommonDataError::UuidInvalid => UserFavouriteCommonError::UuidInvalid,
+            FavouriteCommonDataError::UserNotFound => UserFavouriteCommonError::UserNotFound,
+            FavouriteCommonDataError::SelfCommon => UserFavouriteCommonError::SelfCommon,
+            FavouriteCommonDataError::ScopeInvalid => UserFavouriteCommonError::ScopeInvalid,
+            FavouriteCommonDataError::InternalError => UserFavouriteCommonError::InternalError,
+        }
+    }
+}
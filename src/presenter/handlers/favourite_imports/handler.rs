@@ -0,0 +1,74 @@
+use crate::{
+    data::repository::{
+        favourite_imports::{objects::FavouriteImportDataCreate, FavouriteImportsRepository},
+        favourite_lists::objects::DEFAULT_FAVOURITE_LIST,
+    },
+    presenter::handlers::objects::response::PagingResponse,
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteImportResponse, FavouriteImportRowResponse, UserFavouriteImportError,
+        UserFavouriteImportReportRequest,
+    },
+    FavouriteImportsHandler,
+};
+
+#[async_trait]
+impl FavouriteImportsHandler for Conn {
+    async fn create_import<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: Option<&'a str>,
+        format: &'a str,
+        content: String,
+    ) -> Result<FavouriteImportResponse, UserFavouriteImportError> {
+        let request = FavouriteImportDataCreate {
+            request_uuid: uuid,
+            list_uuid: list_uuid.unwrap_or(DEFAULT_FAVOURITE_LIST),
+            format,
+            content,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        FavouriteImportsRepository::create_import(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_import<'a>(
+        &self,
+        uuid: &'a str,
+        import_uuid: &'a str,
+    ) -> Result<FavouriteImportResponse, UserFavouriteImportError> {
+        FavouriteImportsRepository::get_import(self, uuid, import_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_import_report<'a>(
+        &self,
+        uuid: &'a str,
+        import_uuid: &'a str,
+        params: UserFavouriteImportReportRequest,
+    ) -> Result<PagingResponse<FavouriteImportRowResponse>, UserFavouriteImportError> {
+        FavouriteImportsRepository::get_import_rows(
+            self,
+            uuid,
+            import_uuid,
+            params.page,
+            params.page_size,
+        )
+        .await
+        .map(|response| PagingResponse {
+            page: response.page,
+            total: response.total,
+            has_more: response.has_more,
+            page_size: response.page_size,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        })
+        .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,31 @@
+use self::objects::{
+    FavouriteImportResponse, FavouriteImportRowResponse, UserFavouriteImportError,
+    UserFavouriteImportReportRequest,
+};
+use super::objects::response::PagingResponse;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait FavouriteImportsHandler {
+    async fn create_import<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: Option<&'a str>,
+        format: &'a str,
+        content: String,
+    ) -> Result<FavouriteImportResponse, UserFavouriteImportError>;
+
+    async fn get_import<'a>(
+        &self,
+        uuid: &'a str,
+        import_uuid: &'a str,
+    ) -> Result<FavouriteImportResponse, UserFavouriteImportError>;
+
+    async fn get_import_report<'a>(
+        &self,
+        uuid: &'a str,
+        import_uuid: &'a str,
+        params: UserFavouriteImportReportRequest,
+    ) -> Result<PagingResponse<FavouriteImportRowResponse>, UserFavouriteImportError>;
+}
@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::data::repository::favourite_imports::objects::{
+    FavouriteImportData, FavouriteImportDataError, FavouriteImportRowData,
+};
+
+#[derive(FromForm)]
+pub struct UserFavouriteImportReportRequest {
+    pub page: i64,
+    pub page_size: i64,
+}
+
+/// `status` is one of `pending`, `running`, `done` or `failed`, the counts are filled in
+/// once the import is done.
+#[derive(Serialize)]
+pub struct FavouriteImportResponse {
+    pub uuid: String,
+    pub list_uuid: Option<String>,
+    pub format: String,
+    pub status: String,
+    pub rows_count: i32,
+    pub imported_count: i32,
+    pub duplicates_count: i32,
+    pub unmatched_count: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// A reported row, `reason` is one of `empty`, `not_found`, `rating_invalid` or
+/// `date_invalid`. Only the first two leave the row out of the import.
+#[derive(Serialize)]
+pub struct FavouriteImportRowResponse {
+    pub row_number: i32,
+    pub title: Option<String>,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug)]
+pub enum UserFavouriteImportError {
+    UuidInvalid,
+    ImportUuidInvalid,
+    ImportNotFound,
+    ListUuidInvalid,
+    ListNotFound,
+    FormatInvalid,
+    ContentInvalid,
+    ContentTooLarge,
+    InternalError,
+}
+
+impl From<FavouriteImportData> for FavouriteImportResponse {
+    fn from(data: FavouriteImportData) -> Self {
+        FavouriteImportResponse {
+            uuid: data.uuid.to_string(),
+            list_uuid: data.list_uuid.map(|list_uuid| list_uuid.to_string()),
+            format: data.format,
+            status: data.status,
+            rows_count: data.rows_count,
+            imported_count: data.imported_count,
+            duplicates_count: data.duplicates_count,
+            unmatched_count: data.unmatched_count,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<FavouriteImportRowData> for FavouriteImportRowResponse {
+    fn from(data: FavouriteImportRowData) -> Self {
+        FavouriteImportRowResponse {
+            row_number: data.row_number,
+            title: data.title,
+            external_id: data.external_id,
+            reason: data.reason,
+        }
+    }
+}
+
+impl From<FavouriteImportDataError> for UserFavouriteImportError {
+    fn from(error: FavouriteImportDataError) -> Self {
+        match error {
+            FavouriteImportDataError::UuidInvalid => UserFavouriteImportError::UuidInvalid,
+            FavouriteImportDataError::ImportUuidInvalid => {
+                UserFavouriteImportError::ImportUuidInvalid
+            }
+            FavouriteImportDataError::ImportNotFound => UserFavouriteImportError::ImportNotFound,
+            FavouriteImportDataError::ListUuidInvalid => UserFavouriteImportError::ListUuidInvalid,
+            FavouriteImportDataError::ListNotFound => UserFavouriteImportError::ListNotFound,
+            FavouriteImportDataError::FormatInvalid => UserFavouriteImportError::FormatInvalid,
+            FavouriteImportDataError::ContentInvalid => UserFavouriteImportError::ContentInvalid,
+            FavouriteImportDataError::ContentTooLarge => UserFavouriteImportError::ContentTooLarge,
+            FavouriteImportDataError::InternalError => UserFavouriteImportError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    data::repository::{
+        favourite_lists::{
+            objects::{
+                FavouriteListDataCreate, FavouriteListDataUpdate, FavouriteListEntriesRequest,
+            },
+            FavouriteListsRepository,
+        },
+        objects::PagingDomainRequest,
+    },
+    presenter::handlers::objects::{
+        request::{PagingRequest, PagingUuidRequest},
+        response::PagingResponse,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteListEntryResponse, FavouriteListResponse, UserFavouriteListCreateRequest,
+        UserFavouriteListError, UserFavouriteListMoveRequest, UserFavouriteListOrderRequest,
+        UserFavouriteListPinRequest, UserFavouriteListUpdateRequest,
+    },
+    FavouriteListsHandler,
+};
+
+#[async_trait]
+impl FavouriteListsHandler for Conn {
+    async fn create_list<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteListCreateRequest,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError> {
+        let request = FavouriteListDataCreate {
+            request_uuid: uuid,
+            title: &params.title,
+            visibility: params.visibility.as_deref(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        FavouriteListsRepository::create_list(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_lists<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingUuidRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteListResponse>, UserFavouriteListError> {
+        let request = PagingDomainRequest {
+            user_uuid: params.uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        FavouriteListsRepository::get_lists(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn get_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError> {
+        FavouriteListsRepository::get_list(self, uuid, list_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn update_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListUpdateRequest,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError> {
+        let update = FavouriteListDataUpdate {
+            title: params.title.as_deref(),
+            visibility: params.visibility.as_deref(),
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        FavouriteListsRepository::update_list(self, uuid, list_uuid, update)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn delete_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<(), UserFavouriteListError> {
+        FavouriteListsRepository::delete_list(self, uuid, list_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_list_entries<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteListEntryResponse>, UserFavouriteListError> {
+        let request = FavouriteListEntriesRequest {
+            request_uuid: uuid,
+            list_uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        FavouriteListsRepository::get_list_entries(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn add_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        title: &'a str,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError> {
+        FavouriteListsRepository::add_list_entry(self, uuid, list_uuid, favourite_uuid, title)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn remove_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<(), UserFavouriteListError> {
+        FavouriteListsRepository::remove_list_entry(self, uuid, list_uuid, favourite_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn reorder_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListOrderRequest<'a>,
+    ) -> Result<(), UserFavouriteListError> {
+        FavouriteListsRepository::reorder_list(self, uuid, list_uuid, params.favourites_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn move_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListMoveRequest<'a>,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError> {
+        FavouriteListsRepository::move_list_entry(
+            self,
+            uuid,
+            list_uuid,
+            params.favourite_uuid,
+            params.to_list_uuid,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+
+    async fn pin_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListPinRequest<'a>,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError> {
+        FavouriteListsRepository::pin_list_entry(
+            self,
+            uuid,
+            list_uuid,
+            params.favourite_uuid,
+            params.pinned,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,88 @@
+use self::objects::{
+    FavouriteListEntryResponse, FavouriteListResponse, UserFavouriteListCreateRequest,
+    UserFavouriteListError, UserFavouriteListMoveRequest, UserFavouriteListOrderRequest,
+    UserFavouriteListPinRequest, UserFavouriteListUpdateRequest,
+};
+use super::objects::{
+    request::{PagingRequest, PagingUuidRequest},
+    response::PagingResponse,
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait FavouriteListsHandler {
+    async fn create_list<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteListCreateRequest,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError>;
+
+    async fn get_lists<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingUuidRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteListResponse>, UserFavouriteListError>;
+
+    async fn get_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError>;
+
+    async fn update_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListUpdateRequest,
+    ) -> Result<FavouriteListResponse, UserFavouriteListError>;
+
+    async fn delete_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+    ) -> Result<(), UserFavouriteListError>;
+
+    async fn get_list_entries<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteListEntryResponse>, UserFavouriteListError>;
+
+    async fn add_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+        title: &'a str,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError>;
+
+    async fn remove_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        favourite_uuid: &'a str,
+    ) -> Result<(), UserFavouriteListError>;
+
+    async fn reorder_list<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListOrderRequest<'a>,
+    ) -> Result<(), UserFavouriteListError>;
+
+    async fn move_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListMoveRequest<'a>,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError>;
+
+    async fn pin_list_entry<'a>(
+        &self,
+        uuid: &'a str,
+        list_uuid: &'a str,
+        params: UserFavouriteListPinRequest<'a>,
+    ) -> Result<FavouriteListEntryResponse, UserFavouriteListError>;
+}
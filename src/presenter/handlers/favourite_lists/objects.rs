@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::repository::{
+        favourite::objects::FavouriteDataResponse,
+        favourite_lists::objects::{FavouriteListData, FavouriteListDataError},
+    },
+    presenter::handlers::items::objects::ItemResponse,
+};
+
+/// `visibility` is one of `public` (default), `followers` or `private`.
+#[derive(Deserialize)]
+pub struct UserFavouriteListCreateRequest {
+    pub title: String,
+    pub visibility: Option<String>,
+}
+
+/// Omitted fields are left unchanged.
+#[derive(Deserialize)]
+pub struct UserFavouriteListUpdateRequest {
+    pub title: Option<String>,
+    pub visibility: Option<String>,
+}
+
+/// Every entry of the list in its new order.
+#[derive(Deserialize)]
+pub struct UserFavouriteListOrderRequest<'a> {
+    #[serde(borrow)]
+    pub favourites_uuid: Vec<&'a str>,
+}
+
+/// `to_list_uuid` may be `default` to move the entry out of any named list.
+#[derive(Deserialize)]
+pub struct UserFavouriteListMoveRequest<'a> {
+    pub favourite_uuid: &'a str,
+    pub to_list_uuid: &'a str,
+}
+
+#[derive(Deserialize)]
+pub struct UserFavouriteListPinRequest<'a> {
+    pub favourite_uuid: &'a str,
+    pub pinned: bool,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteListResponse {
+    pub uuid: String,
+    pub owner_uuid: String,
+    pub title: String,
+    pub visibility: String,
+    pub entries_count: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteListEntryResponse {
+    pub uuid: String,
+    pub title: String,
+    pub position: i64,
+    pub pinned: bool,
+    pub status: String,
+    pub status_updated_at: i64,
+    pub tags: Vec<String>,
+    /// Private note, only set when the owner lists their own entries.
+    pub note: Option<String>,
+    pub item: Option<ItemResponse>,
+    pub rating: Option<i16>,
+    pub review: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum UserFavouriteListError {
+    UuidInvalid,
+    ListUuidInvalid,
+    ListNotFound,
+    TitleInvalid,
+    VisibilityInvalid,
+    EntryNotFound,
+    Conflict,
+    OrderInvalid,
+    InternalError,
+}
+
+impl From<FavouriteListData> for FavouriteListResponse {
+    fn from(data: FavouriteListData) -> Self {
+        FavouriteListResponse {
+            uuid: data.uuid.to_string(),
+            owner_uuid: data.owner_uuid.to_string(),
+            title: data.title,
+            visibility: data.visibility,
+            entries_count: data.entries_count,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<FavouriteDataResponse> for FavouriteListEntryResponse {
+    fn from(data: FavouriteDataResponse) -> Self {
+        FavouriteListEntryResponse {
+            uuid: data.favourite_uuid.to_string(),
+            title: data.title,
+            position: data.position,
+            pinned: data.pinned,
+            status: data.status.into(),
+            status_updated_at: data.status_updated_at,
+            tags: data.tags,
+            note: data.note,
+            item: data.item.map(|item| item.into()),
+            rating: data.rating.as_ref().map(|rating| rating.rating),
+            review: data.rating.and_then(|rating| rating.review),
+        }
+    }
+}
+
+impl From<FavouriteListDataError> for UserFavouriteListError {
+    fn from(error: FavouriteListDataError) -> Self {
+        match error {
+            FavouriteListDataError::UuidInvalid => UserFavouriteListError::UuidInvalid,
+            FavouriteListDataError::ListUuidInvalid => UserFavouriteListError::ListUuidInvalid,
+            FavouriteListDataError::ListNotFound => UserFavouriteListError::ListNotFound,
+            FavouriteListDataError::TitleInvalid => UserFavouriteListError::TitleInvalid,
+            FavouriteListDataError::VisibilityInvalid => UserFavouriteListError::VisibilityInvalid,
+            FavouriteListDataError::EntryNotFound => UserFavouriteListError::EntryNotFound,
+            FavouriteListDataError::Conflict => UserFavouriteListError::Conflict,
+            FavouriteListDataError::OrderInvalid => UserFavouriteListError::OrderInvalid,
+            FavouriteListDataError::InternalError => UserFavouriteListError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    data::repository::favourite_tags::{objects::FavouriteTagsDataSet, FavouriteTagsRepository},
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteNoteResponse, FavouriteTagCountResponse, FavouriteTagsResponse,
+        UserFavouriteNoteRequest, UserFavouriteTagError, UserFavouriteTagsRequest,
+        UserFavouriteTagsSuggestRequest,
+    },
+    FavouriteTagsHandler,
+};
+
+#[async_trait]
+impl FavouriteTagsHandler for Conn {
+    async fn set_favourite_tags<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        body: UserFavouriteTagsRequest,
+    ) -> Result<FavouriteTagsResponse, UserFavouriteTagError> {
+        let request = FavouriteTagsDataSet {
+            request_uuid: uuid,
+            favourite_uuid,
+            tags: body.tags.iter().map(String::as_str).collect(),
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        FavouriteTagsRepository::set_favourite_tags(self, request)
+            .await
+            .map(|tags| FavouriteTagsResponse {
+                uuid: favourite_uuid.to_owned(),
+                tags,
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn get_user_tags<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTagsSuggestRequest<'a>,
+    ) -> Result<Vec<FavouriteTagCountResponse>, UserFavouriteTagError> {
+        FavouriteTagsRepository::get_user_tags(
+            self,
+            uuid,
+            params.query.unwrap_or_default(),
+            params.limit,
+        )
+        .await
+        .map(|tags| tags.into_iter().map(|v| v.into()).collect())
+        .map_err(|e| e.into())
+    }
+
+    async fn set_favourite_note<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        body: UserFavouriteNoteRequest,
+    ) -> Result<FavouriteNoteResponse, UserFavouriteTagError> {
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        FavouriteTagsRepository::set_favourite_note(
+            self,
+            uuid,
+            favourite_uuid,
+            body.note.as_deref(),
+            updated_at,
+        )
+        .await
+        .map(|note| FavouriteNoteResponse {
+            uuid: favourite_uuid.to_owned(),
+            note: note.as_ref().map(|note| note.note.to_owned()),
+            updated_at: note.map_or(updated_at, |note| note.updated_at),
+        })
+        .map_err(|e| e.into())
+    }
+}
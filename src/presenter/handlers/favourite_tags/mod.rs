@@ -0,0 +1,30 @@
+use self::objects::{
+    FavouriteNoteResponse, FavouriteTagCountResponse, FavouriteTagsResponse,
+    UserFavouriteNoteRequest, UserFavouriteTagError, UserFavouriteTagsRequest,
+    UserFavouriteTagsSuggestRequest,
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait FavouriteTagsHandler {
+    async fn set_favourite_tags<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        body: UserFavouriteTagsRequest,
+    ) -> Result<FavouriteTagsResponse, UserFavouriteTagError>;
+
+    async fn get_user_tags<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTagsSuggestRequest<'a>,
+    ) -> Result<Vec<FavouriteTagCountResponse>, UserFavouriteTagError>;
+
+    async fn set_favourite_note<'a>(
+        &self,
+        uuid: &'a str,
+        favourite_uuid: &'a str,
+        body: UserFavouriteNoteRequest,
+    ) -> Result<FavouriteNoteResponse, UserFavouriteTagError>;
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::favourite_tags::objects::{
+    FavouriteTagCountData, FavouriteTagDataError,
+};
+
+/// Replaces every tag of the favourite, an empty `tags` clears them.
+#[derive(Deserialize)]
+pub struct UserFavouriteTagsRequest {
+    pub tags: Vec<String>,
+}
+
+/// A blank or missing `note` removes it.
+#[derive(Deserialize)]
+pub struct UserFavouriteNoteRequest {
+    pub note: Option<String>,
+}
+
+#[derive(FromForm)]
+pub struct UserFavouriteTagsSuggestRequest<'a> {
+    pub query: Option<&'a str>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteTagsResponse {
+    pub uuid: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteTagCountResponse {
+    pub tag: String,
+    pub favourites_count: i64,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteNoteResponse {
+    pub uuid: String,
+    pub note: Option<String>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum UserFavouriteTagError {
+    UuidInvalid,
+    EntryNotFound,
+    TagInvalid,
+    NoteInvalid,
+    InternalError,
+}
+
+impl From<FavouriteTagCountData> for FavouriteTagCountResponse {
+    fn from(data: FavouriteTagCountData) -> Self {
+        FavouriteTagCountResponse {
+            tag: data.tag,
+            favourites_count: data.favourites_count,
+        }
+    }
+}
+
+impl From<FavouriteTagDataError> for UserFavouriteTagError {
+    fn from(error: FavouriteTagDataError) -> Self {
+        match error {
+            FavouriteTagDataError::UuidInvalid => UserFavouriteTagError::UuidInvalid,
+            FavouriteTagDataError::EntryNotFound => UserFavouriteTagError::EntryNotFound,
+            FavouriteTagDataError::TagInvalid => UserFavouriteTagError::TagInvalid,
+            FavouriteTagDataError::NoteInvalid => UserFavouriteTagError::NoteInvalid,
+            FavouriteTagDataError::InternalError => UserFavouriteTagError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    data::repository::{
+        favourite_trends::{
+            objects::{FavouriteTrendData, FavouriteTrendsRequest},
+            FavouriteTrendsRepository,
+        },
+        objects::PagingDomainResponse,
+    },
+    presenter::handlers::objects::response::PagingResponse,
+    Conn,
+};
+
+use super::{
+    objects::{
+        FavouriteTrendResponse, UserFavouriteTrendError, UserFavouriteTrendsRequest,
+        FAVOURITE_TREND_PERIOD_DEFAULT,
+    },
+    FavouriteTrendsHandler,
+};
+
+#[async_trait]
+impl FavouriteTrendsHandler for Conn {
+    async fn get_trends<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTrendsRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteTrendResponse>, UserFavouriteTrendError> {
+        let request = FavouriteTrendsRequest {
+            request_uuid: uuid,
+            period: params.period.unwrap_or(FAVOURITE_TREND_PERIOD_DEFAULT),
+            page: params.page,
+            page_size: params.page_size,
+        };
+        FavouriteTrendsRepository::get_trends(self, &request)
+            .await
+            .map(to_paging_response)
+            .map_err(|e| e.into())
+    }
+
+    async fn get_following_trends<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTrendsRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteTrendResponse>, UserFavouriteTrendError> {
+        let request = FavouriteTrendsRequest {
+            request_uuid: uuid,
+            period: params.period.unwrap_or(FAVOURITE_TREND_PERIOD_DEFAULT),
+            page: params.page,
+            page_size: params.page_size,
+        };
+        FavouriteTrendsRepository::get_following_trends(self, &request)
+            .await
+            .map(to_paging_response)
+            .map_err(|e| e.into())
+    }
+}
+
+fn to_paging_response(
+    response: PagingDomainResponse<FavouriteTrendData>,
+) -> PagingResponse<FavouriteTrendResponse> {
+    PagingResponse {
+        page: response.page,
+        total: response.total,
+        has_more: response.has_more,
+        page_size: response.page_size,
+        result: response.result.into_iter().map(|v| v.into()).collect(),
+    }
+}
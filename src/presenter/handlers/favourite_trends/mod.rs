@@ -0,0 +1,19 @@
+use self::objects::{FavouriteTrendResponse, UserFavouriteTrendError, UserFavouriteTrendsRequest};
+use super::objects::response::PagingResponse;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait FavouriteTrendsHandler {
+    async fn get_trends<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTrendsRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteTrendResponse>, UserFavouriteTrendError>;
+
+    async fn get_following_trends<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserFavouriteTrendsRequest<'a>,
+    ) -> Result<PagingResponse<FavouriteTrendResponse>, UserFavouriteTrendError>;
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::{
+    data::repository::favourite_trends::objects::{FavouriteTrendData, FavouriteTrendDataError},
+    presenter::handlers::items::objects::ItemResponse,
+};
+
+pub const FAVOURITE_TREND_PERIOD_DEFAULT: &str = "week";
+
+/// `period` is one of `day`, `week` (default) or `all`.
+#[derive(FromForm)]
+pub struct UserFavouriteTrendsRequest<'a> {
+    pub period: Option<&'a str>,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Serialize)]
+pub struct FavouriteTrendResponse {
+    pub uuid: String,
+    pub title: String,
+    pub favourites_count: i64,
+    pub item: Option<ItemResponse>,
+    /// When the counts were last computed.
+    pub refreshed_at: i64,
+}
+
+#[derive(Debug)]
+pub enum UserFavouriteTrendError {
+    UuidInvalid,
+    PeriodInvalid,
+    InternalError,
+}
+
+impl From<FavouriteTrendData> for FavouriteTrendResponse {
+    fn from(data: FavouriteTrendData) -> Self {
+        FavouriteTrendResponse {
+            uuid: data.item_uuid.to_string(),
+            title: data.title,
+            favourites_count: data.favourites_count,
+            item: data.item.map(|item| item.into()),
+            refreshed_at: data.refreshed_at,
+        }
+    }
+}
+
+impl From<FavouriteTrendDataError> for UserFavouriteTrendError {
+    fn from(error: FavouriteTrendDataError) -> Self {
+        match error {
+            FavouriteTrendDataError::UuidInvalid => UserFavouriteTrendError::UuidInvalid,
+            FavouriteTrendDataError::PeriodInvalid => UserFavouriteTrendError::PeriodInvalid,
+            FavouriteTrendDataError::InternalError => UserFavouriteTrendError::InternalError,
+        }
+    }
+}
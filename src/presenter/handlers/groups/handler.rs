@@ -0,0 +1,98 @@
+use crate::{
+    data::repository::{
+        groups::{
+            objects::{GroupDataCreate, GroupDataUpdate},
+            GroupsRepository,
+        },
+        objects::PagingDomainRequest,
+    },
+    presenter::handlers::objects::{request::PagingRequest, response::PagingResponse},
+    Conn,
+};
+
+use super::{
+    objects::{UserGroupCreateRequest, UserGroupError, UserGroupResponse, UserGroupUpdateRequest},
+    GroupsHandler,
+};
+
+#[async_trait]
+impl GroupsHandler for Conn {
+    async fn create_group<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserGroupCreateRequest<'a>,
+    ) -> Result<UserGroupResponse, UserGroupError> {
+        let request = GroupDataCreate {
+            request_uuid: uuid,
+            title: &params.title,
+            members_uuid: params.members_uuid,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        GroupsRepository::create_group(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<UserGroupResponse, UserGroupError> {
+        GroupsRepository::get_group(self, uuid, group_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_groups<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserGroupResponse>, UserGroupError> {
+        let request = PagingDomainRequest {
+            user_uuid: uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        GroupsRepository::get_groups(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn update_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+        params: UserGroupUpdateRequest<'a>,
+    ) -> Result<UserGroupResponse, UserGroupError> {
+        let update = GroupDataUpdate {
+            title: params.title.as_deref(),
+            members_uuid: params.members_uuid,
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        GroupsRepository::update_group(self, uuid, group_uuid, update)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn delete_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<(), UserGroupError> {
+        GroupsRepository::delete_group(self, uuid, group_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+}
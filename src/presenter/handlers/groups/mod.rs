@@ -0,0 +1,40 @@
+use self::objects::{
+    UserGroupCreateRequest, UserGroupError, UserGroupResponse, UserGroupUpdateRequest,
+};
+use super::objects::{request::PagingRequest, response::PagingResponse};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait GroupsHandler {
+    async fn create_group<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserGroupCreateRequest<'a>,
+    ) -> Result<UserGroupResponse, UserGroupError>;
+
+    async fn get_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<UserGroupResponse, UserGroupError>;
+
+    async fn get_groups<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserGroupResponse>, UserGroupError>;
+
+    async fn update_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+        params: UserGroupUpdateRequest<'a>,
+    ) -> Result<UserGroupResponse, UserGroupError>;
+
+    async fn delete_group<'a>(
+        &self,
+        uuid: &'a str,
+        group_uuid: &'a str,
+    ) -> Result<(), UserGroupError>;
+}
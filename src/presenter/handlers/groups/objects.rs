@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::groups::objects::{GroupData, GroupDataError};
+
+#[derive(Deserialize)]
+pub struct UserGroupCreateRequest<'a> {
+    pub title: String,
+    #[serde(borrow)]
+    pub members_uuid: Vec<&'a str>,
+}
+
+/// Omitted fields are left unchanged, `members_uuid` replaces all members.
+#[derive(Deserialize)]
+pub struct UserGroupUpdateRequest<'a> {
+    pub title: Option<String>,
+    #[serde(borrow)]
+    pub members_uuid: Option<Vec<&'a str>>,
+}
+
+#[derive(Serialize)]
+pub struct UserGroupResponse {
+    pub uuid: String,
+    pub owner_uuid: String,
+    pub title: String,
+    pub members_uuid: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum UserGroupError {
+    UuidInvalid,
+    GroupNotFound,
+    TitleInvalid,
+    MembersNotFollowed,
+    MembersLimitExceeded,
+    InternalError,
+}
+
+impl From<GroupData> for UserGroupResponse {
+    fn from(data: GroupData) -> Self {
+        UserGroupResponse {
+            uuid: data.uuid.to_string(),
+            owner_uuid: data.owner_uuid.to_string(),
+            title: data.title,
+            members_uuid: data
+                .members_uuid
+                .iter()
+                .map(|uuid| uuid.to_string())
+                .collect(),
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<GroupDataError> for UserGroupError {
+    fn from(error: GroupDataError) -> Self {
+        match error {
+            GroupDataError::UuidInvalid => UserGroupError::UuidInvalid,
+            GroupDataError::GroupNotFound => UserGroupError::GroupNotFound,
+            GroupDataError::TitleInvalid => UserGroupError::TitleInvalid,
+            GroupDataError::MembersNotFollowed => UserGroupError::MembersNotFollowed,
+            GroupDataError::MembersLimitExceeded => UserGroupError::MembersLimitExceeded,
+            GroupDataError::InternalError => UserGroupError::InternalError,
+        }
+    }
+}
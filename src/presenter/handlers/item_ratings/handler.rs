@@ -0,0 +1,79 @@
+use crate::{
+    data::repository::item_ratings::{objects::ItemRatingDataSet, ItemRatingsRepository},
+    presenter::handlers::objects::response::PagingResponse,
+    Conn,
+};
+
+use super::{
+    objects::{
+        ItemRatingResponse, ItemRatingStatsResponse, ItemReviewResponse, UserItemRatingError,
+        UserItemRatingRequest, UserItemReviewsRequest,
+    },
+    ItemRatingsHandler,
+};
+
+#[async_trait]
+impl ItemRatingsHandler for Conn {
+    async fn set_rating<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+        body: UserItemRatingRequest,
+    ) -> Result<ItemRatingResponse, UserItemRatingError> {
+        let request = ItemRatingDataSet {
+            request_uuid: uuid,
+            item_uuid,
+            rating: body.rating,
+            review: body.review.as_deref(),
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        ItemRatingsRepository::set_rating(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn remove_rating<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+    ) -> Result<(), UserItemRatingError> {
+        ItemRatingsRepository::remove_rating(self, uuid, item_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_rating_stats<'a>(
+        &self,
+        item_uuid: &'a str,
+    ) -> Result<ItemRatingStatsResponse, UserItemRatingError> {
+        ItemRatingsRepository::get_rating_stats(self, item_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_followed_reviews<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+        params: UserItemReviewsRequest,
+    ) -> Result<PagingResponse<ItemReviewResponse>, UserItemRatingError> {
+        ItemRatingsRepository::get_followed_reviews(
+            self,
+            uuid,
+            item_uuid,
+            params.page,
+            params.page_size,
+        )
+        .await
+        .map(|response| PagingResponse {
+            page: response.page,
+            total: response.total,
+            has_more: response.has_more,
+            page_size: response.page_size,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        })
+        .map_err(|e| e.into())
+    }
+}
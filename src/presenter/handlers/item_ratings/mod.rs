@@ -0,0 +1,35 @@
+use self::objects::{
+    ItemRatingResponse, ItemRatingStatsResponse, ItemReviewResponse, UserItemRatingError,
+    UserItemRatingRequest, UserItemReviewsRequest,
+};
+use super::objects::response::PagingResponse;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait ItemRatingsHandler {
+    async fn set_rating<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+        body: UserItemRatingRequest,
+    ) -> Result<ItemRatingResponse, UserItemRatingError>;
+
+    async fn remove_rating<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+    ) -> Result<(), UserItemRatingError>;
+
+    async fn get_rating_stats<'a>(
+        &self,
+        item_uuid: &'a str,
+    ) -> Result<ItemRatingStatsResponse, UserItemRatingError>;
+
+    async fn get_followed_reviews<'a>(
+        &self,
+        uuid: &'a str,
+        item_uuid: &'a str,
+        params: UserItemReviewsRequest,
+    ) -> Result<PagingResponse<ItemReviewResponse>, UserItemRatingError>;
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::item_ratings::objects::{
+    ItemRatingData, ItemRatingDataError, ItemRatingStatsData, ItemReviewData,
+};
+
+/// `rating` goes from 1 to 10, `review` is optional and at most 500 characters.
+#[derive(Deserialize)]
+pub struct UserItemRatingRequest {
+    pub rating: i16,
+    pub review: Option<String>,
+}
+
+#[derive(FromForm)]
+pub struct UserItemReviewsRequest {
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Serialize)]
+pub struct ItemRatingResponse {
+    pub item_uuid: String,
+    pub rating: i16,
+    pub review: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct ItemRatingStatsResponse {
+    pub item_uuid: String,
+    pub ratings_count: i64,
+    pub average: Option<f64>,
+    /// Amount of ratings for each value from 1 to 10.
+    pub distribution: Vec<i64>,
+}
+
+#[derive(Serialize)]
+pub struct ItemReviewResponse {
+    pub user_uuid: String,
+    pub username: String,
+    pub avatar_url: String,
+    pub rating: i16,
+    pub review: Option<String>,
+    pub updated_at: i64,
+}
+
+#[derive(Debug)]
+pub enum UserItemRatingError {
+    UuidInvalid,
+    ItemNotFound,
+    RatingNotFound,
+    RatingInvalid,
+    ReviewInvalid,
+    InternalError,
+}
+
+impl From<ItemRatingData> for ItemRatingResponse {
+    fn from(data: ItemRatingData) -> Self {
+        ItemRatingResponse {
+            item_uuid: data.item_uuid.to_string(),
+            rating: data.rating,
+            review: data.review,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<ItemRatingStatsData> for ItemRatingStatsResponse {
+    fn from(data: ItemRatingStatsData) -> Self {
+        ItemRatingStatsResponse {
+            item_uuid: data.item_uuid.to_string(),
+            ratings_count: data.ratings_count,
+            average: data.average,
+            distribution: data.distribution,
+        }
+    }
+}
+
+impl From<ItemReviewData> for ItemReviewResponse {
+    fn from(data: ItemReviewData) -> Self {
+        ItemReviewResponse {
+            user_uuid: data.rating.user_uuid.to_string(),
+            username: data.username,
+            avatar_url: data.avatar_url,
+            rating: data.rating.rating,
+            review: data.rating.review,
+            updated_at: data.rating.updated_at,
+        }
+    }
+}
+
+impl From<ItemRatingDataError> for UserItemRatingError {
+    fn from(error: ItemRatingDataError) -> Self {
+        match error {
+            ItemRatingDataError::UuidInvalid => UserItemRatingError::UuidInvalid,
+            ItemRatingDataError::ItemNotFound => UserItemRatingError::ItemNotFound,
+            ItemRatingDataError::RatingNotFound => UserItemRatingError::RatingNotFound,
+            ItemRatingDataError::RatingInvalid => UserItemRatingError::RatingInvalid,
+            ItemRatingDataError::ReviewInvalid => UserItemRatingError::ReviewInvalid,
+            ItemRatingDataError::InternalError => UserItemRatingError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    data::repository::items::ItemsRepository,
+    presenter::handlers::objects::response::PagingResponse, Conn,
+};
+
+use super::{
+    objects::{ItemResponse, UserItemError, UserItemsRequest},
+    ItemsHandler,
+};
+
+#[async_trait]
+impl ItemsHandler for Conn {
+    async fn get_item<'a>(&self, item_uuid: &'a str) -> Result<ItemResponse, UserItemError> {
+        ItemsRepository::get_item(self, item_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn search_items<'a>(
+        &self,
+        params: UserItemsRequest<'a>,
+    ) -> Result<PagingResponse<ItemResponse>, UserItemError> {
+        ItemsRepository::search_items(
+            self,
+            params.query,
+            params.kind,
+            params.page,
+            params.page_size,
+        )
+        .await
+        .map(|response| PagingResponse {
+            page: response.page,
+            total: response.total,
+            has_more: response.has_more,
+            page_size: response.page_size,
+            result: response.result.into_iter().map(|v| v.into()).collect(),
+        })
+        .map_err(|e| e.into())
+    }
+}
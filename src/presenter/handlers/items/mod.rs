@@ -0,0 +1,14 @@
+use self::objects::{ItemResponse, UserItemError, UserItemsRequest};
+use super::objects::response::PagingResponse;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait ItemsHandler {
+    async fn get_item<'a>(&self, item_uuid: &'a str) -> Result<ItemResponse, UserItemError>;
+
+    async fn search_items<'a>(
+        &self,
+        params: UserItemsRequest<'a>,
+    ) -> Result<PagingResponse<ItemResponse>, UserItemError>;
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::data::repository::items::objects::{ItemData, ItemDataError};
+
+/// Catalogue search, `kind` narrows it to one of `movie`, `series`, `book`, `game`,
+/// `music` or `other`.
+#[derive(FromForm)]
+pub struct UserItemsRequest<'a> {
+    pub query: &'a str,
+    pub kind: Option<&'a str>,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Serialize)]
+pub struct ItemResponse {
+    pub uuid: String,
+    pub kind: String,
+    pub title: String,
+    pub year: Option<i32>,
+    pub image_url: Option<String>,
+    pub external_ids: BTreeMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum UserItemError {
+    UuidInvalid,
+    ItemNotFound,
+    KindInvalid,
+    InternalError,
+}
+
+impl From<ItemData> for ItemResponse {
+    fn from(data: ItemData) -> Self {
+        ItemResponse {
+            uuid: data.uuid.to_string(),
+            kind: data.kind,
+            title: data.title,
+            year: data.year,
+            image_url: data.image_url,
+            external_ids: data.external_ids,
+        }
+    }
+}
+
+impl From<ItemDataError> for UserItemError {
+    fn from(error: ItemDataError) -> Self {
+        match error {
+            ItemDataError::UuidInvalid => UserItemError::UuidInvalid,
+            ItemDataError::ItemNotFound => UserItemError::ItemNotFound,
+            ItemDataError::KindInvalid => UserItemError::KindInvalid,
+            ItemDataError::TitleInvalid
+            | ItemDataError::CatalogUnavailable
+            | ItemDataError::CatalogInvalid
+            | ItemDataError::InternalError => UserItemError::InternalError,
+        }
+    }
+}
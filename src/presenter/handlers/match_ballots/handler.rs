@@ -0,0 +1,42 @@
+use crate::{
+    data::repository::match_ballots::{objects::MatchBallotDataCreate, MatchBallotsRepository},
+    presenter::handlers::matches::objects::UserMatchError,
+    Conn,
+};
+
+use super::{
+    objects::{UserMatchBallotRequest, UserMatchBallotResponse, UserMatchResultsResponse},
+    MatchBallotsHandler,
+};
+
+#[async_trait]
+impl MatchBallotsHandler for Conn {
+    async fn submit_ballot<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchBallotRequest<'a>,
+    ) -> Result<UserMatchBallotResponse, UserMatchError> {
+        let ballot = MatchBallotDataCreate {
+            request_uuid: uuid,
+            match_uuid,
+            candidate_uuids: params.candidate_uuids,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchBallotsRepository::submit_ballot(self, ballot)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_results<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchResultsResponse, UserMatchError> {
+        MatchBallotsRepository::get_results(self, uuid, match_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,20 @@
+use self::objects::{UserMatchBallotRequest, UserMatchBallotResponse, UserMatchResultsResponse};
+use super::matches::objects::UserMatchError;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchBallotsHandler {
+    async fn submit_ballot<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchBallotRequest<'a>,
+    ) -> Result<UserMatchBallotResponse, UserMatchError>;
+
+    async fn get_results<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchResultsResponse, UserMatchError>;
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::match_ballots::{
+    objects::{MatchBallotData, MatchBallotDataError, MatchResultsData},
+    tally::{CandidateScore, TallyRound},
+};
+
+use super::super::matches::objects::UserMatchError;
+
+/// Approval ballots list the approved candidates in any order, ranked and Borda ballots
+/// list candidates from most to least preferred.
+#[derive(Deserialize)]
+pub struct UserMatchBallotRequest<'a> {
+    #[serde(borrow)]
+    pub candidate_uuids: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchBallotResponse {
+    pub match_uuid: String,
+    pub candidate_uuids: Vec<String>,
+    pub status: String,
+    pub winner_candidate_uuid: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchCandidateScoreResponse {
+    pub candidate_uuid: String,
+    pub score: i64,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchTallyRoundResponse {
+    pub scores: Vec<UserMatchCandidateScoreResponse>,
+    pub eliminated_candidate_uuid: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchResultsResponse {
+    pub match_uuid: String,
+    pub decision_method: String,
+    pub status: String,
+    pub ballots_count: usize,
+    pub winner_candidate_uuid: Option<String>,
+    pub rounds: Vec<UserMatchTallyRoundResponse>,
+}
+
+impl From<MatchBallotData> for UserMatchBallotResponse {
+    fn from(data: MatchBallotData) -> Self {
+        UserMatchBallotResponse {
+            match_uuid: data.match_uuid.to_string(),
+            candidate_uuids: data
+                .candidate_uuids
+                .iter()
+                .map(|uuid| uuid.to_string())
+                .collect(),
+            status: data.status.into(),
+            winner_candidate_uuid: data.winner_candidate_uuid.map(|uuid| uuid.to_string()),
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<CandidateScore> for UserMatchCandidateScoreResponse {
+    fn from(score: CandidateScore) -> Self {
+        UserMatchCandidateScoreResponse {
+            candidate_uuid: score.candidate_uuid.to_string(),
+            score: score.score,
+        }
+    }
+}
+
+impl From<TallyRound> for UserMatchTallyRoundResponse {
+    fn from(round: TallyRound) -> Self {
+        UserMatchTallyRoundResponse {
+            scores: round.scores.into_iter().map(|v| v.into()).collect(),
+            eliminated_candidate_uuid: round.eliminated.map(|uuid| uuid.to_string()),
+        }
+    }
+}
+
+impl From<MatchResultsData> for UserMatchResultsResponse {
+    fn from(data: MatchResultsData) -> Self {
+        UserMatchResultsResponse {
+            match_uuid: data.match_uuid.to_string(),
+            decision_method: data.decision_method.into(),
+            status: data.status.into(),
+            ballots_count: data.ballots_count,
+            winner_candidate_uuid: data.winner_candidate_uuid.map(|uuid| uuid.to_string()),
+            rounds: data.rounds.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<MatchBallotDataError> for UserMatchError {
+    fn from(error: MatchBallotDataError) -> Self {
+        match error {
+            MatchBallotDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchBallotDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchBallotDataError::NoPermission => UserMatchError::NoPermission,
+            MatchBallotDataError::CandidateNotFound => UserMatchError::CandidateNotFound,
+            MatchBallotDataError::MatchClosed => UserMatchError::MatchClosed,
+            MatchBallotDataError::DecisionMethodMismatch => UserMatchError::DecisionMethodMismatch,
+            MatchBallotDataError::BallotInvalid => UserMatchError::BallotInvalid,
+            MatchBallotDataError::InternalError => UserMatchError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    data::repository::{
+        match_candidates::{objects::MatchCandidateDataCreate, MatchCandidatesRepository},
+        objects::PagingDomainRequest,
+    },
+    presenter::handlers::{
+        matches::objects::UserMatchError,
+        objects::{request::PagingRequest, response::PagingResponse},
+    },
+    Conn,
+};
+
+use super::{
+    objects::{UserAddCandidateRequest, UserMatchCandidateResponse},
+    MatchCandidatesHandler,
+};
+
+#[async_trait]
+impl MatchCandidatesHandler for Conn {
+    async fn add_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserAddCandidateRequest<'a>,
+    ) -> Result<UserMatchCandidateResponse, UserMatchError> {
+        let candidate = MatchCandidateDataCreate {
+            request_uuid: uuid,
+            match_uuid,
+            reference_uuid: params.reference_uuid,
+            title: params.title,
+            image_url: params.image_url,
+            metadata: params.metadata.map(|metadata| metadata.to_string()),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchCandidatesRepository::add_candidate(self, candidate)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_candidates<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserMatchCandidateResponse>, UserMatchError> {
+        let request = PagingDomainRequest {
+            user_uuid: uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        MatchCandidatesRepository::get_candidates(self, match_uuid, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn remove_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), UserMatchError> {
+        MatchCandidatesRepository::remove_candidate(self, uuid, match_uuid, candidate_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,31 @@
+use self::objects::{UserAddCandidateRequest, UserMatchCandidateResponse};
+use super::{
+    matches::objects::UserMatchError,
+    objects::{request::PagingRequest, response::PagingResponse},
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchCandidatesHandler {
+    async fn add_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserAddCandidateRequest<'a>,
+    ) -> Result<UserMatchCandidateResponse, UserMatchError>;
+
+    async fn get_candidates<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserMatchCandidateResponse>, UserMatchError>;
+
+    async fn remove_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+    ) -> Result<(), UserMatchError>;
+}
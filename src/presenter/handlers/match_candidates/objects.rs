@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::repository::match_candidates::objects::MatchCandidateData;
+
+#[derive(Deserialize)]
+pub struct UserAddCandidateRequest<'a> {
+    pub reference_uuid: &'a str,
+    pub title: &'a str,
+    pub image_url: &'a str,
+    pub metadata: Option<Value>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchCandidateResponse {
+    pub uuid: String,
+    pub match_uuid: String,
+    pub reference_uuid: String,
+    pub title: String,
+    pub image_url: String,
+    pub metadata: Option<Value>,
+    pub created_by: String,
+    pub created_at: i64,
+}
+
+impl From<MatchCandidateData> for UserMatchCandidateResponse {
+    fn from(data: MatchCandidateData) -> Self {
+        UserMatchCandidateResponse {
+            uuid: data.uuid.to_string(),
+            match_uuid: data.match_uuid.to_string(),
+            reference_uuid: data.reference_uuid.to_string(),
+            title: data.title,
+            image_url: data.image_url,
+            metadata: data
+                .metadata
+                .and_then(|metadata| serde_json::from_str(&metadata).ok()),
+            created_by: data.created_by.to_string(),
+            created_at: data.created_at,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    data::repository::match_comments::{
+        objects::{
+            MatchCommentDataCreate, MatchCommentsDataRequest, MATCH_COMMENTS_DEFAULT_LIMIT,
+            MATCH_COMMENTS_MAX_LIMIT,
+        },
+        MatchCommentsRepository,
+    },
+    presenter::handlers::matches::objects::UserMatchError,
+    Conn,
+};
+
+use super::{
+    objects::{
+        UserMatchCommentRequest, UserMatchCommentResponse, UserMatchCommentUpdateRequest,
+        UserMatchCommentsRequest, UserMatchCommentsResponse,
+    },
+    MatchCommentsHandler,
+};
+
+#[async_trait]
+impl MatchCommentsHandler for Conn {
+    async fn add_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchCommentRequest<'a>,
+    ) -> Result<UserMatchCommentResponse, UserMatchError> {
+        let comment = MatchCommentDataCreate {
+            request_uuid: uuid,
+            match_uuid,
+            candidate_uuid: params.candidate_uuid,
+            parent_uuid: params.parent_uuid,
+            body: &params.body,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchCommentsRepository::add_comment(self, comment)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_comments<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchCommentsRequest<'a>,
+    ) -> Result<UserMatchCommentsResponse, UserMatchError> {
+        let request = MatchCommentsDataRequest {
+            request_uuid: uuid,
+            match_uuid,
+            candidate_uuid: params.candidate_uuid,
+            cursor: params.cursor,
+            limit: params
+                .limit
+                .unwrap_or(MATCH_COMMENTS_DEFAULT_LIMIT)
+                .clamp(1, MATCH_COMMENTS_MAX_LIMIT),
+        };
+        MatchCommentsRepository::get_comments(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn update_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        params: UserMatchCommentUpdateRequest,
+    ) -> Result<UserMatchCommentResponse, UserMatchError> {
+        let updated_at = chrono::Utc::now().timestamp_millis();
+        MatchCommentsRepository::update_comment(
+            self,
+            uuid,
+            match_uuid,
+            comment_uuid,
+            &params.body,
+            updated_at,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
+
+    async fn delete_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+    ) -> Result<UserMatchCommentResponse, UserMatchError> {
+        let deleted_at = chrono::Utc::now().timestamp_millis();
+        MatchCommentsRepository::delete_comment(self, uuid, match_uuid, comment_uuid, deleted_at)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,39 @@
+use self::objects::{
+    UserMatchCommentRequest, UserMatchCommentResponse, UserMatchCommentUpdateRequest,
+    UserMatchCommentsRequest, UserMatchCommentsResponse,
+};
+use super::matches::objects::UserMatchError;
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchCommentsHandler {
+    async fn add_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchCommentRequest<'a>,
+    ) -> Result<UserMatchCommentResponse, UserMatchError>;
+
+    async fn get_comments<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchCommentsRequest<'a>,
+    ) -> Result<UserMatchCommentsResponse, UserMatchError>;
+
+    async fn update_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+        params: UserMatchCommentUpdateRequest,
+    ) -> Result<UserMatchCommentResponse, UserMatchError>;
+
+    async fn delete_comment<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        comment_uuid: &'a str,
+    ) -> Result<UserMatchCommentResponse, UserMatchError>;
+}
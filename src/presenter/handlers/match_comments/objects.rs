@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::match_comments::objects::{
+    MatchCommentData, MatchCommentDataError, MatchCommentsPageData,
+};
+
+use super::super::matches::objects::UserMatchError;
+
+/// `body` is owned because comments routinely contain escaped characters.
+#[derive(Deserialize)]
+pub struct UserMatchCommentRequest<'a> {
+    pub body: String,
+    pub candidate_uuid: Option<&'a str>,
+    pub parent_uuid: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+pub struct UserMatchCommentUpdateRequest {
+    pub body: String,
+}
+
+#[derive(FromForm)]
+pub struct UserMatchCommentsRequest<'a> {
+    pub candidate_uuid: Option<&'a str>,
+    pub cursor: Option<&'a str>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchCommentResponse {
+    pub uuid: String,
+    pub match_uuid: String,
+    pub candidate_uuid: Option<String>,
+    pub parent_uuid: Option<String>,
+    pub author_uuid: String,
+    pub body: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub is_deleted: bool,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchCommentsResponse {
+    pub result: Vec<UserMatchCommentResponse>,
+    pub next_cursor: Option<String>,
+}
+
+impl From<MatchCommentData> for UserMatchCommentResponse {
+    fn from(data: MatchCommentData) -> Self {
+        UserMatchCommentResponse {
+            uuid: data.uuid.to_string(),
+            match_uuid: data.match_uuid.to_string(),
+            candidate_uuid: data.candidate_uuid.map(|uuid| uuid.to_string()),
+            parent_uuid: data.parent_uuid.map(|uuid| uuid.to_string()),
+            author_uuid: data.author_uuid.to_string(),
+            body: data.body,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+            is_deleted: data.is_deleted,
+        }
+    }
+}
+
+impl From<MatchCommentsPageData> for UserMatchCommentsResponse {
+    fn from(data: MatchCommentsPageData) -> Self {
+        UserMatchCommentsResponse {
+            result: data.result.into_iter().map(|v| v.into()).collect(),
+            next_cursor: data.next_cursor,
+        }
+    }
+}
+
+impl From<MatchCommentDataError> for UserMatchError {
+    fn from(error: MatchCommentDataError) -> Self {
+        match error {
+            MatchCommentDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchCommentDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchCommentDataError::NoPermission => UserMatchError::NoPermission,
+            MatchCommentDataError::CandidateNotFound => UserMatchError::CandidateNotFound,
+            MatchCommentDataError::CommentNotFound => UserMatchError::CommentNotFound,
+            MatchCommentDataError::CommentInvalid => UserMatchError::CommentInvalid,
+            MatchCommentDataError::CursorInvalid => UserMatchError::CursorInvalid,
+            MatchCommentDataError::InternalError => UserMatchError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    data::repository::{match_invites::MatchInvitesRepository, objects::PagingDomainRequest},
+    presenter::handlers::{
+        matches::objects::UserMatchError,
+        objects::{request::PagingRequest, response::PagingResponse},
+    },
+    Conn,
+};
+
+use super::{
+    objects::{UserInviteParticipantsRequest, UserMatchInviteResponse},
+    MatchInvitesHandler,
+};
+
+#[async_trait]
+impl MatchInvitesHandler for Conn {
+    async fn invite_participants<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserInviteParticipantsRequest<'a>,
+    ) -> Result<Vec<UserMatchInviteResponse>, UserMatchError> {
+        MatchInvitesRepository::invite_participants(
+            self,
+            uuid,
+            match_uuid,
+            params.participants_uuid,
+        )
+        .await
+        .map(|result| result.into_iter().map(|v| v.into()).collect())
+        .map_err(|e| e.into())
+    }
+
+    async fn get_user_invites<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserMatchInviteResponse>, UserMatchError> {
+        let request = PagingDomainRequest {
+            user_uuid: uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        MatchInvitesRepository::get_user_invites(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn get_match_invites<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchInviteResponse>, UserMatchError> {
+        MatchInvitesRepository::get_match_invites(self, uuid, match_uuid)
+            .await
+            .map(|result| result.into_iter().map(|v| v.into()).collect())
+            .map_err(|e| e.into())
+    }
+
+    async fn accept_invite<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchInviteResponse, UserMatchError> {
+        MatchInvitesRepository::accept_invite(self, uuid, match_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn decline_invite<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchInviteResponse, UserMatchError> {
+        MatchInvitesRepository::decline_invite(self, uuid, match_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
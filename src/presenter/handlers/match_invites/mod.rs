@@ -0,0 +1,41 @@
+use self::objects::{UserInviteParticipantsRequest, UserMatchInviteResponse};
+use super::{
+    matches::objects::UserMatchError,
+    objects::{request::PagingRequest, response::PagingResponse},
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchInvitesHandler {
+    async fn invite_participants<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserInviteParticipantsRequest<'a>,
+    ) -> Result<Vec<UserMatchInviteResponse>, UserMatchError>;
+
+    async fn get_user_invites<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<UserMatchInviteResponse>, UserMatchError>;
+
+    async fn get_match_invites<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchInviteResponse>, UserMatchError>;
+
+    async fn accept_invite<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchInviteResponse, UserMatchError>;
+
+    async fn decline_invite<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchInviteResponse, UserMatchError>;
+}
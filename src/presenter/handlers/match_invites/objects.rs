@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::match_invites::objects::{MatchInviteData, MatchInviteDataError};
+
+use super::super::matches::objects::UserMatchError;
+
+#[derive(Deserialize)]
+pub struct UserInviteParticipantsRequest<'a> {
+    #[serde(borrow)]
+    pub participants_uuid: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchInviteResponse {
+    pub uuid: String,
+    pub match_uuid: String,
+    pub match_title: String,
+    pub user_uuid: String,
+    pub invited_by: String,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<MatchInviteData> for UserMatchInviteResponse {
+    fn from(data: MatchInviteData) -> Self {
+        UserMatchInviteResponse {
+            uuid: data.uuid.to_string(),
+            match_uuid: data.match_uuid.to_string(),
+            match_title: data.match_title,
+            user_uuid: data.user_uuid.to_string(),
+            invited_by: data.invited_by.to_string(),
+            status: String::from(data.status),
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl From<MatchInviteDataError> for UserMatchError {
+    fn from(error: MatchInviteDataError) -> Self {
+        match error {
+            MatchInviteDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchInviteDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchInviteDataError::NoPermission => UserMatchError::NoPermission,
+            MatchInviteDataError::InviteNotFound => UserMatchError::InviteNotFound,
+            MatchInviteDataError::MatchClosed => UserMatchError::MatchClosed,
+            MatchInviteDataError::ParticipantsNotFound => UserMatchError::ParticipantsNotFound,
+            MatchInviteDataError::InviteNotAllowed => UserMatchError::InviteNotAllowed,
+            MatchInviteDataError::InternalError => UserMatchError::InternalError,
+        }
+    }
+}
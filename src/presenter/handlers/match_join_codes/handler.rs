@@ -0,0 +1,68 @@
+use crate::{
+    data::repository::match_join_codes::{
+        objects::MatchJoinCodeDataCreate, MatchJoinCodesRepository,
+    },
+    presenter::handlers::matches::objects::{UserMatchDetailResponse, UserMatchError},
+    Conn,
+};
+
+use super::{
+    objects::{UserMatchJoinCodeCreateRequest, UserMatchJoinCodeResponse},
+    MatchJoinCodesHandler,
+};
+
+#[async_trait]
+impl MatchJoinCodesHandler for Conn {
+    async fn create_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchJoinCodeCreateRequest,
+    ) -> Result<UserMatchJoinCodeResponse, UserMatchError> {
+        let request = MatchJoinCodeDataCreate {
+            request_uuid: uuid,
+            match_uuid,
+            max_participants: params.max_participants,
+            expires_at: params.expires_at,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchJoinCodesRepository::create_join_code(self, request)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_join_codes<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchJoinCodeResponse>, UserMatchError> {
+        MatchJoinCodesRepository::get_join_codes(self, uuid, match_uuid)
+            .await
+            .map(|codes| codes.into_iter().map(|v| v.into()).collect())
+            .map_err(|e| e.into())
+    }
+
+    async fn revoke_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        code_uuid: &'a str,
+    ) -> Result<UserMatchJoinCodeResponse, UserMatchError> {
+        MatchJoinCodesRepository::revoke_join_code(self, uuid, match_uuid, code_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn redeem_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        code: &'a str,
+    ) -> Result<UserMatchDetailResponse, UserMatchError> {
+        MatchJoinCodesRepository::redeem_join_code(self, uuid, code)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
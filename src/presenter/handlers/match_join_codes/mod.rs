@@ -0,0 +1,33 @@
+use self::objects::{UserMatchJoinCodeCreateRequest, UserMatchJoinCodeResponse};
+use super::matches::objects::{UserMatchDetailResponse, UserMatchError};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchJoinCodesHandler {
+    async fn create_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchJoinCodeCreateRequest,
+    ) -> Result<UserMatchJoinCodeResponse, UserMatchError>;
+
+    async fn get_join_codes<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchJoinCodeResponse>, UserMatchError>;
+
+    async fn revoke_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        code_uuid: &'a str,
+    ) -> Result<UserMatchJoinCodeResponse, UserMatchError>;
+
+    async fn redeem_join_code<'a>(
+        &self,
+        uuid: &'a str,
+        code: &'a str,
+    ) -> Result<UserMatchDetailResponse, UserMatchError>;
+}
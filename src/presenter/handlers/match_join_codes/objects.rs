@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::match_join_codes::objects::{
+    MatchJoinCodeData, MatchJoinCodeDataError,
+};
+
+use super::super::matches::objects::UserMatchError;
+
+/// Where a code is redeemed, shared together with the code as a join link.
+pub const MATCH_JOIN_PATH: &str = "/api/v1/user/match/join";
+
+/// `max_participants` counts every participant including the creator.
+#[derive(Deserialize)]
+pub struct UserMatchJoinCodeCreateRequest {
+    pub max_participants: Option<i64>,
+    pub expires_at: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchJoinCodeResponse {
+    pub uuid: String,
+    pub match_uuid: String,
+    pub code: String,
+    pub join_path: String,
+    pub created_by: String,
+    pub max_participants: i64,
+    pub uses: i64,
+    pub expires_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+    pub created_at: i64,
+}
+
+impl From<MatchJoinCodeData> for UserMatchJoinCodeResponse {
+    fn from(data: MatchJoinCodeData) -> Self {
+        UserMatchJoinCodeResponse {
+            uuid: data.uuid.to_string(),
+            match_uuid: data.match_uuid.to_string(),
+            join_path: format!("{}?code={}", MATCH_JOIN_PATH, data.code),
+            code: data.code,
+            created_by: data.created_by.to_string(),
+            max_participants: data.max_participants,
+            uses: data.uses,
+            expires_at: data.expires_at,
+            revoked_at: data.revoked_at,
+            created_at: data.created_at,
+        }
+    }
+}
+
+impl From<MatchJoinCodeDataError> for UserMatchError {
+    fn from(error: MatchJoinCodeDataError) -> Self {
+        match error {
+            MatchJoinCodeDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchJoinCodeDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchJoinCodeDataError::NoPermission => UserMatchError::NoPermission,
+            MatchJoinCodeDataError::MatchClosed => UserMatchError::MatchClosed,
+            MatchJoinCodeDataError::JoinCodeNotFound => UserMatchError::JoinCodeNotFound,
+            MatchJoinCodeDataError::JoinCodeExpired => UserMatchError::JoinCodeExpired,
+            MatchJoinCodeDataError::ExpiresAtInvalid => UserMatchError::ExpiresAtInvalid,
+            MatchJoinCodeDataError::MaxParticipantsInvalid => UserMatchError::JoinCodeLimitInvalid,
+            MatchJoinCodeDataError::ParticipantsLimitReached => {
+                UserMatchError::ParticipantsLimitReached
+            }
+            MatchJoinCodeDataError::InternalError => UserMatchError::InternalError,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    data::repository::match_votes::{objects::MatchVoteDataCreate, MatchVotesRepository},
+    presenter::handlers::{
+        match_candidates::objects::UserMatchCandidateResponse, matches::objects::UserMatchError,
+    },
+    Conn,
+};
+
+use super::{
+    objects::{UserMatchVoteRequest, UserMatchVoteResponse},
+    MatchVotesHandler,
+};
+
+#[async_trait]
+impl MatchVotesHandler for Conn {
+    async fn vote<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+        params: UserMatchVoteRequest,
+    ) -> Result<UserMatchVoteResponse, UserMatchError> {
+        let vote = MatchVoteDataCreate {
+            request_uuid: uuid,
+            match_uuid,
+            candidate_uuid,
+            liked: params.liked,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchVotesRepository::vote(self, vote)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn get_next_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchCandidateResponse, UserMatchError> {
+        MatchVotesRepository::get_next_candidate(self, uuid, match_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,23 @@
+use self::objects::{UserMatchVoteRequest, UserMatchVoteResponse};
+use super::{
+    match_candidates::objects::UserMatchCandidateResponse, matches::objects::UserMatchError,
+};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait MatchVotesHandler {
+    async fn vote<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+        candidate_uuid: &'a str,
+        params: UserMatchVoteRequest,
+    ) -> Result<UserMatchVoteResponse, UserMatchError>;
+
+    async fn get_next_candidate<'a>(
+        &self,
+        uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<UserMatchCandidateResponse, UserMatchError>;
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::repository::match_votes::objects::MatchVoteData;
+
+#[derive(Deserialize)]
+pub struct UserMatchVoteRequest {
+    pub liked: bool,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchVoteResponse {
+    pub match_uuid: String,
+    pub candidate_uuid: String,
+    pub liked: bool,
+    pub status: String,
+    pub winner_candidate_uuid: Option<String>,
+    pub created_at: i64,
+}
+
+impl From<MatchVoteData> for UserMatchVoteResponse {
+    fn from(data: MatchVoteData) -> Self {
+        UserMatchVoteResponse {
+            match_uuid: data.match_uuid.to_string(),
+            candidate_uuid: data.candidate_uuid.to_string(),
+            liked: data.liked,
+            status: data.status.into(),
+            winner_candidate_uuid: data.winner_candidate_uuid.map(|uuid| uuid.to_string()),
+            created_at: data.created_at,
+        }
+    }
+}
@@ -1,17 +1,21 @@
 use crate::{
-    data::repository::matches::MatchesRepository,
-    presenter::handlers::objects::{
-        request::{map_paging_uuid, PagingUuidRequest},
-        response::PagingResponse,
-    },
+    data::repository::{matches::MatchesRepository, objects::PagingDomainRequest},
+    presenter::handlers::objects::response::PagingResponse,
     Conn,
 };
 
 use super::{
-    objects::{UserCreateMatchRequest, UserMatchDetailResponse, UserMatchError},
+    objects::{
+        UserCreateMatchRequest, UserMatchDetailResponse, UserMatchError,
+        UserMatchParticipantResponse, UserMatchRoleRequest, UserMatchSeedPreviewResponse,
+        UserMatchSeedRequest, UserMatchTransferRequest, UserMatchesRequest, UserUpdateMatchRequest,
+    },
     MatchesHandler,
 };
-use crate::data::repository::matches::objects::MatchesDataCreate;
+use crate::data::repository::matches::objects::{
+    MatchDecisionMethod, MatchParticipantRole, MatchTransition, MatchesDataCreate,
+    MatchesDataFilter, MatchesDataUpdate, MATCH_SEED_DEFAULT_LIMIT,
+};
 
 #[async_trait]
 impl MatchesHandler for Conn {
@@ -21,21 +25,52 @@ impl MatchesHandler for Conn {
         params: UserCreateMatchRequest<'a>,
     ) -> Result<UserMatchDetailResponse, UserMatchError> {
         let current_time_ms = chrono::Utc::now().timestamp_millis();
+        let expires_at =
+            i64::try_from(params.expires_at).map_err(|_| UserMatchError::ExpiresAtInvalid)?;
+        let decision_method = match params.decision_method {
+            Some(value) => MatchDecisionMethod::try_from(value)
+                .map_err(|_| UserMatchError::DecisionMethodInvalid)?,
+            None => MatchDecisionMethod::Unanimous,
+        };
+        let seed_limit = match params.seed_from_favourites {
+            Some(true) => Some(params.seed_limit.unwrap_or(MATCH_SEED_DEFAULT_LIMIT)),
+            _ => None,
+        };
         let match_data = MatchesDataCreate {
             creator_uuid: uuid,
             participants_uuid: params.participants_uuid,
+            group_uuid: params.group_uuid,
             title: params.title,
             description: params.description,
             cover_url: params.cover_url,
             created_at: current_time_ms,
-            expires_at: current_time_ms,
+            expires_at,
             updated_at: current_time_ms,
+            decision_method,
+            seed_limit,
         };
         self.create_matches(match_data)
             .await
             .map(|v| v.into())
             .map_err(|e| e.into())
     }
+    async fn preview_seed_candidates<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserMatchSeedRequest<'a>,
+    ) -> Result<UserMatchSeedPreviewResponse, UserMatchError> {
+        let limit = params.seed_limit.unwrap_or(MATCH_SEED_DEFAULT_LIMIT);
+        MatchesRepository::preview_seed_candidates(
+            self,
+            uuid,
+            params.participants_uuid,
+            params.group_uuid,
+            limit,
+        )
+        .await
+        .map(|v| v.into())
+        .map_err(|e| e.into())
+    }
     async fn get_match<'a>(
         &self,
         user_uuid: &'a str,
@@ -49,10 +84,17 @@ impl MatchesHandler for Conn {
     async fn get_matches<'a>(
         &self,
         uuid: &'a str,
-        params: PagingUuidRequest<'a>,
+        params: UserMatchesRequest<'a>,
     ) -> Result<PagingResponse<UserMatchDetailResponse>, UserMatchError> {
-        let request = map_paging_uuid(uuid, params).await;
-        MatchesRepository::get_matches(self, request)
+        let filter = MatchesDataFilter::try_from(&params)?;
+        let request = PagingDomainRequest {
+            user_uuid: params.uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        MatchesRepository::get_matches(self, request, filter)
             .await
             .map(|response| PagingResponse {
                 page: response.page,
@@ -63,4 +105,117 @@ impl MatchesHandler for Conn {
             })
             .map_err(|e| e.into())
     }
+    async fn transition_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        transition: MatchTransition,
+    ) -> Result<UserMatchDetailResponse, UserMatchError> {
+        MatchesRepository::transition_match(self, user_uuid, match_uuid, transition)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn update_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserUpdateMatchRequest<'a>,
+    ) -> Result<UserMatchDetailResponse, UserMatchError> {
+        let expires_at = params
+            .expires_at
+            .map(i64::try_from)
+            .transpose()
+            .map_err(|_| UserMatchError::ExpiresAtInvalid)?;
+        let update = MatchesDataUpdate {
+            title: params.title,
+            description: params.description,
+            cover_url: params.cover_url,
+            expires_at,
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        MatchesRepository::update_match(self, user_uuid, match_uuid, update)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn delete_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), UserMatchError> {
+        MatchesRepository::delete_match(self, user_uuid, match_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn remove_participant<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        participant_uuid: &'a str,
+    ) -> Result<UserMatchDetailResponse, UserMatchError> {
+        MatchesRepository::remove_participant(self, user_uuid, match_uuid, participant_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
+
+    async fn leave_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), UserMatchError> {
+        MatchesRepository::leave_match(self, user_uuid, match_uuid)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn get_match_roles<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchParticipantResponse>, UserMatchError> {
+        MatchesRepository::get_match_roles(self, user_uuid, match_uuid)
+            .await
+            .map(|v| {
+                v.into_iter()
+                    .map(|participant| participant.into())
+                    .collect()
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn set_participant_role<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        participant_uuid: &'a str,
+        params: UserMatchRoleRequest<'a>,
+    ) -> Result<Vec<UserMatchParticipantResponse>, UserMatchError> {
+        let role = MatchParticipantRole::try_from(params.role)
+            .map_err(|e| -> UserMatchError { e.into() })?;
+        MatchesRepository::set_participant_role(self, user_uuid, match_uuid, participant_uuid, role)
+            .await
+            .map(|v| {
+                v.into_iter()
+                    .map(|participant| participant.into())
+                    .collect()
+            })
+            .map_err(|e| e.into())
+    }
+
+    async fn transfer_ownership<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchTransferRequest<'a>,
+    ) -> Result<UserMatchDetailResponse, UserMatchError> {
+        MatchesRepository::transfer_ownership(self, user_uuid, match_uuid, params.user_uuid)
+            .await
+            .map(|v| v.into())
+            .map_err(|e| e.into())
+    }
 }
@@ -1,5 +1,11 @@
-use self::objects::{UserCreateMatchRequest, UserMatchDetailResponse, UserMatchError};
-use super::objects::{request::PagingUuidRequest, response::PagingResponse};
+use crate::data::repository::matches::objects::MatchTransition;
+
+use self::objects::{
+    UserCreateMatchRequest, UserMatchDetailResponse, UserMatchError, UserMatchParticipantResponse,
+    UserMatchRoleRequest, UserMatchSeedPreviewResponse, UserMatchSeedRequest,
+    UserMatchTransferRequest, UserMatchesRequest, UserUpdateMatchRequest,
+};
+use super::objects::response::PagingResponse;
 mod handler;
 pub mod objects;
 
@@ -11,6 +17,12 @@ pub trait MatchesHandler {
         params: UserCreateMatchRequest<'a>,
     ) -> Result<UserMatchDetailResponse, UserMatchError>;
 
+    async fn preview_seed_candidates<'a>(
+        &self,
+        uuid: &'a str,
+        params: UserMatchSeedRequest<'a>,
+    ) -> Result<UserMatchSeedPreviewResponse, UserMatchError>;
+
     async fn get_match<'a>(
         &self,
         user_uuid: &'a str,
@@ -20,6 +32,60 @@ pub trait MatchesHandler {
     async fn get_matches<'a>(
         &self,
         uuid: &'a str,
-        params: PagingUuidRequest<'a>,
+        params: UserMatchesRequest<'a>,
     ) -> Result<PagingResponse<UserMatchDetailResponse>, UserMatchError>;
+
+    async fn transition_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        transition: MatchTransition,
+    ) -> Result<UserMatchDetailResponse, UserMatchError>;
+
+    async fn update_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserUpdateMatchRequest<'a>,
+    ) -> Result<UserMatchDetailResponse, UserMatchError>;
+
+    async fn delete_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), UserMatchError>;
+
+    async fn remove_participant<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        participant_uuid: &'a str,
+    ) -> Result<UserMatchDetailResponse, UserMatchError>;
+
+    async fn leave_match<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<(), UserMatchError>;
+
+    async fn get_match_roles<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+    ) -> Result<Vec<UserMatchParticipantResponse>, UserMatchError>;
+
+    async fn set_participant_role<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        participant_uuid: &'a str,
+        params: UserMatchRoleRequest<'a>,
+    ) -> Result<Vec<UserMatchParticipantResponse>, UserMatchError>;
+
+    async fn transfer_ownership<'a>(
+        &self,
+        user_uuid: &'a str,
+        match_uuid: &'a str,
+        params: UserMatchTransferRequest<'a>,
+    ) -> Result<UserMatchDetailResponse, UserMatchError>;
 }
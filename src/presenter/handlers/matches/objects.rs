@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::data::repository::matches::objects::{MatchesData, MatchesDataError};
+use crate::data::repository::{
+    match_candidates::objects::MatchCandidateDataError,
+    match_votes::objects::MatchVoteDataError,
+    matches::objects::{
+        MatchParticipantData, MatchRole, MatchSeedPreviewData, MatchSort, MatchStatus, MatchesData,
+        MatchesDataError, MatchesDataFilter,
+    },
+};
 
 #[derive(Deserialize, FromForm)]
 #[allow(dead_code)]
@@ -9,7 +16,81 @@ pub struct UserCreateMatchRequest<'a> {
     pub description: &'a str,
     pub expires_at: u128,
     pub participants_uuid: Vec<&'a str>,
+    pub group_uuid: Option<&'a str>,
     pub cover_url: &'a str,
+    pub decision_method: Option<&'a str>,
+    pub seed_from_favourites: Option<bool>,
+    pub seed_limit: Option<i64>,
+}
+
+#[derive(FromForm)]
+pub struct UserMatchSeedRequest<'a> {
+    pub participants_uuid: Vec<&'a str>,
+    pub group_uuid: Option<&'a str>,
+    pub seed_limit: Option<i64>,
+}
+
+/// Paging of the match list with optional filters, `status` may repeat.
+#[derive(FromForm)]
+pub struct UserMatchesRequest<'a> {
+    pub uuid: &'a str,
+    pub query: &'a str,
+    pub page: i64,
+    pub page_size: i64,
+    pub status: Vec<&'a str>,
+    pub role: Option<&'a str>,
+    pub created_from: Option<i64>,
+    pub created_to: Option<i64>,
+    pub expires_from: Option<i64>,
+    pub expires_to: Option<i64>,
+    pub sort: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchSeedCandidateResponse {
+    pub reference_uuid: String,
+    pub title: String,
+    pub overlap: i64,
+    pub shared_by_all: bool,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchSeedPreviewResponse {
+    pub participants_count: i64,
+    pub candidates: Vec<UserMatchSeedCandidateResponse>,
+}
+
+#[derive(Deserialize)]
+pub struct UserUpdateMatchRequest<'a> {
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub cover_url: Option<&'a str>,
+    pub expires_at: Option<u128>,
+}
+
+#[derive(Deserialize)]
+pub struct UserMatchRoleRequest<'a> {
+    pub role: &'a str,
+}
+
+#[derive(Deserialize)]
+pub struct UserMatchTransferRequest<'a> {
+    pub user_uuid: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct UserMatchParticipantResponse {
+    pub user_uuid: String,
+    pub role: String,
+}
+
+impl From<MatchParticipantData> for UserMatchParticipantResponse {
+    fn from(participant: MatchParticipantData) -> Self {
+        UserMatchParticipantResponse {
+            user_uuid: participant.user_uuid.to_string(),
+            role: participant.role.into(),
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -24,6 +105,8 @@ pub struct UserMatchDetailResponse {
     pub expires_at: i64,
     pub created_at: i64,
     pub updated_at: i64,
+    pub winner_candidate_uuid: Option<String>,
+    pub decision_method: String,
 }
 
 pub enum UserMatchError {
@@ -31,6 +114,32 @@ pub enum UserMatchError {
     UuidInvalid,
     MatchesNotFound,
     MatchesNotCreated,
+    CandidateNotFound,
+    CandidateConflict,
+    CandidateLimitExceeded,
+    MatchClosed,
+    NoCandidatesLeft,
+    InvalidTransition,
+    ExpiresAtInvalid,
+    ParticipantsNotFound,
+    InviteNotAllowed,
+    InviteNotFound,
+    ParticipantNotFound,
+    CreatorCannotLeave,
+    DecisionMethodInvalid,
+    DecisionMethodMismatch,
+    BallotInvalid,
+    SeedLimitInvalid,
+    CommentNotFound,
+    CommentInvalid,
+    CursorInvalid,
+    GroupNotFound,
+    FilterInvalid,
+    JoinCodeNotFound,
+    JoinCodeExpired,
+    JoinCodeLimitInvalid,
+    ParticipantsLimitReached,
+    RoleInvalid,
     InternalError,
 }
 
@@ -51,6 +160,8 @@ impl Into<UserMatchDetailResponse> for MatchesData {
             expires_at: self.expires_at,
             updated_at: self.updated_at,
             status: self.status.into(),
+            winner_candidate_uuid: self.winner_candidate_uuid.map(|id| id.to_string()),
+            decision_method: self.decision_method.into(),
         }
     }
 }
@@ -63,6 +174,99 @@ impl Into<UserMatchError> for MatchesDataError {
             MatchesDataError::MatchesNotCreated => UserMatchError::MatchesNotCreated,
             MatchesDataError::InternalError => UserMatchError::InternalError,
             MatchesDataError::NoPermission => UserMatchError::NoPermission,
+            MatchesDataError::InvalidTransition => UserMatchError::InvalidTransition,
+            MatchesDataError::ExpiresAtInvalid => UserMatchError::ExpiresAtInvalid,
+            MatchesDataError::ParticipantsNotFound => UserMatchError::ParticipantsNotFound,
+            MatchesDataError::InviteNotAllowed => UserMatchError::InviteNotAllowed,
+            MatchesDataError::MatchClosed => UserMatchError::MatchClosed,
+            MatchesDataError::ParticipantNotFound => UserMatchError::ParticipantNotFound,
+            MatchesDataError::CreatorCannotLeave => UserMatchError::CreatorCannotLeave,
+            MatchesDataError::DecisionMethodInvalid => UserMatchError::DecisionMethodInvalid,
+            MatchesDataError::SeedLimitInvalid => UserMatchError::SeedLimitInvalid,
+            MatchesDataError::GroupNotFound => UserMatchError::GroupNotFound,
+            MatchesDataError::FilterInvalid => UserMatchError::FilterInvalid,
+            MatchesDataError::RoleInvalid => UserMatchError::RoleInvalid,
+            MatchesDataError::StatusInvalid => UserMatchError::InternalError,
+        }
+    }
+}
+
+impl<'a> TryFrom<&UserMatchesRequest<'a>> for MatchesDataFilter {
+    type Error = UserMatchError;
+
+    fn try_from(request: &UserMatchesRequest<'a>) -> Result<Self, Self::Error> {
+        let statuses = request
+            .status
+            .iter()
+            .map(|status| MatchStatus::try_from(status.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| UserMatchError::FilterInvalid)?;
+        let role = request
+            .role
+            .map(MatchRole::try_from)
+            .transpose()
+            .map_err(|_| UserMatchError::FilterInvalid)?;
+        let sort = request
+            .sort
+            .map(MatchSort::try_from)
+            .transpose()
+            .map_err(|_| UserMatchError::FilterInvalid)?
+            .unwrap_or_default();
+        Ok(MatchesDataFilter {
+            statuses,
+            role,
+            created_from: request.created_from,
+            created_to: request.created_to,
+            expires_from: request.expires_from,
+            expires_to: request.expires_to,
+            sort,
+        })
+    }
+}
+
+impl From<MatchSeedPreviewData> for UserMatchSeedPreviewResponse {
+    fn from(data: MatchSeedPreviewData) -> Self {
+        UserMatchSeedPreviewResponse {
+            participants_count: data.participants_count,
+            candidates: data
+                .candidates
+                .into_iter()
+                .map(|candidate| UserMatchSeedCandidateResponse {
+                    reference_uuid: candidate.reference_uuid.to_string(),
+                    title: candidate.title,
+                    overlap: candidate.overlap,
+                    shared_by_all: candidate.overlap >= data.participants_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<MatchCandidateDataError> for UserMatchError {
+    fn from(error: MatchCandidateDataError) -> Self {
+        match error {
+            MatchCandidateDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchCandidateDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchCandidateDataError::NoPermission => UserMatchError::NoPermission,
+            MatchCandidateDataError::CandidateNotFound => UserMatchError::CandidateNotFound,
+            MatchCandidateDataError::Conflict => UserMatchError::CandidateConflict,
+            MatchCandidateDataError::LimitExceeded => UserMatchError::CandidateLimitExceeded,
+            MatchCandidateDataError::InternalError => UserMatchError::InternalError,
+        }
+    }
+}
+
+impl From<MatchVoteDataError> for UserMatchError {
+    fn from(error: MatchVoteDataError) -> Self {
+        match error {
+            MatchVoteDataError::UuidInvalid => UserMatchError::UuidInvalid,
+            MatchVoteDataError::MatchesNotFound => UserMatchError::MatchesNotFound,
+            MatchVoteDataError::NoPermission => UserMatchError::NoPermission,
+            MatchVoteDataError::CandidateNotFound => UserMatchError::CandidateNotFound,
+            MatchVoteDataError::MatchClosed => UserMatchError::MatchClosed,
+            MatchVoteDataError::NoCandidatesLeft => UserMatchError::NoCandidatesLeft,
+            MatchVoteDataError::DecisionMethodMismatch => UserMatchError::DecisionMethodMismatch,
+            MatchVoteDataError::InternalError => UserMatchError::InternalError,
         }
     }
 }
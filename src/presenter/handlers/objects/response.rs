@@ -156,6 +156,10 @@ pub const ERROR_FOLLOW_CONFLICT: &'static ErrorResponse<'static> = &ErrorRespons
     cause: "conflict",
     status: Status::Conflict,
 };
+pub const ERROR_FOLLOW_BATCH_LIMIT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "follow_batch_limit_exceeded",
+    status: Status::PayloadTooLarge,
+};
 
 // favourite error
 pub const ERROR_FAVOURITE_UUID_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
@@ -187,3 +191,275 @@ pub const ERROR_MATCHES_CONFLICT: &'static ErrorResponse<'static> = &ErrorRespon
     cause: "matches_conflict",
     status: Status::Conflict,
 };
+
+pub const ERROR_MATCH_CANDIDATE_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_candidate_not_found",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_CANDIDATE_CONFLICT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_candidate_conflict",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_CANDIDATE_LIMIT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_candidate_limit_exceeded",
+    status: Status::UnprocessableEntity,
+};
+
+pub const ERROR_MATCH_CLOSED: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_closed",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_NO_CANDIDATES_LEFT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_no_candidates_left",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_INVALID_TRANSITION: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_invalid_transition",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_EXPIRES_AT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_expires_at_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_PARTICIPANTS_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_participants_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_INVITE_NOT_ALLOWED: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_invite_not_allowed",
+    status: Status::Forbidden,
+};
+
+pub const ERROR_MATCH_INVITE_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_invite_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_PARTICIPANT_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_participant_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_CREATOR_CANNOT_LEAVE: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_creator_cannot_leave",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_DECISION_METHOD_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_decision_method_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_DECISION_METHOD_MISMATCH: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_decision_method_mismatch",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_BALLOT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_ballot_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_SEED_LIMIT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_seed_limit_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_COMMENT_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_comment_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_COMMENT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_comment_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_CURSOR_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_cursor_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_GROUP_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "group_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_GROUP_UUID_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "group_uuid_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_GROUP_TITLE_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "group_title_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_GROUP_MEMBERS_NOT_FOLLOWED: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "group_members_not_followed",
+    status: Status::Forbidden,
+};
+
+pub const ERROR_GROUP_MEMBERS_LIMIT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "group_members_limit",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_COMPATIBILITY_SELF: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "compatibility_self",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_FILTER_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_filter_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_JOIN_CODE_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_join_code_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_MATCH_JOIN_CODE_EXPIRED: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_join_code_expired",
+    status: Status::Gone,
+};
+
+pub const ERROR_MATCH_JOIN_CODE_LIMIT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_join_code_limit_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_MATCH_PARTICIPANTS_LIMIT: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_participants_limit",
+    status: Status::Conflict,
+};
+
+pub const ERROR_MATCH_ROLE_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "match_role_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_ITEM_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_ITEM_UUID_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_uuid_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_ITEM_KIND_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_kind_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_FAVOURITE_ORDER_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_order_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_STATUS_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_status_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_LIST_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_list_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_FAVOURITE_LIST_UUID_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_list_uuid_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_LIST_TITLE_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_list_title_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_LIST_VISIBILITY_INVALID: &'static ErrorResponse<'static> =
+    &ErrorResponse {
+        cause: "favourite_list_visibility_invalid",
+        status: Status::BadRequest,
+    };
+
+pub const ERROR_ITEM_RATING_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_rating_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_ITEM_RATING_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_rating_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_ITEM_REVIEW_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "item_review_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_IMPORT_NOT_FOUND: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_import_not_found",
+    status: Status::NotFound,
+};
+
+pub const ERROR_FAVOURITE_IMPORT_UUID_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_import_uuid_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_IMPORT_FORMAT_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_import_format_invalid",
+    status: Status::UnsupportedMediaType,
+};
+
+pub const ERROR_FAVOURITE_IMPORT_CONTENT_INVALID: &'static ErrorResponse<'static> =
+    &ErrorResponse {
+        cause: "favourite_import_content_invalid",
+        status: Status::BadRequest,
+    };
+
+pub const ERROR_FAVOURITE_IMPORT_TOO_LARGE: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_import_too_large",
+    status: Status::PayloadTooLarge,
+};
+
+pub const ERROR_FAVOURITE_TREND_PERIOD_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_trend_period_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_COMMON_SELF: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_common_self",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_COMMON_SCOPE_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_common_scope_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_TAG_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_tag_invalid",
+    status: Status::BadRequest,
+};
+
+pub const ERROR_FAVOURITE_NOTE_INVALID: &'static ErrorResponse<'static> = &ErrorResponse {
+    cause: "favourite_note_invalid",
+    status: Status::BadRequest,
+};
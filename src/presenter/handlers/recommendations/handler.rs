@@ -0,0 +1,37 @@
+use crate::{
+    data::repository::{objects::PagingDomainRequest, recommendations::RecommendationsRepository},
+    presenter::handlers::objects::{request::PagingRequest, response::PagingResponse},
+    Conn,
+};
+
+use super::{
+    objects::{RecommendationResponse, UserRecommendationError},
+    RecommendationsHandler,
+};
+
+#[async_trait]
+impl RecommendationsHandler for Conn {
+    async fn get_recommendations<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<RecommendationResponse>, UserRecommendationError> {
+        let request = PagingDomainRequest {
+            user_uuid: uuid,
+            request_uuid: uuid,
+            query: params.query,
+            page: params.page,
+            page_size: params.page_size,
+        };
+        RecommendationsRepository::get_recommendations(self, &request)
+            .await
+            .map(|response| PagingResponse {
+                page: response.page,
+                total: response.total,
+                has_more: response.has_more,
+                page_size: response.page_size,
+                result: response.result.into_iter().map(|v| v.into()).collect(),
+            })
+            .map_err(|e| e.into())
+    }
+}
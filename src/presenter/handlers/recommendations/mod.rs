@@ -0,0 +1,13 @@
+use self::objects::{RecommendationResponse, UserRecommendationError};
+use super::objects::{request::PagingRequest, response::PagingResponse};
+mod handler;
+pub mod objects;
+
+#[async_trait]
+pub trait RecommendationsHandler {
+    async fn get_recommendations<'a>(
+        &self,
+        uuid: &'a str,
+        params: PagingRequest<'a>,
+    ) -> Result<PagingResponse<RecommendationResponse>, UserRecommendationError>;
+}
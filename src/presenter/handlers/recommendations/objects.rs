@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+use crate::{
+    data::repository::recommendations::objects::{RecommendationData, RecommendationDataError},
+    presenter::handlers::items::objects::ItemResponse,
+};
+
+#[derive(Serialize)]
+pub struct RecommendationResponse {
+    pub uuid: String,
+    pub title: String,
+    pub score: f64,
+    pub liked_count: i64,
+    pub picked_count: i64,
+    pub similar_to: Option<String>,
+    /// Why the item is recommended, e.g. "3 people you follow liked this".
+    pub explanations: Vec<String>,
+    pub item: Option<ItemResponse>,
+}
+
+#[derive(Debug)]
+pub enum UserRecommendationError {
+    UuidInvalid,
+    InternalError,
+}
+
+fn people_you_follow(count: i64) -> String {
+    match count {
+        1 => "1 person you follow".to_string(),
+        count => format!("{} people you follow", count),
+    }
+}
+
+impl From<RecommendationData> for RecommendationResponse {
+    fn from(data: RecommendationData) -> Self {
+        let mut explanations = Vec::new();
+        if data.liked_count > 0 {
+            explanations.push(format!(
+                "{} liked this",
+                people_you_follow(data.liked_count)
+            ));
+        }
+        if data.picked_count > 0 {
+            explanations.push(format!(
+                "{} picked this in a match",
+                people_you_follow(data.picked_count)
+            ));
+        }
+        if let Some(title) = &data.similar_to {
+            explanations.push(format!("Similar to {}", title));
+        }
+        RecommendationResponse {
+            uuid: data.item_uuid.to_string(),
+            title: data.title,
+            score: data.score,
+            liked_count: data.liked_count,
+            picked_count: data.picked_count,
+            similar_to: data.similar_to,
+            explanations,
+            item: data.item.map(|item| item.into()),
+        }
+    }
+}
+
+impl From<RecommendationDataError> for UserRecommendationError {
+    fn from(error: RecommendationDataError) -> Self {
+        match error {
+            RecommendationDataError::UuidInvalid => UserRecommendationError::UuidInvalid,
+            RecommendationDataError::InternalError => UserRecommendationError::InternalError,
+        }
+    }
+}
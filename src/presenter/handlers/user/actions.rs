@@ -1,5 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    data::repository::follow::{objects::FollowDataError, FollowRepository},
+    data::repository::follow::{
+        objects::{FollowBatchDataOutcome, FollowDataError},
+        FollowRepository,
+    },
     Conn,
 };
 
@@ -30,6 +35,26 @@ pub async fn un_follow_user<'a>(
     }
 }
 
+pub async fn follow_users<'a>(
+    follower_uuid: &'a str,
+    request: &'a FollowBatchRequest<'a>,
+    db: Conn,
+) -> Result<FollowBatchResponse, FollowDataError> {
+    db.follow_users(follower_uuid, &request.uuids)
+        .await
+        .map(|outcomes| outcomes.into())
+}
+
+pub async fn un_follow_users<'a>(
+    follower_uuid: &'a str,
+    request: &'a FollowBatchRequest<'a>,
+    db: Conn,
+) -> Result<FollowBatchResponse, FollowDataError> {
+    db.un_follow_users(follower_uuid, &request.uuids)
+        .await
+        .map(|outcomes| outcomes.into())
+}
+
 pub async fn is_following<'a>(
     follower_uuid: &'a str,
     followed_uuid: &'a str,
@@ -40,3 +65,34 @@ pub async fn is_following<'a>(
         Err(err) => Err(err),
     }
 }
+
+#[derive(Deserialize)]
+pub struct FollowBatchRequest<'a> {
+    #[serde(borrow)]
+    pub uuids: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct FollowBatchResponse {
+    pub result: Vec<FollowBatchItemResponse>,
+}
+
+#[derive(Serialize)]
+pub struct FollowBatchItemResponse {
+    pub uuid: String,
+    pub status: String,
+}
+
+impl From<Vec<FollowBatchDataOutcome>> for FollowBatchResponse {
+    fn from(outcomes: Vec<FollowBatchDataOutcome>) -> Self {
+        FollowBatchResponse {
+            result: outcomes
+                .into_iter()
+                .map(|outcome| FollowBatchItemResponse {
+                    uuid: outcome.uuid,
+                    status: outcome.status.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
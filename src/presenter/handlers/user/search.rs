@@ -4,7 +4,10 @@ use serde::Serialize;
 use super::single_user::{map_user_info, UserResponse};
 use crate::{
     data::repository::{
-        favourite::{objects::FavouriteDataError, FavouriteRepository},
+        favourite::{
+            objects::{FavouriteDataError, FavouriteDataFilter},
+            FavouriteRepository,
+        },
         follow::{objects::FollowDataError, FollowRepository},
         objects::PagingDomainRequest,
         user::{
@@ -12,7 +15,7 @@ use crate::{
             UserRepository,
         },
     },
-    presenter::handlers::objects::response::PagingResponse,
+    presenter::handlers::{items::objects::ItemResponse, objects::response::PagingResponse},
     Conn,
 };
 use std::sync::Arc;
@@ -38,6 +41,7 @@ pub async fn search_user<'a>(
 
 pub async fn get_user_favourites<'a>(
     request: &'a UserPagingSearchRequest<'a>,
+    filter: &'a FavouriteDataFilter<'a>,
     db: Conn,
 ) -> Result<PagingResponse<FavouriteResponse>, UserSearchError> {
     let db = Arc::new(db);
@@ -48,7 +52,7 @@ pub async fn get_user_favourites<'a>(
         page: request.page,
         page_size: request.page_size,
     };
-    match db.get_user_favourites(&request).await {
+    match db.get_user_favourites(&request, filter).await {
         Ok(response) => Result::Ok(PagingResponse {
             page: response.page,
             page_size: response.page_size,
@@ -64,6 +68,13 @@ pub async fn get_user_favourites<'a>(
                             FavouriteResponse {
                                 uuid: favourite.favourite_uuid.to_string(),
                                 title: favourite.title,
+                                item: favourite.item.map(|item| item.into()),
+                                rating: favourite.rating.as_ref().map(|rating| rating.rating),
+                                review: favourite.rating.and_then(|rating| rating.review),
+                                status: favourite.status.into(),
+                                status_updated_at: favourite.status_updated_at,
+                                tags: favourite.tags,
+                                note: favourite.note,
                                 is_favourite: if request.request_uuid
                                     == favourite.user_uuid.to_string()
                                 {
@@ -86,6 +97,7 @@ pub async fn get_user_favourites<'a>(
 
         Err(err) => match err {
             FavouriteDataError::UuidInvalid => Err(UserSearchError::UuidInvalid),
+            FavouriteDataError::StatusInvalid => Err(UserSearchError::FilterInvalid),
             _ => Err(UserSearchError::InternalError),
         },
     }
@@ -188,6 +200,44 @@ pub async fn get_user_following<'a>(
     }
 }
 
+pub async fn get_favourite_users<'a>(
+    request: &'a UserPagingSearchRequest<'a>,
+    db: Conn,
+) -> Result<PagingResponse<FollowerResponse>, UserSearchError> {
+    match db
+        .get_favourite_users(
+            request.request_uuid,
+            request.uuid,
+            request.query,
+            request.page,
+            request.page_size,
+        )
+        .await
+    {
+        Ok(result) => Result::Ok(PagingResponse {
+            page: result.page,
+            page_size: result.page_size,
+            total: result.total,
+            has_more: result.has_more,
+            result: result
+                .result
+                .into_iter()
+                .map(|user| FollowerResponse {
+                    uuid: user.uuid.to_string(),
+                    username: user.username,
+                    avatar_url: user.avatar_url,
+                    is_following: user.is_following,
+                })
+                .collect(),
+        }),
+
+        Err(err) => match err {
+            FavouriteDataError::UuidInvalid => Err(UserSearchError::UuidInvalid),
+            _ => Err(UserSearchError::InternalError),
+        },
+    }
+}
+
 pub struct UserPagingSearchRequest<'a> {
     pub request_uuid: &'a str,
     pub uuid: &'a str,
@@ -209,6 +259,17 @@ pub struct FavouriteResponse {
     pub uuid: String,
     pub title: String,
     pub is_favourite: bool,
+    /// Catalogue metadata when the favourite refers to a known item.
+    pub item: Option<ItemResponse>,
+    /// The owner's rating from 1 to 10 and short review, if given.
+    pub rating: Option<i16>,
+    pub review: Option<String>,
+    /// One of `want`, `in_progress`, `done` or `dropped`.
+    pub status: String,
+    pub status_updated_at: i64,
+    pub tags: Vec<String>,
+    /// Private note, only set when the owner lists their own favourites.
+    pub note: Option<String>,
 }
 
 #[derive(Serialize)]
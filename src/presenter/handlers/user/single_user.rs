@@ -12,6 +12,7 @@ use crate::{
             UserRepository,
         },
     },
+    presenter::handlers::favourite::response::FavouriteStatusCountResponse,
     Conn,
 };
 
@@ -63,6 +64,11 @@ pub async fn map_user_info(uuid: &str, user: &UserDataResponse, db: Arc<Conn>) -
             .get_favourites_count(&user.id.to_string())
             .await
             .unwrap_or(0),
+        favourites_status_count: db
+            .get_favourites_status_count(&user.id.to_string())
+            .await
+            .map(|count| count.into())
+            .unwrap_or_default(),
         matches_count: db
             .get_match_count(uuid, &user.id.to_string())
             .await
@@ -94,6 +100,8 @@ pub struct UserResponse {
     pub followers_count: i64,
     pub following_count: i64,
     pub favourites_count: i64,
+    /// Favourites of the default list per status.
+    pub favourites_status_count: FavouriteStatusCountResponse,
     pub matches_count: i64,
     pub is_following: bool,
     pub is_followed: bool,
@@ -0,0 +1,7 @@
+mod objects;
+mod route_mount;
+mod routes;
+
+pub trait RouteCompatibilityInitialize {
+    fn mount_compatibility_routes(self) -> Self;
+}
@@ -0,0 +1,18 @@
+use crate::presenter::handlers::{
+    compatibility::objects::UserCompatibilityError,
+    objects::response::{
+        ErrorResponse, ERROR_COMPATIBILITY_SELF, ERROR_UNKNOWN, ERROR_USER_NOT_FOUND_BY_UUID,
+        ERROR_USER_UUID_INVALID,
+    },
+};
+
+impl From<UserCompatibilityError> for &'static ErrorResponse<'static> {
+    fn from(error: UserCompatibilityError) -> Self {
+        match error {
+            UserCompatibilityError::UuidInvalid => ERROR_USER_UUID_INVALID,
+            UserCompatibilityError::UserNotFound => ERROR_USER_NOT_FOUND_BY_UUID,
+            UserCompatibilityError::SelfCompatibility => ERROR_COMPATIBILITY_SELF,
+            UserCompatibilityError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use rocket::{Build, Rocket};
+
+use super::RouteCompatibilityInitialize;
+use crate::presenter::routes::user::compatibility::routes;
+use crate::presenter::routes::{routes_setup::BASE_API_URL, user::routes_setup::BASE_USER_URL};
+
+impl RouteCompatibilityInitialize for Rocket<Build> {
+    fn mount_compatibility_routes(self) -> Self {
+        let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL);
+        self.mount(
+            base_url,
+            routes![routes::get_compatibility_ranking, routes::get_compatibility,],
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::compatibility::objects::{
+    UserCompatibilityFriendResponse, UserCompatibilityResponse,
+};
+use crate::presenter::handlers::compatibility::CompatibilityHandler;
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/compatibility?<params..>")]
+pub async fn get_compatibility_ranking<'a>(
+    access_token: AccessToken,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<UserCompatibilityFriendResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_compatibility_ranking(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<user_uuid>/compatibility")]
+pub async fn get_compatibility(
+    access_token: AccessToken,
+    user_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserCompatibilityResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_compatibility(&uuid, &user_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,24 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::favourite_common::objects::{
+    FavouriteCommonResponse, UserFavouriteCommonRequest,
+};
+use crate::presenter::handlers::favourite_common::FavouriteCommonHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/<user_uuid>/favourite/common?<params..>")]
+pub async fn get_common_favourites<'a>(
+    access_token: AccessToken,
+    user_uuid: &'a str,
+    params: UserFavouriteCommonRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteCommonResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_common_favourites(&uuid, user_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,71 @@
+use rocket::data::{Data, ToByteUnit};
+use rocket::http::ContentType;
+use rocket::serde::json::Json;
+
+use crate::config;
+use crate::presenter::handlers::favourite_imports::objects::{
+    FavouriteImportResponse, FavouriteImportRowResponse, UserFavouriteImportError,
+    UserFavouriteImportReportRequest,
+};
+use crate::presenter::handlers::favourite_imports::FavouriteImportsHandler;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+/// Takes a `text/csv` or `application/json` file, `list_uuid` defaults to the default list.
+#[post("/import?<list_uuid>", data = "<body>")]
+pub async fn create_import(
+    access_token: AccessToken,
+    list_uuid: Option<String>,
+    content_type: Option<&ContentType>,
+    body: Data<'_>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteImportResponse>> {
+    let uuid = access_token.uuid;
+    let content = match body
+        .open(config::favourite_import_max_bytes().bytes())
+        .into_string()
+        .await
+    {
+        Ok(content) if content.is_complete() => content.into_inner(),
+        Ok(_) => return ApiResponse::Err(UserFavouriteImportError::ContentTooLarge.into()),
+        Err(_) => return ApiResponse::Err(UserFavouriteImportError::ContentInvalid.into()),
+    };
+    let format = content_type.map_or("", |content_type| content_type.sub().as_str());
+    match db
+        .create_import(&uuid, list_uuid.as_deref(), format, content)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/import/<import_uuid>")]
+pub async fn get_import(
+    access_token: AccessToken,
+    import_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteImportResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_import(&uuid, &import_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/import/<import_uuid>/report?<params..>")]
+pub async fn get_import_report(
+    access_token: AccessToken,
+    import_uuid: String,
+    params: UserFavouriteImportReportRequest,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FavouriteImportRowResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_import_report(&uuid, &import_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,177 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::favourite::request::{FavouriteAddBody, FavouriteDeleteParams};
+use crate::presenter::handlers::favourite_lists::objects::{
+    FavouriteListEntryResponse, FavouriteListResponse, UserFavouriteListCreateRequest,
+    UserFavouriteListMoveRequest, UserFavouriteListOrderRequest, UserFavouriteListPinRequest,
+    UserFavouriteListUpdateRequest,
+};
+use crate::presenter::handlers::favourite_lists::FavouriteListsHandler;
+use crate::presenter::handlers::objects::request::{PagingRequest, PagingUuidRequest};
+use crate::presenter::handlers::objects::response::{ApiMessageResponse, PagingResponse};
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/list", format = "json", data = "<body>")]
+pub async fn create_list(
+    access_token: AccessToken,
+    body: Json<UserFavouriteListCreateRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListResponse>> {
+    let uuid = access_token.uuid;
+    match db.create_list(&uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/list?<params..>")]
+pub async fn get_lists<'a>(
+    access_token: AccessToken,
+    params: PagingUuidRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FavouriteListResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_lists(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/list/<list_uuid>")]
+pub async fn get_list(
+    access_token: AccessToken,
+    list_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_list(&uuid, &list_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[patch("/list/<list_uuid>", format = "json", data = "<body>")]
+pub async fn update_list(
+    access_token: AccessToken,
+    list_uuid: String,
+    body: Json<UserFavouriteListUpdateRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListResponse>> {
+    let uuid = access_token.uuid;
+    match db.update_list(&uuid, &list_uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/list/<list_uuid>")]
+pub async fn delete_list(
+    access_token: AccessToken,
+    list_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db.delete_list(&uuid, &list_uuid).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
+
+#[get("/list/<list_uuid>/entries?<params..>")]
+pub async fn get_list_entries<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FavouriteListEntryResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_list_entries(&uuid, &list_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/list/<list_uuid>/entries", format = "json", data = "<body>")]
+pub async fn add_list_entry<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    body: Json<FavouriteAddBody<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListEntryResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .add_list_entry(&uuid, &list_uuid, body.favourite_uuid, body.title)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/list/<list_uuid>/entries?<params..>")]
+pub async fn remove_list_entry<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    params: FavouriteDeleteParams<'a>,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db
+        .remove_list_entry(&uuid, &list_uuid, params.favourite_uuid)
+        .await
+    {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
+
+#[put("/list/<list_uuid>/order", format = "json", data = "<body>")]
+pub async fn reorder_list<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    body: Json<UserFavouriteListOrderRequest<'a>>,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db.reorder_list(&uuid, &list_uuid, body.into_inner()).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
+
+#[post("/list/<list_uuid>/move", format = "json", data = "<body>")]
+pub async fn move_list_entry<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    body: Json<UserFavouriteListMoveRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListEntryResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .move_list_entry(&uuid, &list_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[put("/list/<list_uuid>/pin", format = "json", data = "<body>")]
+pub async fn pin_list_entry<'a>(
+    access_token: AccessToken,
+    list_uuid: String,
+    body: Json<UserFavouriteListPinRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteListEntryResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .pin_list_entry(&uuid, &list_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
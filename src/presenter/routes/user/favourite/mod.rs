@@ -1,5 +1,11 @@
+mod common;
+mod imports;
+mod lists;
+mod objects;
 mod route_mount;
 mod routes;
+mod tags;
+mod trends;
 
 pub trait RouteFavouriteInitialize {
     fn mount_favourite_routes(self) -> Self;
@@ -0,0 +1,85 @@
+use crate::presenter::handlers::{
+    favourite_common::objects::UserFavouriteCommonError,
+    favourite_imports::objects::UserFavouriteImportError,
+    favourite_lists::objects::UserFavouriteListError,
+    favourite_tags::objects::UserFavouriteTagError,
+    favourite_trends::objects::UserFavouriteTrendError,
+    objects::response::{
+        ErrorResponse, ERROR_FAVOURITE_COMMON_SCOPE_INVALID, ERROR_FAVOURITE_COMMON_SELF,
+        ERROR_FAVOURITE_CONFLICT, ERROR_FAVOURITE_IMPORT_CONTENT_INVALID,
+        ERROR_FAVOURITE_IMPORT_FORMAT_INVALID, ERROR_FAVOURITE_IMPORT_NOT_FOUND,
+        ERROR_FAVOURITE_IMPORT_TOO_LARGE, ERROR_FAVOURITE_IMPORT_UUID_INVALID,
+        ERROR_FAVOURITE_LIST_NOT_FOUND, ERROR_FAVOURITE_LIST_TITLE_INVALID,
+        ERROR_FAVOURITE_LIST_UUID_INVALID, ERROR_FAVOURITE_LIST_VISIBILITY_INVALID,
+        ERROR_FAVOURITE_NOTE_INVALID, ERROR_FAVOURITE_NOT_FOUND, ERROR_FAVOURITE_ORDER_INVALID,
+        ERROR_FAVOURITE_TAG_INVALID, ERROR_FAVOURITE_TREND_PERIOD_INVALID,
+        ERROR_FAVOURITE_UUID_INVALID, ERROR_UNKNOWN, ERROR_USER_NOT_FOUND_BY_UUID,
+        ERROR_USER_UUID_INVALID,
+    },
+};
+
+impl From<UserFavouriteListError> for &'static ErrorResponse<'static> {
+    fn from(error: UserFavouriteListError) -> Self {
+        match error {
+            UserFavouriteListError::UuidInvalid => ERROR_FAVOURITE_UUID_INVALID,
+            UserFavouriteListError::ListUuidInvalid => ERROR_FAVOURITE_LIST_UUID_INVALID,
+            UserFavouriteListError::ListNotFound => ERROR_FAVOURITE_LIST_NOT_FOUND,
+            UserFavouriteListError::TitleInvalid => ERROR_FAVOURITE_LIST_TITLE_INVALID,
+            UserFavouriteListError::VisibilityInvalid => ERROR_FAVOURITE_LIST_VISIBILITY_INVALID,
+            UserFavouriteListError::EntryNotFound => ERROR_FAVOURITE_NOT_FOUND,
+            UserFavouriteListError::Conflict => ERROR_FAVOURITE_CONFLICT,
+            UserFavouriteListError::OrderInvalid => ERROR_FAVOURITE_ORDER_INVALID,
+            UserFavouriteListError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
+
+impl From<UserFavouriteImportError> for &'static ErrorResponse<'static> {
+    fn from(error: UserFavouriteImportError) -> Self {
+        match error {
+            UserFavouriteImportError::UuidInvalid => ERROR_FAVOURITE_UUID_INVALID,
+            UserFavouriteImportError::ImportUuidInvalid => ERROR_FAVOURITE_IMPORT_UUID_INVALID,
+            UserFavouriteImportError::ImportNotFound => ERROR_FAVOURITE_IMPORT_NOT_FOUND,
+            UserFavouriteImportError::ListUuidInvalid => ERROR_FAVOURITE_LIST_UUID_INVALID,
+            UserFavouriteImportError::ListNotFound => ERROR_FAVOURITE_LIST_NOT_FOUND,
+            UserFavouriteImportError::FormatInvalid => ERROR_FAVOURITE_IMPORT_FORMAT_INVALID,
+            UserFavouriteImportError::ContentInvalid => ERROR_FAVOURITE_IMPORT_CONTENT_INVALID,
+            UserFavouriteImportError::ContentTooLarge => ERROR_FAVOURITE_IMPORT_TOO_LARGE,
+            UserFavouriteImportError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
+
+impl From<UserFavouriteTrendError> for &'static ErrorResponse<'static> {
+    fn from(error: UserFavouriteTrendError) -> Self {
+        match error {
+            UserFavouriteTrendError::UuidInvalid => ERROR_FAVOURITE_UUID_INVALID,
+            UserFavouriteTrendError::PeriodInvalid => ERROR_FAVOURITE_TREND_PERIOD_INVALID,
+            UserFavouriteTrendError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
+
+impl From<UserFavouriteCommonError> for &'static ErrorResponse<'static> {
+    fn from(error: UserFavouriteCommonError) -> Self {
+        match error {
+            UserFavouriteCommonError::UuidInvalid => ERROR_USER_UUID_INVALID,
+            UserFavouriteCommonError::UserNotFound => ERROR_USER_NOT_FOUND_BY_UUID,
+            UserFavouriteCommonError::SelfCommon => ERROR_FAVOURITE_COMMON_SELF,
+            UserFavouriteCommonError::ScopeInvalid => ERROR_FAVOURITE_COMMON_SCOPE_INVALID,
+            UserFavouriteCommonError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
+
+impl From<UserFavouriteTagError> for &'static ErrorResponse<'static> {
+    fn from(error: UserFavouriteTagError) -> Self {
+        match error {
+            UserFavouriteTagError::UuidInvalid => ERROR_FAVOURITE_UUID_INVALID,
+            UserFavouriteTagError::EntryNotFound => ERROR_FAVOURITE_NOT_FOUND,
+            UserFavouriteTagError::TagInvalid => ERROR_FAVOURITE_TAG_INVALID,
+            UserFavouriteTagError::NoteInvalid => ERROR_FAVOURITE_NOTE_INVALID,
+            UserFavouriteTagError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
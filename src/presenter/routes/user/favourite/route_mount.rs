@@ -3,19 +3,46 @@ use rocket::{Build, Rocket};
 use super::RouteFavouriteInitialize;
 use crate::presenter::routes::{
     routes_setup::BASE_API_URL,
-    user::{favourite::routes, routes_setup::BASE_USER_URL},
+    user::{
+        favourite::{common, imports, lists, routes, tags, trends},
+        routes_setup::BASE_USER_URL,
+    },
 };
 
 impl RouteFavouriteInitialize for Rocket<Build> {
     fn mount_favourite_routes(self) -> Self {
+        let user_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL);
         let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL + BASE_FAVOURITE_URL);
-        self.mount(
+        let rocket = self.mount(user_url, routes![common::get_common_favourites]);
+        rocket.mount(
             base_url,
             routes![
                 routes::post_add_favourite,
                 routes::delete_remove_favourite,
                 routes::get_is_favourite,
                 routes::get_user_favourites,
+                routes::get_favourite_users,
+                routes::get_favourite_status,
+                routes::put_favourite_status,
+                lists::create_list,
+                lists::get_lists,
+                lists::get_list,
+                lists::update_list,
+                lists::delete_list,
+                lists::get_list_entries,
+                lists::add_list_entry,
+                lists::remove_list_entry,
+                lists::reorder_list,
+                lists::move_list_entry,
+                lists::pin_list_entry,
+                imports::create_import,
+                imports::get_import,
+                imports::get_import_report,
+                trends::get_trends,
+                trends::get_following_trends,
+                tags::get_tags,
+                tags::set_tags,
+                tags::set_note,
             ],
         )
     }
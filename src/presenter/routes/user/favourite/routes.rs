@@ -1,14 +1,19 @@
 use crate::presenter::handlers::favourite::FavouriteHandler;
-use crate::presenter::handlers::objects::request::PagingUuidRequest;
-use crate::presenter::handlers::user::search::FavouriteResponse;
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::user::search::{FavouriteResponse, FollowerResponse};
 use rocket::serde::json::Json;
 
+use crate::data::repository::favourite::objects::FavouriteDataFilter;
 use crate::data::repository::user::objects::UserSearchError;
 use crate::presenter::handlers;
-use crate::presenter::handlers::favourite::request::{FavouriteAddBody, FavouriteDeleteParams};
+use crate::presenter::handlers::favourite::request::{
+    FavouriteAddBody, FavouriteDeleteParams, FavouriteStatusBody, FavouritesRequest,
+};
+use crate::presenter::handlers::favourite::response::FavouriteStatusResponse;
 
 use crate::presenter::handlers::objects::response::{
-    ApiMessageResponse, ApiResponse, ERROR_UNKNOWN, ERROR_USER_NOT_FOUND_BY_UUID,
+    ApiMessageResponse, ApiResponse, ERROR_FAVOURITE_STATUS_INVALID, ERROR_UNKNOWN,
+    ERROR_USER_NOT_FOUND_BY_UUID, ERROR_WRONG_REQUEST,
 };
 use crate::presenter::handlers::objects::response::{BooleanResponse, PagingResponse};
 
@@ -18,7 +23,7 @@ use crate::Conn;
 #[get("/?<params..>")]
 pub async fn get_user_favourites<'a>(
     access_token: AccessToken,
-    params: PagingUuidRequest<'a>,
+    params: FavouritesRequest<'a>,
     db: Conn,
 ) -> ApiResponse<'static, Json<PagingResponse<FavouriteResponse>>> {
     let request = handlers::user::search::UserPagingSearchRequest {
@@ -28,18 +33,48 @@ pub async fn get_user_favourites<'a>(
         page: params.page,
         page_size: params.page_size,
     };
-    match handlers::user::search::get_user_favourites(&request, db).await {
+    let filter = FavouriteDataFilter {
+        status: params.status,
+        tags: params.tag,
+    };
+    match handlers::user::search::get_user_favourites(&request, &filter, db).await {
         Ok(response) => ApiResponse::Ok(Json(response)),
         Err(err) => {
             eprint!("Error: {:?}", err);
             return match err {
                 UserSearchError::UuidInvalid => ApiResponse::Err(ERROR_USER_NOT_FOUND_BY_UUID),
+                UserSearchError::FilterInvalid => ApiResponse::Err(ERROR_FAVOURITE_STATUS_INVALID),
                 UserSearchError::InternalError => ApiResponse::Err(&ERROR_UNKNOWN),
             };
         }
     }
 }
 
+#[get("/<favourite_uuid>/users?<params..>", rank = 2)]
+pub async fn get_favourite_users<'a>(
+    access_token: AccessToken,
+    favourite_uuid: &'a str,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FollowerResponse>>> {
+    let request = handlers::user::search::UserPagingSearchRequest {
+        request_uuid: &access_token.uuid,
+        uuid: favourite_uuid,
+        query: params.query,
+        page: params.page,
+        page_size: params.page_size,
+    };
+    match handlers::user::search::get_favourite_users(&request, db).await {
+        Ok(response) => ApiResponse::Ok(Json(response)),
+        Err(err) => match err {
+            UserSearchError::UuidInvalid | UserSearchError::FilterInvalid => {
+                ApiResponse::Err(ERROR_WRONG_REQUEST)
+            }
+            UserSearchError::InternalError => ApiResponse::Err(ERROR_UNKNOWN),
+        },
+    }
+}
+
 #[post("/", format = "json", data = "<body>")]
 pub async fn post_add_favourite<'a>(
     access_token: AccessToken,
@@ -66,3 +101,23 @@ pub async fn get_is_favourite(
 ) -> ApiResponse<'static, Json<BooleanResponse>> {
     FavouriteHandler::is_favourite(&db, &access_token.uuid, &uuid).await
 }
+
+#[get("/<favourite_uuid>/status", rank = 2)]
+pub async fn get_favourite_status(
+    access_token: AccessToken,
+    favourite_uuid: &str,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteStatusResponse>> {
+    FavouriteHandler::get_favourite_status(&db, &access_token.uuid, favourite_uuid).await
+}
+
+#[put("/<favourite_uuid>/status", format = "json", data = "<body>")]
+pub async fn put_favourite_status<'a>(
+    access_token: AccessToken,
+    favourite_uuid: &'a str,
+    body: Json<FavouriteStatusBody<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteStatusResponse>> {
+    FavouriteHandler::update_favourite_status(&db, &access_token.uuid, favourite_uuid, body.status)
+        .await
+}
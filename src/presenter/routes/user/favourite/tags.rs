@@ -0,0 +1,58 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::favourite_tags::objects::{
+    FavouriteNoteResponse, FavouriteTagCountResponse, FavouriteTagsResponse,
+    UserFavouriteNoteRequest, UserFavouriteTagsRequest, UserFavouriteTagsSuggestRequest,
+};
+use crate::presenter::handlers::favourite_tags::FavouriteTagsHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/tags?<params..>")]
+pub async fn get_tags<'a>(
+    access_token: AccessToken,
+    params: UserFavouriteTagsSuggestRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<FavouriteTagCountResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_user_tags(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[put("/<favourite_uuid>/tags", format = "json", data = "<body>")]
+pub async fn set_tags(
+    access_token: AccessToken,
+    favourite_uuid: String,
+    body: Json<UserFavouriteTagsRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteTagsResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .set_favourite_tags(&uuid, &favourite_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[put("/<favourite_uuid>/note", format = "json", data = "<body>")]
+pub async fn set_note(
+    access_token: AccessToken,
+    favourite_uuid: String,
+    body: Json<UserFavouriteNoteRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FavouriteNoteResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .set_favourite_note(&uuid, &favourite_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
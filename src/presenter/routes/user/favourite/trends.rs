@@ -0,0 +1,37 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::favourite_trends::objects::{
+    FavouriteTrendResponse, UserFavouriteTrendsRequest,
+};
+use crate::presenter::handlers::favourite_trends::FavouriteTrendsHandler;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/trending?<params..>")]
+pub async fn get_trends<'a>(
+    access_token: AccessToken,
+    params: UserFavouriteTrendsRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FavouriteTrendResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_trends(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/trending/following?<params..>")]
+pub async fn get_following_trends<'a>(
+    access_token: AccessToken,
+    params: UserFavouriteTrendsRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<FavouriteTrendResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_following_trends(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -13,7 +13,9 @@ impl RouteFollowerInitialize for Rocket<Build> {
             base_url,
             routes![
                 routes::post_follow,
+                routes::post_follow_batch,
                 routes::delete_follow,
+                routes::delete_follow_batch,
                 routes::get_is_following,
                 routes::get_user_followers,
                 routes::get_user_following,
@@ -6,11 +6,14 @@ use crate::presenter::handlers;
 
 use crate::presenter::handlers::objects::request::PagingUuidRequest;
 use crate::presenter::handlers::objects::response::{
-    ApiMessageResponse, ApiResponse, ERROR_FOLLOW_CONFLICT, ERROR_FOLLOW_USER_NOT_FOUND,
-    ERROR_FOLLOW_UUID_INVALID, ERROR_UNKNOWN, ERROR_USER_NOT_FOUND_BY_UUID,
+    ApiMessageResponse, ApiResponse, ERROR_FOLLOW_BATCH_LIMIT, ERROR_FOLLOW_CONFLICT,
+    ERROR_FOLLOW_USER_NOT_FOUND, ERROR_FOLLOW_UUID_INVALID, ERROR_UNKNOWN,
+    ERROR_USER_NOT_FOUND_BY_UUID, ERROR_WRONG_REQUEST,
 };
 use crate::presenter::handlers::objects::response::{BooleanResponse, PagingResponse};
-use crate::presenter::handlers::user::actions::{self, FollowResponse};
+use crate::presenter::handlers::user::actions::{
+    self, FollowBatchRequest, FollowBatchResponse, FollowResponse,
+};
 
 use crate::presenter::handlers::user::search::FollowerResponse;
 use crate::presenter::routes::auth::validators::AccessToken;
@@ -35,6 +38,7 @@ pub async fn get_user_followers<'a>(
             eprint!("Error: {:?}", err);
             return match err {
                 UserSearchError::UuidInvalid => ApiResponse::Err(ERROR_USER_NOT_FOUND_BY_UUID),
+                UserSearchError::FilterInvalid => ApiResponse::Err(ERROR_WRONG_REQUEST),
                 UserSearchError::InternalError => ApiResponse::Err(&ERROR_UNKNOWN),
             };
         }
@@ -60,6 +64,7 @@ pub async fn get_user_following<'a>(
             eprint!("Error: {:?}", err);
             return match err {
                 UserSearchError::UuidInvalid => ApiResponse::Err(ERROR_USER_NOT_FOUND_BY_UUID),
+                UserSearchError::FilterInvalid => ApiResponse::Err(ERROR_WRONG_REQUEST),
                 UserSearchError::InternalError => ApiResponse::Err(&ERROR_UNKNOWN),
             };
         }
@@ -82,12 +87,57 @@ pub async fn post_follow(
                     ApiMessageResponse::Err(ERROR_FOLLOW_USER_NOT_FOUND)
                 }
                 FollowDataError::Conflict => ApiMessageResponse::Err(ERROR_FOLLOW_CONFLICT),
+                FollowDataError::BatchLimitExceeded => {
+                    ApiMessageResponse::Err(ERROR_FOLLOW_BATCH_LIMIT)
+                }
                 FollowDataError::InternalError => ApiMessageResponse::Err(&ERROR_UNKNOWN),
             }
         }
     }
 }
 
+#[post("/batch", format = "json", data = "<body>")]
+pub async fn post_follow_batch<'a>(
+    access_token: AccessToken,
+    body: Json<FollowBatchRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FollowBatchResponse>> {
+    match actions::follow_users(&access_token.uuid, &body, db).await {
+        Ok(response) => ApiResponse::Ok(Json(response)),
+        Err(err) => {
+            eprint!("Error: {:?}", err);
+            match err {
+                FollowDataError::UuidInvalid => ApiResponse::Err(ERROR_FOLLOW_UUID_INVALID),
+                FollowDataError::UserNotFound => ApiResponse::Err(ERROR_FOLLOW_USER_NOT_FOUND),
+                FollowDataError::Conflict => ApiResponse::Err(ERROR_FOLLOW_CONFLICT),
+                FollowDataError::BatchLimitExceeded => ApiResponse::Err(ERROR_FOLLOW_BATCH_LIMIT),
+                FollowDataError::InternalError => ApiResponse::Err(ERROR_UNKNOWN),
+            }
+        }
+    }
+}
+
+#[delete("/batch", format = "json", data = "<body>")]
+pub async fn delete_follow_batch<'a>(
+    access_token: AccessToken,
+    body: Json<FollowBatchRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<FollowBatchResponse>> {
+    match actions::un_follow_users(&access_token.uuid, &body, db).await {
+        Ok(response) => ApiResponse::Ok(Json(response)),
+        Err(err) => {
+            eprint!("Error: {:?}", err);
+            match err {
+                FollowDataError::UuidInvalid => ApiResponse::Err(ERROR_FOLLOW_UUID_INVALID),
+                FollowDataError::UserNotFound => ApiResponse::Err(ERROR_FOLLOW_USER_NOT_FOUND),
+                FollowDataError::Conflict => ApiResponse::Err(ERROR_FOLLOW_CONFLICT),
+                FollowDataError::BatchLimitExceeded => ApiResponse::Err(ERROR_FOLLOW_BATCH_LIMIT),
+                FollowDataError::InternalError => ApiResponse::Err(ERROR_UNKNOWN),
+            }
+        }
+    }
+}
+
 #[delete("/<uuid>")]
 pub async fn delete_follow(
     access_token: AccessToken,
@@ -104,6 +154,9 @@ pub async fn delete_follow(
                     ApiMessageResponse::Err(ERROR_FOLLOW_USER_NOT_FOUND)
                 }
                 FollowDataError::Conflict => ApiMessageResponse::Err(ERROR_FOLLOW_CONFLICT),
+                FollowDataError::BatchLimitExceeded => {
+                    ApiMessageResponse::Err(ERROR_FOLLOW_BATCH_LIMIT)
+                }
                 FollowDataError::InternalError => ApiMessageResponse::Err(&ERROR_UNKNOWN),
             }
         }
@@ -126,6 +179,7 @@ pub async fn get_is_following(
                 FollowDataError::UuidInvalid => ApiResponse::Err(ERROR_FOLLOW_UUID_INVALID),
                 FollowDataError::UserNotFound => ApiResponse::Err(ERROR_FOLLOW_USER_NOT_FOUND),
                 FollowDataError::Conflict => ApiResponse::Err(ERROR_FOLLOW_CONFLICT),
+                FollowDataError::BatchLimitExceeded => ApiResponse::Err(ERROR_FOLLOW_BATCH_LIMIT),
                 FollowDataError::InternalError => ApiResponse::Err(&ERROR_UNKNOWN),
             }
         }
@@ -0,0 +1,7 @@
+mod objects;
+mod route_mount;
+mod routes;
+
+pub trait RouteGroupsInitialize {
+    fn mount_groups_routes(self) -> Self;
+}
@@ -0,0 +1,20 @@
+use crate::presenter::handlers::{
+    groups::objects::UserGroupError,
+    objects::response::{
+        ErrorResponse, ERROR_GROUP_MEMBERS_LIMIT, ERROR_GROUP_MEMBERS_NOT_FOLLOWED,
+        ERROR_GROUP_NOT_FOUND, ERROR_GROUP_TITLE_INVALID, ERROR_GROUP_UUID_INVALID, ERROR_UNKNOWN,
+    },
+};
+
+impl From<UserGroupError> for &'static ErrorResponse<'static> {
+    fn from(error: UserGroupError) -> Self {
+        match error {
+            UserGroupError::UuidInvalid => ERROR_GROUP_UUID_INVALID,
+            UserGroupError::GroupNotFound => ERROR_GROUP_NOT_FOUND,
+            UserGroupError::TitleInvalid => ERROR_GROUP_TITLE_INVALID,
+            UserGroupError::MembersNotFollowed => ERROR_GROUP_MEMBERS_NOT_FOLLOWED,
+            UserGroupError::MembersLimitExceeded => ERROR_GROUP_MEMBERS_LIMIT,
+            UserGroupError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
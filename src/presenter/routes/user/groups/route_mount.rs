@@ -0,0 +1,23 @@
+use rocket::{Build, Rocket};
+
+use super::RouteGroupsInitialize;
+use crate::presenter::routes::user::groups::routes;
+use crate::presenter::routes::{routes_setup::BASE_API_URL, user::routes_setup::BASE_USER_URL};
+
+const BASE_GROUP_URL: &str = "/group";
+
+impl RouteGroupsInitialize for Rocket<Build> {
+    fn mount_groups_routes(self) -> Self {
+        let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL + BASE_GROUP_URL);
+        self.mount(
+            base_url,
+            routes![
+                routes::create_group,
+                routes::get_groups,
+                routes::get_group,
+                routes::update_group,
+                routes::delete_group,
+            ],
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::groups::objects::{
+    UserGroupCreateRequest, UserGroupResponse, UserGroupUpdateRequest,
+};
+use crate::presenter::handlers::groups::GroupsHandler;
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::objects::response::{ApiMessageResponse, PagingResponse};
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/", format = "json", data = "<body>")]
+pub async fn create_group<'a>(
+    access_token: AccessToken,
+    body: Json<UserGroupCreateRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserGroupResponse>> {
+    let uuid = access_token.uuid;
+    match db.create_group(&uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/?<params..>")]
+pub async fn get_groups<'a>(
+    access_token: AccessToken,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<UserGroupResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_groups(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<group_uuid>")]
+pub async fn get_group(
+    access_token: AccessToken,
+    group_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserGroupResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_group(&uuid, &group_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[patch("/<group_uuid>", format = "json", data = "<body>")]
+pub async fn update_group<'a>(
+    access_token: AccessToken,
+    group_uuid: String,
+    body: Json<UserGroupUpdateRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserGroupResponse>> {
+    let uuid = access_token.uuid;
+    match db.update_group(&uuid, &group_uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<group_uuid>")]
+pub async fn delete_group(
+    access_token: AccessToken,
+    group_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db.delete_group(&uuid, &group_uuid).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,8 @@
+mod objects;
+mod ratings;
+mod route_mount;
+mod routes;
+
+pub trait RouteItemsInitialize {
+    fn mount_items_routes(self) -> Self;
+}
@@ -0,0 +1,33 @@
+use crate::presenter::handlers::{
+    item_ratings::objects::UserItemRatingError,
+    items::objects::UserItemError,
+    objects::response::{
+        ErrorResponse, ERROR_ITEM_KIND_INVALID, ERROR_ITEM_NOT_FOUND, ERROR_ITEM_RATING_INVALID,
+        ERROR_ITEM_RATING_NOT_FOUND, ERROR_ITEM_REVIEW_INVALID, ERROR_ITEM_UUID_INVALID,
+        ERROR_UNKNOWN,
+    },
+};
+
+impl From<UserItemError> for &'static ErrorResponse<'static> {
+    fn from(error: UserItemError) -> Self {
+        match error {
+            UserItemError::UuidInvalid => ERROR_ITEM_UUID_INVALID,
+            UserItemError::ItemNotFound => ERROR_ITEM_NOT_FOUND,
+            UserItemError::KindInvalid => ERROR_ITEM_KIND_INVALID,
+            UserItemError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
+
+impl From<UserItemRatingError> for &'static ErrorResponse<'static> {
+    fn from(error: UserItemRatingError) -> Self {
+        match error {
+            UserItemRatingError::UuidInvalid => ERROR_ITEM_UUID_INVALID,
+            UserItemRatingError::ItemNotFound => ERROR_ITEM_NOT_FOUND,
+            UserItemRatingError::RatingNotFound => ERROR_ITEM_RATING_NOT_FOUND,
+            UserItemRatingError::RatingInvalid => ERROR_ITEM_RATING_INVALID,
+            UserItemRatingError::ReviewInvalid => ERROR_ITEM_REVIEW_INVALID,
+            UserItemRatingError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::item_ratings::objects::{
+    ItemRatingResponse, ItemRatingStatsResponse, ItemReviewResponse, UserItemRatingRequest,
+    UserItemReviewsRequest,
+};
+use crate::presenter::handlers::item_ratings::ItemRatingsHandler;
+use crate::presenter::handlers::objects::response::{ApiMessageResponse, PagingResponse};
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[put("/<item_uuid>/rating", format = "json", data = "<body>")]
+pub async fn set_rating(
+    access_token: AccessToken,
+    item_uuid: String,
+    body: Json<UserItemRatingRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<ItemRatingResponse>> {
+    let uuid = access_token.uuid;
+    match db.set_rating(&uuid, &item_uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<item_uuid>/rating")]
+pub async fn remove_rating(
+    access_token: AccessToken,
+    item_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db.remove_rating(&uuid, &item_uuid).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
+
+#[get("/<item_uuid>/rating/stats")]
+pub async fn get_rating_stats(
+    _access_token: AccessToken,
+    item_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<ItemRatingStatsResponse>> {
+    match db.get_rating_stats(&item_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<item_uuid>/reviews?<params..>")]
+pub async fn get_followed_reviews(
+    access_token: AccessToken,
+    item_uuid: String,
+    params: UserItemReviewsRequest,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<ItemReviewResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_followed_reviews(&uuid, &item_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
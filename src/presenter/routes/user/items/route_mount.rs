@@ -0,0 +1,24 @@
+use rocket::{Build, Rocket};
+
+use super::RouteItemsInitialize;
+use crate::presenter::routes::user::items::{ratings, routes};
+use crate::presenter::routes::{routes_setup::BASE_API_URL, user::routes_setup::BASE_USER_URL};
+
+const BASE_ITEM_URL: &str = "/item";
+
+impl RouteItemsInitialize for Rocket<Build> {
+    fn mount_items_routes(self) -> Self {
+        let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL + BASE_ITEM_URL);
+        self.mount(
+            base_url,
+            routes![
+                routes::search_items,
+                routes::get_item,
+                ratings::set_rating,
+                ratings::remove_rating,
+                ratings::get_rating_stats,
+                ratings::get_followed_reviews,
+            ],
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::items::objects::{ItemResponse, UserItemsRequest};
+use crate::presenter::handlers::items::ItemsHandler;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/?<params..>")]
+pub async fn search_items<'a>(
+    _access_token: AccessToken,
+    params: UserItemsRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<ItemResponse>>> {
+    match db.search_items(params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<item_uuid>")]
+pub async fn get_item(
+    _access_token: AccessToken,
+    item_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<ItemResponse>> {
+    match db.get_item(&item_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,40 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_ballots::objects::{
+    UserMatchBallotRequest, UserMatchBallotResponse, UserMatchResultsResponse,
+};
+use crate::presenter::handlers::match_ballots::MatchBallotsHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/<match_uuid>/ballot", format = "json", data = "<body>")]
+pub async fn submit_ballot<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserMatchBallotRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchBallotResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .submit_ballot(&uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/results")]
+pub async fn get_results(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchResultsResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_results(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
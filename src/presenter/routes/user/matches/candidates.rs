@@ -0,0 +1,60 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_candidates::objects::{
+    UserAddCandidateRequest, UserMatchCandidateResponse,
+};
+use crate::presenter::handlers::match_candidates::MatchCandidatesHandler;
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::objects::response::{ApiMessageResponse, PagingResponse};
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/<match_uuid>/candidates", format = "json", data = "<body>")]
+pub async fn add_candidate<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserAddCandidateRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCandidateResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .add_candidate(&uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/candidates?<params..>")]
+pub async fn get_candidates<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<UserMatchCandidateResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_candidates(&uuid, &match_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<match_uuid>/candidates/<candidate_uuid>")]
+pub async fn remove_candidate(
+    access_token: AccessToken,
+    match_uuid: String,
+    candidate_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let uuid = access_token.uuid;
+    match db
+        .remove_candidate(&uuid, &match_uuid, &candidate_uuid)
+        .await
+    {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,75 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_comments::objects::{
+    UserMatchCommentRequest, UserMatchCommentResponse, UserMatchCommentUpdateRequest,
+    UserMatchCommentsRequest, UserMatchCommentsResponse,
+};
+use crate::presenter::handlers::match_comments::MatchCommentsHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/<match_uuid>/comments", format = "json", data = "<body>")]
+pub async fn add_comment<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserMatchCommentRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCommentResponse>> {
+    let uuid = access_token.uuid;
+    match db.add_comment(&uuid, &match_uuid, body.into_inner()).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/comments?<params..>")]
+pub async fn get_comments<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    params: UserMatchCommentsRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCommentsResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_comments(&uuid, &match_uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[patch(
+    "/<match_uuid>/comments/<comment_uuid>",
+    format = "json",
+    data = "<body>"
+)]
+pub async fn update_comment(
+    access_token: AccessToken,
+    match_uuid: String,
+    comment_uuid: String,
+    body: Json<UserMatchCommentUpdateRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCommentResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .update_comment(&uuid, &match_uuid, &comment_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<match_uuid>/comments/<comment_uuid>")]
+pub async fn delete_comment(
+    access_token: AccessToken,
+    match_uuid: String,
+    comment_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCommentResponse>> {
+    let uuid = access_token.uuid;
+    match db.delete_comment(&uuid, &match_uuid, &comment_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
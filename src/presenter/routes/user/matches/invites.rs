@@ -0,0 +1,81 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_invites::objects::{
+    UserInviteParticipantsRequest, UserMatchInviteResponse,
+};
+use crate::presenter::handlers::match_invites::MatchInvitesHandler;
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/<match_uuid>/participants", format = "json", data = "<body>")]
+pub async fn invite_participants<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserInviteParticipantsRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<UserMatchInviteResponse>>> {
+    let uuid = access_token.uuid;
+    match db
+        .invite_participants(&uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/invites?<params..>")]
+pub async fn get_user_invites<'a>(
+    access_token: AccessToken,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<UserMatchInviteResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_user_invites(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/invites")]
+pub async fn get_match_invites(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<UserMatchInviteResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_match_invites(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/invite/accept")]
+pub async fn accept_invite(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchInviteResponse>> {
+    let uuid = access_token.uuid;
+    match db.accept_invite(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/invite/decline")]
+pub async fn decline_invite(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchInviteResponse>> {
+    let uuid = access_token.uuid;
+    match db.decline_invite(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,68 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_join_codes::objects::{
+    UserMatchJoinCodeCreateRequest, UserMatchJoinCodeResponse,
+};
+use crate::presenter::handlers::match_join_codes::MatchJoinCodesHandler;
+use crate::presenter::handlers::matches::objects::UserMatchDetailResponse;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post("/<match_uuid>/join-codes", format = "json", data = "<body>")]
+pub async fn create_join_code(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserMatchJoinCodeCreateRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchJoinCodeResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .create_join_code(&uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/join-codes")]
+pub async fn get_join_codes(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<UserMatchJoinCodeResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_join_codes(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<match_uuid>/join-codes/<code_uuid>")]
+pub async fn revoke_join_code(
+    access_token: AccessToken,
+    match_uuid: String,
+    code_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchJoinCodeResponse>> {
+    let uuid = access_token.uuid;
+    match db.revoke_join_code(&uuid, &match_uuid, &code_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/join?<code>")]
+pub async fn redeem_join_code(
+    access_token: AccessToken,
+    code: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let uuid = access_token.uuid;
+    match db.redeem_join_code(&uuid, &code).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -1,6 +1,13 @@
+mod ballots;
+mod candidates;
+mod comments;
+mod invites;
+mod join_codes;
 mod objects;
+mod roles;
 mod route_mount;
 mod routes;
+mod votes;
 
 pub trait RouteMatchesInitialize {
     fn mount_matches_routes(self) -> Self;
@@ -1,8 +1,19 @@
 use crate::presenter::handlers::{
     matches::objects::UserMatchError,
     objects::response::{
-        ErrorResponse, ERROR_MATCHES_CONFLICT, ERROR_MATCHES_NOT_FOUND, ERROR_MATCHES_UUID_INVALID,
-        ERROR_NO_PERMISSION, ERROR_UNKNOWN,
+        ErrorResponse, ERROR_GROUP_NOT_FOUND, ERROR_MATCHES_CONFLICT, ERROR_MATCHES_NOT_FOUND,
+        ERROR_MATCHES_UUID_INVALID, ERROR_MATCH_BALLOT_INVALID, ERROR_MATCH_CANDIDATE_CONFLICT,
+        ERROR_MATCH_CANDIDATE_LIMIT, ERROR_MATCH_CANDIDATE_NOT_FOUND, ERROR_MATCH_CLOSED,
+        ERROR_MATCH_COMMENT_INVALID, ERROR_MATCH_COMMENT_NOT_FOUND,
+        ERROR_MATCH_CREATOR_CANNOT_LEAVE, ERROR_MATCH_CURSOR_INVALID,
+        ERROR_MATCH_DECISION_METHOD_INVALID, ERROR_MATCH_DECISION_METHOD_MISMATCH,
+        ERROR_MATCH_EXPIRES_AT_INVALID, ERROR_MATCH_FILTER_INVALID, ERROR_MATCH_INVALID_TRANSITION,
+        ERROR_MATCH_INVITE_NOT_ALLOWED, ERROR_MATCH_INVITE_NOT_FOUND,
+        ERROR_MATCH_JOIN_CODE_EXPIRED, ERROR_MATCH_JOIN_CODE_LIMIT_INVALID,
+        ERROR_MATCH_JOIN_CODE_NOT_FOUND, ERROR_MATCH_NO_CANDIDATES_LEFT,
+        ERROR_MATCH_PARTICIPANTS_LIMIT, ERROR_MATCH_PARTICIPANTS_NOT_FOUND,
+        ERROR_MATCH_PARTICIPANT_NOT_FOUND, ERROR_MATCH_ROLE_INVALID,
+        ERROR_MATCH_SEED_LIMIT_INVALID, ERROR_NO_PERMISSION, ERROR_UNKNOWN,
     },
 };
 
@@ -14,6 +25,32 @@ impl Into<&'static ErrorResponse<'static>> for UserMatchError {
             UserMatchError::UuidInvalid => ERROR_MATCHES_UUID_INVALID,
             UserMatchError::MatchesNotFound => ERROR_MATCHES_NOT_FOUND,
             UserMatchError::MatchesNotCreated => ERROR_MATCHES_CONFLICT,
+            UserMatchError::CandidateNotFound => ERROR_MATCH_CANDIDATE_NOT_FOUND,
+            UserMatchError::CandidateConflict => ERROR_MATCH_CANDIDATE_CONFLICT,
+            UserMatchError::CandidateLimitExceeded => ERROR_MATCH_CANDIDATE_LIMIT,
+            UserMatchError::MatchClosed => ERROR_MATCH_CLOSED,
+            UserMatchError::NoCandidatesLeft => ERROR_MATCH_NO_CANDIDATES_LEFT,
+            UserMatchError::InvalidTransition => ERROR_MATCH_INVALID_TRANSITION,
+            UserMatchError::ExpiresAtInvalid => ERROR_MATCH_EXPIRES_AT_INVALID,
+            UserMatchError::ParticipantsNotFound => ERROR_MATCH_PARTICIPANTS_NOT_FOUND,
+            UserMatchError::InviteNotAllowed => ERROR_MATCH_INVITE_NOT_ALLOWED,
+            UserMatchError::InviteNotFound => ERROR_MATCH_INVITE_NOT_FOUND,
+            UserMatchError::ParticipantNotFound => ERROR_MATCH_PARTICIPANT_NOT_FOUND,
+            UserMatchError::CreatorCannotLeave => ERROR_MATCH_CREATOR_CANNOT_LEAVE,
+            UserMatchError::DecisionMethodInvalid => ERROR_MATCH_DECISION_METHOD_INVALID,
+            UserMatchError::DecisionMethodMismatch => ERROR_MATCH_DECISION_METHOD_MISMATCH,
+            UserMatchError::BallotInvalid => ERROR_MATCH_BALLOT_INVALID,
+            UserMatchError::SeedLimitInvalid => ERROR_MATCH_SEED_LIMIT_INVALID,
+            UserMatchError::CommentNotFound => ERROR_MATCH_COMMENT_NOT_FOUND,
+            UserMatchError::CommentInvalid => ERROR_MATCH_COMMENT_INVALID,
+            UserMatchError::CursorInvalid => ERROR_MATCH_CURSOR_INVALID,
+            UserMatchError::GroupNotFound => ERROR_GROUP_NOT_FOUND,
+            UserMatchError::FilterInvalid => ERROR_MATCH_FILTER_INVALID,
+            UserMatchError::JoinCodeNotFound => ERROR_MATCH_JOIN_CODE_NOT_FOUND,
+            UserMatchError::JoinCodeExpired => ERROR_MATCH_JOIN_CODE_EXPIRED,
+            UserMatchError::JoinCodeLimitInvalid => ERROR_MATCH_JOIN_CODE_LIMIT_INVALID,
+            UserMatchError::ParticipantsLimitReached => ERROR_MATCH_PARTICIPANTS_LIMIT,
+            UserMatchError::RoleInvalid => ERROR_MATCH_ROLE_INVALID,
         }
     }
 }
@@ -0,0 +1,63 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::matches::objects::{
+    UserMatchDetailResponse, UserMatchParticipantResponse, UserMatchRoleRequest,
+    UserMatchTransferRequest,
+};
+use crate::presenter::handlers::matches::MatchesHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/<match_uuid>/roles")]
+pub async fn get_match_roles(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<UserMatchParticipantResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_match_roles(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[put(
+    "/<match_uuid>/participants/<participant_uuid>/role",
+    format = "json",
+    data = "<body>"
+)]
+pub async fn set_participant_role<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    participant_uuid: String,
+    body: Json<UserMatchRoleRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<Vec<UserMatchParticipantResponse>>> {
+    let uuid = access_token.uuid;
+    match db
+        .set_participant_role(&uuid, &match_uuid, &participant_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/transfer", format = "json", data = "<body>")]
+pub async fn transfer_ownership<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserMatchTransferRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .transfer_ownership(&uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
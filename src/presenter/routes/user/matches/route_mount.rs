@@ -1,7 +1,9 @@
 use rocket::{Build, Rocket};
 
 use super::RouteMatchesInitialize;
-use crate::presenter::routes::user::matches::routes;
+use crate::presenter::routes::user::matches::{
+    ballots, candidates, comments, invites, join_codes, roles, routes, votes,
+};
 use crate::presenter::routes::{routes_setup::BASE_API_URL, user::routes_setup::BASE_USER_URL};
 
 const BASE_MATCH_URL: &str = "/match";
@@ -11,7 +13,43 @@ impl RouteMatchesInitialize for Rocket<Build> {
         let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL + BASE_MATCH_URL);
         self.mount(
             base_url,
-            routes![routes::create_match, routes::get_match, routes::get_matches],
+            routes![
+                routes::create_match,
+                routes::preview_seed_candidates,
+                routes::get_match,
+                routes::get_matches,
+                routes::start_match,
+                routes::complete_match,
+                routes::cancel_match,
+                routes::expire_match,
+                routes::update_match,
+                routes::delete_match,
+                routes::remove_participant,
+                routes::leave_match,
+                candidates::add_candidate,
+                candidates::get_candidates,
+                candidates::remove_candidate,
+                votes::vote,
+                votes::get_next_candidate,
+                ballots::submit_ballot,
+                ballots::get_results,
+                comments::add_comment,
+                comments::get_comments,
+                comments::update_comment,
+                comments::delete_comment,
+                invites::invite_participants,
+                invites::get_user_invites,
+                invites::get_match_invites,
+                invites::accept_invite,
+                invites::decline_invite,
+                join_codes::create_join_code,
+                join_codes::get_join_codes,
+                join_codes::revoke_join_code,
+                join_codes::redeem_join_code,
+                roles::get_match_roles,
+                roles::set_participant_role,
+                roles::transfer_ownership,
+            ],
         )
     }
 }
@@ -1,11 +1,13 @@
 use rocket::serde::json::Json;
 
+use crate::data::repository::matches::objects::MatchTransition;
+
 use crate::presenter::handlers::matches::objects::{
-    UserCreateMatchRequest, UserMatchDetailResponse,
+    UserCreateMatchRequest, UserMatchDetailResponse, UserMatchSeedPreviewResponse,
+    UserMatchSeedRequest, UserMatchesRequest, UserUpdateMatchRequest,
 };
 use crate::presenter::handlers::matches::MatchesHandler;
-use crate::presenter::handlers::objects::request::PagingUuidRequest;
-use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::presenter::handlers::objects::response::{ApiMessageResponse, PagingResponse};
 use crate::{
     presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
     Conn,
@@ -24,6 +26,19 @@ pub async fn create_match<'a>(
     }
 }
 
+#[get("/seed?<params..>")]
+pub async fn preview_seed_candidates<'a>(
+    access_token: AccessToken,
+    params: UserMatchSeedRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchSeedPreviewResponse>> {
+    let uuid = access_token.uuid;
+    match db.preview_seed_candidates(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
 #[get("/<match_uuid>")]
 pub async fn get_match<'a>(
     access_token: AccessToken,
@@ -40,7 +55,7 @@ pub async fn get_match<'a>(
 #[get("/?<params..>")]
 pub async fn get_matches<'a>(
     access_token: AccessToken,
-    params: PagingUuidRequest<'a>,
+    params: UserMatchesRequest<'a>,
     db: Conn,
 ) -> ApiResponse<'static, Json<PagingResponse<UserMatchDetailResponse>>> {
     let uuid = access_token.uuid;
@@ -49,3 +64,127 @@ pub async fn get_matches<'a>(
         Result::Err(e) => ApiResponse::Err(e.into()),
     }
 }
+
+#[post("/<match_uuid>/start")]
+pub async fn start_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .transition_match(&user_uuid, &match_uuid, MatchTransition::Start)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/complete")]
+pub async fn complete_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .transition_match(&user_uuid, &match_uuid, MatchTransition::Complete)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/cancel")]
+pub async fn cancel_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .transition_match(&user_uuid, &match_uuid, MatchTransition::Cancel)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/expire")]
+pub async fn expire_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .transition_match(&user_uuid, &match_uuid, MatchTransition::Expire)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[patch("/<match_uuid>", format = "json", data = "<body>")]
+pub async fn update_match<'a>(
+    access_token: AccessToken,
+    match_uuid: String,
+    body: Json<UserUpdateMatchRequest<'a>>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .update_match(&user_uuid, &match_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<match_uuid>")]
+pub async fn delete_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let user_uuid = access_token.uuid;
+    match db.delete_match(&user_uuid, &match_uuid).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
+
+#[delete("/<match_uuid>/participants/<participant_uuid>")]
+pub async fn remove_participant(
+    access_token: AccessToken,
+    match_uuid: String,
+    participant_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchDetailResponse>> {
+    let user_uuid = access_token.uuid;
+    match db
+        .remove_participant(&user_uuid, &match_uuid, &participant_uuid)
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[post("/<match_uuid>/leave")]
+pub async fn leave_match(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiMessageResponse<'static> {
+    let user_uuid = access_token.uuid;
+    match db.leave_match(&user_uuid, &match_uuid).await {
+        Result::Ok(_) => ApiMessageResponse::Ok("success"),
+        Result::Err(e) => ApiMessageResponse::Err(e.into()),
+    }
+}
@@ -0,0 +1,46 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::match_candidates::objects::UserMatchCandidateResponse;
+use crate::presenter::handlers::match_votes::objects::{
+    UserMatchVoteRequest, UserMatchVoteResponse,
+};
+use crate::presenter::handlers::match_votes::MatchVotesHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[post(
+    "/<match_uuid>/candidates/<candidate_uuid>/vote",
+    format = "json",
+    data = "<body>"
+)]
+pub async fn vote(
+    access_token: AccessToken,
+    match_uuid: String,
+    candidate_uuid: String,
+    body: Json<UserMatchVoteRequest>,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchVoteResponse>> {
+    let uuid = access_token.uuid;
+    match db
+        .vote(&uuid, &match_uuid, &candidate_uuid, body.into_inner())
+        .await
+    {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
+
+#[get("/<match_uuid>/candidates/next")]
+pub async fn get_next_candidate(
+    access_token: AccessToken,
+    match_uuid: String,
+    db: Conn,
+) -> ApiResponse<'static, Json<UserMatchCandidateResponse>> {
+    let uuid = access_token.uuid;
+    match db.get_next_candidate(&uuid, &match_uuid).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
@@ -1,6 +1,10 @@
+mod compatibility;
 mod favourite;
 mod follower;
+mod groups;
+mod items;
 mod matches;
+mod recommendations;
 mod routes;
 mod routes_setup;
 
@@ -0,0 +1,7 @@
+mod objects;
+mod route_mount;
+mod routes;
+
+pub trait RouteRecommendationsInitialize {
+    fn mount_recommendations_routes(self) -> Self;
+}
@@ -0,0 +1,13 @@
+use crate::presenter::handlers::{
+    objects::response::{ErrorResponse, ERROR_UNKNOWN, ERROR_USER_UUID_INVALID},
+    recommendations::objects::UserRecommendationError,
+};
+
+impl From<UserRecommendationError> for &'static ErrorResponse<'static> {
+    fn from(error: UserRecommendationError) -> Self {
+        match error {
+            UserRecommendationError::UuidInvalid => ERROR_USER_UUID_INVALID,
+            UserRecommendationError::InternalError => ERROR_UNKNOWN,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use rocket::{Build, Rocket};
+
+use super::RouteRecommendationsInitialize;
+use crate::presenter::routes::user::recommendations::routes;
+use crate::presenter::routes::{routes_setup::BASE_API_URL, user::routes_setup::BASE_USER_URL};
+
+impl RouteRecommendationsInitialize for Rocket<Build> {
+    fn mount_recommendations_routes(self) -> Self {
+        let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL);
+        self.mount(base_url, routes![routes::get_recommendations])
+    }
+}
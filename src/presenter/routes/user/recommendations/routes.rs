@@ -0,0 +1,23 @@
+use rocket::serde::json::Json;
+
+use crate::presenter::handlers::objects::request::PagingRequest;
+use crate::presenter::handlers::objects::response::PagingResponse;
+use crate::presenter::handlers::recommendations::objects::RecommendationResponse;
+use crate::presenter::handlers::recommendations::RecommendationsHandler;
+use crate::{
+    presenter::{handlers::objects::response::ApiResponse, routes::auth::validators::AccessToken},
+    Conn,
+};
+
+#[get("/recommendations?<params..>")]
+pub async fn get_recommendations<'a>(
+    access_token: AccessToken,
+    params: PagingRequest<'a>,
+    db: Conn,
+) -> ApiResponse<'static, Json<PagingResponse<RecommendationResponse>>> {
+    let uuid = access_token.uuid;
+    match db.get_recommendations(&uuid, params).await {
+        Result::Ok(response) => ApiResponse::Ok(Json(response)),
+        Result::Err(e) => ApiResponse::Err(e.into()),
+    }
+}
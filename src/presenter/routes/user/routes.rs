@@ -6,6 +6,7 @@ use crate::presenter::handlers;
 use crate::presenter::handlers::objects::request::PagingRequest;
 use crate::presenter::handlers::objects::response::{
     ApiResponse, ERROR_UNKNOWN, ERROR_USER_NOT_FOUND_BY_UUID, ERROR_USER_UUID_INVALID,
+    ERROR_WRONG_REQUEST,
 };
 
 use crate::presenter::handlers::user::search::UserSearchResponse;
@@ -66,6 +67,7 @@ pub async fn get_user_search<'a>(
             eprint!("Error: {:?}", err);
             return match err {
                 UserSearchError::UuidInvalid => ApiResponse::Err(ERROR_USER_NOT_FOUND_BY_UUID),
+                UserSearchError::FilterInvalid => ApiResponse::Err(ERROR_WRONG_REQUEST),
                 UserSearchError::InternalError => ApiResponse::Err(&ERROR_UNKNOWN),
             };
         }
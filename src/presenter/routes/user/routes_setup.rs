@@ -5,17 +5,25 @@ use crate::presenter::routes::routes_setup::BASE_API_URL;
 use crate::presenter::routes::user::routes;
 use crate::presenter::routes::user::RoutesUserInitialized;
 
+use super::compatibility::RouteCompatibilityInitialize;
 use super::favourite::RouteFavouriteInitialize;
 use super::follower::RouteFollowerInitialize;
+use super::groups::RouteGroupsInitialize;
+use super::items::RouteItemsInitialize;
 use super::matches::RouteMatchesInitialize;
+use super::recommendations::RouteRecommendationsInitialize;
 
 impl RoutesUserInitialized for Rocket<Build> {
     fn mount_user_routes(self) -> Self {
         let base_url = &*(BASE_API_URL.to_owned() + BASE_USER_URL);
 
-        self.mount_favourite_routes()
+        self.mount_compatibility_routes()
+            .mount_favourite_routes()
             .mount_follower_routes()
+            .mount_groups_routes()
+            .mount_items_routes()
             .mount_matches_routes()
+            .mount_recommendations_routes()
             .mount(
                 base_url,
                 routes![
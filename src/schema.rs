@@ -6,6 +6,13 @@ diesel::table! {
         user_uuid -> Uuid,
         favourite_uuid -> Uuid,
         title -> Varchar,
+        item_uuid -> Nullable<Uuid>,
+        list_uuid -> Nullable<Uuid>,
+        position -> Int8,
+        pinned -> Bool,
+        created_at -> Int8,
+        status -> Varchar,
+        status_updated_at -> Int8,
     }
 }
 
@@ -44,5 +51,299 @@ diesel::table! {
         created_at -> Int8,
         updated_at -> Int8,
         expires_at -> Int8,
+        winner_candidate_uuid -> Nullable<Uuid>,
+        decision_method -> Varchar,
+    }
+}
+
+diesel::table! {
+    match_candidates (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        reference_uuid -> Uuid,
+        title -> Varchar,
+        image_url -> Varchar,
+        metadata -> Nullable<Text>,
+        created_by -> Uuid,
+        created_at -> Int8,
+        item_uuid -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    match_votes (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        candidate_uuid -> Uuid,
+        user_uuid -> Uuid,
+        liked -> Bool,
+        created_at -> Int8,
+    }
+}
+
+diesel::table! {
+    match_ballots (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        user_uuid -> Uuid,
+        candidate_uuids -> Array<Uuid>,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    match_invites (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        user_uuid -> Uuid,
+        invited_by -> Uuid,
+        status -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    match_join_codes (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        code -> Varchar,
+        created_by -> Uuid,
+        max_participants -> Int8,
+        uses -> Int8,
+        expires_at -> Nullable<Int8>,
+        revoked_at -> Nullable<Int8>,
+        created_at -> Int8,
     }
 }
+
+diesel::table! {
+    match_roles (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        user_uuid -> Uuid,
+        role -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    match_comments (uuid) {
+        uuid -> Uuid,
+        match_uuid -> Uuid,
+        candidate_uuid -> Nullable<Uuid>,
+        parent_uuid -> Nullable<Uuid>,
+        author_uuid -> Uuid,
+        body -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+        deleted_at -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    groups (uuid) {
+        uuid -> Uuid,
+        owner_uuid -> Uuid,
+        title -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    group_members (uuid) {
+        uuid -> Uuid,
+        group_uuid -> Uuid,
+        user_uuid -> Uuid,
+        created_at -> Int8,
+    }
+}
+
+diesel::table! {
+    compatibility_scores (user_uuid, other_uuid) {
+        user_uuid -> Uuid,
+        other_uuid -> Uuid,
+        shared_matches -> Int8,
+        compared_votes -> Int8,
+        agreed_votes -> Int8,
+        shared_favourites -> Int8,
+        union_favourites -> Int8,
+        score -> Nullable<Int8>,
+        computed_at -> Int8,
+    }
+}
+
+diesel::table! {
+    items (uuid) {
+        uuid -> Uuid,
+        kind -> Varchar,
+        title -> Varchar,
+        year -> Nullable<Int4>,
+        image_url -> Nullable<Varchar>,
+        provider -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    item_external_ids (uuid) {
+        uuid -> Uuid,
+        item_uuid -> Uuid,
+        source -> Varchar,
+        external_id -> Varchar,
+    }
+}
+
+diesel::table! {
+    favourite_lists (uuid) {
+        uuid -> Uuid,
+        owner_uuid -> Uuid,
+        title -> Varchar,
+        visibility -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    item_ratings (uuid) {
+        uuid -> Uuid,
+        user_uuid -> Uuid,
+        item_uuid -> Uuid,
+        rating -> Int2,
+        review -> Nullable<Varchar>,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    favourite_imports (uuid) {
+        uuid -> Uuid,
+        user_uuid -> Uuid,
+        list_uuid -> Nullable<Uuid>,
+        format -> Varchar,
+        status -> Varchar,
+        content -> Text,
+        rows_count -> Int4,
+        imported_count -> Int4,
+        duplicates_count -> Int4,
+        unmatched_count -> Int4,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+diesel::table! {
+    favourite_import_rows (uuid) {
+        uuid -> Uuid,
+        import_uuid -> Uuid,
+        row_number -> Int4,
+        title -> Nullable<Varchar>,
+        external_id -> Nullable<Varchar>,
+        reason -> Varchar,
+    }
+}
+
+diesel::table! {
+    favourite_status_transitions (uuid) {
+        uuid -> Uuid,
+        user_uuid -> Uuid,
+        favourite_uuid -> Uuid,
+        from_status -> Varchar,
+        to_status -> Varchar,
+        created_at -> Int8,
+    }
+}
+
+diesel::table! {
+    favourite_tags (uuid) {
+        uuid -> Uuid,
+        user_uuid -> Uuid,
+        favourite_uuid -> Uuid,
+        tag -> Varchar,
+        created_at -> Int8,
+    }
+}
+
+diesel::table! {
+    favourite_notes (uuid) {
+        uuid -> Uuid,
+        user_uuid -> Uuid,
+        favourite_uuid -> Uuid,
+        note -> Varchar,
+        created_at -> Int8,
+        updated_at -> Int8,
+    }
+}
+
+// materialised view, refreshed by the favourite trends job
+diesel::table! {
+    favourite_trends (item_uuid) {
+        item_uuid -> Uuid,
+        title -> Varchar,
+        day_count -> Int8,
+        week_count -> Int8,
+        all_count -> Int8,
+        refreshed_at -> Int8,
+    }
+}
+
+// materialised view, refreshed by the favourite trends job
+diesel::table! {
+    favourite_following_trends (follower_uuid, item_uuid) {
+        follower_uuid -> Uuid,
+        item_uuid -> Uuid,
+        title -> Varchar,
+        day_count -> Int8,
+        week_count -> Int8,
+        all_count -> Int8,
+        refreshed_at -> Int8,
+    }
+}
+
+diesel::joinable!(favourite -> favourite_lists (list_uuid));
+diesel::joinable!(favourite -> items (item_uuid));
+diesel::joinable!(favourite_import_rows -> favourite_imports (import_uuid));
+diesel::joinable!(favourite_imports -> favourite_lists (list_uuid));
+diesel::joinable!(group_members -> groups (group_uuid));
+diesel::joinable!(item_external_ids -> items (item_uuid));
+diesel::joinable!(match_ballots -> matches (match_uuid));
+diesel::joinable!(match_candidates -> items (item_uuid));
+diesel::joinable!(match_comments -> matches (match_uuid));
+diesel::joinable!(match_invites -> matches (match_uuid));
+diesel::joinable!(match_join_codes -> matches (match_uuid));
+diesel::joinable!(match_roles -> matches (match_uuid));
+diesel::joinable!(match_votes -> match_candidates (candidate_uuid));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    compatibility_scores,
+    favourite,
+    favourite_following_trends,
+    favourite_import_rows,
+    favourite_imports,
+    favourite_lists,
+    favourite_notes,
+    favourite_status_transitions,
+    favourite_tags,
+    favourite_trends,
+    follow,
+    group_members,
+    groups,
+    item_external_ids,
+    item_ratings,
+    items,
+    match_ballots,
+    match_candidates,
+    match_comments,
+    match_invites,
+    match_join_codes,
+    match_roles,
+    match_votes,
+    matches,
+    users,
+);